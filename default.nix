@@ -13,7 +13,6 @@ let
       coreutils     # audio:   stdbuf
       dnsutils      # network: dig
       iproute       # network: ip
-      wirelesstools # network: iwgetid
     ]
     ++ lib.optional (!useGlobalAlsaUtils) alsaUtils # audio: alsactl, amixer
   );
@@ -25,7 +24,7 @@ naersk.buildPackage {
     ./.;
 
   nativeBuildInputs = [ makeWrapper pkgconfig ];
-  buildInputs = [ dbus gdk_pixbuf libnotify xorg.libX11 ];
+  buildInputs = [ dbus gdk_pixbuf libnotify xorg.libX11 xorg.libXft xorg.libXrandr ];
 
   postInstall = ''
     # run only when building the final package
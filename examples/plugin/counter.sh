@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+# Example plugin process: counts up every 5 seconds, left click resets the counter.
+
+echo '{"type": "register", "name": "counter", "protocol": 1}'
+
+count=0
+echo "{\"type\": \"update\", \"text\": \"count $count\"}"
+
+while true; do
+    if read -r -t 5 line; then
+        case "$line" in
+            *'"button":1'*) count=0 ;;
+        esac
+    else
+        count=$((count + 1))
+    fi
+
+    echo "{\"type\": \"update\", \"text\": \"count $count\"}"
+done
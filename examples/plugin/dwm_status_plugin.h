@@ -0,0 +1,24 @@
+#ifndef DWM_STATUS_PLUGIN_H
+#define DWM_STATUS_PLUGIN_H
+
+#include <stdint.h>
+
+#define DWM_STATUS_PLUGIN_API_VERSION 1
+
+struct dwm_status_plugin {
+    /* has to be DWM_STATUS_PLUGIN_API_VERSION */
+    uint32_t api_version;
+    /* interval in seconds in which update gets called, has to be greater than 0 */
+    uint64_t update_interval;
+    /* creates the plugin state from the plugin options as JSON object, returns NULL on failure */
+    void *(*create)(const char *options);
+    /* returns the rendered text, which gets released with free_text, returns NULL on failure */
+    char *(*update)(void *state);
+    void (*free_text)(char *text);
+    void (*destroy)(void *state);
+};
+
+/* entry point of every plugin */
+const struct dwm_status_plugin *dwm_status_plugin(void);
+
+#endif
@@ -0,0 +1,55 @@
+/* build with: cc -shared -fPIC -o uptime.so uptime.c */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "dwm_status_plugin.h"
+
+static int state;
+
+static void *create(const char *options) {
+    (void) options;
+    return &state;
+}
+
+static char *update(void *state) {
+    (void) state;
+
+    double uptime;
+    FILE *file = fopen("/proc/uptime", "r");
+    if (file == NULL) {
+        return NULL;
+    }
+    int read = fscanf(file, "%lf", &uptime);
+    fclose(file);
+    if (read != 1) {
+        return NULL;
+    }
+
+    char *text = malloc(32);
+    if (text != NULL) {
+        snprintf(text, 32, "up %.0fh", uptime / 3600);
+    }
+    return text;
+}
+
+static void free_text(char *text) {
+    free(text);
+}
+
+static void destroy(void *state) {
+    (void) state;
+}
+
+static const struct dwm_status_plugin plugin = {
+    .api_version = DWM_STATUS_PLUGIN_API_VERSION,
+    .update_interval = 60,
+    .create = create,
+    .update = update,
+    .free_text = free_text,
+    .destroy = destroy,
+};
+
+const struct dwm_status_plugin *dwm_status_plugin(void) {
+    return &plugin;
+}
@@ -12,13 +12,14 @@ pkgs.mkShell {
     libnotify
     pkgconfig
     xorg.libX11
+    xorg.libXft
+    xorg.libXrandr
 
     # run-time dependencies
     alsaUtils
     coreutils
     dnsutils
     iproute
-    wirelesstools
 
     # dev tools
     cargo-edit
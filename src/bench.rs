@@ -0,0 +1,42 @@
+use crate::allocator;
+use crate::feature;
+use crate::metrics::Timing;
+use std::fmt::Write;
+use std::time::Instant;
+
+pub(super) fn run(features: &mut [Box<dyn feature::Feature>], iterations: usize) -> String {
+    let mut report = String::new();
+
+    for feature in features {
+        let mut failures = 0;
+        let mut timing = Timing::default();
+        let allocations_before = allocator::allocations();
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let result = feature.update();
+            timing.record(start.elapsed());
+
+            if let Err(error) = result {
+                failures += 1;
+                error.show_error();
+            }
+        }
+
+        let allocations = allocator::allocations() - allocations_before;
+
+        let _ = writeln!(
+            report,
+            "{}: {} updates, {} failures, min {:?}, avg {:?}, max {:?}, {} allocations per update",
+            feature.name(),
+            iterations,
+            failures,
+            timing.min(),
+            timing.average(),
+            timing.max(),
+            allocations / iterations.max(1),
+        );
+    }
+
+    report
+}
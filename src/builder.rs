@@ -0,0 +1,411 @@
+use crate::bench;
+use crate::click;
+use crate::communication;
+use crate::condition;
+use crate::control;
+use crate::error::*;
+use crate::feature;
+use crate::features;
+use crate::locale;
+use crate::logging;
+use crate::marquee;
+use crate::metrics;
+use crate::mock;
+use crate::notification;
+use crate::output;
+use crate::recording;
+use crate::registry;
+use crate::resume;
+use crate::settings;
+use crate::snapshot;
+use crate::status_bar::StatusBar;
+use crate::systemd;
+use crate::watcher;
+use crate::wrapper::channel;
+use crate::wrapper::termination;
+use log::LevelFilter;
+use log::debug;
+use log::info;
+#[cfg(feature = "plugin-api")]
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem;
+use std::time::Duration;
+use std::time::Instant;
+
+#[cfg(feature = "plugin-api")]
+type FeatureFactory = Box<
+    dyn FnOnce(
+        usize,
+        &channel::Sender<communication::Message>,
+    ) -> Result<Box<dyn feature::Feature>>,
+>;
+
+/// Builder to embed the status bar in other programs.
+///
+/// ```no_run
+/// dwm_status::Builder::new()
+///     .config_file("/home/user/.config/dwm-status.toml")
+///     .feature("battery")
+///     .feature("time")
+///     .output(dwm_status::output::Stdout)
+///     .run()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct Builder {
+    config_path: Option<String>,
+    #[cfg(feature = "plugin-api")]
+    custom_features: HashMap<String, FeatureFactory>,
+    features: Vec<String>,
+    log_level: Option<LevelFilter>,
+    logging: bool,
+    mock_path: Option<String>,
+    output: Option<Box<dyn output::Output>>,
+    record_path: Option<String>,
+    replay_path: Option<String>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the config file, all options have their default values without config file.
+    pub fn config_file<P: Into<String>>(mut self, path: P) -> Self {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// Registers a feature implemented outside of dwm-status, it needs to be enabled like any
+    /// other feature.
+    #[cfg(feature = "plugin-api")]
+    pub fn custom_feature<N, F>(mut self, name: N, create: F) -> Self
+    where
+        N: Into<String>,
+        F: FnOnce(
+                usize,
+                &channel::Sender<communication::Message>,
+            ) -> Result<Box<dyn feature::Feature>>
+            + 'static,
+    {
+        self.custom_features
+            .insert(name.into().to_lowercase(), Box::new(create));
+        self
+    }
+
+    /// Enables the feature with the given name, if any feature is enabled via the builder the
+    /// option `features` of the config file is ignored.
+    pub fn feature<N: Into<String>>(mut self, name: N) -> Self {
+        self.features.push(name.into());
+        self
+    }
+
+    /// Overrides the log level of the config file.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn log_level(mut self, log_level: LevelFilter) -> Self {
+        self.log_level = Some(log_level);
+        self
+    }
+
+    /// Registers the built-in logger configured by the `logging` settings, otherwise the log
+    /// messages go to the logger of the embedding program, if any.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// Renders the fixtures of the given file once instead of reading any system information and
+    /// writes the status text to stdout, if no other output is set.
+    pub fn mock<P: Into<String>>(mut self, fixtures_path: P) -> Self {
+        self.mock_path = Some(fixtures_path.into());
+        self
+    }
+
+    /// Sets the sink of the status text, defaults to the name of the X11 root window.
+    pub fn output<O: output::Output + 'static>(mut self, output: O) -> Self {
+        self.output = Some(Box::new(output));
+        self
+    }
+
+    /// Updates every enabled feature the given number of times and returns a report with the
+    /// update durations and allocations per feature, see
+    /// [`CountingAllocator`](struct.CountingAllocator.html).
+    pub fn bench(mut self, iterations: usize) -> Result<String> {
+        let settings = self.init_settings()?;
+        let (sender, _receiver) = channel::create();
+        let registry = registry::Registry::new(&settings.general.features).shared();
+        let mut features = self.create_features(&settings, &sender, &registry)?;
+
+        Ok(bench::run(&mut features, iterations))
+    }
+
+    /// Writes every feature update with its rendered text to the given file.
+    pub fn record<P: Into<String>>(mut self, path: P) -> Self {
+        self.record_path = Some(path.into());
+        self
+    }
+
+    /// Replays the feature updates of the given recording with their original timing instead of
+    /// reading any system information.
+    pub fn replay<P: Into<String>>(mut self, path: P) -> Self {
+        self.replay_path = Some(path.into());
+        self
+    }
+
+    /// Runs the status bar until SIGINT or SIGTERM is received.
+    pub fn run(mut self) -> Result<()> {
+        let settings = self.init_settings()?;
+
+        if let Some(ref fixtures_path) = self.mock_path {
+            let features = mock::create_features(fixtures_path, &settings.general.features)?;
+            let metrics = init_metrics(&features);
+            let snapshot = init_snapshot(&features);
+            let output = match self.output {
+                Some(output) => output,
+                None => Box::new(output::Stdout),
+            };
+
+            let registry = registry::Registry::new(&settings.general.features).shared();
+
+            let mut status_bar = StatusBar::init(
+                features, metrics, snapshot, output, None, registry, &settings,
+            )?;
+
+            return status_bar.update(&communication::Message::UpdateAll, &settings.general);
+        }
+
+        if let Some(ref replay_path) = self.replay_path {
+            let output = match self.output {
+                Some(output) => output,
+                None => Box::new(output::RootWindow::init_with_settings(&settings.output)?),
+            };
+
+            return recording::replay(replay_path, &settings.general, |features| {
+                let metrics = init_metrics(&features);
+                let snapshot = init_snapshot(&features);
+                let registry = registry::Registry::new(&feature_names(&features)).shared();
+
+                StatusBar::init(
+                    features, metrics, snapshot, output, None, registry, &settings,
+                )
+            });
+        }
+
+        let (sender, receiver) = channel::create();
+        let registry = registry::Registry::new(&settings.general.features).shared();
+        let mut features = self.create_features(&settings, &sender, &registry)?;
+
+        for feature in &mut features {
+            feature.init_notifier()?;
+        }
+
+        watcher::init_file_watcher(&features, &sender)?;
+
+        resume::init_resume_notifier(&sender)?;
+
+        let mut systemd = systemd::Notifier::init();
+        systemd.init_watchdog(&sender)?;
+
+        let metrics = init_metrics(&features);
+        let snapshot = init_snapshot(&features);
+
+        let recorder = match self.record_path {
+            Some(ref path) => Some(recording::Recorder::init(path, &feature_names(&features))?),
+            None => None,
+        };
+
+        let _control_socket =
+            control::init_control_socket(metrics.clone(), snapshot.clone(), &sender).show_error();
+
+        click::init_stdin_reader(&settings.click, feature_names(&features), &sender)?;
+        marquee::init_ticker(&settings.marquee, &sender)?;
+
+        sender.send(communication::Message::UpdateAll)?;
+
+        termination::register_handler(move || {
+            sender
+                .send(communication::Message::Kill)
+                .show_error()
+                .unwrap()
+        })?;
+
+        let output = match self.output {
+            Some(output) => output,
+            None => Box::new(output::RootWindow::init_with_settings(&settings.output)?),
+        };
+
+        let mut status_bar = StatusBar::init(
+            features, metrics, snapshot, output, recorder, registry, &settings,
+        )?;
+
+        loop {
+            let message = match status_bar.timeout(Instant::now()) {
+                Some(timeout) if timeout == Duration::from_secs(0) => Ok(None),
+                Some(timeout) => receiver.read_timeout(timeout),
+                None => receiver.read_blocking().map(Some),
+            };
+
+            let message = match message {
+                Ok(Some(message)) => message,
+                Ok(None) => {
+                    status_bar.update_scheduled(&settings.general)?;
+                    systemd.ready();
+                    continue;
+                },
+                Err(_) => break,
+            };
+
+            debug!("Received message {:?}", message);
+            let start = Instant::now();
+
+            match message {
+                communication::Message::Kill => break,
+                communication::Message::Watchdog => systemd.watchdog(),
+                _ => {
+                    status_bar.update(&message, &settings.general)?;
+                    systemd.ready();
+                },
+            }
+
+            debug!("Handled message {:?} in {:?}", message, start.elapsed());
+        }
+
+        systemd.stopping();
+
+        Ok(())
+    }
+
+    fn init_settings(&mut self) -> Result<settings::Settings> {
+        let logger = if self.logging {
+            logging::Logger::init()
+        } else {
+            None
+        };
+        let mut settings = settings::Settings::init(self.config_path.as_deref())?;
+
+        if let Some(logger) = logger {
+            logger.configure(&settings.logging, self.log_level)?;
+        }
+
+        if !self.features.is_empty() {
+            settings.general.features = mem::take(&mut self.features);
+        }
+
+        if self.mock_path.is_none() {
+            disable_unavailable_features(&mut settings)?;
+        }
+
+        validate_settings(&settings)?;
+        locale::init(settings.general.locale)?;
+        notification::init(&settings.notification)?;
+
+        Ok(settings)
+    }
+
+    fn create_features(
+        &mut self,
+        settings: &settings::Settings,
+        sender: &channel::Sender<communication::Message>,
+        registry: &registry::SharedRegistry,
+    ) -> Result<Vec<Box<dyn feature::Feature>>> {
+        let mut features = Vec::new();
+
+        for (index, feature_name) in settings.general.features.iter().enumerate() {
+            #[cfg(feature = "plugin-api")]
+            let feature = match self.custom_features.remove(&feature_name.to_lowercase()) {
+                Some(create) => create(index, sender)?,
+                None => features::create_feature(index, feature_name, sender, registry, settings)?,
+            };
+            #[cfg(not(feature = "plugin-api"))]
+            let feature =
+                features::create_feature(index, feature_name, sender, registry, settings)?;
+
+            features.push(feature);
+        }
+
+        Ok(features)
+    }
+}
+
+impl Builder {
+    #[cfg(feature = "plugin-api")]
+    fn custom_feature_names(&self) -> Vec<&String> {
+        self.custom_features.keys().collect()
+    }
+
+    #[cfg(not(feature = "plugin-api"))]
+    fn custom_feature_names(&self) -> Vec<&String> {
+        Vec::new()
+    }
+}
+
+impl fmt::Debug for Builder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("config_path", &self.config_path)
+            .field("custom_features", &self.custom_feature_names())
+            .field("features", &self.features)
+            .field("log_level", &self.log_level)
+            .field("logging", &self.logging)
+            .field("mock_path", &self.mock_path)
+            .field("output", &self.output.as_ref().map(|_| "custom"))
+            .field("record_path", &self.record_path)
+            .field("replay_path", &self.replay_path)
+            .finish()
+    }
+}
+
+fn feature_names(features: &[Box<dyn feature::Feature>]) -> Vec<&'static str> {
+    features.iter().map(|feature| feature.name()).collect()
+}
+
+fn init_metrics(features: &[Box<dyn feature::Feature>]) -> metrics::SharedMetrics {
+    metrics::Metrics::new(&feature_names(features)).shared()
+}
+
+fn init_snapshot(features: &[Box<dyn feature::Feature>]) -> snapshot::SharedSnapshot {
+    snapshot::Snapshot::new(&feature_names(features)).shared()
+}
+
+fn disable_unavailable_features(settings: &mut settings::Settings) -> Result<()> {
+    let mut features = Vec::new();
+
+    for feature in mem::take(&mut settings.general.features) {
+        let conditions = settings
+            .general
+            .enable_if
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&feature))
+            .map_or(&[][..], |(_, conditions)| &conditions[..]);
+
+        if condition::all_hold(conditions)? {
+            features.push(feature);
+        } else {
+            info!("Feature {} disabled by enable_if", feature);
+        }
+    }
+
+    settings.general.features = features;
+
+    Ok(())
+}
+
+fn validate_settings(settings: &settings::Settings) -> Result<()> {
+    if settings.general.features.is_empty() {
+        return Err(Error::new_custom("settings", "no features enabled"));
+    }
+
+    let set: HashSet<&String> = HashSet::from_iter(settings.general.features.iter());
+    if set.len() < settings.general.features.len() {
+        return Err(Error::new_custom(
+            "settings",
+            "features must not have more than one entry of one feature",
+        ));
+    }
+
+    Ok(())
+}
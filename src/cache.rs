@@ -0,0 +1,249 @@
+use crate::error::*;
+use crate::wrapper::file;
+use crate::wrapper::xdg;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+const ERROR_NAME: &str = "cache";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Entry {
+    pub(crate) etag: Option<String>,
+    pub(crate) expires_at: u64,
+    pub(crate) last_modified: Option<String>,
+    pub(crate) value: String,
+}
+
+impl Entry {
+    fn parse(content: &str) -> Option<Self> {
+        let mut lines = content.splitn(4, '\n');
+
+        let expires_at = lines.next()?.parse().ok()?;
+        let etag = optional(lines.next()?);
+        let last_modified = optional(lines.next()?);
+        let value = lines.next()?.to_owned();
+
+        Some(Self {
+            etag,
+            expires_at,
+            last_modified,
+            value,
+        })
+    }
+
+    fn serialize(&self) -> String {
+        format!(
+            "{}\n{}\n{}\n{}",
+            self.expires_at,
+            self.etag.as_deref().unwrap_or(""),
+            self.last_modified.as_deref().unwrap_or(""),
+            self.value
+        )
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Cache {
+    directory: Option<PathBuf>,
+    entries: HashMap<String, Entry>,
+}
+
+impl Cache {
+    pub(crate) fn init(persistent: bool) -> Self {
+        Self {
+            directory: if persistent { xdg::cache_dir() } else { None },
+            entries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get_or_update<F>(&mut self, key: &str, ttl: u64, builder: F) -> Option<String>
+    where
+        F: FnOnce() -> Option<String>,
+    {
+        if ttl == 0 {
+            return builder();
+        }
+
+        let now = now();
+
+        if let Some(entry) = self.entry(key).filter(|entry| entry.expires_at > now) {
+            return Some(entry.value);
+        }
+
+        let value = builder()?;
+
+        self.set(
+            key,
+            Entry {
+                etag: None,
+                expires_at: now.saturating_add(ttl),
+                last_modified: None,
+                value: value.clone(),
+            },
+        );
+
+        Some(value)
+    }
+
+    pub(crate) fn entry(&mut self, key: &str) -> Option<Entry> {
+        if !self.entries.contains_key(key) {
+            if let Some(entry) = self.read(key) {
+                self.entries.insert(key.to_owned(), entry);
+            }
+        }
+
+        self.entries.get(key).cloned()
+    }
+
+    pub(crate) fn set(&mut self, key: &str, entry: Entry) {
+        self.write(key, &entry).show_error_and_ignore();
+        self.entries.insert(key.to_owned(), entry);
+    }
+
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        self.directory.as_ref().map(|directory| directory.join(key))
+    }
+
+    fn read(&self, key: &str) -> Option<Entry> {
+        let content = file::read(self.path(key)?).ok()?;
+
+        Entry::parse(&content)
+    }
+
+    fn write(&self, key: &str, entry: &Entry) -> Result<()> {
+        let path = match self.path(key) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .wrap_error(ERROR_NAME, "failed to create cache directory")?;
+        }
+
+        fs::write(&path, entry.serialize())
+            .wrap_error(ERROR_NAME, format!("failed to write {}", path.display()))
+    }
+}
+
+fn optional(line: &str) -> Option<String> {
+    if line.is_empty() {
+        None
+    } else {
+        Some(line.to_owned())
+    }
+}
+
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn entry_parse_with_value() {
+        assert_that!(
+            Entry::parse("42\n\n\nsome\nvalue"),
+            is(equal_to(Some(Entry {
+                etag: None,
+                expires_at: 42,
+                last_modified: None,
+                value: "some\nvalue".to_owned(),
+            })))
+        );
+    }
+
+    #[test]
+    fn entry_parse_with_validators() {
+        assert_that!(
+            Entry::parse("42\n\"abc\"\nWed, 21 Oct 2015 07:28:00 GMT\nvalue"),
+            is(equal_to(Some(Entry {
+                etag: Some("\"abc\"".to_owned()),
+                expires_at: 42,
+                last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_owned()),
+                value: "value".to_owned(),
+            })))
+        );
+    }
+
+    #[test]
+    fn entry_parse_without_value() {
+        assert_that!(Entry::parse("42\n\n"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn entry_parse_with_invalid_content() {
+        assert_that!(Entry::parse("value"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn entry_serialize() {
+        let entry = Entry {
+            etag: Some("\"abc\"".to_owned()),
+            expires_at: 42,
+            last_modified: None,
+            value: "value".to_owned(),
+        };
+
+        assert_that!(Entry::parse(&entry.serialize()), is(equal_to(Some(entry))));
+    }
+
+    #[test]
+    fn get_or_update_caches_value() {
+        let mut cache = Cache::init(false);
+        let mut counter = 0;
+
+        for _ in 0..2 {
+            let value = cache.get_or_update("key", 60, || {
+                counter += 1;
+                Some("value".to_owned())
+            });
+
+            assert_that!(value, is(equal_to(Some("value".to_owned()))));
+        }
+
+        assert_that!(counter, is(equal_to(1)));
+    }
+
+    #[test]
+    fn get_or_update_does_not_cache_none() {
+        let mut cache = Cache::init(false);
+        let mut counter = 0;
+
+        for _ in 0..2 {
+            let value = cache.get_or_update("key", 60, || {
+                counter += 1;
+                None
+            });
+
+            assert_that!(value, is(equal_to(None)));
+        }
+
+        assert_that!(counter, is(equal_to(2)));
+    }
+
+    #[test]
+    fn get_or_update_without_ttl() {
+        let mut cache = Cache::init(false);
+        let mut counter = 0;
+
+        for _ in 0..2 {
+            cache.get_or_update("key", 0, || {
+                counter += 1;
+                Some("value".to_owned())
+            });
+        }
+
+        assert_that!(counter, is(equal_to(2)));
+    }
+}
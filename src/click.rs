@@ -0,0 +1,308 @@
+mod config;
+
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::process;
+use crate::wrapper::thread;
+use log::debug;
+use serde_derive::*;
+use std::collections::HashMap;
+use std::io;
+use std::io::BufRead;
+
+pub(crate) use self::config::ConfigEntry;
+
+const ACTION_REFRESH: &str = "refresh";
+const ENV_BUTTON: &str = "BUTTON";
+const ENV_FEATURE: &str = "FEATURE";
+const ERROR_NAME: &str = "click";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Button {
+    Left,
+    Middle,
+    Right,
+    ScrollDown,
+    ScrollUp,
+}
+
+impl Button {
+    pub(crate) fn from_number(number: u8) -> Result<Self> {
+        match number {
+            1 => Ok(Self::Left),
+            2 => Ok(Self::Middle),
+            3 => Ok(Self::Right),
+            4 => Ok(Self::ScrollUp),
+            5 => Ok(Self::ScrollDown),
+            _ => Err(Error::new_custom(
+                ERROR_NAME,
+                format!("unsupported button {}", number),
+            )),
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "left" => Ok(Self::Left),
+            "middle" => Ok(Self::Middle),
+            "right" => Ok(Self::Right),
+            "scroll_down" => Ok(Self::ScrollDown),
+            "scroll_up" => Ok(Self::ScrollUp),
+            _ => Err(Error::new_custom(
+                ERROR_NAME,
+                format!(
+                    "unknown button {}, expected one of left, middle, right, scroll_up and \
+                     scroll_down",
+                    name
+                ),
+            )),
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(crate) fn number(self) -> u8 {
+        match self {
+            Self::Left => 1,
+            Self::Middle => 2,
+            Self::Right => 3,
+            Self::ScrollUp => 4,
+            Self::ScrollDown => 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Action {
+    Command(String),
+    Feature,
+    Refresh,
+}
+
+impl Action {
+    fn parse(action: &str) -> Self {
+        match action {
+            ACTION_REFRESH => Self::Refresh,
+            command => Self::Command(command.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Bindings {
+    actions: HashMap<(String, Button), Action>,
+}
+
+impl Bindings {
+    pub(crate) fn init(settings: &ConfigEntry) -> Result<Self> {
+        let mut actions = HashMap::new();
+
+        for (feature, bindings) in &settings.bindings {
+            for (button, action) in bindings {
+                actions.insert(
+                    (feature.to_lowercase(), Button::from_name(button)?),
+                    Action::parse(action),
+                );
+            }
+        }
+
+        Ok(Self { actions })
+    }
+
+    pub(crate) fn action(&self, feature: &str, button: Button) -> Action {
+        self.actions
+            .get(&(feature.to_owned(), button))
+            .cloned()
+            .unwrap_or(Action::Feature)
+    }
+}
+
+pub(crate) fn run_command(command: &str, feature: &str, button: Button) -> Result<()> {
+    debug!("Run click command {:?} of feature {}", command, feature);
+
+    process::Command::new("sh", &["-c", command])
+        .env(ENV_BUTTON, &button.number().to_string())
+        .env(ENV_FEATURE, feature)
+        .spawn()
+}
+
+pub(crate) fn parse_target<F>(target: &str, feature_id: F) -> Result<communication::ClickTarget>
+where
+    F: FnOnce(&str) -> Option<usize>,
+{
+    if target.starts_with('@') {
+        return target[1..]
+            .parse()
+            .map(communication::ClickTarget::Position)
+            .wrap_error(ERROR_NAME, format!("invalid position {}", target));
+    }
+
+    feature_id(target)
+        .map(communication::ClickTarget::Feature)
+        .wrap_error(ERROR_NAME, format!("unknown feature {}", target))
+}
+
+pub(crate) fn init_stdin_reader(
+    settings: &ConfigEntry,
+    names: Vec<&'static str>,
+    sender: &channel::Sender<communication::Message>,
+) -> Result<()> {
+    if !settings.stdin {
+        return Ok(());
+    }
+
+    let reader = StdinReader {
+        names,
+        sender: sender.clone(),
+    };
+
+    thread::Thread::new("click events", reader).run()
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct I3barEvent {
+    button: u8,
+    name: String,
+}
+
+fn parse_i3bar_event(line: &str) -> Option<I3barEvent> {
+    let line = line.trim().trim_start_matches(|c| c == '[' || c == ',');
+
+    if line.is_empty() {
+        return None;
+    }
+
+    serde_json::from_str(line).ok()
+}
+
+struct StdinReader {
+    names: Vec<&'static str>,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl thread::Runnable for StdinReader {
+    fn run(&self) -> Result<()> {
+        let stdin = io::stdin();
+
+        for line in stdin.lock().lines() {
+            let line = line.wrap_error(ERROR_NAME, "failed to read click event")?;
+
+            let event = match parse_i3bar_event(&line) {
+                Some(event) => event,
+                None => continue,
+            };
+
+            match self.names.iter().position(|name| *name == event.name) {
+                Some(id) => self.sender.send(communication::Message::Click {
+                    button: event.button,
+                    target: communication::ClickTarget::Feature(id),
+                })?,
+                None => debug!("Ignore click event of unknown feature {}", event.name),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn bindings_action() {
+        let mut bindings = HashMap::new();
+        bindings.insert("left".to_owned(), "refresh".to_owned());
+        bindings.insert("right".to_owned(), "pavucontrol".to_owned());
+        bindings.insert("scroll_up".to_owned(), "amixer set Master 1%+".to_owned());
+
+        let mut settings = ConfigEntry {
+            bindings: HashMap::new(),
+            stdin: false,
+        };
+        settings.bindings.insert("Audio".to_owned(), bindings);
+
+        let bindings = Bindings::init(&settings).unwrap();
+
+        assert_that!(
+            bindings.action("audio", Button::Left),
+            is(equal_to(Action::Refresh))
+        );
+        assert_that!(
+            bindings.action("audio", Button::Middle),
+            is(equal_to(Action::Feature))
+        );
+        assert_that!(
+            bindings.action("audio", Button::Right),
+            is(equal_to(Action::Command("pavucontrol".to_owned())))
+        );
+        assert_that!(
+            bindings.action("audio", Button::ScrollUp),
+            is(equal_to(Action::Command(
+                "amixer set Master 1%+".to_owned()
+            )))
+        );
+        assert_that!(
+            bindings.action("audio", Button::ScrollDown),
+            is(equal_to(Action::Feature))
+        );
+    }
+
+    #[test]
+    fn bindings_init_with_unknown_button() {
+        let mut bindings = HashMap::new();
+        bindings.insert("double".to_owned(), "refresh".to_owned());
+
+        let mut settings = ConfigEntry {
+            bindings: HashMap::new(),
+            stdin: false,
+        };
+        settings.bindings.insert("audio".to_owned(), bindings);
+
+        assert_that!(Bindings::init(&settings).is_err(), is(true));
+    }
+
+    #[test]
+    fn parse_target_with_feature() {
+        assert_that!(
+            parse_target("time", |name| if name == "time" { Some(2) } else { None }),
+            is(equal_to(Ok(communication::ClickTarget::Feature(2))))
+        );
+    }
+
+    #[test]
+    fn parse_target_with_unknown_feature() {
+        assert_that!(parse_target("time", |_| None).is_err(), is(true));
+    }
+
+    #[test]
+    fn parse_target_with_position() {
+        assert_that!(
+            parse_target("@42", |_| None),
+            is(equal_to(Ok(communication::ClickTarget::Position(42))))
+        );
+    }
+
+    #[test]
+    fn parse_target_with_invalid_position() {
+        assert_that!(parse_target("@left", |_| None).is_err(), is(true));
+    }
+
+    #[test]
+    fn parse_i3bar_event_with_array_start() {
+        assert_that!(parse_i3bar_event("["), is(equal_to(None)));
+    }
+
+    #[test]
+    fn parse_i3bar_event_with_event() {
+        assert_that!(
+            parse_i3bar_event(r#",{"name":"battery","instance":"0","button":3,"x":1500}"#),
+            is(equal_to(Some(I3barEvent {
+                button: 3,
+                name: "battery".to_owned(),
+            })))
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) bindings: HashMap<String, HashMap<String, String>>,
+    pub(super) stdin: bool,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "click",
+            map!(
+                "bindings" => HashMap::<String, Value>::new(),
+                "stdin"    => false,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("click", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("click", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert(
+            "bindings".to_owned(),
+            HashMap::<String, Value>::new().into(),
+        );
+        map.insert("stdin".to_owned(), false.into());
+
+        map
+    }
+}
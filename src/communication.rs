@@ -1,11 +1,27 @@
+#![cfg_attr(not(feature = "plugin-api"), allow(unreachable_pub))]
+
 use crate::error::*;
 use crate::wrapper::channel;
+use crate::wrapper::thread;
 
+/// Messages handled by the main loop, features send `FeatureUpdate` with their id to get updated.
 #[derive(Clone, Copy, Debug)]
-pub(crate) enum Message {
+#[non_exhaustive]
+pub enum Message {
+    Click { button: u8, target: ClickTarget },
     FeatureUpdate(usize),
     Kill,
+    Tick,
     UpdateAll,
+    Watchdog,
+}
+
+/// Clicked feature, either its id or a position in characters of the status text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ClickTarget {
+    Feature(usize),
+    Position(usize),
 }
 
 pub(crate) fn send_message(id: usize, sender: &channel::Sender<Message>) -> Result<()> {
@@ -13,3 +29,29 @@ pub(crate) fn send_message(id: usize, sender: &channel::Sender<Message>) -> Resu
 
     sender.send(message)
 }
+
+pub(crate) struct Ticker {
+    id: usize,
+    interval: u64,
+    sender: channel::Sender<Message>,
+}
+
+impl Ticker {
+    pub(crate) const fn new(id: usize, sender: channel::Sender<Message>, interval: u64) -> Self {
+        Self {
+            id,
+            interval,
+            sender,
+        }
+    }
+}
+
+impl thread::Runnable for Ticker {
+    fn run(&self) -> Result<()> {
+        loop {
+            thread::sleep_secs(self.interval);
+
+            send_message(self.id, &self.sender)?;
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::error::*;
+use crate::wrapper::file;
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+const PATH_NET: &str = "/sys/class/net";
+const PATH_POWER_SUPPLY: &str = "/sys/class/power_supply";
+const PREFIX_PROGRAM: &str = "program:";
+
+#[derive(Clone, Debug, PartialEq)]
+enum Check {
+    Battery,
+    Program(String),
+    Wireless,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Condition {
+    check: Check,
+    negated: bool,
+}
+
+impl Condition {
+    pub(crate) fn parse(condition: &str) -> Result<Self> {
+        let (negated, name) = if condition.starts_with('!') {
+            (true, condition[1..].trim())
+        } else {
+            (false, condition.trim())
+        };
+
+        let check = match name {
+            "battery" => Check::Battery,
+            "wireless" => Check::Wireless,
+            program if program.starts_with(PREFIX_PROGRAM) => {
+                let program = program[PREFIX_PROGRAM.len()..].trim();
+
+                if program.is_empty() {
+                    return Err(Error::new_custom(
+                        "settings",
+                        "enable_if without program name",
+                    ));
+                }
+
+                Check::Program(program.to_owned())
+            },
+            _ => {
+                return Err(Error::new_custom(
+                    "settings",
+                    format!(
+                        "unknown enable_if condition {}, expected battery, wireless or \
+                         program:<name>",
+                        condition
+                    ),
+                ));
+            },
+        };
+
+        Ok(Self { check, negated })
+    }
+
+    pub(crate) fn holds(&self) -> bool {
+        let holds = match self.check {
+            Check::Battery => has_battery(),
+            Check::Program(ref program) => is_installed(program),
+            Check::Wireless => has_wireless_interface(),
+        };
+
+        holds != self.negated
+    }
+}
+
+pub(crate) fn all_hold(conditions: &[String]) -> Result<bool> {
+    for condition in conditions {
+        if !Condition::parse(condition)?.holds() {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn has_battery() -> bool {
+    fs::read_dir(PATH_POWER_SUPPLY)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .any(|entry| {
+            file::read(entry.path().join("type")).map_or(false, |kind| kind.trim() == "Battery")
+        })
+}
+
+fn has_wireless_interface() -> bool {
+    fs::read_dir(PATH_NET)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .any(|entry| entry.path().join("wireless").exists())
+}
+
+fn is_installed(program: &str) -> bool {
+    if program.contains('/') {
+        return is_executable(Path::new(program));
+    }
+
+    env::var_os("PATH").map_or(false, |paths| {
+        env::split_paths(&paths).any(|directory| is_executable(&directory.join(program)))
+    })
+}
+
+fn is_executable(path: &Path) -> bool {
+    fs::metadata(path).map_or(false, |metadata| {
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_conditions() {
+        assert_that!(
+            Condition::parse("battery"),
+            is(equal_to(Ok(Condition {
+                check: Check::Battery,
+                negated: false,
+            })))
+        );
+        assert_that!(
+            Condition::parse("!wireless"),
+            is(equal_to(Ok(Condition {
+                check: Check::Wireless,
+                negated: true,
+            })))
+        );
+        assert_that!(
+            Condition::parse("program: pactl"),
+            is(equal_to(Ok(Condition {
+                check: Check::Program("pactl".to_owned()),
+                negated: false,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_unknown_or_incomplete_condition() {
+        assert_that!(Condition::parse("laptop").is_err(), is(true));
+        assert_that!(Condition::parse("program:").is_err(), is(true));
+    }
+
+    #[test]
+    fn negated_condition_holds_if_check_fails() {
+        let condition = Condition::parse("!program:/nonexistent/dwm-status").unwrap();
+
+        assert_that!(condition.holds(), is(true));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::click;
+use crate::communication;
+use crate::error::*;
+use crate::metrics;
+use crate::snapshot;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use crate::wrapper::xdg;
+use log::debug;
+use std::fs;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::net::UnixListener;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+const COMMAND_CLICK: &str = "click";
+const COMMAND_STATS: &str = "stats";
+const COMMAND_STATUS: &str = "status";
+const ERROR_NAME: &str = "control socket";
+const SOCKET_NAME: &str = "control.sock";
+
+#[derive(Debug)]
+pub(super) struct ControlSocket {
+    path: PathBuf,
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+pub(super) fn init_control_socket(
+    metrics: metrics::SharedMetrics,
+    snapshot: snapshot::SharedSnapshot,
+    sender: &channel::Sender<communication::Message>,
+) -> Result<ControlSocket> {
+    let path = socket_path().wrap_error(
+        ERROR_NAME,
+        "XDG_RUNTIME_DIR is not set, control socket is disabled",
+    )?;
+
+    if UnixStream::connect(&path).is_ok() {
+        return Err(Error::new_custom(
+            ERROR_NAME,
+            format!("{} is used by another instance", path.display()),
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).wrap_error(ERROR_NAME, "failed to create runtime directory")?;
+    }
+
+    let _ = fs::remove_file(&path);
+
+    let listener = UnixListener::bind(&path)
+        .wrap_error(ERROR_NAME, format!("failed to bind {}", path.display()))?;
+
+    let control_socket = ControlSocket { path };
+
+    let listener = Listener {
+        listener,
+        metrics,
+        sender: sender.clone(),
+        snapshot,
+    };
+
+    thread::Thread::new(ERROR_NAME, listener).run()?;
+
+    Ok(control_socket)
+}
+
+pub(super) fn send_command(command: &str) -> Result<String> {
+    let path = socket_path().wrap_error(ERROR_NAME, "XDG_RUNTIME_DIR is not set")?;
+
+    let mut stream = UnixStream::connect(&path).wrap_error(
+        ERROR_NAME,
+        format!(
+            "failed to connect to {}, is dwm-status running?",
+            path.display()
+        ),
+    )?;
+
+    writeln!(stream, "{}", command).wrap_error(ERROR_NAME, "failed to send command")?;
+
+    let mut answer = String::new();
+    stream
+        .read_to_string(&mut answer)
+        .wrap_error(ERROR_NAME, "failed to read answer")?;
+
+    Ok(answer)
+}
+
+fn socket_path() -> Option<PathBuf> {
+    xdg::runtime_dir().map(|directory| directory.join(SOCKET_NAME))
+}
+
+struct Listener {
+    listener: UnixListener,
+    metrics: metrics::SharedMetrics,
+    sender: channel::Sender<communication::Message>,
+    snapshot: snapshot::SharedSnapshot,
+}
+
+impl Listener {
+    fn handle(&self, stream: UnixStream) -> Result<()> {
+        let mut command = String::new();
+        BufReader::new(&stream)
+            .read_line(&mut command)
+            .wrap_error(ERROR_NAME, "failed to read command")?;
+
+        let command = command.trim();
+        debug!("Received control command {:?}", command);
+
+        let mut arguments = command.split_whitespace();
+
+        let answer = match arguments.next().unwrap_or_default() {
+            COMMAND_CLICK => match self.click(arguments.next(), arguments.next()) {
+                Ok(()) => "ok\n".to_owned(),
+                Err(error) => format!("{}\n", error),
+            },
+            COMMAND_STATS => self.metrics.lock().unwrap().summary(),
+            COMMAND_STATUS => format!("{}\n", self.snapshot.lock().unwrap().to_json()),
+            _ => format!("unknown command: {}\n", command),
+        };
+
+        (&stream)
+            .write_all(answer.as_bytes())
+            .wrap_error(ERROR_NAME, "failed to send answer")
+    }
+
+    fn click(&self, button: Option<&str>, target: Option<&str>) -> Result<()> {
+        let button = button
+            .and_then(|button| button.parse().ok())
+            .wrap_error(ERROR_NAME, "usage: click <button> <feature|@position>")?;
+        let target = target.wrap_error(ERROR_NAME, "usage: click <button> <feature|@position>")?;
+
+        click::Button::from_number(button)?;
+        let target = click::parse_target(target, |name| {
+            self.snapshot.lock().unwrap().feature_id(name)
+        })?;
+
+        self.sender
+            .send(communication::Message::Click { button, target })
+    }
+}
+
+impl thread::Runnable for Listener {
+    fn run(&self) -> Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream.wrap_error(ERROR_NAME, "failed to accept connection")?;
+
+            self.handle(stream).show_error_and_ignore();
+        }
+
+        Ok(())
+    }
+}
@@ -36,7 +36,7 @@ impl Error {
         Self::new(name, description, cause)
     }
 
-    pub(crate) fn new_custom<N, D>(name: N, description: D) -> Self
+    pub fn new_custom<N, D>(name: N, description: D) -> Self
     where
         N: Into<String>,
         D: Into<String>,
@@ -1,20 +1,56 @@
+// public with the cargo feature `plugin-api` to implement features in other crates
+#![cfg_attr(not(feature = "plugin-api"), allow(unreachable_pub))]
+
 use crate::error::*;
 use crate::wrapper::thread;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Raw values of a feature keyed by placeholder name.
+pub type Values = BTreeMap<&'static str, String>;
 
-pub(crate) trait Renderable {
+pub trait Renderable {
     fn render(&self) -> &str;
+
+    fn values(&self) -> Values {
+        Values::new()
+    }
 }
 
-pub(crate) trait Updatable {
+pub trait Updatable {
     fn renderable(&self) -> &dyn Renderable;
 
     fn update(&mut self) -> Result<()>;
+
+    /// Handles a click with the given mouse button on the feature, ignored by default.
+    fn click(&mut self, _button: u8) -> Result<()> {
+        Ok(())
+    }
 }
 
-pub(crate) trait Feature: Updatable {
+pub trait Feature: Updatable {
     fn init_notifier(&mut self) -> Result<()>;
 
     fn name(&self) -> &'static str;
+
+    fn update_interval(&self) -> Option<u64>;
+
+    /// Delay until the next periodic update, features returning a delay get updated by the main
+    /// loop when it elapsed without sending `FeatureUpdate` themselves.
+    fn next_update(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Files whose modification triggers an update of the feature, watched by one shared inotify
+    /// instance for all features without sending `FeatureUpdate` themselves.
+    fn watched_files(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Names of features whose changed raw values trigger an update of the feature after them.
+    fn subscriptions(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 pub(crate) struct Composer<N, U>
@@ -24,6 +60,7 @@ where
 {
     name: &'static str,
     notifier: Option<N>,
+    update_interval: Option<u64>,
     updater: U,
 }
 
@@ -35,6 +72,7 @@ where
     pub(crate) fn new(name: &'static str, notifier: N, updater: U) -> Self {
         Self {
             name,
+            update_interval: notifier.update_interval(),
             notifier: Some(notifier),
             updater,
         }
@@ -47,17 +85,48 @@ where
     U: Updatable,
 {
     fn init_notifier(&mut self) -> Result<()> {
-        if let Some(notifier) = self.notifier.take() {
-            let thread = thread::Thread::new(self.name, notifier);
-            thread.run()
-        } else {
-            Err(Error::new_custom("feature", "can not start notifier twice"))
+        match self.notifier.take() {
+            Some(notifier)
+                if notifier.next_update().is_some()
+                    || !notifier.watched_files().is_empty()
+                    || !notifier.subscriptions().is_empty() =>
+            {
+                self.notifier = Some(notifier);
+                Ok(())
+            },
+            Some(notifier) => {
+                let thread = thread::Thread::new(self.name, notifier);
+                thread.run()
+            },
+            None => Err(Error::new_custom("feature", "can not start notifier twice")),
         }
     }
 
     fn name(&self) -> &'static str {
         self.name
     }
+
+    fn update_interval(&self) -> Option<u64> {
+        self.update_interval
+    }
+
+    fn next_update(&self) -> Option<Duration> {
+        self.notifier.as_ref().and_then(N::next_update)
+    }
+
+    fn watched_files(&self) -> Vec<String> {
+        self.notifier
+            .as_ref()
+            .map(N::watched_files)
+            .unwrap_or_default()
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        self.notifier
+            .as_ref()
+            .map(N::subscriptions)
+            .unwrap_or_default()
+    }
 }
 
 impl<N, U> Updatable for Composer<N, U>
@@ -72,4 +141,8 @@ where
     fn update(&mut self) -> Result<()> {
         self.updater.update()
     }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        self.updater.click(button)
+    }
 }
@@ -1,42 +1,148 @@
 pub(super) mod audio;
 pub(super) mod backlight;
 pub(super) mod battery;
+pub(super) mod bluetooth;
+pub(super) mod ci;
+pub(super) mod composite;
 pub(super) mod cpu_load;
+pub(super) mod cpu_temp;
+pub(super) mod dhcp;
+pub(super) mod disk_usage;
+pub(super) mod git;
+pub(super) mod gpu;
+pub(super) mod homeassistant;
+pub(super) mod inhibitors;
+pub(super) mod inodes;
+pub(super) mod keyboard;
+pub(super) mod kubernetes;
+pub(super) mod lan;
+pub(super) mod library;
+pub(super) mod memory;
+pub(super) mod meter;
+pub(super) mod mpd;
 pub(super) mod network;
+pub(super) mod nightlight;
+pub(super) mod pacman;
+pub(super) mod plugin;
+pub(super) mod ports;
+pub(super) mod pressure;
+pub(super) mod rapl;
+pub(super) mod remote;
+pub(super) mod snmp;
+pub(super) mod speedtest;
+pub(super) mod throughput;
 pub(super) mod time;
+pub(super) mod tls;
+pub(super) mod tracking;
+pub(super) mod units;
+pub(super) mod updates;
+pub(super) mod uptime;
+pub(super) mod urgent;
+pub(super) mod vpn;
+pub(super) mod weather;
+pub(super) mod zram;
 
 use crate::communication;
 use crate::error::*;
 use crate::feature;
+use crate::registry;
 use crate::settings;
 use crate::wrapper::channel;
 
 macro_rules! features {
-    ( $id:expr, $name:expr, $sender:expr, $settings:expr; $( $mod:ident, )* ) => {
+    (
+        $id:expr, $name:expr, $sender:expr, $registry:expr, $settings:expr;
+        [ $( $mod:ident, )* ]
+        http: [ $( $http_mod:ident, )* ]
+        registry: [ $( $registry_mod:ident, )* ]
+    ) => {
         match &$name.to_lowercase()[..] {
             $(
                 $mod::FEATURE_NAME => $mod::create($id, $sender, &$settings.$mod),
             )*
-            _ => Err(Error::new_custom(
-                "create feature",
-                format!("feature {} does not exist", $name),
-            )),
+            $(
+                $http_mod::FEATURE_NAME => {
+                    $http_mod::create($id, $sender, &$settings.$http_mod, &$settings.http)
+                },
+            )*
+            $(
+                $registry_mod::FEATURE_NAME => {
+                    $registry_mod::create($id, $sender, &$settings.$registry_mod, $registry)
+                },
+            )*
+            _ => create_external_feature($id, $name, $sender, $settings),
         }
     }
 }
 
+fn create_external_feature(
+    id: usize,
+    name: &str,
+    sender: &channel::Sender<communication::Message>,
+    settings: &settings::Settings,
+) -> Result<Box<dyn feature::Feature>> {
+    if let Some(feature) = plugin::create(id, name, sender, &settings.plugin)? {
+        return Ok(feature);
+    }
+
+    library::create(id, name, sender, &settings.library)?
+        .wrap_error("create feature", format!("feature {} does not exist", name))
+}
+
 pub(super) fn create_feature(
     id: usize,
     name: &str,
     sender: &channel::Sender<communication::Message>,
+    registry: &registry::SharedRegistry,
     settings: &settings::Settings,
 ) -> Result<Box<dyn feature::Feature>> {
-    features!(id, name, sender, settings;
-        audio,
-        backlight,
-        battery,
-        cpu_load,
-        network,
-        time,
+    features!(id, name, sender, registry, settings;
+        [
+            audio,
+            backlight,
+            battery,
+            bluetooth,
+            cpu_load,
+            cpu_temp,
+            dhcp,
+            disk_usage,
+            git,
+            gpu,
+            inhibitors,
+            inodes,
+            keyboard,
+            kubernetes,
+            lan,
+            memory,
+            meter,
+            mpd,
+            nightlight,
+            pacman,
+            ports,
+            pressure,
+            rapl,
+            remote,
+            snmp,
+            throughput,
+            time,
+            tls,
+            units,
+            updates,
+            urgent,
+            vpn,
+            zram,
+        ]
+        http: [
+            ci,
+            homeassistant,
+            network,
+            speedtest,
+            tracking,
+            uptime,
+            weather,
+        ]
+        registry: [
+            composite,
+        ]
     )
 }
@@ -1,5 +1,7 @@
+mod backend;
 mod config;
 mod data;
+mod jack;
 mod notifier;
 mod updater;
 
@@ -8,6 +10,7 @@ use crate::error::*;
 use crate::feature;
 use crate::wrapper::channel;
 
+pub(self) use self::backend::Backend;
 pub(crate) use self::config::ConfigEntry;
 pub(self) use self::config::RenderConfig;
 pub(self) use self::data::Data;
@@ -15,17 +18,33 @@ pub(self) use self::notifier::Notifier;
 pub(self) use self::updater::Updater;
 
 pub(super) const FEATURE_NAME: &str = "audio";
+pub(self) const PLACEHOLDER_HEADPHONES: &str = "{Headphones}";
+pub(self) const PLACEHOLDER_JACK_RATE: &str = "{JackRate}";
+pub(self) const PLACEHOLDER_JACK_XRUNS: &str = "{JackXruns}";
+pub(self) const PLACEHOLDER_MIC_MUTE: &str = "{MicMute}";
+pub(self) const PLACEHOLDER_MIC_VOLUME: &str = "{MicVolume}";
+pub(self) const PLACEHOLDER_SINK_NAME: &str = "{SinkName}";
 
 pub(super) fn create(
     id: usize,
     sender: &channel::Sender<communication::Message>,
     settings: &ConfigEntry,
 ) -> Result<Box<dyn feature::Feature>> {
+    let backend = Backend::parse(&settings.backend)?;
     let data = Data::new(settings.render.clone());
 
     Ok(Box::new(feature::Composer::new(
         FEATURE_NAME,
-        Notifier::new(id, sender.clone()),
-        Updater::new(data, settings.clone()),
+        Notifier::new(
+            id,
+            sender.clone(),
+            backend,
+            if settings.update.show_jack {
+                settings.jack_interval
+            } else {
+                0
+            },
+        ),
+        Updater::new(backend, data, settings.clone()),
     )))
 }
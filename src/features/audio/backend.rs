@@ -0,0 +1,272 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+use crate::wrapper::pulse;
+
+const FILTER: &[char] = &['[', ']', '%'];
+const PULSE_EVENTS: u32 =
+    pulse::MASK_CARD | pulse::MASK_SERVER | pulse::MASK_SINK | pulse::MASK_SOURCE;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Control {
+    pub(super) muted: bool,
+    pub(super) name: String,
+    pub(super) volume: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Backend {
+    Alsa,
+    PulseAudio,
+}
+
+impl Backend {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "alsa" => Ok(Self::Alsa),
+            "pulseaudio" => Ok(Self::PulseAudio),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown backend {}, expected alsa or pulseaudio", name),
+            )),
+        }
+    }
+
+    pub(super) fn output(self, control: &str) -> Result<Control> {
+        match self {
+            Self::Alsa => read_amixer(control),
+            Self::PulseAudio => read_pactl("Sink", "sinks"),
+        }
+    }
+
+    pub(super) fn input(self, control: &str) -> Result<Control> {
+        match self {
+            Self::Alsa => read_amixer(control),
+            Self::PulseAudio => read_pactl("Source", "sources"),
+        }
+    }
+
+    pub(super) fn change_volume(self, control: &str, step: u32, direction: char) -> Result<()> {
+        let (program, args) = match self {
+            Self::Alsa => (
+                "amixer",
+                vec![
+                    "set".to_owned(),
+                    control.to_owned(),
+                    format!("{}%{}", step, direction),
+                ],
+            ),
+            Self::PulseAudio => (
+                "pactl",
+                vec![
+                    "set-sink-volume".to_owned(),
+                    "@DEFAULT_SINK@".to_owned(),
+                    format!("{}{}%", direction, step),
+                ],
+            ),
+        };
+
+        process::Command::new(
+            program,
+            &args.iter().map(String::as_str).collect::<Vec<_>>(),
+        )
+        .output()
+        .wrap_error(FEATURE_NAME, "volume could not be changed")?;
+
+        Ok(())
+    }
+
+    pub(super) fn monitor<F>(self, handle_change: F) -> Result<()>
+    where
+        F: Fn() -> Result<()>,
+    {
+        match self {
+            Self::Alsa => process::Command::new("stdbuf", &["-oL", "alsactl", "monitor"])
+                .listen_stdout(handle_change),
+            Self::PulseAudio => pulse::subscribe(PULSE_EVENTS, handle_change),
+        }
+    }
+}
+
+fn read_amixer(control: &str) -> Result<Control> {
+    // originally taken from https://github.com/greshake/i3status-rust/blob/master/src/blocks/sound.rs
+    let output = process::Command::new("amixer", &["get", control])
+        .output()
+        .wrap_error(
+            FEATURE_NAME,
+            format!("amixer info for control '{}' could not be fetched", control),
+        )?;
+
+    let last_line = &output
+        .lines()
+        .last()
+        .wrap_error(FEATURE_NAME, "empty amixer output")?;
+
+    let last = last_line
+        .split_whitespace()
+        .filter(|x| x.starts_with('[') && !x.contains("dB"))
+        .map(|s| s.trim_matches(FILTER))
+        .collect::<Vec<_>>();
+
+    let muted = last.get(1).map_or(false, |muted| *muted == "off");
+
+    let volume = last
+        .get(0)
+        .map(|volume| volume.parse())
+        .transpose()
+        .wrap_error(FEATURE_NAME, "volume not parsable")?;
+
+    Ok(Control {
+        muted,
+        name: control.to_owned(),
+        volume,
+    })
+}
+
+fn read_pactl(kind: &str, list: &str) -> Result<Control> {
+    let info = pactl(&["info"])?;
+
+    let name = parse_default(&info, kind)
+        .wrap_error(FEATURE_NAME, format!("no default {} found", list))?;
+
+    let devices = pactl(&["list", list])?;
+
+    parse_device(&devices, &name).wrap_error(
+        FEATURE_NAME,
+        format!("default {} {} not found", kind.to_lowercase(), name),
+    )
+}
+
+fn pactl(args: &[&str]) -> Result<String> {
+    process::Command::new("pactl", args)
+        .env("LC_ALL", "C")
+        .output()
+        .wrap_error(FEATURE_NAME, format!("pactl {} failed", args.join(" ")))
+}
+
+fn parse_default(info: &str, kind: &str) -> Option<String> {
+    let prefix = format!("Default {}: ", kind);
+
+    info.lines()
+        .find(|line| line.starts_with(&prefix))
+        .map(|line| line[prefix.len()..].trim().to_owned())
+}
+
+fn parse_device(list: &str, name: &str) -> Option<Control> {
+    let name_line = format!("Name: {}", name);
+    let mut found = false;
+    let mut description = None;
+    let mut muted = false;
+    let mut volume = None;
+
+    for line in list.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            if found {
+                break;
+            }
+
+            continue;
+        }
+
+        let line = line.trim();
+
+        if !found {
+            found = line == name_line;
+        } else if line.starts_with("Description: ") {
+            description = Some(line["Description: ".len()..].to_owned());
+        } else if line.starts_with("Mute: ") {
+            muted = &line["Mute: ".len()..] == "yes";
+        } else if line.starts_with("Volume: ") && volume.is_none() {
+            volume = line
+                .split_whitespace()
+                .find(|part| part.ends_with('%'))
+                .and_then(|part| part.trim_end_matches('%').parse().ok());
+        }
+    }
+
+    if !found {
+        return None;
+    }
+
+    Some(Control {
+        muted,
+        name: description.unwrap_or_else(|| name.to_owned()),
+        volume,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const SINKS: &str = "\
+Sink #0
+\tState: SUSPENDED
+\tName: alsa_output.pci-0000_00_1f.3.analog-stereo
+\tDescription: Built-in Audio Analog Stereo
+\tMute: no
+\tVolume: front-left: 32768 /  50% / -18.06 dB,   front-right: 32768 /  50% / -18.06 dB
+\t        balance 0.00
+\tBase Volume: 65536 / 100% / 0.00 dB
+
+Sink #1
+\tState: RUNNING
+\tName: alsa_output.usb-Dock-00.analog-stereo
+\tDescription: USB Dock Analog Stereo
+\tMute: yes
+\tVolume: front-left: 49152 /  75% / -7.50 dB,   front-right: 49152 /  75% / -7.50 dB
+\t        balance 0.00
+";
+
+    #[test]
+    fn parse_default_sink() {
+        let info = "\
+Server Name: PulseAudio (on PipeWire 1.0.5)
+Default Sink: alsa_output.usb-Dock-00.analog-stereo
+Default Source: alsa_input.pci-0000_00_1f.3.analog-stereo
+";
+
+        assert_that!(
+            parse_default(info, "Sink"),
+            is(equal_to(Some(
+                "alsa_output.usb-Dock-00.analog-stereo".to_owned()
+            )))
+        );
+        assert_that!(
+            parse_default("Server Name: PulseAudio\n", "Sink"),
+            is(none())
+        );
+    }
+
+    #[test]
+    fn parse_device_of_list() {
+        assert_that!(
+            parse_device(SINKS, "alsa_output.pci-0000_00_1f.3.analog-stereo"),
+            is(equal_to(Some(Control {
+                muted: false,
+                name: "Built-in Audio Analog Stereo".to_owned(),
+                volume: Some(50),
+            })))
+        );
+        assert_that!(
+            parse_device(SINKS, "alsa_output.usb-Dock-00.analog-stereo"),
+            is(equal_to(Some(Control {
+                muted: true,
+                name: "USB Dock Analog Stereo".to_owned(),
+                volume: Some(75),
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_device_missing_in_list() {
+        assert_that!(parse_device(SINKS, "bluez_output.00_11_22"), is(none()));
+    }
+
+    #[test]
+    fn parse_unknown_backend() {
+        assert_that!(Backend::parse("oss").is_err(), is(true));
+    }
+}
@@ -1,22 +1,54 @@
 use super::FEATURE_NAME;
+use super::PLACEHOLDER_HEADPHONES;
+use super::PLACEHOLDER_JACK_RATE;
+use super::PLACEHOLDER_JACK_XRUNS;
+use super::PLACEHOLDER_MIC_MUTE;
+use super::PLACEHOLDER_MIC_VOLUME;
 use crate::error::*;
+use crate::notification::NotificationConfig;
 use crate::settings::ConfigType;
 use crate::wrapper::config;
 use crate::wrapper::config::Value;
 use serde_derive::*;
 
+const NOTIFICATION_HEADPHONES_BODY: &str = "";
+const NOTIFICATION_HEADPHONES_SUMMARY: &str = "Headphones {STATE}";
+const NOTIFICATION_VOLUME_BODY: &str = "";
+const NOTIFICATION_VOLUME_SUMMARY: &str = "Volume {STATE}";
+
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct RenderConfig {
+    pub(super) headphones: String,
     pub(super) icons: Vec<String>,
+    pub(super) jack_no_value: String,
+    pub(super) mic_mute: String,
     pub(super) mute: String,
     pub(super) template: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(super) struct UpdateConfig {
+    pub(super) show_headphones: bool,
+    pub(super) show_jack: bool,
+    pub(super) show_mic: bool,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ConfigEntry {
+    pub(super) backend: String,
     pub(super) control: String,
+    pub(super) headphones_control: String,
+    pub(super) headphones_notifier: bool,
+    pub(super) jack_interval: u64,
+    pub(super) mic_control: String,
+    pub(super) notification_headphones: NotificationConfig,
+    pub(super) notification_volume: NotificationConfig,
     #[serde(flatten)]
     pub(super) render: RenderConfig,
+    pub(super) scroll_step: u32,
+    #[doc(hidden)]
+    pub(super) update: UpdateConfig,
+    pub(super) volume_notifier: bool,
 }
 
 impl ConfigType for ConfigEntry {
@@ -24,21 +56,83 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
-                "control"  => "Master",
-                "icons"    => Vec::<String>::new(),
-                "mute"     => "MUTE",
-                "template" => "S {VOL}%",
+                "backend"                 => "alsa",
+                "control"                 => "Master",
+                "headphones"              => "HP",
+                "headphones_control"      => "Headphone Jack",
+                "headphones_notifier"     => false,
+                "icons"                   => Vec::<String>::new(),
+                "jack_interval"           => 10,
+                "jack_no_value"           => "NA",
+                "mic_control"             => "Capture",
+                "mic_mute"                => "MIC MUTE",
+                "mute"                    => "MUTE",
+                "notification_headphones" => NotificationConfig::default_map(
+                    NOTIFICATION_HEADPHONES_SUMMARY,
+                    NOTIFICATION_HEADPHONES_BODY,
+                    "low",
+                ),
+                "notification_volume"     => NotificationConfig::default_map(
+                    NOTIFICATION_VOLUME_SUMMARY,
+                    NOTIFICATION_VOLUME_BODY,
+                    "low",
+                ),
+                "scroll_step"             => 5,
+                "template"                => "S {VOL}%",
+                "volume_notifier"         => false,
             ),
         )
     }
+
+    fn set_values(config: &mut config::Config) -> Result<()> {
+        let template = config.get_str(&format!("{}.template", FEATURE_NAME))?;
+        let mute = config.get_str(&format!("{}.mute", FEATURE_NAME))?;
+        let texts = [template, mute];
+
+        set_update_config(
+            config,
+            &texts,
+            &[PLACEHOLDER_MIC_MUTE, PLACEHOLDER_MIC_VOLUME],
+            "mic",
+        )?;
+        set_update_config(
+            config,
+            &texts,
+            &[PLACEHOLDER_JACK_RATE, PLACEHOLDER_JACK_XRUNS],
+            "jack",
+        )?;
+        set_update_config(config, &texts, &[PLACEHOLDER_HEADPHONES], "headphones")?;
+
+        Ok(())
+    }
+}
+
+fn set_update_config(
+    config: &mut config::Config,
+    texts: &[String],
+    placeholders: &[&str],
+    key: &str,
+) -> Result<()> {
+    config.set(
+        &format!("{}.update.show_{}", FEATURE_NAME, key),
+        texts.iter().any(|text| {
+            placeholders
+                .iter()
+                .any(|placeholder| text.contains(placeholder))
+        }),
+    )
 }
 
 #[cfg(test)]
 #[cfg(feature = "mocking")]
 mod tests {
     use super::*;
+    use crate::test_utils::config::notification_map;
     use crate::test_utils::config::test_set_default_err;
     use crate::test_utils::config::test_set_default_ok;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use mocktopus::mocking::*;
     use std::collections::HashMap;
 
     #[test]
@@ -53,11 +147,134 @@ mod tests {
 
     fn default_map() -> HashMap<String, Value> {
         let mut map = HashMap::new();
+        map.insert("backend".to_owned(), "alsa".into());
         map.insert("control".to_owned(), "Master".into());
+        map.insert("headphones".to_owned(), "HP".into());
+        map.insert("headphones_control".to_owned(), "Headphone Jack".into());
+        map.insert("headphones_notifier".to_owned(), false.into());
         map.insert("icons".to_owned(), Vec::<String>::new().into());
+        map.insert("jack_interval".to_owned(), 10.into());
+        map.insert("jack_no_value".to_owned(), "NA".into());
+        map.insert("mic_control".to_owned(), "Capture".into());
+        map.insert("mic_mute".to_owned(), "MIC MUTE".into());
         map.insert("mute".to_owned(), "MUTE".into());
+        map.insert(
+            "notification_headphones".to_owned(),
+            notification_map("Headphones {STATE}", "", "low").into(),
+        );
+        map.insert(
+            "notification_volume".to_owned(),
+            notification_map("Volume {STATE}", "", "low").into(),
+        );
+        map.insert("scroll_step".to_owned(), 5.into());
         map.insert("template".to_owned(), "S {VOL}%".into());
+        map.insert("volume_notifier".to_owned(), false.into());
 
         map
     }
+
+    #[test]
+    fn config_type_set_values_with_mic_volume_in_template() {
+        test_set_values("S {VOL}% M {MicVolume}%", "MUTE", true, false, false);
+    }
+
+    #[test]
+    fn config_type_set_values_with_mic_mute_in_mute() {
+        test_set_values("S {VOL}%", "MUTE {MicMute}", true, false, false);
+    }
+
+    #[test]
+    fn config_type_set_values_without_mic() {
+        test_set_values("S {VOL}%", "MUTE", false, false, false);
+    }
+
+    #[test]
+    fn config_type_set_values_with_jack_in_template() {
+        test_set_values(
+            "S {VOL}% {JackRate}Hz {JackXruns}",
+            "MUTE",
+            false,
+            true,
+            false,
+        );
+    }
+
+    #[test]
+    fn config_type_set_values_with_headphones_in_mute() {
+        test_set_values("S {VOL}%", "MUTE {Headphones}", false, false, true);
+    }
+
+    #[test]
+    fn config_type_set_values_when_err_in_get_str() {
+        config::Config::get_str.mock_safe(|_, key| {
+            assert_that!(key, is(equal_to("audio.template")));
+            MockResult::Return(Err(Error::new_custom("name", "description")))
+        });
+
+        let mut config = config::Config::new();
+
+        assert_that!(
+            ConfigEntry::set_values(&mut config),
+            is(equal_to(Err(Error::new_custom("name", "description"))))
+        );
+    }
+
+    #[test]
+    fn config_type_set_values_when_err_in_set() {
+        config::Config::get_str.mock_safe(|_, _| MockResult::Return(Ok("template".to_owned())));
+
+        config::Config::set::<bool>.mock_safe(|_, key, value| {
+            assert_that!(key, is(equal_to("audio.update.show_mic")));
+            assert_that!(value, is(equal_to(false)));
+            MockResult::Return(Err(Error::new_custom("name", "description")))
+        });
+
+        let mut config = config::Config::new();
+
+        assert_that!(
+            ConfigEntry::set_values(&mut config),
+            is(equal_to(Err(Error::new_custom("name", "description"))))
+        );
+    }
+
+    fn test_set_values(
+        template: &'static str,
+        mute: &'static str,
+        show_mic: bool,
+        show_jack: bool,
+        show_headphones: bool,
+    ) {
+        config::Config::get_str.mock_safe(move |_, key| match key {
+            "audio.template" => MockResult::Return(Ok(template.to_owned())),
+            "audio.mute" => MockResult::Return(Ok(mute.to_owned())),
+            _ => panic!("unexpected key {}", key),
+        });
+
+        let mut counter = 0;
+        config::Config::set::<bool>.mock_safe(move |_, key, value| {
+            counter += 1;
+
+            match counter {
+                1 => {
+                    assert_that!(key, is(equal_to("audio.update.show_mic")));
+                    assert_that!(value, is(equal_to(show_mic)));
+                },
+                2 => {
+                    assert_that!(key, is(equal_to("audio.update.show_jack")));
+                    assert_that!(value, is(equal_to(show_jack)));
+                },
+                3 => {
+                    assert_that!(key, is(equal_to("audio.update.show_headphones")));
+                    assert_that!(value, is(equal_to(show_headphones)));
+                },
+                _ => panic!("set called to often: {} times", counter),
+            }
+
+            MockResult::Return(Ok(()))
+        });
+
+        let mut config = config::Config::new();
+
+        assert_that!(ConfigEntry::set_values(&mut config), is(equal_to(Ok(()))));
+    }
 }
@@ -1,10 +1,23 @@
+use super::PLACEHOLDER_HEADPHONES;
+use super::PLACEHOLDER_JACK_RATE;
+use super::PLACEHOLDER_JACK_XRUNS;
+use super::PLACEHOLDER_MIC_MUTE;
+use super::PLACEHOLDER_MIC_VOLUME;
+use super::PLACEHOLDER_SINK_NAME;
 use super::RenderConfig;
+use super::jack::JackStatus;
 use crate::feature::Renderable;
+use crate::feature::Values;
 use crate::utils::icon_by_percentage;
 
 pub(super) struct Data {
     cache: String,
     config: RenderConfig,
+    headphones: Option<bool>,
+    jack: Option<JackStatus>,
+    mic: Option<(bool, u32)>,
+    sink: Option<String>,
+    values: Values,
 }
 
 impl Data {
@@ -13,17 +26,39 @@ impl Data {
         Self {
             cache: String::new(),
             config,
+            headphones: None,
+            jack: None,
+            mic: None,
+            sink: None,
+            values: Values::new(),
         }
     }
 
+    pub(super) fn update_mic(&mut self, muted: bool, volume: u32) {
+        self.mic = Some((muted, volume));
+    }
+
+    pub(super) fn update_headphones(&mut self, plugged: bool) {
+        self.headphones = Some(plugged);
+    }
+
+    pub(super) fn update_sink(&mut self, name: String) {
+        self.sink = Some(name);
+    }
+
+    pub(super) fn update_jack(&mut self, status: Option<JackStatus>) {
+        self.jack = status;
+    }
+
     pub(super) fn update_mute(&mut self) {
-        self.cache = self.config.mute.clone()
+        self.cache = self.replace_placeholders(&self.config.mute);
+        self.values = map_values!("MUTE" => "true");
+        self.insert_values();
     }
 
     pub(super) fn update_volume(&mut self, volume: u32) {
         let mut rendered = self
-            .config
-            .template
+            .replace_placeholders(&self.config.template)
             .replace("{VOL}", &format!("{}", volume));
 
         if let Some(icon) = icon_by_percentage(&self.config.icons, volume) {
@@ -31,6 +66,62 @@ impl Data {
         }
 
         self.cache = rendered;
+        self.values = map_values!("MUTE" => "false", "VOL" => volume);
+        self.insert_values();
+    }
+
+    fn replace_placeholders(&self, text: &str) -> String {
+        let text = text.replace(
+            PLACEHOLDER_HEADPHONES,
+            if self.headphones == Some(true) {
+                &self.config.headphones
+            } else {
+                ""
+            },
+        );
+        let text = match self.jack {
+            Some(status) => text
+                .replace(PLACEHOLDER_JACK_RATE, &status.sample_rate.to_string())
+                .replace(PLACEHOLDER_JACK_XRUNS, &status.xruns.to_string()),
+            None => text
+                .replace(PLACEHOLDER_JACK_RATE, &self.config.jack_no_value)
+                .replace(PLACEHOLDER_JACK_XRUNS, &self.config.jack_no_value),
+        };
+        let text = text.replace(
+            PLACEHOLDER_SINK_NAME,
+            self.sink.as_ref().map_or("", String::as_str),
+        );
+
+        match self.mic {
+            Some((muted, volume)) => text
+                .replace(
+                    PLACEHOLDER_MIC_MUTE,
+                    if muted { &self.config.mic_mute } else { "" },
+                )
+                .replace(PLACEHOLDER_MIC_VOLUME, &format!("{}", volume)),
+            None => text,
+        }
+    }
+
+    fn insert_values(&mut self) {
+        if let Some(plugged) = self.headphones {
+            self.values.insert("HEADPHONES", plugged.to_string());
+        }
+
+        if let Some((muted, volume)) = self.mic {
+            self.values.insert("MIC_MUTE", muted.to_string());
+            self.values.insert("MIC_VOL", volume.to_string());
+        }
+
+        if let Some(status) = self.jack {
+            self.values
+                .insert("JACK_RATE", status.sample_rate.to_string());
+            self.values.insert("JACK_XRUNS", status.xruns.to_string());
+        }
+
+        if let Some(sink) = &self.sink {
+            self.values.insert("SINK_NAME", sink.clone());
+        }
     }
 }
 
@@ -38,6 +129,10 @@ impl Renderable for Data {
     fn render(&self) -> &str {
         &self.cache
     }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
 }
 
 #[cfg(test)]
@@ -51,7 +146,10 @@ mod tests {
     #[test]
     fn render_with_default() {
         let config = RenderConfig {
+            headphones: "HP".to_owned(),
             icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
             mute: "MUTE".to_owned(),
             template: "TEMPLATE".to_owned(),
         };
@@ -64,7 +162,10 @@ mod tests {
     #[test]
     fn render_with_mute() {
         let config = RenderConfig {
+            headphones: "HP".to_owned(),
             icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
             mute: "MUTE".to_owned(),
             template: "TEMPLATE".to_owned(),
         };
@@ -80,7 +181,10 @@ mod tests {
     #[test]
     fn render_with_volume() {
         let config = RenderConfig {
+            headphones: "HP".to_owned(),
             icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
             mute: "MUTE".to_owned(),
             template: "TEMPLATE {VOL} {ICO}".to_owned(),
         };
@@ -103,7 +207,10 @@ mod tests {
     #[test]
     fn render_with_volume_and_icon() {
         let config = RenderConfig {
+            headphones: "HP".to_owned(),
             icons: vec!["ico1".to_owned(), "ico2".to_owned()],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
             mute: "MUTE".to_owned(),
             template: "TEMPLATE {VOL} {ICO}".to_owned(),
         };
@@ -122,4 +229,168 @@ mod tests {
 
         assert_that!(object.render(), is(equal_to("TEMPLATE 10 ICON")));
     }
+
+    #[test]
+    fn values_with_mute() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE".to_owned(),
+            template: "TEMPLATE".to_owned(),
+        };
+
+        let mut object = Data::new(config);
+
+        object.update_mute();
+
+        assert_that!(object.values(), is(equal_to(map_values!("MUTE" => "true"))));
+    }
+
+    #[test]
+    fn render_with_mute_and_mic() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE {MicVolume}%{MicMute}".to_owned(),
+            template: "TEMPLATE".to_owned(),
+        };
+
+        let mut object = Data::new(config);
+
+        object.update_mic(false, 40);
+        object.update_mute();
+
+        assert_that!(object.render(), is(equal_to("MUTE 40%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(
+                map_values!("MIC_MUTE" => "false", "MIC_VOL" => "40", "MUTE" => "true")
+            ))
+        );
+    }
+
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_volume_and_muted_mic() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE".to_owned(),
+            template: "S {VOL}% M {MicVolume}% {MicMute}".to_owned(),
+        };
+
+        icon_by_percentage.mock_safe(|_, _| MockResult::Return(None));
+
+        let mut object = Data::new(config);
+
+        object.update_mic(true, 40);
+        object.update_volume(10);
+
+        assert_that!(object.render(), is(equal_to("S 10% M 40% MIC MUTE")));
+    }
+
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_volume_and_jack() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE".to_owned(),
+            template: "S {VOL}% {JackRate}Hz {JackXruns}".to_owned(),
+        };
+
+        icon_by_percentage.mock_safe(|_, _| MockResult::Return(None));
+
+        let mut object = Data::new(config);
+
+        object.update_jack(Some(JackStatus {
+            sample_rate: 48000,
+            xruns: 2,
+        }));
+        object.update_volume(10);
+
+        assert_that!(object.render(), is(equal_to("S 10% 48000Hz 2")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "JACK_RATE" => "48000",
+                "JACK_XRUNS" => "2",
+                "MUTE" => "false",
+                "VOL" => "10",
+            )))
+        );
+
+        object.update_jack(None);
+        object.update_volume(10);
+
+        assert_that!(object.render(), is(equal_to("S 10% NAHz NA")));
+    }
+
+    #[test]
+    fn render_with_mute_and_headphones() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE {Headphones}".to_owned(),
+            template: "TEMPLATE".to_owned(),
+        };
+
+        let mut object = Data::new(config);
+
+        object.update_headphones(true);
+        object.update_mute();
+
+        assert_that!(object.render(), is(equal_to("MUTE HP")));
+        assert_that!(
+            object.values(),
+            is(equal_to(
+                map_values!("HEADPHONES" => "true", "MUTE" => "true")
+            ))
+        );
+
+        object.update_headphones(false);
+        object.update_mute();
+
+        assert_that!(object.render(), is(equal_to("MUTE ")));
+    }
+
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_volume_and_sink_name() {
+        let config = RenderConfig {
+            headphones: "HP".to_owned(),
+            icons: vec![],
+            jack_no_value: "NA".to_owned(),
+            mic_mute: "MIC MUTE".to_owned(),
+            mute: "MUTE".to_owned(),
+            template: "{SinkName} {VOL}%".to_owned(),
+        };
+
+        icon_by_percentage.mock_safe(|_, _| MockResult::Return(None));
+
+        let mut object = Data::new(config);
+
+        object.update_sink("USB Dock Analog Stereo".to_owned());
+        object.update_volume(10);
+
+        assert_that!(object.render(), is(equal_to("USB Dock Analog Stereo 10%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "MUTE" => "false",
+                "SINK_NAME" => "USB Dock Analog Stereo",
+                "VOL" => "10",
+            )))
+        );
+    }
 }
@@ -0,0 +1,136 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::dbus;
+use crate::wrapper::process;
+use log::debug;
+
+const INTERFACE_DBUS: &str = "org.freedesktop.DBus";
+const INTERFACE_JACK_CONTROL: &str = "org.jackaudio.JackControl";
+const PATH_DBUS: &str = "/org/freedesktop/DBus";
+const PATH_JACK_CONTROLLER: &str = "/org/jackaudio/Controller";
+const SERVICE_DBUS: &str = "org.freedesktop.DBus";
+const SERVICE_JACK: &str = "org.jackaudio.service";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct JackStatus {
+    pub(super) sample_rate: u32,
+    pub(super) xruns: u32,
+}
+
+pub(super) fn status() -> Option<JackStatus> {
+    match jackdbus_status() {
+        Ok(Some(status)) => return Some(status),
+        Ok(None) => {},
+        Err(error) => debug!("{}", error),
+    }
+
+    match pipewire_status() {
+        Ok(status) => status,
+        Err(error) => {
+            debug!("{}", error);
+            None
+        },
+    }
+}
+
+fn jackdbus_status() -> Result<Option<JackStatus>> {
+    let connection = dbus::Connection::init_session()?;
+
+    let message =
+        dbus::Message::init_method_call(SERVICE_DBUS, PATH_DBUS, INTERFACE_DBUS, "NameHasOwner")?
+            .append(SERVICE_JACK);
+
+    if !connection.send_message(message)?.return_value::<bool>()? {
+        return Ok(None);
+    }
+
+    if !call_jack_control(&connection, "IsStarted")?.return_value::<bool>()? {
+        return Ok(None);
+    }
+
+    Ok(Some(JackStatus {
+        sample_rate: call_jack_control(&connection, "GetSampleRate")?.return_value()?,
+        xruns: call_jack_control(&connection, "GetXruns")?.return_value()?,
+    }))
+}
+
+fn call_jack_control(connection: &dbus::Connection, method: &'static str) -> Result<dbus::Message> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_JACK,
+        PATH_JACK_CONTROLLER,
+        INTERFACE_JACK_CONTROL,
+        method,
+    )?;
+
+    connection.send_message(message)
+}
+
+fn pipewire_status() -> Result<Option<JackStatus>> {
+    let output = process::Command::new("pw-top", &["-b", "-n", "1"])
+        .output()
+        .wrap_error(FEATURE_NAME, "status of PipeWire could not be fetched")?;
+
+    Ok(parse_pw_top(&output))
+}
+
+fn parse_pw_top(output: &str) -> Option<JackStatus> {
+    let drivers = output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns = line.split_whitespace().collect::<Vec<_>>();
+
+            if columns.iter().skip(9).any(|column| *column == "+") {
+                return None;
+            }
+
+            let rate = columns.get(3)?.parse::<u32>().ok()?;
+            let xruns = columns.get(8)?.parse::<u32>().ok()?;
+
+            if rate == 0 { None } else { Some((rate, xruns)) }
+        })
+        .collect::<Vec<_>>();
+
+    let (sample_rate, _) = drivers.first()?;
+
+    Some(JackStatus {
+        sample_rate: *sample_rate,
+        xruns: drivers.iter().map(|(_, xruns)| xruns).sum(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_pw_top_with_drivers() {
+        let output = "\
+S   ID  QUANT   RATE    WAIT    BUSY   W/Q   B/Q  ERR FORMAT           NAME
+S   28      0      0    ---     ---   ---   ---     0                  Dummy-Driver
+R   47   1024  48000  44.5us  27.0us  0.00  0.00    3    S32LE 2 48000 alsa_output.pci-0000_00_1f.3
+R   70   1024  48000  26.3us  22.9us  0.00  0.00    1    F32LE 2 48000  + Firefox
+R   52    256  44100  12.0us   8.1us  0.00  0.00    2    S16LE 2 44100 alsa_output.usb-interface
+";
+
+        assert_that!(
+            parse_pw_top(output),
+            is(equal_to(Some(JackStatus {
+                sample_rate: 48000,
+                xruns: 5,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_pw_top_without_drivers() {
+        let output = "\
+S   ID  QUANT   RATE    WAIT    BUSY   W/Q   B/Q  ERR FORMAT           NAME
+S   28      0      0    ---     ---   ---   ---     0                  Dummy-Driver
+";
+
+        assert_that!(parse_pw_top(output), is(equal_to(None)));
+    }
+}
@@ -1,24 +1,55 @@
+use super::Backend;
 use crate::communication;
 use crate::error::*;
 use crate::wrapper::channel;
-use crate::wrapper::process;
 use crate::wrapper::thread;
+use std::sync::Once;
 
 pub(super) struct Notifier {
+    backend: Backend,
     id: usize,
+    jack_interval: u64,
     sender: channel::Sender<communication::Message>,
+    ticker: Once,
 }
 
 impl Notifier {
-    pub(super) const fn new(id: usize, sender: channel::Sender<communication::Message>) -> Self {
-        Self { id, sender }
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        backend: Backend,
+        jack_interval: u64,
+    ) -> Self {
+        Self {
+            backend,
+            id,
+            jack_interval,
+            sender,
+            ticker: Once::new(),
+        }
+    }
+
+    fn start_ticker(&self) -> Result<()> {
+        let mut result = Ok(());
+
+        if self.jack_interval > 0 {
+            self.ticker.call_once(|| {
+                let ticker =
+                    communication::Ticker::new(self.id, self.sender.clone(), self.jack_interval);
+
+                result = thread::Thread::new("audio jack", ticker).run();
+            });
+        }
+
+        result
     }
 }
 
 impl thread::Runnable for Notifier {
     fn run(&self) -> Result<()> {
-        let command = process::Command::new("stdbuf", &["-oL", "alsactl", "monitor"]);
+        self.start_ticker()?;
 
-        command.listen_stdout(|| communication::send_message(self.id, &self.sender))
+        self.backend
+            .monitor(|| communication::send_message(self.id, &self.sender))
     }
 }
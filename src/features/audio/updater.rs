@@ -1,20 +1,87 @@
+use super::Backend;
 use super::ConfigEntry;
 use super::Data;
 use super::FEATURE_NAME;
+use super::jack;
+use crate::click;
 use crate::error::*;
 use crate::feature;
+use crate::notification;
 use crate::wrapper::process;
 
-const FILTER: &[char] = &['[', ']', '%'];
+const PLACEHOLDER_STATE: &str = "{STATE}";
+const PLACEHOLDER_VOLUME: &str = "{VOL}";
 
 pub(super) struct Updater {
+    backend: Backend,
     data: Data,
+    headphones: Option<bool>,
+    notifier: notification::LazyNotifier,
     settings: ConfigEntry,
+    volume: Option<(bool, Option<u32>)>,
+    volume_notifier: notification::LazyNotifier,
 }
 
 impl Updater {
-    pub(super) const fn new(data: Data, settings: ConfigEntry) -> Self {
-        Self { data, settings }
+    pub(super) fn new(backend: Backend, data: Data, settings: ConfigEntry) -> Self {
+        Self {
+            backend,
+            data,
+            headphones: None,
+            notifier: notification::LazyNotifier::default(),
+            settings,
+            volume: None,
+            volume_notifier: notification::LazyNotifier::default(),
+        }
+    }
+
+    fn update_headphones(&mut self) -> Result<()> {
+        let plugged = read_switch(&self.settings.headphones_control)?;
+
+        if self.settings.headphones_notifier
+            && self.headphones.map_or(false, |last| last != plugged)
+        {
+            self.notify_headphones(plugged).show_error_and_ignore();
+        }
+
+        self.headphones = Some(plugged);
+        self.data.update_headphones(plugged);
+
+        Ok(())
+    }
+
+    fn notify_headphones(&mut self, plugged: bool) -> Result<()> {
+        let notifier = self.notifier.get()?;
+
+        notifier.send(
+            &self.settings.notification_headphones,
+            &[(
+                PLACEHOLDER_STATE,
+                if plugged { "plugged in" } else { "unplugged" }.to_owned(),
+            )],
+        )
+    }
+
+    fn notify_volume(&mut self, muted: bool, volume: Option<u32>) -> Result<()> {
+        let notifier = self.volume_notifier.get()?;
+
+        let volume = volume.unwrap_or(0);
+
+        notifier.send_with_progress(
+            &self.settings.notification_volume,
+            &[
+                (
+                    PLACEHOLDER_STATE,
+                    if muted {
+                        "muted".to_owned()
+                    } else {
+                        format!("{}%", volume)
+                    },
+                ),
+                (PLACEHOLDER_VOLUME, volume.to_string()),
+            ],
+            if muted { 0 } else { volume },
+        )
     }
 }
 
@@ -24,40 +91,108 @@ impl feature::Updatable for Updater {
     }
 
     fn update(&mut self) -> Result<()> {
-        // originally taken from https://github.com/greshake/i3status-rust/blob/master/src/blocks/sound.rs
-        let output = process::Command::new("amixer", &["get", &self.settings.control])
-            .output()
-            .wrap_error(
-                FEATURE_NAME,
-                format!(
-                    "amixer info for control '{}' could not be fetched",
-                    &self.settings.control,
-                ),
-            )?;
-
-        let last_line = &output
-            .lines()
-            .last()
-            .wrap_error(FEATURE_NAME, "empty amixer output")?;
-
-        let last = last_line
-            .split_whitespace()
-            .filter(|x| x.starts_with('[') && !x.contains("dB"))
-            .map(|s| s.trim_matches(FILTER))
-            .collect::<Vec<_>>();
-
-        if last.get(1).map_or(false, |muted| *muted == "off") {
-            self.data.update_mute();
-        } else {
-            let volume = last
-                .get(0)
-                .wrap_error(FEATURE_NAME, "no volume part found")?
-                .parse()
-                .wrap_error(FEATURE_NAME, "volume not parsable")?;
-
-            self.data.update_volume(volume);
+        if self.settings.update.show_headphones || self.settings.headphones_notifier {
+            self.update_headphones()?;
+        }
+
+        if self.settings.update.show_jack {
+            self.data.update_jack(jack::status());
+        }
+
+        if self.settings.update.show_mic {
+            let mic = self.backend.input(&self.settings.mic_control)?;
+
+            self.data.update_mic(
+                mic.muted,
+                mic.volume
+                    .wrap_error(FEATURE_NAME, "no microphone volume part found")?,
+            );
+        }
+
+        let output = self.backend.output(&self.settings.control)?;
+        let control = (output.muted, output.volume);
+
+        if self.settings.volume_notifier && self.volume.map_or(false, |last| last != control) {
+            let (muted, volume) = control;
+            self.notify_volume(muted, volume).show_error_and_ignore();
+        }
+
+        self.volume = Some(control);
+        self.data.update_sink(output.name);
+
+        match control {
+            (true, _) => self.data.update_mute(),
+            (false, volume) => self
+                .data
+                .update_volume(volume.wrap_error(FEATURE_NAME, "no volume part found")?),
         }
 
         Ok(())
     }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        let direction = match click::Button::from_number(button)? {
+            click::Button::ScrollUp => '+',
+            click::Button::ScrollDown => '-',
+            _ => return Ok(()),
+        };
+
+        self.backend
+            .change_volume(&self.settings.control, self.settings.scroll_step, direction)
+    }
+}
+
+fn read_switch(control: &str) -> Result<bool> {
+    let output = process::Command::new(
+        "amixer",
+        &["cget", &format!("iface=CARD,name='{}'", control)],
+    )
+    .output()
+    .wrap_error(
+        FEATURE_NAME,
+        format!("amixer info for control '{}' could not be fetched", control),
+    )?;
+
+    parse_switch(&output).wrap_error(
+        FEATURE_NAME,
+        format!("no switch value of control '{}' found", control),
+    )
+}
+
+fn parse_switch(output: &str) -> Option<bool> {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with(": values="))
+        .map(|line| line.trim_start_matches(": values=") == "on")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_switch_with_values() {
+        let output = "\
+numid=18,iface=CARD,name='Headphone Jack'
+  ; type=BOOLEAN,access=r-------,values=1
+  : values=on
+";
+
+        assert_that!(parse_switch(output), is(equal_to(Some(true))));
+        assert_that!(
+            parse_switch(&output.replace("=on", "=off")),
+            is(equal_to(Some(false)))
+        );
+    }
+
+    #[test]
+    fn parse_switch_without_values() {
+        assert_that!(
+            parse_switch("amixer: Cannot find the given element from control default"),
+            is(equal_to(None))
+        );
+    }
 }
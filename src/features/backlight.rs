@@ -8,6 +8,7 @@ use crate::communication;
 use crate::error::*;
 use crate::feature;
 use crate::wrapper::channel;
+use std::iter;
 
 pub(crate) use self::config::ConfigEntry;
 pub(self) use self::config::RenderConfig;
@@ -17,6 +18,7 @@ pub(self) use self::notifier::Notifier;
 pub(self) use self::updater::Updater;
 
 pub(super) const FEATURE_NAME: &str = "backlight";
+pub(self) const PLACEHOLDER_KEYBOARD: &str = "{Keyboard}";
 
 pub(super) fn create(
     id: usize,
@@ -24,11 +26,21 @@ pub(super) fn create(
     settings: &ConfigEntry,
 ) -> Result<Box<dyn feature::Feature>> {
     let data = Data::new(settings.render.clone());
-    let device = BacklightDevice::init(&settings.device)?;
+    let device = BacklightDevice::init(&settings.device, settings.perceptual)?;
+    let keyboard = if settings.update.show_keyboard {
+        Some(BacklightDevice::init_keyboard()?)
+    } else {
+        None
+    };
+
+    let brightness_files = iter::once(&device)
+        .chain(keyboard.as_ref())
+        .map(BacklightDevice::brightness_file)
+        .collect();
 
     Ok(Box::new(feature::Composer::new(
         FEATURE_NAME,
-        Notifier::new(id, sender.clone(), device.brightness_file()),
-        Updater::new(data, device),
+        Notifier::new(id, sender.clone(), brightness_files),
+        Updater::new(data, device, keyboard, settings.scroll_step),
     )))
 }
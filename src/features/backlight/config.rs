@@ -1,4 +1,5 @@
 use super::FEATURE_NAME;
+use super::PLACEHOLDER_KEYBOARD;
 use crate::error::*;
 use crate::settings::ConfigType;
 use crate::wrapper::config;
@@ -11,11 +12,20 @@ pub(crate) struct RenderConfig {
     pub(super) template: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(super) struct UpdateConfig {
+    pub(super) show_keyboard: bool,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ConfigEntry {
     pub(super) device: String,
+    pub(super) perceptual: bool,
     #[serde(flatten)]
     pub(super) render: RenderConfig,
+    pub(super) scroll_step: u32,
+    #[doc(hidden)]
+    pub(super) update: UpdateConfig,
 }
 
 impl ConfigType for ConfigEntry {
@@ -23,12 +33,23 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
-                "device"   => "intel_backlight",
-                "icons"    => Vec::<String>::new(),
-                "template" => "L {BL}%",
+                "device"      => "intel_backlight",
+                "icons"       => Vec::<String>::new(),
+                "perceptual"  => false,
+                "scroll_step" => 5,
+                "template"    => "L {BL}%",
             ),
         )
     }
+
+    fn set_values(config: &mut config::Config) -> Result<()> {
+        let template = config.get_str(&format!("{}.template", FEATURE_NAME))?;
+
+        config.set(
+            &format!("{}.update.show_keyboard", FEATURE_NAME),
+            template.contains(PLACEHOLDER_KEYBOARD),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -37,6 +58,9 @@ mod tests {
     use super::*;
     use crate::test_utils::config::test_set_default_err;
     use crate::test_utils::config::test_set_default_ok;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use mocktopus::mocking::*;
     use std::collections::HashMap;
 
     #[test]
@@ -53,8 +77,51 @@ mod tests {
         let mut map = HashMap::new();
         map.insert("device".to_owned(), "intel_backlight".into());
         map.insert("icons".to_owned(), Vec::<String>::new().into());
+        map.insert("perceptual".to_owned(), false.into());
+        map.insert("scroll_step".to_owned(), 5.into());
         map.insert("template".to_owned(), "L {BL}%".into());
 
         map
     }
+    #[test]
+    fn config_type_set_values_with_keyboard() {
+        test_set_values("L {BL}% K {Keyboard}%", true);
+    }
+
+    #[test]
+    fn config_type_set_values_without_keyboard() {
+        test_set_values("L {BL}%", false);
+    }
+
+    #[test]
+    fn config_type_set_values_when_err_in_get_str() {
+        config::Config::get_str.mock_safe(|_, key| {
+            assert_that!(key, is(equal_to("backlight.template")));
+            MockResult::Return(Err(Error::new_custom("name", "description")))
+        });
+
+        let mut config = config::Config::new();
+
+        assert_that!(
+            ConfigEntry::set_values(&mut config),
+            is(equal_to(Err(Error::new_custom("name", "description"))))
+        );
+    }
+
+    fn test_set_values(template: &'static str, show_keyboard: bool) {
+        config::Config::get_str.mock_safe(move |_, key| {
+            assert_that!(key, is(equal_to("backlight.template")));
+            MockResult::Return(Ok(template.to_owned()))
+        });
+
+        config::Config::set::<bool>.mock_safe(move |_, key, value| {
+            assert_that!(key, is(equal_to("backlight.update.show_keyboard")));
+            assert_that!(value, is(equal_to(show_keyboard)));
+            MockResult::Return(Ok(()))
+        });
+
+        let mut config = config::Config::new();
+
+        assert_that!(ConfigEntry::set_values(&mut config), is(equal_to(Ok(()))));
+    }
 }
@@ -1,11 +1,14 @@
+use super::PLACEHOLDER_KEYBOARD;
 use super::RenderConfig;
 use crate::feature::Renderable;
+use crate::feature::Values;
 use crate::utils::icon_by_percentage;
 
 #[derive(Debug)]
 pub(super) struct Data {
     cache: String,
     config: RenderConfig,
+    values: Values,
 }
 
 impl Data {
@@ -14,16 +17,24 @@ impl Data {
         Self {
             cache: String::new(),
             config,
+            values: Values::new(),
         }
     }
 
-    pub(super) fn update(&mut self, value: u32) {
+    pub(super) fn update(&mut self, value: u32, keyboard: Option<u32>) {
         let mut rendered = self.config.template.replace("{BL}", &format!("{}", value));
 
         if let Some(icon) = icon_by_percentage(&self.config.icons, value) {
             rendered = rendered.replace("{ICO}", icon);
         }
 
+        self.values = map_values!("BL" => value);
+
+        if let Some(keyboard) = keyboard {
+            rendered = rendered.replace(PLACEHOLDER_KEYBOARD, &format!("{}", keyboard));
+            self.values.insert("KBD", keyboard.to_string());
+        }
+
         self.cache = rendered;
     }
 }
@@ -32,6 +43,10 @@ impl Renderable for Data {
     fn render(&self) -> &str {
         &self.cache
     }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +86,7 @@ mod tests {
 
         let mut object = Data::new(config);
 
-        object.update(10);
+        object.update(10, None);
 
         assert_that!(object.render(), is(equal_to("TEMPLATE 10 {ICO}")));
     }
@@ -94,8 +109,28 @@ mod tests {
 
         let mut object = Data::new(config);
 
-        object.update(10);
+        object.update(10, None);
 
         assert_that!(object.render(), is(equal_to("TEMPLATE 10 ICON")));
     }
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_keyboard() {
+        let config = RenderConfig {
+            icons: vec![],
+            template: "L {BL}% K {Keyboard}%".to_owned(),
+        };
+
+        icon_by_percentage.mock_safe(|_, _| MockResult::Return(None));
+
+        let mut object = Data::new(config);
+
+        object.update(10, Some(50));
+
+        assert_that!(object.render(), is(equal_to("L 10% K 50%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!("BL" => "10", "KBD" => "50")))
+        );
+    }
 }
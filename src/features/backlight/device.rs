@@ -1,43 +1,163 @@
 use super::FEATURE_NAME;
 use crate::error::*;
 use crate::wrapper::file;
+use std::fs;
+
+const PATH_LEDS: &str = "/sys/class/leds";
 
 pub(super) struct BacklightDevice {
+    current: &'static str,
     max: u32,
     path: String,
+    perceptual: bool,
 }
 
 impl BacklightDevice {
-    pub(super) fn init(device: &str) -> Result<Self> {
+    pub(super) fn init(device: &str, perceptual: bool) -> Result<Self> {
+        Self::init_path(
+            format!("/sys/class/backlight/{}", device),
+            "actual_brightness",
+            perceptual,
+        )
+    }
+
+    pub(super) fn init_keyboard() -> Result<Self> {
+        let mut names = fs::read_dir(PATH_LEDS)
+            .wrap_error(FEATURE_NAME, format!("error reading {}", PATH_LEDS))?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.contains("kbd_backlight"))
+            .collect::<Vec<_>>();
+
+        names.sort();
+
+        let name = names
+            .first()
+            .wrap_error(FEATURE_NAME, "no keyboard backlight found")?;
+
+        Self::init_path(format!("{}/{}", PATH_LEDS, name), "brightness", false)
+    }
+
+    fn init_path(path: String, current: &'static str, perceptual: bool) -> Result<Self> {
         let mut device = Self {
+            current,
             max: 0,
-            path: format!("/sys/class/backlight/{}", device),
+            path,
+            perceptual,
         };
 
-        device.max = device.get_brightness("max")?;
+        device.max = device.get_brightness("max_brightness")?;
 
         Ok(device)
     }
 
     pub(super) fn brightness_file(&self) -> String {
-        self.build_path("actual")
+        self.build_path(self.current)
     }
 
     pub(super) fn value(&self) -> Result<u32> {
-        let current = self.get_brightness("actual")?;
-        let value = current * 100 / self.max;
+        let current = self.get_brightness(self.current)?;
 
-        Ok(value)
+        Ok(to_percent(current, self.max, self.perceptual))
+    }
+
+    pub(super) fn change_value(&self, step: u32, increase: bool) -> Result<()> {
+        let current = self.get_brightness(self.current)?;
+        let value = to_percent(current, self.max, self.perceptual);
+
+        let brightness = if increase {
+            let value = value.saturating_add(step).min(100);
+            from_percent(value, self.max, self.perceptual)
+                .max(current.saturating_add(1))
+                .min(self.max)
+        } else {
+            let value = value.saturating_sub(step);
+            from_percent(value, self.max, self.perceptual).min(current.saturating_sub(1))
+        };
+
+        let path = self.build_path("brightness");
+
+        fs::write(&path, brightness.to_string())
+            .wrap_error(FEATURE_NAME, format!("error writing {}", path))
     }
 
     fn build_path(&self, name: &str) -> String {
-        format!("{}/{}_brightness", self.path, name)
+        format!("{}/{}", self.path, name)
     }
 
     fn get_brightness(&self, name: &str) -> Result<u32> {
         let brightness = file::parse_file_content(self.build_path(name))
-            .wrap_error(FEATURE_NAME, format!("error reading {} brightness", name))?;
+            .wrap_error(FEATURE_NAME, format!("error reading {}", name))?;
 
         Ok(brightness)
     }
 }
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_percent(brightness: u32, max: u32, perceptual: bool) -> u32 {
+    if max == 0 {
+        return 0;
+    }
+
+    if !perceptual {
+        return brightness * 100 / max;
+    }
+
+    let percent = 100. * (f64::from(brightness) + 1.).ln() / (f64::from(max) + 1.).ln();
+
+    percent.round() as u32
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_percent(percent: u32, max: u32, perceptual: bool) -> u32 {
+    if !perceptual {
+        return percent * max / 100;
+    }
+
+    let brightness = (f64::from(max) + 1.).powf(f64::from(percent) / 100.) - 1.;
+
+    (brightness.round() as u32).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn to_percent_linear() {
+        assert_that!(to_percent(0, 1000, false), is(equal_to(0)));
+        assert_that!(to_percent(10, 1000, false), is(equal_to(1)));
+        assert_that!(to_percent(500, 1000, false), is(equal_to(50)));
+        assert_that!(to_percent(1000, 1000, false), is(equal_to(100)));
+    }
+
+    #[test]
+    fn to_percent_perceptual() {
+        assert_that!(to_percent(0, 1000, true), is(equal_to(0)));
+        assert_that!(to_percent(10, 1000, true), is(equal_to(35)));
+        assert_that!(to_percent(500, 1000, true), is(equal_to(90)));
+        assert_that!(to_percent(1000, 1000, true), is(equal_to(100)));
+    }
+
+    #[test]
+    fn to_percent_without_max() {
+        assert_that!(to_percent(0, 0, true), is(equal_to(0)));
+        assert_that!(to_percent(0, 0, false), is(equal_to(0)));
+    }
+
+    #[test]
+    fn from_percent_linear() {
+        assert_that!(from_percent(0, 1000, false), is(equal_to(0)));
+        assert_that!(from_percent(50, 1000, false), is(equal_to(500)));
+        assert_that!(from_percent(100, 1000, false), is(equal_to(1000)));
+    }
+
+    #[test]
+    fn from_percent_perceptual() {
+        assert_that!(from_percent(0, 1000, true), is(equal_to(0)));
+        assert_that!(from_percent(35, 1000, true), is(equal_to(10)));
+        assert_that!(from_percent(90, 1000, true), is(equal_to(501)));
+        assert_that!(from_percent(100, 1000, true), is(equal_to(1000)));
+    }
+}
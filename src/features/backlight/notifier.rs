@@ -7,19 +7,19 @@ use crate::wrapper::thread;
 pub(super) struct Notifier {
     id: usize,
     sender: channel::Sender<communication::Message>,
-    brightness_file: String,
+    brightness_files: Vec<String>,
 }
 
 impl Notifier {
     pub(super) const fn new(
         id: usize,
         sender: channel::Sender<communication::Message>,
-        brightness_file: String,
+        brightness_files: Vec<String>,
     ) -> Self {
         Self {
             id,
             sender,
-            brightness_file,
+            brightness_files,
         }
     }
 }
@@ -28,7 +28,9 @@ impl thread::Runnable for Notifier {
     fn run(&self) -> Result<()> {
         let mut inotify = inotify::Inotify::init()?;
 
-        inotify.add_watch(&self.brightness_file, inotify::WatchMask::MODIFY)?;
+        for brightness_file in &self.brightness_files {
+            inotify.add_watch(brightness_file, inotify::WatchMask::MODIFY)?;
+        }
 
         inotify.listen_for_any_events(|| {
             communication::send_message(self.id, &self.sender)?;
@@ -38,4 +40,8 @@ impl thread::Runnable for Notifier {
             Ok(())
         })
     }
+
+    fn watched_files(&self) -> Vec<String> {
+        self.brightness_files.clone()
+    }
 }
@@ -1,16 +1,29 @@
 use super::BacklightDevice;
 use super::Data;
+use crate::click;
 use crate::error::*;
 use crate::feature;
 
 pub(super) struct Updater {
     data: Data,
     device: BacklightDevice,
+    keyboard: Option<BacklightDevice>,
+    scroll_step: u32,
 }
 
 impl Updater {
-    pub(super) const fn new(data: Data, device: BacklightDevice) -> Self {
-        Self { data, device }
+    pub(super) const fn new(
+        data: Data,
+        device: BacklightDevice,
+        keyboard: Option<BacklightDevice>,
+        scroll_step: u32,
+    ) -> Self {
+        Self {
+            data,
+            device,
+            keyboard,
+            scroll_step,
+        }
     }
 }
 
@@ -20,8 +33,24 @@ impl feature::Updatable for Updater {
     }
 
     fn update(&mut self) -> Result<()> {
-        self.data.update(self.device.value()?);
+        let keyboard = self
+            .keyboard
+            .as_ref()
+            .map(BacklightDevice::value)
+            .transpose()?;
+
+        self.data.update(self.device.value()?, keyboard);
 
         Ok(())
     }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        let increase = match click::Button::from_number(button)? {
+            click::Button::ScrollUp => true,
+            click::Button::ScrollDown => false,
+            _ => return Ok(()),
+        };
+
+        self.device.change_value(self.scroll_step, increase)
+    }
 }
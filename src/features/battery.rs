@@ -1,7 +1,9 @@
 mod config;
+mod conservation;
 mod data;
 mod dbus;
 mod notifier;
+mod power;
 mod updater;
 
 use crate::communication;
@@ -1,24 +1,47 @@
 use super::FEATURE_NAME;
 use crate::error::*;
+use crate::notification::NotificationConfig;
 use crate::settings::ConfigType;
 use crate::wrapper::config;
 use crate::wrapper::config::Value;
 use serde_derive::*;
 
+const NOTIFICATION_BODY: &str = "{TIME} remaining";
+const NOTIFICATION_FINAL_BODY: &str = "Suspending in 60s";
+const NOTIFICATION_FINAL_SUMMARY: &str = "Battery at {CAP}%";
+const NOTIFICATION_SUMMARY: &str = "Battery under {LEVEL}%";
+const NOTIFICATION_RECOVERED_BODY: &str = "Battery is charging at {CAP}%";
+const NOTIFICATION_RECOVERED_SUMMARY: &str = "Battery recovered";
+
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct NotifierConfig {
     pub(super) enable_notifier: bool,
+    pub(super) notification: NotificationConfig,
+    pub(super) notification_critical: NotificationConfig,
+    pub(super) notification_final: NotificationConfig,
+    pub(super) notification_recovered: NotificationConfig,
+    pub(super) notifier_actions: Vec<String>,
     pub(super) notifier_critical: u64,
+    pub(super) notifier_final: u64,
+    pub(super) notifier_floor: u64,
+    pub(super) notifier_floor_action: String,
     pub(super) notifier_levels: Vec<u64>,
+    pub(super) notifier_recovery: bool,
+    pub(super) notifier_repeat: u64,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct RenderConfig {
     pub(super) charging: String,
+    pub(super) combined: bool,
+    pub(super) conservation: String,
     pub(super) discharging: String,
     pub(super) icons: Vec<String>,
     pub(super) no_battery: String,
     pub(super) separator: String,
+    pub(super) template: String,
+    pub(super) template_held: String,
+    pub(super) template_idle: String,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -34,14 +57,45 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
-                "charging"          => "▲",
-                "discharging"       => "▼",
-                "enable_notifier"   => true,
-                "icons"             => Vec::<String>::new(),
-                "no_battery"        => "NO BATT",
-                "notifier_critical" => 10,
-                "notifier_levels"   => vec![2, 5, 10, 15, 20],
-                "separator"         => " · ",
+                "charging"               => "▲",
+                "combined"               => false,
+                "conservation"           => "held at {LIMIT}%",
+                "discharging"            => "▼",
+                "enable_notifier"        => true,
+                "icons"                  => Vec::<String>::new(),
+                "no_battery"             => "NO BATT",
+                "notification"           => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "normal",
+                ),
+                "notification_critical"  => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "critical",
+                ),
+                "notification_final"     => NotificationConfig::default_map(
+                    NOTIFICATION_FINAL_SUMMARY,
+                    NOTIFICATION_FINAL_BODY,
+                    "critical",
+                ),
+                "notification_recovered" => NotificationConfig::default_map(
+                    NOTIFICATION_RECOVERED_SUMMARY,
+                    NOTIFICATION_RECOVERED_BODY,
+                    "low",
+                ),
+                "notifier_actions"       => Vec::<String>::new(),
+                "notifier_critical"      => 10,
+                "notifier_final"         => 0,
+                "notifier_floor"         => 0,
+                "notifier_floor_action"  => "suspend",
+                "notifier_levels"        => vec![2, 5, 10, 15, 20],
+                "notifier_recovery"      => false,
+                "notifier_repeat"        => 0,
+                "separator"              => " · ",
+                "template"               => "{STATE} {ICO} {CAPACITY}% ({ESTIMATION})",
+                "template_held"          => "{ICO} {CAPACITY}% {Conservation}",
+                "template_idle"          => "{ICO} {CAPACITY}%",
             ),
         )
     }
@@ -51,6 +105,7 @@ impl ConfigType for ConfigEntry {
 #[cfg(feature = "mocking")]
 mod tests {
     use super::*;
+    use crate::test_utils::config::notification_map;
     use crate::test_utils::config::test_set_default_err;
     use crate::test_utils::config::test_set_default_ok;
     use std::collections::HashMap;
@@ -68,13 +123,46 @@ mod tests {
     fn default_map() -> HashMap<String, Value> {
         let mut map = HashMap::new();
         map.insert("charging".to_owned(), "▲".into());
+        map.insert("combined".to_owned(), false.into());
+        map.insert("conservation".to_owned(), "held at {LIMIT}%".into());
         map.insert("discharging".to_owned(), "▼".into());
         map.insert("enable_notifier".to_owned(), true.into());
         map.insert("icons".to_owned(), Vec::<String>::new().into());
         map.insert("no_battery".to_owned(), "NO BATT".into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map("Battery under {LEVEL}%", "{TIME} remaining", "normal").into(),
+        );
+        map.insert(
+            "notification_critical".to_owned(),
+            notification_map("Battery under {LEVEL}%", "{TIME} remaining", "critical").into(),
+        );
+        map.insert(
+            "notification_final".to_owned(),
+            notification_map("Battery at {CAP}%", "Suspending in 60s", "critical").into(),
+        );
+        map.insert(
+            "notification_recovered".to_owned(),
+            notification_map("Battery recovered", "Battery is charging at {CAP}%", "low").into(),
+        );
+        map.insert("notifier_actions".to_owned(), Vec::<String>::new().into());
         map.insert("notifier_critical".to_owned(), 10.into());
+        map.insert("notifier_final".to_owned(), 0.into());
+        map.insert("notifier_floor".to_owned(), 0.into());
+        map.insert("notifier_floor_action".to_owned(), "suspend".into());
         map.insert("notifier_levels".to_owned(), vec![2, 5, 10, 15, 20].into());
+        map.insert("notifier_recovery".to_owned(), false.into());
+        map.insert("notifier_repeat".to_owned(), 0.into());
         map.insert("separator".to_owned(), " · ".into());
+        map.insert(
+            "template".to_owned(),
+            "{STATE} {ICO} {CAPACITY}% ({ESTIMATION})".into(),
+        );
+        map.insert(
+            "template_held".to_owned(),
+            "{ICO} {CAPACITY}% {Conservation}".into(),
+        );
+        map.insert("template_idle".to_owned(), "{ICO} {CAPACITY}%".into());
 
         map
     }
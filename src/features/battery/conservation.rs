@@ -0,0 +1,35 @@
+use crate::wrapper::file;
+use std::fs;
+
+const FILE_CONSERVATION_MODE: &str = "conservation_mode";
+const FILE_END_THRESHOLD: &str = "charge_control_end_threshold";
+const IDEAPAD_LIMIT: u64 = 60;
+const PATH_IDEAPAD: &str = "/sys/bus/platform/drivers/ideapad_acpi";
+const PATH_POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+pub(super) fn charge_limit() -> Option<u64> {
+    ideapad_limit().or_else(end_threshold)
+}
+
+fn ideapad_limit() -> Option<u64> {
+    let enabled = fs::read_dir(PATH_IDEAPAD)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .any(|entry| {
+            file::parse_file_content::<_, u8>(entry.path().join(FILE_CONSERVATION_MODE)).ok()
+                == Some(1)
+        });
+
+    if enabled { Some(IDEAPAD_LIMIT) } else { None }
+}
+
+fn end_threshold() -> Option<u64> {
+    fs::read_dir(PATH_POWER_SUPPLY)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            file::parse_file_content::<_, u64>(entry.path().join(FILE_END_THRESHOLD)).ok()
+        })
+        .filter(|threshold| *threshold < 100)
+        .min()
+}
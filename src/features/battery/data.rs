@@ -1,16 +1,30 @@
 use super::RenderConfig;
 use crate::feature::Renderable;
+use crate::feature::Values;
 use crate::utils::icon_by_percentage;
 use crate::wrapper::battery::Battery;
+use crate::wrapper::battery::Energy;
 use crate::wrapper::uom::get_raw_hours;
 use crate::wrapper::uom::get_raw_minutes;
 use crate::wrapper::uom::get_raw_percent;
+use uom::si::f32::Ratio;
 use uom::si::f32::Time;
+use uom::si::ratio::percent;
+use uom::si::time::hour;
+
+const PLACEHOLDER_CAPACITY: &str = "{CAPACITY}";
+const PLACEHOLDER_CONSERVATION: &str = "{Conservation}";
+const PLACEHOLDER_ESTIMATION: &str = "{ESTIMATION}";
+const PLACEHOLDER_ICON: &str = "{ICO}";
+const PLACEHOLDER_INDEX: &str = "{INDEX}";
+const PLACEHOLDER_LIMIT: &str = "{LIMIT}";
+const PLACEHOLDER_STATE: &str = "{STATE}";
 
 #[derive(Debug)]
 pub(super) struct Data {
     cache: String,
     config: RenderConfig,
+    values: Values,
 }
 
 impl Data {
@@ -19,86 +33,354 @@ impl Data {
         Self {
             cache: String::new(),
             config,
+            values: Values::new(),
         }
     }
 
-    pub(super) fn update(&mut self, batteries: &[Battery]) {
+    pub(super) fn update(
+        &mut self,
+        batteries: &[Battery],
+        energies: &[Energy],
+        charge_limit: Option<u64>,
+    ) {
+        let combined;
+        let batteries = if self.config.combined && batteries.len() > 1 {
+            combined = [combine(batteries, energies)];
+            &combined[..]
+        } else {
+            batteries
+        };
+
         self.cache = if batteries.is_empty() {
             self.config.no_battery.clone()
         } else {
             batteries
                 .iter()
-                .map(|battery| {
-                    self.render_battery(battery)
-                        .into_iter()
-                        .collect::<Vec<_>>()
-                        .join(" ")
-                })
+                .enumerate()
+                .map(|(index, battery)| self.render_battery(index, battery, charge_limit))
                 .collect::<Vec<_>>()
                 .join(&self.config.separator)
+        };
+        self.values = battery_values(batteries);
+
+        if let Some(limit) = charge_limit {
+            self.values.insert("CONSERVATION", limit.to_string());
         }
     }
 
-    fn render_battery(&self, battery: &Battery) -> Vec<String> {
-        match *battery {
-            Battery::Charging {
-                percentage,
-                time_to_full,
-            } => {
-                let capacity = get_raw_percent(percentage);
-
-                let mut list = vec![];
-                list.push(self.config.charging.to_owned());
-                self.push_capacity(&mut list, capacity);
-                self.push_time(&mut list, time_to_full);
-                list
-            },
-            Battery::Discharging {
-                percentage,
-                time_to_empty,
-            } => {
-                let capacity = get_raw_percent(percentage);
-
-                let mut list = vec![];
-                list.push(self.config.discharging.to_owned());
-                self.push_capacity(&mut list, capacity);
-                self.push_time(&mut list, time_to_empty);
-                list
-            },
-            Battery::Empty => {
-                let mut list = vec![];
-                self.push_capacity(&mut list, 0.);
-                list
-            },
-            Battery::Full => {
-                let mut list = vec![];
-                self.push_capacity(&mut list, 100.);
-                list
-            },
-        }
+    fn render_battery(&self, index: usize, battery: &Battery, charge_limit: Option<u64>) -> String {
+        let (template, state, estimation) = match *battery {
+            Battery::Charging { time_to_full, .. } => (
+                &self.config.template,
+                &self.config.charging[..],
+                Some(time_to_full),
+            ),
+            Battery::Discharging { time_to_empty, .. } => (
+                &self.config.template,
+                &self.config.discharging[..],
+                Some(time_to_empty),
+            ),
+            Battery::Empty | Battery::Full => (&self.config.template_idle, "", None),
+            Battery::Held { .. } => (&self.config.template_held, "", None),
+        };
+        let conservation = charge_limit
+            .map(|limit| {
+                self.config
+                    .conservation
+                    .replace(PLACEHOLDER_LIMIT, &limit.to_string())
+            })
+            .unwrap_or_default();
+        let capacity = capacity(battery);
+
+        let rendered = template
+            .replace(PLACEHOLDER_CAPACITY, &format!("{:.0}", capacity))
+            .replace(PLACEHOLDER_CONSERVATION, &conservation)
+            .replace(
+                PLACEHOLDER_ESTIMATION,
+                &estimation.map(format_time).unwrap_or_default(),
+            )
+            .replace(
+                PLACEHOLDER_ICON,
+                icon_by_percentage(&self.config.icons, capacity).unwrap_or_default(),
+            )
+            .replace(PLACEHOLDER_INDEX, &(index + 1).to_string())
+            .replace(PLACEHOLDER_STATE, state);
+
+        collapse_spaces(&rendered)
     }
+}
 
-    fn push_capacity(&self, list: &mut Vec<String>, capacity: f32) {
-        let icon = icon_by_percentage(&self.config.icons, capacity);
+fn format_time(time: Time) -> String {
+    format!("{:02}:{:02}", get_raw_hours(time), get_raw_minutes(time))
+}
 
-        if let Some(icon_str) = icon {
-            list.push(icon_str.to_owned());
+fn collapse_spaces(text: &str) -> String {
+    text.split(' ')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn combine(batteries: &[Battery], energies: &[Energy]) -> Battery {
+    let has_design = energies.iter().any(|energy| energy.full_design > 0.);
+    let weights = energies
+        .iter()
+        .map(|energy| if has_design { energy.full_design } else { 1. })
+        .collect::<Vec<_>>();
+    let total_weight = weights.iter().sum::<f32>();
+
+    let capacity = batteries
+        .iter()
+        .zip(&weights)
+        .map(|(battery, weight)| capacity(battery) * weight / total_weight)
+        .sum::<f32>();
+    let percentage = Ratio::new::<percent>(capacity);
+
+    let rate = energies.iter().map(|energy| energy.rate).sum::<f32>();
+    let hours = |energy: f32| {
+        if rate > 0. {
+            Time::new::<hour>(energy / rate)
+        } else {
+            Time::new::<hour>(0.)
         }
+    };
 
-        list.push(format!("{:.0}%", capacity));
+    if batteries
+        .iter()
+        .any(|battery| status(battery) == "discharging")
+    {
+        Battery::Discharging {
+            percentage,
+            time_to_empty: hours(energies.iter().map(|energy| energy.current).sum()),
+        }
+    } else if batteries.iter().all(|battery| status(battery) == "full") {
+        Battery::Full
+    } else if batteries.iter().all(|battery| status(battery) == "empty") {
+        Battery::Empty
+    } else if batteries
+        .iter()
+        .all(|battery| status(battery) == "held" || status(battery) == "full")
+    {
+        Battery::Held { percentage }
+    } else {
+        let missing = energies
+            .iter()
+            .map(|energy| (energy.full - energy.current).max(0.))
+            .sum();
+
+        Battery::Charging {
+            percentage,
+            time_to_full: hours(missing),
+        }
     }
+}
 
-    fn push_time(&self, list: &mut Vec<String>, time: Time) {
-        list.push(format!(
-            "({:02}:{:02})",
-            get_raw_hours(time),
-            get_raw_minutes(time)
-        ));
+fn capacity(battery: &Battery) -> f32 {
+    match *battery {
+        Battery::Charging { percentage, .. }
+        | Battery::Discharging { percentage, .. }
+        | Battery::Held { percentage } => get_raw_percent(percentage),
+        Battery::Empty => 0.,
+        Battery::Full => 100.,
     }
 }
 
+#[allow(clippy::missing_const_for_fn)]
+fn status(battery: &Battery) -> &'static str {
+    match *battery {
+        Battery::Charging { .. } => "charging",
+        Battery::Discharging { .. } => "discharging",
+        Battery::Empty => "empty",
+        Battery::Full => "full",
+        Battery::Held { .. } => "held",
+    }
+}
+
+fn battery_values(batteries: &[Battery]) -> Values {
+    let mut values = map_values!("COUNT" => batteries.len());
+
+    if let Some(battery) = batteries.first() {
+        values.insert("CAP", format!("{:.0}", capacity(battery)));
+        values.insert("STATUS", status(battery).to_owned());
+    }
+
+    values
+}
+
 impl Renderable for Data {
     fn render(&self) -> &str {
         &self.cache
     }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn discharging(capacity: f32) -> Battery {
+        Battery::Discharging {
+            percentage: Ratio::new::<percent>(capacity),
+            time_to_empty: Time::new::<hour>(0.),
+        }
+    }
+
+    fn energy(current: f32, full_design: f32, rate: f32) -> Energy {
+        Energy {
+            current,
+            full: full_design,
+            full_design,
+            rate,
+        }
+    }
+
+    fn render_config(icons: &[&str]) -> RenderConfig {
+        RenderConfig {
+            charging: "▲".to_owned(),
+            combined: false,
+            conservation: "held at {LIMIT}%".to_owned(),
+            discharging: "▼".to_owned(),
+            icons: icons.iter().map(|icon| (*icon).to_owned()).collect(),
+            no_battery: "NO BATT".to_owned(),
+            separator: " · ".to_owned(),
+            template: "{STATE} {ICO} {CAPACITY}% ({ESTIMATION})".to_owned(),
+            template_held: "{ICO} {CAPACITY}% {Conservation}".to_owned(),
+            template_idle: "{ICO} {CAPACITY}%".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_without_battery() {
+        let mut object = Data::new(render_config(&[]));
+
+        object.update(&[], &[], None);
+
+        assert_that!(object.render(), is(equal_to("NO BATT")));
+    }
+
+    #[test]
+    fn render_with_default_templates() {
+        let mut object = Data::new(render_config(&[]));
+
+        object.update(
+            &[
+                Battery::Discharging {
+                    percentage: Ratio::new::<percent>(50.),
+                    time_to_empty: Time::new::<hour>(2.5),
+                },
+                Battery::Full,
+            ],
+            &[Energy::default(), Energy::default()],
+            None,
+        );
+
+        assert_that!(object.render(), is(equal_to("▼ 50% (02:30) · 100%")));
+    }
+
+    #[test]
+    fn render_with_custom_template() {
+        let mut config = render_config(&["LOW", "HIGH"]);
+        config.template = "{INDEX}: {ICO} {CAPACITY} {STATE}".to_owned();
+        let mut object = Data::new(config);
+
+        object.update(&[discharging(20.)], &[Energy::default()], None);
+
+        assert_that!(object.render(), is(equal_to("1: LOW 20 ▼")));
+    }
+
+    #[test]
+    fn render_with_conservation() {
+        let mut object = Data::new(render_config(&[]));
+
+        object.update(
+            &[Battery::Held {
+                percentage: Ratio::new::<percent>(60.),
+            }],
+            &[Energy::default()],
+            Some(60),
+        );
+
+        assert_that!(object.render(), is(equal_to("60% held at 60%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CAP" => "60",
+                "CONSERVATION" => "60",
+                "COUNT" => "1",
+                "STATUS" => "held",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_held_without_conservation() {
+        let mut object = Data::new(render_config(&[]));
+
+        object.update(
+            &[Battery::Held {
+                percentage: Ratio::new::<percent>(80.),
+            }],
+            &[Energy::default()],
+            None,
+        );
+
+        assert_that!(object.render(), is(equal_to("80%")));
+    }
+
+    #[test]
+    fn combine_weighted_by_design_capacity() {
+        let combined = combine(
+            &[discharging(50.), Battery::Full],
+            &[energy(12., 24., 6.), energy(72., 72., 0.)],
+        );
+
+        assert_that!(status(&combined), is(equal_to("discharging")));
+        assert_that!(capacity(&combined), is(equal_to(88.)));
+
+        if let Battery::Discharging { time_to_empty, .. } = combined {
+            assert_that!(time_to_empty.get::<hour>(), is(equal_to(14.)));
+        }
+    }
+
+    #[test]
+    fn combine_without_design_capacity() {
+        let combined = combine(
+            &[Battery::Empty, Battery::Full],
+            &[Energy::default(), Energy::default()],
+        );
+
+        assert_that!(status(&combined), is(equal_to("charging")));
+        assert_that!(capacity(&combined), is(equal_to(50.)));
+    }
+
+    #[test]
+    fn combine_all_full() {
+        let combined = combine(
+            &[Battery::Full, Battery::Full],
+            &[energy(24., 24., 0.), energy(72., 72., 0.)],
+        );
+
+        assert_that!(status(&combined), is(equal_to("full")));
+    }
+
+    #[test]
+    fn combine_held_and_full() {
+        let combined = combine(
+            &[
+                Battery::Held {
+                    percentage: Ratio::new::<percent>(80.),
+                },
+                Battery::Full,
+            ],
+            &[Energy::default(), Energy::default()],
+        );
+
+        assert_that!(status(&combined), is(equal_to("held")));
+        assert_that!(capacity(&combined), is(equal_to(90.)));
+    }
 }
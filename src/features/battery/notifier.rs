@@ -1,10 +1,16 @@
+use super::FEATURE_NAME;
 use super::NotifierConfig;
+use super::power;
 use crate::error::*;
+use crate::notification;
+use crate::state;
 use crate::wrapper::battery::Battery;
-use crate::wrapper::libnotify;
+use crate::wrapper::thread;
 use crate::wrapper::uom::get_raw_hours;
 use crate::wrapper::uom::get_raw_minutes;
 use crate::wrapper::uom::get_raw_percent;
+use std::time::Duration;
+use std::time::Instant;
 use uom::si::f32::Ratio;
 use uom::si::f32::Time;
 
@@ -13,18 +19,53 @@ struct DischargingBattery {
     time_to_empty: Time,
 }
 
+const STATE_CAPACITY: &str = "capacity";
+
 pub(super) struct BatteryNotifier {
-    libnotify: libnotify::LibNotify,
+    notifier: notification::LazyNotifier,
     settings: NotifierConfig,
+    actions: Vec<power::PowerAction>,
     capacity: Option<u64>,
+    final_notified: bool,
+    floor_action: power::PowerAction,
+    floor_reached: bool,
+    last_notification: Option<Instant>,
+    notification_id: power::SharedNotificationId,
+    notified: bool,
+    repeats: usize,
+    state: state::State,
 }
 
 impl BatteryNotifier {
     pub(super) fn init(settings: NotifierConfig) -> Result<Self> {
+        let state = state::State::load(FEATURE_NAME);
+
+        let actions = settings
+            .notifier_actions
+            .iter()
+            .map(|action| power::PowerAction::parse(action))
+            .collect::<Result<Vec<_>>>()?;
+        let floor_action = power::PowerAction::parse(&settings.notifier_floor_action)?;
+        let notification_id = power::SharedNotificationId::default();
+
+        if settings.enable_notifier && !actions.is_empty() {
+            let listener = power::ActionListener::new(actions.clone(), notification_id.clone());
+            thread::Thread::new("battery actions", listener).run()?;
+        }
+
         Ok(Self {
-            libnotify: libnotify::LibNotify::init()?,
+            notifier: notification::LazyNotifier::default(),
             settings,
-            capacity: None,
+            actions,
+            capacity: state.get(STATE_CAPACITY).unwrap_or(None),
+            final_notified: false,
+            floor_action,
+            floor_reached: false,
+            last_notification: None,
+            notification_id,
+            notified: false,
+            repeats: 0,
+            state,
         })
     }
 
@@ -60,41 +101,201 @@ impl BatteryNotifier {
         if let Some(battery) = discharging {
             self.notify(&battery);
         } else {
-            self.capacity = None
+            self.capacity = None;
+            self.final_notified = false;
+            self.floor_reached = false;
+            self.last_notification = None;
+            self.repeats = 0;
+            *self.notification_id.lock().unwrap() = None;
+
+            if self.notified {
+                self.notify_recovery(batteries);
+            }
         }
+
+        self.state
+            .set(STATE_CAPACITY, self.capacity)
+            .show_error_and_ignore();
     }
 
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn notify(&mut self, battery: &DischargingBattery) {
         let capacity = get_raw_percent(battery.percentage) as u64;
+        let level = self
+            .settings
+            .notifier_levels
+            .iter()
+            .copied()
+            .find(|level| *level >= capacity);
 
-        for level in &self.settings.notifier_levels {
-            if *level >= capacity {
-                if match self.capacity {
-                    Some(value) if *level >= value => false,
-                    _ => true,
-                } {
-                    self.libnotify
-                        .send_notification(
-                            &format!("Battery under {}%", level),
-                            &format!(
-                                "{:02}:{:02} remaining",
-                                get_raw_hours(battery.time_to_empty),
-                                get_raw_minutes(battery.time_to_empty),
-                            ),
-                            if *level <= self.settings.notifier_critical {
-                                libnotify::Urgency::Critical
-                            } else {
-                                libnotify::Urgency::Normal
-                            },
-                        )
-                        .show_error_and_ignore();
-                }
+        let notified_level = match (level, self.capacity) {
+            (Some(level), Some(value)) if level >= value => false,
+            (Some(level), _) => {
+                let placeholders = placeholders(battery, capacity, level);
+
+                let result = if level <= self.settings.notifier_critical {
+                    let notification = self.settings.notification_critical.clone();
+                    self.send_with_actions(&notification, &placeholders)
+                } else {
+                    let notification = &self.settings.notification;
+                    self.notifier
+                        .get()
+                        .and_then(|notifier| notifier.send(notification, &placeholders))
+                };
+
+                result.show_error_and_ignore();
+
+                self.notified = true;
+                true
+            },
+            (None, _) => false,
+        };
+
+        self.capacity = Some(capacity);
 
-                break;
+        if self.settings.notifier_floor > 0
+            && capacity <= self.settings.notifier_floor
+            && !self.floor_reached
+        {
+            self.floor_reached = true;
+            self.floor_action.run().show_error_and_ignore();
+        }
+
+        if self.settings.notifier_final > 0 && capacity <= self.settings.notifier_final {
+            self.notify_final(battery, capacity);
+        } else if capacity <= self.settings.notifier_critical {
+            self.notify_repeated(battery, capacity, notified_level);
+        } else {
+            self.last_notification = None;
+            self.repeats = 0;
+        }
+    }
+
+    fn notify_repeated(&mut self, battery: &DischargingBattery, capacity: u64, notified: bool) {
+        if self.settings.notifier_repeat == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let due = !notified
+            && self.last_notification.map_or(false, |last_notification| {
+                now.duration_since(last_notification)
+                    >= Duration::from_secs(self.settings.notifier_repeat)
+            });
+
+        if !due {
+            if notified || self.last_notification.is_none() {
+                self.last_notification = Some(now);
             }
+
+            return;
         }
 
-        self.capacity = Some(capacity)
+        self.last_notification = Some(now);
+        self.repeats += 1;
+
+        let level = self
+            .settings
+            .notifier_levels
+            .iter()
+            .filter(|level| **level >= capacity)
+            .min()
+            .copied()
+            .unwrap_or(self.settings.notifier_critical);
+
+        let result = self
+            .settings
+            .notification_critical
+            .with_raised_urgency(self.repeats)
+            .and_then(|notification| {
+                self.send_with_actions(&notification, &placeholders(battery, capacity, level))
+            });
+
+        result.show_error_and_ignore();
+        self.notified = true;
+    }
+
+    fn notify_final(&mut self, battery: &DischargingBattery, capacity: u64) {
+        if self.final_notified {
+            return;
+        }
+
+        let notification = self.settings.notification_final.clone();
+        self.send_with_actions(
+            &notification,
+            &placeholders(battery, capacity, self.settings.notifier_final),
+        )
+        .show_error_and_ignore();
+
+        self.final_notified = true;
+        self.notified = true;
+    }
+
+    fn send_with_actions(
+        &mut self,
+        notification: &notification::NotificationConfig,
+        placeholders: &[(&str, String)],
+    ) -> Result<()> {
+        let actions = self
+            .actions
+            .iter()
+            .map(|action| (action.key(), action.label()))
+            .collect::<Vec<_>>();
+
+        let notifier = self.notifier.get()?;
+        notifier.send_with_actions(notification, placeholders, &actions)?;
+        *self.notification_id.lock().unwrap() = notifier.last_id();
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn notify_recovery(&mut self, batteries: &[Battery]) {
+        self.notified = false;
+
+        if !self.settings.notifier_recovery {
+            return;
+        }
+
+        let capacity = batteries
+            .iter()
+            .map(|battery| match *battery {
+                Battery::Charging { percentage, .. }
+                | Battery::Discharging { percentage, .. }
+                | Battery::Held { percentage } => get_raw_percent(percentage) as u64,
+                Battery::Empty => 0,
+                Battery::Full => 100,
+            })
+            .max()
+            .unwrap_or(0);
+
+        let notification = &self.settings.notification_recovered;
+
+        self.notifier
+            .get()
+            .and_then(|notifier| {
+                notifier.close().show_error_and_ignore();
+                notifier.send(notification, &[("{CAP}", capacity.to_string())])
+            })
+            .show_error_and_ignore();
     }
 }
+
+fn placeholders(
+    battery: &DischargingBattery,
+    capacity: u64,
+    level: u64,
+) -> [(&'static str, String); 3] {
+    [
+        ("{CAP}", capacity.to_string()),
+        ("{LEVEL}", level.to_string()),
+        (
+            "{TIME}",
+            format!(
+                "{:02}:{:02}",
+                get_raw_hours(battery.time_to_empty),
+                get_raw_minutes(battery.time_to_empty),
+            ),
+        ),
+    ]
+}
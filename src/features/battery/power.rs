@@ -0,0 +1,125 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification;
+use crate::wrapper::dbus;
+use crate::wrapper::thread;
+use log::info;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+const INTERFACE_LOGIN_MANAGER: &str = "org.freedesktop.login1.Manager";
+const PATH_LOGIN: &str = "/org/freedesktop/login1";
+const SERVICE_LOGIN: &str = "org.freedesktop.login1";
+
+pub(super) type SharedNotificationId = Arc<Mutex<Option<u32>>>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum PowerAction {
+    Hibernate,
+    Suspend,
+}
+
+impl PowerAction {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "hibernate" => Ok(Self::Hibernate),
+            "suspend" => Ok(Self::Suspend),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "unknown power action {}, expected hibernate or suspend",
+                    name
+                ),
+            )),
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn key(self) -> &'static str {
+        match self {
+            Self::Hibernate => "hibernate",
+            Self::Suspend => "suspend",
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Hibernate => "Hibernate",
+            Self::Suspend => "Suspend",
+        }
+    }
+
+    pub(super) fn run(self) -> Result<()> {
+        info!("Run power action {}", self.key());
+
+        let connection = dbus::Connection::init()?;
+        let message = dbus::Message::init_method_call(
+            SERVICE_LOGIN,
+            PATH_LOGIN,
+            INTERFACE_LOGIN_MANAGER,
+            self.label(),
+        )?
+        .append(true);
+
+        connection.send_message(message)?;
+
+        Ok(())
+    }
+}
+
+pub(super) struct ActionListener {
+    actions: Vec<PowerAction>,
+    notification_id: SharedNotificationId,
+}
+
+impl ActionListener {
+    pub(super) const fn new(
+        actions: Vec<PowerAction>,
+        notification_id: SharedNotificationId,
+    ) -> Self {
+        Self {
+            actions,
+            notification_id,
+        }
+    }
+}
+
+impl thread::Runnable for ActionListener {
+    fn run(&self) -> Result<()> {
+        notification::listen_for_actions(|id, key| {
+            if *self.notification_id.lock().unwrap() != Some(id) {
+                return Ok(());
+            }
+
+            match self.actions.iter().find(|action| action.key() == key) {
+                Some(action) => action.run(),
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn power_action_parse() {
+        assert_that!(
+            PowerAction::parse("suspend"),
+            is(equal_to(Ok(PowerAction::Suspend)))
+        );
+        assert_that!(
+            PowerAction::parse("hibernate"),
+            is(equal_to(Ok(PowerAction::Hibernate)))
+        );
+    }
+
+    #[test]
+    fn power_action_parse_unknown() {
+        assert_that!(PowerAction::parse("poweroff").is_err(), is(true));
+    }
+}
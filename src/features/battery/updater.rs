@@ -1,5 +1,6 @@
 use super::BatteryNotifier;
 use super::Data;
+use super::conservation;
 use crate::error::*;
 use crate::feature;
 use crate::wrapper::battery::all_batteries;
@@ -21,10 +22,11 @@ impl feature::Updatable for Updater {
     }
 
     fn update(&mut self) -> Result<()> {
-        let batteries = all_batteries()?;
+        let (batteries, energies): (Vec<_>, Vec<_>) = all_batteries()?.into_iter().unzip();
 
         self.notifier.update(&batteries);
-        self.data.update(&batteries);
+        self.data
+            .update(&batteries, &energies, conservation::charge_limit());
 
         Ok(())
     }
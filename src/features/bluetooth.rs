@@ -0,0 +1,39 @@
+mod bluez;
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(self) use self::bluez::Status;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "bluetooth";
+pub(self) const PLACEHOLDER_COUNT: &str = "{Count}";
+pub(self) const PLACEHOLDER_DEVICES: &str = "{Devices}";
+
+pub(self) type SharedStatus = Arc<Mutex<Option<Status>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let status = SharedStatus::default();
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone(), Arc::clone(&status)),
+        Updater::new(data, settings.clone(), status),
+    )))
+}
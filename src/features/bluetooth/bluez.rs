@@ -0,0 +1,191 @@
+use crate::error::*;
+use crate::wrapper::dbus;
+use std::collections::HashMap;
+
+const INTERFACE_ADAPTER: &str = "org.bluez.Adapter1";
+const INTERFACE_DBUS_OBJECT_MANAGER: &str = "org.freedesktop.DBus.ObjectManager";
+const INTERFACE_DBUS_PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+const INTERFACE_DEVICE: &str = "org.bluez.Device1";
+const MEMBER_PROPERTIES_CHANGED: &str = "PropertiesChanged";
+const PATH_BLUEZ: &str = "/org/bluez";
+const PATH_ROOT: &str = "/";
+const PROPERTY_ADDRESS: &str = "Address";
+const PROPERTY_ALIAS: &str = "Alias";
+const PROPERTY_CONNECTED: &str = "Connected";
+const PROPERTY_NAME: &str = "Name";
+const PROPERTY_POWERED: &str = "Powered";
+const SERVICE_BLUEZ: &str = "org.bluez";
+
+type Properties = HashMap<String, dbus::Variant<Box<dyn dbus::RefArg>>>;
+
+type ManagedObjects<'a> = HashMap<dbus::Path<'a>, HashMap<String, Properties>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Status {
+    pub(super) devices: Vec<String>,
+    pub(super) powered: bool,
+}
+
+pub(super) fn status(connection: &dbus::Connection) -> Result<Option<Status>> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_BLUEZ,
+        PATH_ROOT,
+        INTERFACE_DBUS_OBJECT_MANAGER,
+        "GetManagedObjects",
+    )?;
+
+    let response = connection.send_message(message)?;
+    let objects = response.return_value::<ManagedObjects<'_>>()?;
+
+    Ok(parse(objects.values()))
+}
+
+pub(super) fn watch(connection: &dbus::Connection) -> Result<()> {
+    connection.add_match(dbus::Match::new(
+        INTERFACE_DBUS_OBJECT_MANAGER,
+        None,
+        PATH_ROOT,
+    ))?;
+
+    connection.add_match(dbus::Match::in_namespace(
+        INTERFACE_DBUS_PROPERTIES,
+        MEMBER_PROPERTIES_CHANGED,
+        PATH_BLUEZ,
+    ))
+}
+
+fn parse<'a, I>(objects: I) -> Option<Status>
+where
+    I: Iterator<Item = &'a HashMap<String, Properties>>,
+{
+    let mut adapters = 0;
+    let mut powered = false;
+    let mut devices = Vec::new();
+
+    for interfaces in objects {
+        if let Some(adapter) = interfaces.get(INTERFACE_ADAPTER) {
+            adapters += 1;
+            powered |= property_bool(adapter, PROPERTY_POWERED);
+        }
+
+        if let Some(device) = interfaces.get(INTERFACE_DEVICE) {
+            if property_bool(device, PROPERTY_CONNECTED) {
+                devices.extend(device_name(device));
+            }
+        }
+    }
+
+    if adapters == 0 {
+        return None;
+    }
+
+    devices.sort();
+
+    Some(Status { devices, powered })
+}
+
+fn device_name(device: &Properties) -> Option<String> {
+    [PROPERTY_ALIAS, PROPERTY_NAME, PROPERTY_ADDRESS]
+        .iter()
+        .find_map(|name| {
+            device
+                .get(*name)
+                .and_then(|value| value.0.as_str())
+                .filter(|value| !value.is_empty())
+        })
+        .map(str::to_owned)
+}
+
+fn property_bool(properties: &Properties, name: &str) -> bool {
+    properties
+        .get(name)
+        .and_then(|value| value.0.as_u64())
+        .map_or(false, |value| value != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn interface(
+        name: &str,
+        properties: Vec<(&str, Box<dyn dbus::RefArg>)>,
+    ) -> HashMap<String, Properties> {
+        let properties = properties
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), dbus::Variant(value)))
+            .collect();
+
+        let mut interfaces = HashMap::new();
+        interfaces.insert(name.to_owned(), properties);
+
+        interfaces
+    }
+
+    fn value<T: dbus::RefArg + 'static>(value: T) -> Box<dyn dbus::RefArg> {
+        Box::new(value)
+    }
+
+    fn adapter(powered: bool) -> HashMap<String, Properties> {
+        interface(INTERFACE_ADAPTER, vec![("Powered", value(powered))])
+    }
+
+    fn device(alias: &str, connected: bool) -> HashMap<String, Properties> {
+        interface(
+            INTERFACE_DEVICE,
+            vec![
+                ("Address", value("00:11:22:33:44:55".to_owned())),
+                ("Alias", value(alias.to_owned())),
+                ("Connected", value(connected)),
+            ],
+        )
+    }
+
+    #[test]
+    fn parse_connected_devices() {
+        let objects = vec![
+            adapter(true),
+            device("Mouse", true),
+            device("Headphones", true),
+            device("Phone", false),
+        ];
+
+        assert_that!(
+            parse(objects.iter()),
+            is(equal_to(Some(Status {
+                devices: vec!["Headphones".to_owned(), "Mouse".to_owned()],
+                powered: true,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_device_without_alias() {
+        let objects = vec![adapter(true), device("", true)];
+
+        assert_that!(
+            parse(objects.iter()).map(|status| status.devices),
+            is(equal_to(Some(vec!["00:11:22:33:44:55".to_owned()])))
+        );
+    }
+
+    #[test]
+    fn parse_powered_off_adapters() {
+        let objects = vec![adapter(false), adapter(false)];
+
+        assert_that!(
+            parse(objects.iter()),
+            is(equal_to(Some(Status {
+                devices: Vec::new(),
+                powered: false,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_without_adapter() {
+        assert_that!(parse(vec![device("Mouse", true)].iter()), is(none()));
+    }
+}
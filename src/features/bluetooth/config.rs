@@ -0,0 +1,69 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) no_value: String,
+    pub(super) notification: NotificationConfig,
+    pub(super) powered_off: String,
+    pub(super) separator: String,
+    pub(super) template: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "no_value"     => "NA",
+                "notification" => NotificationConfig::default_map(
+                    "Bluetooth: {COUNT} connected",
+                    "{DEVICES}",
+                    "low",
+                ),
+                "powered_off"  => "BT off",
+                "separator"    => ", ",
+                "template"     => "BT {Count}",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("bluetooth", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("bluetooth", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map("Bluetooth: {COUNT} connected", "{DEVICES}", "low").into(),
+        );
+        map.insert("powered_off".to_owned(), "BT off".into());
+        map.insert("separator".to_owned(), ", ".into());
+        map.insert("template".to_owned(), "BT {Count}".into());
+
+        map
+    }
+}
@@ -0,0 +1,137 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_COUNT;
+use super::PLACEHOLDER_DEVICES;
+use super::Status;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, status: Option<&Status>) {
+        self.cache = match status {
+            Some(status) if !status.powered => self.settings.powered_off.clone(),
+            Some(status) => self
+                .settings
+                .template
+                .replace(PLACEHOLDER_COUNT, &status.devices.len().to_string())
+                .replace(
+                    PLACEHOLDER_DEVICES,
+                    &status.devices.join(&self.settings.separator),
+                ),
+            None => self
+                .settings
+                .template
+                .replace(PLACEHOLDER_COUNT, &self.settings.no_value)
+                .replace(PLACEHOLDER_DEVICES, &self.settings.no_value),
+        };
+
+        self.values = match status {
+            Some(status) => map_values!(
+                "COUNT" => status.devices.len(),
+                "DEVICES" => status.devices.join(&self.settings.separator),
+                "POWERED" => status.powered,
+            ),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            no_value: "NA".to_owned(),
+            notification: NotificationConfig::default(),
+            powered_off: "BT off".to_owned(),
+            separator: ", ".to_owned(),
+            template: "BT {Count} {Devices}".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Status {
+            devices: vec!["Headphones".to_owned(), "Mouse".to_owned()],
+            powered: true,
+        }));
+
+        assert_that!(object.render(), is(equal_to("BT 2 Headphones, Mouse")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "2",
+                "DEVICES" => "Headphones, Mouse",
+                "POWERED" => "true",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_powered_off_adapter() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Status {
+            devices: Vec::new(),
+            powered: false,
+        }));
+
+        assert_that!(object.render(), is(equal_to("BT off")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "0",
+                "DEVICES" => "",
+                "POWERED" => "false",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_unknown_status() {
+        let mut object = Data::new(settings());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("BT NA NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
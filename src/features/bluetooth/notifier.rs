@@ -0,0 +1,61 @@
+use super::SharedStatus;
+use super::bluez;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::dbus;
+use crate::wrapper::thread;
+
+pub(super) struct Notifier {
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    status: SharedStatus,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        status: SharedStatus,
+    ) -> Self {
+        Self { id, sender, status }
+    }
+
+    fn refresh(&self, connection: &dbus::Connection) -> Result<()> {
+        let status = bluez::status(connection)?;
+
+        let changed = {
+            let mut shared = self.status.lock().unwrap();
+            let changed = *shared != status;
+            *shared = status;
+
+            changed
+        };
+
+        if changed {
+            communication::send_message(self.id, &self.sender)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let connection = dbus::Connection::init()?;
+
+        bluez::watch(&connection)?;
+
+        let result = self
+            .refresh(&connection)
+            .and_then(|_| connection.listen_for_signals(|_| self.refresh(&connection)));
+
+        let known = self.status.lock().unwrap().take().is_some();
+
+        if known {
+            communication::send_message(self.id, &self.sender)?;
+        }
+
+        result
+    }
+}
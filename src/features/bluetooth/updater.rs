@@ -0,0 +1,66 @@
+use super::ConfigEntry;
+use super::Data;
+use super::SharedStatus;
+use crate::click;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+
+const PLACEHOLDER_COUNT: &str = "{COUNT}";
+const PLACEHOLDER_DEVICES: &str = "{DEVICES}";
+
+pub(super) struct Updater {
+    data: Data,
+    notifier: notification::LazyNotifier,
+    settings: ConfigEntry,
+    status: SharedStatus,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, settings: ConfigEntry, status: SharedStatus) -> Self {
+        Self {
+            data,
+            notifier: notification::LazyNotifier::default(),
+            settings,
+            status,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let status = self.status.lock().unwrap().clone();
+
+        self.data.update(status.as_ref());
+
+        Ok(())
+    }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        if click::Button::from_number(button)? != click::Button::Left {
+            return Ok(());
+        }
+
+        let devices = self
+            .status
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|status| status.devices.clone())
+            .unwrap_or_default();
+
+        let notifier = self.notifier.get()?;
+
+        notifier.send(
+            &self.settings.notification,
+            &[
+                (PLACEHOLDER_COUNT, devices.len().to_string()),
+                (PLACEHOLDER_DEVICES, devices.join("\n")),
+            ],
+        )
+    }
+}
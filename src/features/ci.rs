@@ -0,0 +1,68 @@
+mod api;
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(self) use self::api::Provider;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::config::PipelineConfig;
+pub(self) use self::data::Data;
+pub(self) use self::data::State;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "ci";
+pub(self) const PLACEHOLDER_NAME: &str = "{Name}";
+pub(self) const PLACEHOLDER_STATE: &str = "{State}";
+
+pub(self) type SharedRuns = Arc<Mutex<Vec<Option<api::Run>>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let providers = settings
+        .pipelines
+        .iter()
+        .map(|pipeline| Provider::parse(&pipeline.provider))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut github = http::Client::init(FEATURE_NAME, http_settings)
+        .with_header("Accept: application/vnd.github+json");
+    if !settings.github_token.is_empty() {
+        github = github.with_header(format!("Authorization: Bearer {}", settings.github_token));
+    }
+
+    let mut gitlab = http::Client::init(FEATURE_NAME, http_settings);
+    if !settings.gitlab_token.is_empty() {
+        gitlab = gitlab.with_header(format!("PRIVATE-TOKEN: {}", settings.gitlab_token));
+    }
+
+    let runs = Arc::new(Mutex::new(vec![None; settings.pipelines.len()]));
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            github,
+            gitlab,
+            providers,
+            settings.clone(),
+            Arc::clone(&runs),
+        ),
+        Updater::new(data, settings.clone(), runs),
+    )))
+}
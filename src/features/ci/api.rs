@@ -0,0 +1,240 @@
+use super::FEATURE_NAME;
+use super::PipelineConfig;
+use super::State;
+use crate::error::*;
+use crate::http;
+use serde_derive::*;
+
+const URL_GITHUB: &str = "https://api.github.com";
+const URL_GITLAB: &str = "https://gitlab.com";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Provider {
+    GitHub,
+    GitLab,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Run {
+    pub(super) state: State,
+    pub(super) url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRun {
+    #[serde(default)]
+    conclusion: Option<String>,
+    html_url: String,
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRuns {
+    workflow_runs: Vec<GitHubRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabPipeline {
+    status: String,
+    web_url: String,
+}
+
+impl Provider {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "github" => Ok(Self::GitHub),
+            "gitlab" => Ok(Self::GitLab),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown provider {}, expected github or gitlab", name),
+            )),
+        }
+    }
+
+    fn url(self, pipeline: &PipelineConfig) -> String {
+        let branch = |parameter: &str| {
+            pipeline.branch.as_ref().map_or_else(String::new, |branch| {
+                format!("&{}={}", parameter, encode(branch))
+            })
+        };
+
+        match self {
+            Self::GitHub => format!(
+                "{}/repos/{}/actions/runs?per_page=1{}",
+                base(pipeline.url.as_deref().unwrap_or(URL_GITHUB)),
+                pipeline.repository,
+                branch("branch")
+            ),
+            Self::GitLab => format!(
+                "{}/api/v4/projects/{}/pipelines?per_page=1{}",
+                base(pipeline.url.as_deref().unwrap_or(URL_GITLAB)),
+                encode(&pipeline.repository),
+                branch("ref")
+            ),
+        }
+    }
+
+    fn parse_run(self, body: &str) -> Result<Option<Run>> {
+        match self {
+            Self::GitHub => {
+                let runs = serde_json::from_str::<GitHubRuns>(body)
+                    .wrap_error(FEATURE_NAME, "invalid workflow runs of GitHub")?;
+
+                Ok(runs.workflow_runs.into_iter().next().and_then(|run| {
+                    Some(Run {
+                        state: github_state(&run.status, run.conclusion.as_deref())?,
+                        url: run.html_url,
+                    })
+                }))
+            },
+            Self::GitLab => {
+                let pipelines = serde_json::from_str::<Vec<GitLabPipeline>>(body)
+                    .wrap_error(FEATURE_NAME, "invalid pipelines of GitLab")?;
+
+                Ok(pipelines.into_iter().next().and_then(|pipeline| {
+                    Some(Run {
+                        state: gitlab_state(&pipeline.status)?,
+                        url: pipeline.web_url,
+                    })
+                }))
+            },
+        }
+    }
+}
+
+pub(super) fn latest_run(
+    client: &mut http::Client,
+    provider: Provider,
+    pipeline: &PipelineConfig,
+) -> Result<Option<Run>> {
+    let body = client.get(&provider.url(pipeline), 0)?;
+
+    provider.parse_run(&body)
+}
+
+fn github_state(status: &str, conclusion: Option<&str>) -> Option<State> {
+    if status != "completed" {
+        return Some(State::Running);
+    }
+
+    match conclusion? {
+        "success" | "neutral" | "skipped" => Some(State::Passed),
+        "cancelled" => Some(State::Canceled),
+        _ => Some(State::Failed),
+    }
+}
+
+fn gitlab_state(status: &str) -> Option<State> {
+    match status {
+        "success" => Some(State::Passed),
+        "failed" => Some(State::Failed),
+        "canceled" | "skipped" => Some(State::Canceled),
+        "created" | "pending" | "preparing" | "running" | "scheduled" | "waiting_for_resource" => {
+            Some(State::Running)
+        },
+        _ => None,
+    }
+}
+
+fn base(url: &str) -> &str {
+    url.trim_end_matches('/')
+}
+
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            },
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use serde_json::json;
+
+    fn pipeline(provider: &str, repository: &str, branch: Option<&str>) -> PipelineConfig {
+        serde_json::from_value(json!({
+            "branch": branch,
+            "provider": provider,
+            "repository": repository,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn url_of_pipelines() {
+        assert_that!(
+            Provider::GitHub.url(&pipeline("github", "ForyoungYu/dwm-status", Some("main"))),
+            is(equal_to(
+                "https://api.github.com/repos/ForyoungYu/dwm-status/actions/runs?per_page=1&\
+                 branch=main"
+            ))
+        );
+        assert_that!(
+            Provider::GitLab.url(&pipeline("gitlab", "group/sub/project", Some("feature/a"))),
+            is(equal_to(
+                "https://gitlab.com/api/v4/projects/group%2Fsub%2Fproject/pipelines?per_page=1&\
+                 ref=feature%2Fa"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_run_of_github() {
+        let body = r#"{"total_count":2,"workflow_runs":[
+            {"status":"completed","conclusion":"failure","html_url":"https://github.com/r/1"},
+            {"status":"completed","conclusion":"success","html_url":"https://github.com/r/0"}
+        ]}"#;
+
+        assert_that!(
+            Provider::GitHub.parse_run(body),
+            is(equal_to(Ok(Some(Run {
+                state: State::Failed,
+                url: "https://github.com/r/1".to_owned(),
+            }))))
+        );
+    }
+
+    #[test]
+    fn parse_run_of_gitlab() {
+        let body =
+            r#"[{"id":7,"status":"running","web_url":"https://gitlab.com/p/-/pipelines/7"}]"#;
+
+        assert_that!(
+            Provider::GitLab.parse_run(body),
+            is(equal_to(Ok(Some(Run {
+                state: State::Running,
+                url: "https://gitlab.com/p/-/pipelines/7".to_owned(),
+            }))))
+        );
+        assert_that!(Provider::GitLab.parse_run("[]"), is(equal_to(Ok(None))));
+    }
+
+    #[test]
+    fn state_of_github_runs() {
+        assert_that!(
+            github_state("in_progress", None),
+            is(equal_to(Some(State::Running)))
+        );
+        assert_that!(
+            github_state("completed", Some("cancelled")),
+            is(equal_to(Some(State::Canceled)))
+        );
+        assert_that!(
+            github_state("completed", Some("timed_out")),
+            is(equal_to(Some(State::Failed)))
+        );
+    }
+
+    #[test]
+    fn parse_unknown_provider() {
+        assert_that!(Provider::parse("jenkins").is_err(), is(true));
+    }
+}
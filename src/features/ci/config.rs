@@ -0,0 +1,117 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct PipelineConfig {
+    #[serde(default)]
+    pub(super) branch: Option<String>,
+    #[serde(default)]
+    pub(super) name: Option<String>,
+    pub(super) provider: String,
+    pub(super) repository: String,
+    #[serde(default)]
+    pub(super) url: Option<String>,
+}
+
+impl PipelineConfig {
+    pub(super) fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            self.repository
+                .rsplit('/')
+                .next()
+                .unwrap_or(&self.repository)
+                .to_owned()
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) canceled: String,
+    pub(super) failed: String,
+    pub(super) github_token: String,
+    pub(super) gitlab_token: String,
+    pub(super) no_value: String,
+    pub(super) notification: NotificationConfig,
+    pub(super) notifier: bool,
+    pub(super) passed: String,
+    pub(super) pipelines: Vec<PipelineConfig>,
+    pub(super) running: String,
+    pub(super) separator: String,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "canceled"        => "CANCELED",
+                "failed"          => "FAIL",
+                "github_token"    => "",
+                "gitlab_token"    => "",
+                "no_value"        => "NA",
+                "notification"    => NotificationConfig::default_map(
+                    "{NAME} {STATE}",
+                    "{URL}",
+                    "normal",
+                ),
+                "notifier"        => true,
+                "passed"          => "PASS",
+                "pipelines"       => Vec::<Value>::new(),
+                "running"         => "RUN",
+                "separator"       => " ",
+                "template"        => "{Name} {State}",
+                "update_interval" => 300,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("ci", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("ci", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("canceled".to_owned(), "CANCELED".into());
+        map.insert("failed".to_owned(), "FAIL".into());
+        map.insert("github_token".to_owned(), "".into());
+        map.insert("gitlab_token".to_owned(), "".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map("{NAME} {STATE}", "{URL}", "normal").into(),
+        );
+        map.insert("notifier".to_owned(), true.into());
+        map.insert("passed".to_owned(), "PASS".into());
+        map.insert("pipelines".to_owned(), Vec::<Value>::new().into());
+        map.insert("running".to_owned(), "RUN".into());
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("template".to_owned(), "{Name} {State}".into());
+        map.insert("update_interval".to_owned(), 300.into());
+
+        map
+    }
+}
@@ -0,0 +1,134 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_NAME;
+use super::PLACEHOLDER_STATE;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum State {
+    Canceled,
+    Failed,
+    Passed,
+    Running,
+}
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn state_text(&self, state: Option<State>) -> &str {
+        match state {
+            Some(State::Canceled) => &self.settings.canceled,
+            Some(State::Failed) => &self.settings.failed,
+            Some(State::Passed) => &self.settings.passed,
+            Some(State::Running) => &self.settings.running,
+            None => &self.settings.no_value,
+        }
+    }
+
+    pub(super) fn update(&mut self, pipelines: &[(&str, Option<State>)]) {
+        self.cache = pipelines
+            .iter()
+            .map(|(name, state)| {
+                self.settings
+                    .template
+                    .replace(PLACEHOLDER_NAME, name)
+                    .replace(PLACEHOLDER_STATE, self.state_text(*state))
+            })
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        let count = |expected: State| {
+            pipelines
+                .iter()
+                .filter(|(_, state)| *state == Some(expected))
+                .count()
+        };
+
+        self.values = map_values!(
+            "FAILED" => count(State::Failed),
+            "PASSED" => count(State::Passed),
+            "RUNNING" => count(State::Running),
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            canceled: "CANCELED".to_owned(),
+            failed: "FAIL".to_owned(),
+            github_token: String::new(),
+            gitlab_token: String::new(),
+            no_value: "NA".to_owned(),
+            notification: NotificationConfig::default(),
+            notifier: false,
+            passed: "PASS".to_owned(),
+            pipelines: Vec::new(),
+            running: "RUN".to_owned(),
+            separator: " · ".to_owned(),
+            template: "{Name} {State}".to_owned(),
+            update_interval: 300,
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(&[
+            ("dwm-status", Some(State::Passed)),
+            ("website", Some(State::Running)),
+            ("infra", None),
+        ]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("dwm-status PASS · website RUN · infra NA"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "FAILED" => "0",
+                "PASSED" => "1",
+                "RUNNING" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
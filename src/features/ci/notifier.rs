@@ -0,0 +1,79 @@
+use super::ConfigEntry;
+use super::Provider;
+use super::SharedRuns;
+use super::api;
+use crate::communication;
+use crate::error::*;
+use crate::http;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use std::sync::Mutex;
+
+pub(super) struct Notifier {
+    github: Mutex<http::Client>,
+    gitlab: Mutex<http::Client>,
+    id: usize,
+    providers: Vec<Provider>,
+    runs: SharedRuns,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+}
+
+impl Notifier {
+    pub(super) fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        github: http::Client,
+        gitlab: http::Client,
+        providers: Vec<Provider>,
+        settings: ConfigEntry,
+        runs: SharedRuns,
+    ) -> Self {
+        Self {
+            github: Mutex::new(github),
+            gitlab: Mutex::new(gitlab),
+            id,
+            providers,
+            runs,
+            sender,
+            settings,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            let mut github = self.github.lock().unwrap();
+            let mut gitlab = self.gitlab.lock().unwrap();
+
+            let runs = self
+                .settings
+                .pipelines
+                .iter()
+                .zip(&self.providers)
+                .map(|(pipeline, provider)| {
+                    let client = match provider {
+                        Provider::GitHub => &mut github,
+                        Provider::GitLab => &mut gitlab,
+                    };
+
+                    api::latest_run(client, *provider, pipeline)
+                        .show_error()
+                        .ok()
+                        .flatten()
+                })
+                .collect();
+
+            *self.runs.lock().unwrap() = runs;
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.settings.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.settings.update_interval)
+    }
+}
@@ -0,0 +1,100 @@
+use super::ConfigEntry;
+use super::Data;
+use super::SharedRuns;
+use super::State;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+
+const PLACEHOLDER_NAME: &str = "{NAME}";
+const PLACEHOLDER_STATE: &str = "{STATE}";
+const PLACEHOLDER_URL: &str = "{URL}";
+
+struct Pipeline {
+    name: String,
+    notifier: notification::LazyNotifier,
+    state: Option<State>,
+    url: String,
+}
+
+pub(super) struct Updater {
+    data: Data,
+    pipelines: Vec<Pipeline>,
+    runs: SharedRuns,
+    settings: ConfigEntry,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, settings: ConfigEntry, runs: SharedRuns) -> Self {
+        let pipelines = settings
+            .pipelines
+            .iter()
+            .map(|config| Pipeline {
+                name: config.name(),
+                notifier: notification::LazyNotifier::default(),
+                state: None,
+                url: String::new(),
+            })
+            .collect();
+
+        Self {
+            data,
+            pipelines,
+            runs,
+            settings,
+        }
+    }
+
+    fn notify(&mut self, index: usize) -> Result<()> {
+        let pipeline = &mut self.pipelines[index];
+
+        pipeline.notifier.get()?.send(
+            &self.settings.notification,
+            &[
+                (PLACEHOLDER_NAME, pipeline.name.clone()),
+                (
+                    PLACEHOLDER_STATE,
+                    self.data.state_text(pipeline.state).to_owned(),
+                ),
+                (PLACEHOLDER_URL, pipeline.url.clone()),
+            ],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let runs = self.runs.lock().unwrap().clone();
+
+        for (index, run) in runs.into_iter().enumerate() {
+            let pipeline = &mut self.pipelines[index];
+            let last = pipeline.state;
+            pipeline.state = run.as_ref().map(|run| run.state);
+            if let Some(run) = run {
+                pipeline.url = run.url;
+            }
+
+            let finished = last == Some(State::Running)
+                && pipeline.state.is_some()
+                && pipeline.state != Some(State::Running);
+
+            if self.settings.notifier && finished {
+                self.notify(index).show_error_and_ignore();
+            }
+        }
+
+        let pipelines = self
+            .pipelines
+            .iter()
+            .map(|pipeline| (&pipeline.name[..], pipeline.state))
+            .collect::<Vec<_>>();
+
+        self.data.update(&pipelines);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::registry;
+use crate::wrapper::channel;
+use std::sync::Arc;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "composite";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    registry: &registry::SharedRegistry,
+) -> Result<Box<dyn feature::Feature>> {
+    let features = data::referenced_features(&settings.template);
+
+    if features.is_empty() {
+        return Err(Error::new_custom(
+            FEATURE_NAME,
+            "template contains no placeholders {<feature>.<key>}",
+        ));
+    }
+
+    let data = Data::new(settings.template.clone(), settings.no_value.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(features),
+        Updater::new(data, Arc::clone(registry)),
+    )))
+}
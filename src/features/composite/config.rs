@@ -0,0 +1,51 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) no_value: String,
+    pub(super) template: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "no_value" => "NA",
+                "template" => "",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("composite", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("composite", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("template".to_owned(), "".into());
+
+        map
+    }
+}
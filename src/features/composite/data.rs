@@ -0,0 +1,150 @@
+use crate::feature::Renderable;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+        }
+    }
+
+    pub(super) fn update<F>(&mut self, value: F)
+    where
+        F: Fn(&str, &str) -> Option<String>,
+    {
+        let no_value = &self.no_value;
+
+        self.cache = segments(&self.template)
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Placeholder(name, key) => {
+                    value(name, key).unwrap_or_else(|| no_value.clone())
+                },
+                Segment::Text(text) => text.to_owned(),
+            })
+            .collect();
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Placeholder(&'a str, &'a str),
+    Text(&'a str),
+}
+
+pub(super) fn referenced_features(template: &str) -> Vec<String> {
+    let mut features = Vec::<String>::new();
+
+    for segment in segments(template) {
+        if let Segment::Placeholder(name, _) = segment {
+            if !features
+                .iter()
+                .any(|feature| feature.eq_ignore_ascii_case(name))
+            {
+                features.push(name.to_lowercase());
+            }
+        }
+    }
+
+    features
+}
+
+fn segments(template: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        let mut parts = rest[start + 1..end].splitn(2, '.');
+
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(key)) if !name.is_empty() && !key.is_empty() => {
+                segments.push(Segment::Text(&rest[..start]));
+                segments.push(Segment::Placeholder(name, key));
+            },
+            _ => segments.push(Segment::Text(&rest[..=end])),
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    segments.push(Segment::Text(rest));
+
+    segments
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn value(name: &str, key: &str) -> Option<String> {
+        match (name, key) {
+            ("network", "ESSID") => Some("home".to_owned()),
+            ("battery", "CAP") => Some("87".to_owned()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("{network.ESSID}".to_owned(), "NA".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(
+            "{network.ESSID} {battery.CAP}% {vpn.STATE}".to_owned(),
+            "NA".to_owned(),
+        );
+
+        object.update(value);
+
+        assert_that!(object.render(), is(equal_to("home 87% NA")));
+    }
+
+    #[test]
+    fn render_with_update_keeps_other_braces() {
+        let mut object = Data::new(
+            "^c#fff^{Plain} {.KEY} {network.ESSID} {unclosed".to_owned(),
+            "NA".to_owned(),
+        );
+
+        object.update(value);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("^c#fff^{Plain} {.KEY} home {unclosed"))
+        );
+    }
+
+    #[test]
+    fn referenced_features_once() {
+        assert_that!(
+            referenced_features("{network.ESSID} {Network.IPv4} {battery.CAP} {Plain}"),
+            is(equal_to(vec!["network".to_owned(), "battery".to_owned()]))
+        );
+    }
+}
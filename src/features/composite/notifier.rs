@@ -0,0 +1,22 @@
+use crate::error::*;
+use crate::wrapper::thread;
+
+pub(super) struct Notifier {
+    features: Vec<String>,
+}
+
+impl Notifier {
+    pub(super) const fn new(features: Vec<String>) -> Self {
+        Self { features }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        self.features.clone()
+    }
+}
@@ -0,0 +1,30 @@
+use super::Data;
+use crate::error::*;
+use crate::feature;
+use crate::registry;
+
+pub(super) struct Updater {
+    data: Data,
+    registry: registry::SharedRegistry,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, registry: registry::SharedRegistry) -> Self {
+        Self { data, registry }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let registry = self.registry.lock().unwrap();
+
+        self.data
+            .update(|name, key| registry.value(name, key).map(str::to_owned));
+
+        Ok(())
+    }
+}
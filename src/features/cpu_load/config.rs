@@ -7,6 +7,7 @@ use serde_derive::*;
 
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ConfigEntry {
+    pub(super) per_core: bool,
     pub(super) template: String,
     pub(super) update_interval: u64,
 }
@@ -16,6 +17,7 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
+                "per_core"        => false,
                 "template"        => "{CL1} {CL5} {CL15}",
                 "update_interval" => 20,
             ),
@@ -43,6 +45,7 @@ mod tests {
 
     fn default_map() -> HashMap<String, Value> {
         let mut map = HashMap::new();
+        map.insert("per_core".to_owned(), false.into());
         map.insert("template".to_owned(), "{CL1} {CL5} {CL15}".into());
         map.insert("update_interval".to_owned(), 20.into());
 
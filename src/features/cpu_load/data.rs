@@ -1,26 +1,52 @@
 use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
 
 #[derive(Debug)]
 pub(super) struct Data {
     cache: String,
+    decimal_separator: Option<String>,
+    per_core: bool,
     template: String,
+    values: Values,
 }
 
 impl Data {
     #[allow(clippy::missing_const_for_fn)]
-    pub(super) fn new(template: String) -> Self {
+    pub(super) fn new(template: String, decimal_separator: Option<String>, per_core: bool) -> Self {
         Self {
             cache: String::new(),
+            decimal_separator,
+            per_core,
             template,
+            values: Values::new(),
         }
     }
 
-    pub(super) fn update(&mut self, one: f32, five: f32, fifteen: f32) {
+    pub(super) fn update(&mut self, one: f32, five: f32, fifteen: f32, cores: u16) {
+        let divisor = if self.per_core && cores > 0 {
+            f32::from(cores)
+        } else {
+            1.
+        };
+
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let one = locale::format_decimal(one / divisor, 2, separator);
+        let five = locale::format_decimal(five / divisor, 2, separator);
+        let fifteen = locale::format_decimal(fifteen / divisor, 2, separator);
+
         self.cache = self
             .template
-            .replace("{CL1}", &format!("{:.2}", one))
-            .replace("{CL5}", &format!("{:.2}", five))
-            .replace("{CL15}", &format!("{:.2}", fifteen));
+            .replace("{CL1}", &one)
+            .replace("{CL5}", &five)
+            .replace("{CL15}", &fifteen)
+            .replace("{Cores}", &cores.to_string());
+        self.values = map_values!(
+            "CL1" => one,
+            "CL15" => fifteen,
+            "CL5" => five,
+            "CORES" => cores,
+        );
     }
 }
 
@@ -28,6 +54,10 @@ impl Renderable for Data {
     fn render(&self) -> &str {
         &self.cache
     }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
 }
 
 #[cfg(test)]
@@ -38,26 +68,60 @@ mod tests {
 
     #[test]
     fn render_with_default() {
-        let object = Data::new("{CL1} {CL5} {CL15}".to_owned());
+        let object = Data::new("{CL1} {CL5} {CL15}".to_owned(), None, false);
 
         assert_that!(object.render(), is(equal_to("")));
     }
 
     #[test]
     fn render_with_update() {
-        let mut object = Data::new("{CL1} {CL5} {CL15}".to_owned());
+        let mut object = Data::new("{CL1} {CL5} {CL15}".to_owned(), None, false);
 
-        object.update(20.1234, 0.005, 5.3);
+        object.update(20.1234, 0.005, 5.3, 4);
 
         assert_that!(object.render(), is(equal_to("20.12 0.00 5.30")));
     }
 
     #[test]
     fn render_with_update_and_missing_placeholder() {
-        let mut object = Data::new("{CL1} - {CL15}".to_owned());
+        let mut object = Data::new("{CL1} - {CL15}".to_owned(), None, false);
 
-        object.update(20.1234, 0.005, 5.3);
+        object.update(20.1234, 0.005, 5.3, 4);
 
         assert_that!(object.render(), is(equal_to("20.12 - 5.30")));
     }
+
+    #[test]
+    fn render_with_update_and_decimal_separator() {
+        let mut object = Data::new("{CL1} {CL5} {CL15}".to_owned(), Some(",".to_owned()), false);
+
+        object.update(20.1234, 0.005, 5.3, 4);
+
+        assert_that!(object.render(), is(equal_to("20,12 0,00 5,30")));
+    }
+
+    #[test]
+    fn values_with_update() {
+        let mut object = Data::new("{CL1}".to_owned(), None, false);
+
+        object.update(20.1234, 0.005, 5.3, 4);
+
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CL1" => "20.12",
+                "CL15" => "5.30",
+                "CL5" => "0.00",
+                "CORES" => "4",
+            )))
+        );
+    }
+    #[test]
+    fn render_with_update_per_core() {
+        let mut object = Data::new("{CL1} {CL5} {CL15} / {Cores}".to_owned(), None, true);
+
+        object.update(2., 1., 0.6, 4);
+
+        assert_that!(object.render(), is(equal_to("0.50 0.25 0.15 / 4")));
+    }
 }
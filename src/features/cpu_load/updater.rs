@@ -5,6 +5,7 @@ use crate::feature;
 use crate::wrapper::file;
 
 const PATH_LOADAVG: &str = "/proc/loadavg";
+const PATH_ONLINE_CPUS: &str = "/sys/devices/system/cpu/online";
 
 pub(super) struct Updater {
     data: Data,
@@ -31,7 +32,10 @@ impl feature::Updatable for Updater {
         let five = convert_to_float(iterator.next())?;
         let fifteen = convert_to_float(iterator.next())?;
 
-        self.data.update(one, five, fifteen);
+        let online = file::read(PATH_ONLINE_CPUS)
+            .wrap_error(FEATURE_NAME, format!("failed to read {}", PATH_ONLINE_CPUS))?;
+
+        self.data.update(one, five, fifteen, count_cpus(&online)?);
 
         Ok(())
     }
@@ -42,3 +46,49 @@ fn convert_to_float(data: Option<&str>) -> Result<f32> {
         .parse()
         .wrap_error(FEATURE_NAME, "could not convert to float")
 }
+
+fn count_cpus(list: &str) -> Result<u16> {
+    let mut count = 0;
+
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        let mut bounds = range.splitn(2, '-');
+
+        let start = convert_to_cpu(bounds.next())?;
+        let end = match bounds.next() {
+            Some(end) => convert_to_cpu(Some(end))?,
+            None => start,
+        };
+
+        count += end.saturating_sub(start) + 1;
+    }
+
+    Ok(count)
+}
+
+fn convert_to_cpu(data: Option<&str>) -> Result<u16> {
+    data.wrap_error(FEATURE_NAME, "no cpu found")?
+        .parse()
+        .wrap_error(FEATURE_NAME, "could not convert cpu to number")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn count_cpus_with_ranges() {
+        assert_that!(count_cpus("0-3,6,8-9\n"), is(equal_to(Ok(7))));
+    }
+
+    #[test]
+    fn count_cpus_with_single_cpu() {
+        assert_that!(count_cpus("0\n"), is(equal_to(Ok(1))));
+    }
+
+    #[test]
+    fn count_cpus_with_invalid_list() {
+        assert_that!(count_cpus("0-a").is_err(), is(true));
+    }
+}
@@ -0,0 +1,37 @@
+mod config;
+mod data;
+mod sensor;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::sensor::Readings;
+pub(self) use self::sensor::Sensor;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "cpu_temp";
+pub(self) const PLACEHOLDER_AVG: &str = "{Avg}";
+pub(self) const PLACEHOLDER_MAX: &str = "{Max}";
+pub(self) const PLACEHOLDER_MIN: &str = "{Min}";
+pub(self) const PLACEHOLDER_PACKAGE: &str = "{Package}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let sensor = Sensor::detect(&settings.chip)?;
+    let data = Data::new(settings.template.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, sensor),
+    )))
+}
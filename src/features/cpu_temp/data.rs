@@ -0,0 +1,129 @@
+use super::PLACEHOLDER_AVG;
+use super::PLACEHOLDER_MAX;
+use super::PLACEHOLDER_MIN;
+use super::PLACEHOLDER_PACKAGE;
+use super::Readings;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String) -> Self {
+        Self {
+            cache: String::new(),
+            template,
+            values: Values::new(),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub(super) fn update(&mut self, readings: &Readings) {
+        let temperatures: Vec<f32> = if readings.cores.is_empty() {
+            readings.package.into_iter().collect()
+        } else {
+            readings.cores.clone()
+        };
+
+        let min = temperatures
+            .iter()
+            .copied()
+            .fold(std::f32::INFINITY, f32::min);
+        let max = temperatures
+            .iter()
+            .copied()
+            .fold(std::f32::NEG_INFINITY, f32::max);
+        let avg = temperatures.iter().sum::<f32>() / temperatures.len().max(1) as f32;
+        let package = readings.package.unwrap_or(max);
+
+        let format = |temperature: f32| format!("{:.0}", temperature);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_AVG, &format(avg))
+            .replace(PLACEHOLDER_MAX, &format(max))
+            .replace(PLACEHOLDER_MIN, &format(min))
+            .replace(PLACEHOLDER_PACKAGE, &format(package));
+        self.values = map_values!(
+            "AVG" => format(avg),
+            "MAX" => format(max),
+            "MIN" => format(min),
+            "PACKAGE" => format(package),
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("T {Package}°C".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("{Package} {Min}/{Avg}/{Max}".to_owned());
+
+        object.update(&Readings {
+            cores: vec![41., 47.5, 52., 44.],
+            package: Some(55.),
+        });
+
+        assert_that!(object.render(), is(equal_to("55 41/46/52")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "AVG" => "46",
+                "MAX" => "52",
+                "MIN" => "41",
+                "PACKAGE" => "55",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_package() {
+        let mut object = Data::new("{Package} {Min}/{Avg}/{Max}".to_owned());
+
+        object.update(&Readings {
+            cores: vec![62., 58.],
+            package: None,
+        });
+
+        assert_that!(object.render(), is(equal_to("62 58/60/62")));
+    }
+
+    #[test]
+    fn render_with_update_without_cores() {
+        let mut object = Data::new("{Package} {Min}/{Avg}/{Max}".to_owned());
+
+        object.update(&Readings {
+            cores: Vec::new(),
+            package: Some(48.2),
+        });
+
+        assert_that!(object.render(), is(equal_to("48 48/48/48")));
+    }
+}
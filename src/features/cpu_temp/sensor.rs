@@ -0,0 +1,180 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+use std::fs;
+use std::path;
+
+const CHIPS: &[&str] = &["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+const PATH_HWMON: &str = "/sys/class/hwmon";
+const PATH_THERMAL: &str = "/sys/class/thermal";
+const ZONES: &[&str] = &["x86_pkg_temp", "cpu-thermal", "cpu_thermal", "soc_thermal"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Input {
+    Core,
+    Other,
+    Package,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Readings {
+    pub(super) cores: Vec<f32>,
+    pub(super) package: Option<f32>,
+}
+
+#[derive(Debug)]
+pub(super) struct Sensor {
+    cores: Vec<path::PathBuf>,
+    package: Option<path::PathBuf>,
+}
+
+impl Sensor {
+    pub(super) fn detect(chip: &str) -> Result<Self> {
+        let configured = [chip];
+        let chips: &[&str] = if chip.is_empty() { CHIPS } else { &configured };
+
+        for name in chips {
+            if let Some(sensor) = hwmon_chips(name).into_iter().find_map(from_hwmon) {
+                return Ok(sensor);
+            }
+        }
+
+        if !chip.is_empty() {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("no temperature inputs of hwmon chip {} found", chip),
+            ));
+        }
+
+        thermal_zone()
+            .map(|zone| Self {
+                cores: Vec::new(),
+                package: Some(zone.join("temp")),
+            })
+            .wrap_error(FEATURE_NAME, "no CPU temperature sensor found")
+    }
+
+    pub(super) fn read(&self) -> Result<Readings> {
+        Ok(Readings {
+            cores: self
+                .cores
+                .iter()
+                .map(|path| read_temperature(path))
+                .collect::<Result<Vec<_>>>()?,
+            package: self
+                .package
+                .as_ref()
+                .map(|path| read_temperature(path))
+                .transpose()?,
+        })
+    }
+}
+
+fn hwmon_chips(name: &str) -> Vec<path::PathBuf> {
+    let mut paths = fs::read_dir(PATH_HWMON)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| file::read(path.join("name")).map_or(false, |chip| chip.trim() == name))
+        .collect::<Vec<_>>();
+
+    paths.sort();
+
+    paths
+}
+
+fn from_hwmon(chip: path::PathBuf) -> Option<Sensor> {
+    let mut inputs = fs::read_dir(&chip)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with("temp") && name.ends_with("_input"))
+        .collect::<Vec<_>>();
+
+    inputs.sort();
+
+    let mut sensor = Sensor {
+        cores: Vec::new(),
+        package: None,
+    };
+    let mut other = None;
+
+    for input in inputs {
+        let label = file::read(chip.join(input.replace("_input", "_label"))).unwrap_or_default();
+        let path = chip.join(&input);
+
+        match classify(label.trim()) {
+            Input::Core => sensor.cores.push(path),
+            Input::Package if sensor.package.is_none() => sensor.package = Some(path),
+            Input::Package | Input::Other => {
+                if other.is_none() {
+                    other = Some(path);
+                }
+            },
+        }
+    }
+
+    if sensor.package.is_none() && sensor.cores.is_empty() {
+        sensor.package = Some(other?);
+    }
+
+    Some(sensor)
+}
+
+fn thermal_zone() -> Option<path::PathBuf> {
+    let mut zones = fs::read_dir(PATH_THERMAL)
+        .ok()?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            file::read(path.join("type")).map_or(false, |kind| ZONES.contains(&kind.trim()))
+        })
+        .collect::<Vec<_>>();
+
+    zones.sort();
+    zones.into_iter().next()
+}
+
+fn classify(label: &str) -> Input {
+    if label.starts_with("Core") || label.starts_with("Tccd") {
+        Input::Core
+    } else if label.starts_with("Package") || label == "Tctl" || label == "Tdie" {
+        Input::Package
+    } else {
+        Input::Other
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn read_temperature(path: &path::Path) -> Result<f32> {
+    let millidegrees = file::parse_file_content::<_, i64>(path).wrap_error(
+        FEATURE_NAME,
+        format!("temperature of {} could not be read", path.display()),
+    )?;
+
+    Ok(millidegrees as f32 / 1000.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn classify_labels_of_coretemp() {
+        assert_that!(classify("Package id 0"), is(equal_to(Input::Package)));
+        assert_that!(classify("Core 12"), is(equal_to(Input::Core)));
+    }
+
+    #[test]
+    fn classify_labels_of_k10temp() {
+        assert_that!(classify("Tctl"), is(equal_to(Input::Package)));
+        assert_that!(classify("Tdie"), is(equal_to(Input::Package)));
+        assert_that!(classify("Tccd1"), is(equal_to(Input::Core)));
+    }
+
+    #[test]
+    fn classify_unknown_labels() {
+        assert_that!(classify(""), is(equal_to(Input::Other)));
+        assert_that!(classify("SoC"), is(equal_to(Input::Other)));
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::Sensor;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    sensor: Sensor,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, sensor: Sensor) -> Self {
+        Self { data, sensor }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let readings = self.sensor.read()?;
+
+        self.data.update(&readings);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+mod config;
+mod data;
+mod lease;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::lease::Lease;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "dhcp";
+pub(self) const PLACEHOLDER_EXPIRY: &str = "{Expiry}";
+pub(self) const PLACEHOLDER_INTERFACE: &str = "{Interface}";
+pub(self) const PLACEHOLDER_RENEW: &str = "{Renew}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.template.clone(), settings.no_value.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, settings.interface.clone()),
+    )))
+}
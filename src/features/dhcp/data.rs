@@ -0,0 +1,135 @@
+use super::Lease;
+use super::PLACEHOLDER_EXPIRY;
+use super::PLACEHOLDER_INTERFACE;
+use super::PLACEHOLDER_RENEW;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, lease: Option<&Lease>, now: i64) {
+        let lease = match lease {
+            Some(lease) => lease,
+            None => {
+                self.cache = self
+                    .template
+                    .replace(PLACEHOLDER_EXPIRY, &self.no_value)
+                    .replace(PLACEHOLDER_INTERFACE, &self.no_value)
+                    .replace(PLACEHOLDER_RENEW, &self.no_value);
+                self.values = Values::new();
+                return;
+            },
+        };
+
+        let expiry = (lease.expiry - now).max(0);
+        let renewal = (lease.renewal - now).max(0);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_EXPIRY, &format_time(expiry))
+            .replace(PLACEHOLDER_INTERFACE, &lease.interface)
+            .replace(PLACEHOLDER_RENEW, &format_time(renewal));
+        self.values = map_values!(
+            "EXPIRY" => expiry,
+            "INTERFACE" => lease.interface,
+            "RENEW" => renewal,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+fn format_time(seconds: i64) -> String {
+    format!("{:02}:{:02}", seconds / 3600, seconds % 3600 / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "{Interface} R {Renew} E {Expiry}";
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        object.update(
+            Some(&Lease {
+                expiry: 10_000 + 86_400,
+                interface: "wlan0".to_owned(),
+                renewal: 10_000 + 43_200,
+            }),
+            10_000 + 3_600 + 90,
+        );
+
+        assert_that!(object.render(), is(equal_to("wlan0 R 10:58 E 22:58")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "EXPIRY" => "82710",
+                "INTERFACE" => "wlan0",
+                "RENEW" => "39510",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_with_overdue_renewal() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        object.update(
+            Some(&Lease {
+                expiry: 3_600,
+                interface: "eth0".to_owned(),
+                renewal: 1_800,
+            }),
+            2_000,
+        );
+
+        assert_that!(object.render(), is(equal_to("eth0 R 00:00 E 00:26")));
+    }
+
+    #[test]
+    fn render_with_update_without_lease() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        object.update(None, 1_000);
+
+        assert_that!(object.render(), is(equal_to("NA R NA E NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
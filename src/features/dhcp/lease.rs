@@ -0,0 +1,224 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+use crate::wrapper::process;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path;
+use std::time::UNIX_EPOCH;
+
+const PATH_DHCPCD: &str = "/var/lib/dhcpcd";
+const PATH_NETWORK_MANAGER: &str = "/var/lib/NetworkManager";
+const PATH_NETWORKD: &str = "/run/systemd/netif/leases";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Lease {
+    pub(super) expiry: i64,
+    pub(super) interface: String,
+    pub(super) renewal: i64,
+}
+
+pub(super) fn find(interface: &str) -> Result<Option<Lease>> {
+    let mut leases = Vec::new();
+
+    for (path, lease_interface) in network_manager_files().chain(networkd_files()) {
+        if !interface.is_empty() && lease_interface != interface {
+            continue;
+        }
+
+        let content = file::read(&path).wrap_error(
+            FEATURE_NAME,
+            format!("lease could not be read from {}", path.display()),
+        )?;
+
+        if let Some((renewal, lifetime)) = parse_systemd_lease(&content) {
+            leases.push(lease(&path, lease_interface, renewal, lifetime)?);
+        }
+    }
+
+    for (path, lease_interface) in dhcpcd_files() {
+        if !interface.is_empty() && lease_interface != interface {
+            continue;
+        }
+
+        let output =
+            process::Command::new("dhcpcd", &["--dumplease", "-4", lease_interface.as_str()])
+                .output()
+                .wrap_error(FEATURE_NAME, "dhcpcd --dumplease failed")?;
+
+        if let Some((renewal, lifetime)) = parse_dhcpcd_lease(&output) {
+            leases.push(lease(&path, lease_interface, renewal, lifetime)?);
+        }
+    }
+
+    Ok(leases.into_iter().max_by_key(|lease| lease.expiry))
+}
+
+fn lease(path: &path::Path, interface: String, renewal: i64, lifetime: i64) -> Result<Lease> {
+    let acquired = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .wrap_error(
+            FEATURE_NAME,
+            format!("modification time of {} could not be read", path.display()),
+        )?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| i64::try_from(duration.as_secs()).unwrap_or_default())
+        .unwrap_or_default();
+
+    Ok(Lease {
+        expiry: acquired + lifetime,
+        interface,
+        renewal: acquired + renewal,
+    })
+}
+
+fn network_manager_files() -> impl Iterator<Item = (path::PathBuf, String)> {
+    files(PATH_NETWORK_MANAGER).filter_map(|(path, name)| {
+        if !name.starts_with("internal-") || !name.ends_with(".lease") {
+            return None;
+        }
+
+        let interface = name
+            .trim_end_matches(".lease")
+            .get("internal-".len() + 36 + 1..)?
+            .to_owned();
+
+        Some((path, interface))
+    })
+}
+
+fn networkd_files() -> impl Iterator<Item = (path::PathBuf, String)> {
+    let interfaces = interface_indices();
+
+    files(PATH_NETWORKD).filter_map(move |(path, name)| {
+        let interface = interfaces.get(&name)?.clone();
+
+        Some((path, interface))
+    })
+}
+
+fn dhcpcd_files() -> impl Iterator<Item = (path::PathBuf, String)> {
+    files(PATH_DHCPCD).filter_map(|(path, name)| {
+        if !name.ends_with(".lease") {
+            return None;
+        }
+
+        let interface = name.trim_end_matches(".lease").to_owned();
+
+        Some((path, interface))
+    })
+}
+
+fn files(directory: &str) -> impl Iterator<Item = (path::PathBuf, String)> {
+    fs::read_dir(directory)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+
+            Some((entry.path(), name))
+        })
+}
+
+fn interface_indices() -> HashMap<String, String> {
+    files("/sys/class/net")
+        .filter_map(|(path, name)| {
+            let index = file::read(path.join("ifindex")).ok()?.trim().to_owned();
+
+            Some((index, name))
+        })
+        .collect()
+}
+
+fn parse_systemd_lease(content: &str) -> Option<(i64, i64)> {
+    let values = content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            Some((parts.next()?.trim(), parts.next()?.trim()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let lifetime = values.get("LIFETIME")?.parse::<i64>().ok()?;
+    let renewal = values
+        .get("T1")
+        .and_then(|t1| t1.parse::<i64>().ok())
+        .unwrap_or(lifetime / 2);
+
+    Some((renewal, lifetime))
+}
+
+fn parse_dhcpcd_lease(output: &str) -> Option<(i64, i64)> {
+    let values = output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim().trim_matches('\'');
+            Some((key, value))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let lifetime = values.get("dhcp_lease_time")?.parse::<i64>().ok()?;
+    let renewal = values
+        .get("dhcp_renewal_time")
+        .and_then(|t1| t1.parse::<i64>().ok())
+        .unwrap_or(lifetime / 2);
+
+    Some((renewal, lifetime))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_systemd_lease_with_t1() {
+        let content = "\
+# This is private data. Do not parse.
+ADDRESS=192.168.1.23
+NETMASK=255.255.255.0
+ROUTER=192.168.1.1
+SERVER_ADDRESS=192.168.1.1
+T1=1800
+T2=3150
+LIFETIME=3600
+DNS=192.168.1.1
+";
+
+        assert_that!(
+            parse_systemd_lease(content),
+            is(equal_to(Some((1800, 3600))))
+        );
+    }
+
+    #[test]
+    fn parse_systemd_lease_without_t1() {
+        assert_that!(
+            parse_systemd_lease("ADDRESS=10.0.0.2\nLIFETIME=86400\n"),
+            is(equal_to(Some((43200, 86400))))
+        );
+    }
+
+    #[test]
+    fn parse_systemd_lease_without_lifetime() {
+        assert_that!(parse_systemd_lease("ADDRESS=10.0.0.2\n"), is(none()));
+    }
+
+    #[test]
+    fn parse_dhcpcd_lease_with_times() {
+        let output = "\
+broadcast_address='192.168.1.255'
+dhcp_lease_time='7200'
+dhcp_renewal_time='3600'
+dhcp_server_identifier='192.168.1.1'
+ip_address='192.168.1.23'
+";
+
+        assert_that!(parse_dhcpcd_lease(output), is(equal_to(Some((3600, 7200)))));
+    }
+}
@@ -0,0 +1,31 @@
+use super::Data;
+use super::lease;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::date_time;
+
+pub(super) struct Updater {
+    data: Data,
+    interface: String,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, interface: String) -> Self {
+        Self { data, interface }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let lease = lease::find(&self.interface)?;
+
+        self.data
+            .update(lease.as_ref(), date_time::DateTime::now().timestamp());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_FREE;
+use super::PLACEHOLDER_MOUNT;
+use super::PLACEHOLDER_PERCENT;
+use super::PLACEHOLDER_TOTAL;
+use super::PLACEHOLDER_USED;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils;
+use crate::wrapper::statvfs::Usage;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, usages: &[Option<Usage>]) {
+        self.cache = self
+            .settings
+            .mounts
+            .iter()
+            .zip(usages)
+            .map(|(mount, usage)| self.render_mount(mount, usage.as_ref()))
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        self.values = match usages.first() {
+            Some(Some(usage)) => map_values!(
+                "FREE" => usage.available,
+                "PERCENT" => usage.percent(),
+                "TOTAL" => usage.total,
+                "USED" => usage.used,
+            ),
+            _ => Values::new(),
+        };
+    }
+
+    fn render_mount(&self, mount: &str, usage: Option<&Usage>) -> String {
+        let value = |format: fn(&Usage) -> String| {
+            usage.map_or_else(|| self.settings.no_value.clone(), format)
+        };
+
+        self.settings
+            .template
+            .replace(
+                PLACEHOLDER_FREE,
+                &value(|usage| utils::format_bytes(usage.available)),
+            )
+            .replace(PLACEHOLDER_MOUNT, mount)
+            .replace(
+                PLACEHOLDER_PERCENT,
+                &value(|usage| usage.percent().to_string()),
+            )
+            .replace(
+                PLACEHOLDER_TOTAL,
+                &value(|usage| utils::format_bytes(usage.total)),
+            )
+            .replace(
+                PLACEHOLDER_USED,
+                &value(|usage| utils::format_bytes(usage.used)),
+            )
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            mounts: vec!["/".to_owned(), "/home".to_owned(), "/mnt/usb".to_owned()],
+            no_value: "NA".to_owned(),
+            notification_critical: NotificationConfig::default(),
+            notification_recovered: NotificationConfig::default(),
+            notification_warning: NotificationConfig::default(),
+            notifier_critical: 95,
+            notifier_recovery: false,
+            notifier_warning: 90,
+            separator: " · ".to_owned(),
+            template: "{Mount} {Free} {Percent}%".to_owned(),
+            update_interval: 60,
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(&[
+            Some(Usage {
+                available: 20 * GIB,
+                total: 50 * GIB,
+                used: 30 * GIB,
+            }),
+            Some(Usage {
+                available: 150 * GIB,
+                total: 400 * GIB,
+                used: 250 * GIB,
+            }),
+            None,
+        ]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to(
+                "/ 20.0 GiB 60% · /home 150.0 GiB 63% · /mnt/usb NA NA%"
+            ))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "FREE" => "21474836480",
+                "PERCENT" => "60",
+                "TOTAL" => "53687091200",
+                "USED" => "32212254720",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_failed_first_mount() {
+        let mut object = Data::new(settings());
+
+        object.update(&[None, None, None]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("/ NA NA% · /home NA NA% · /mnt/usb NA NA%"))
+        );
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
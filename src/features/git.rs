@@ -0,0 +1,49 @@
+mod config;
+mod data;
+mod fetcher;
+mod notifier;
+mod repository;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::fetcher::Fetcher;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::repository::Status;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "git";
+pub(self) const PLACEHOLDER_AHEAD: &str = "{Ahead}";
+pub(self) const PLACEHOLDER_BEHIND: &str = "{Behind}";
+pub(self) const PLACEHOLDER_DIRTY: &str = "{Dirty}";
+pub(self) const PLACEHOLDER_NAME: &str = "{Name}";
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    if settings.fetch_interval > 0 {
+        let fetcher = Fetcher::new(settings.repositories.clone(), settings.fetch_interval);
+        thread::Thread::new("git fetch", fetcher).run()?;
+    }
+
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            settings.repositories.clone(),
+            settings.update_interval,
+        ),
+        Updater::new(data, settings.repositories.clone()),
+    )))
+}
@@ -0,0 +1,72 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) ahead: String,
+    pub(super) behind: String,
+    pub(super) dirty: String,
+    pub(super) fetch_interval: u64,
+    pub(super) repositories: Vec<String>,
+    pub(super) separator: String,
+    pub(super) show_clean: bool,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "ahead"           => "↑",
+                "behind"          => "↓",
+                "dirty"           => "*",
+                "fetch_interval"  => 0,
+                "repositories"    => Vec::<String>::new(),
+                "separator"       => " ",
+                "show_clean"      => false,
+                "template"        => "{Name}{Dirty}{Ahead}{Behind}",
+                "update_interval" => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("git", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("git", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("ahead".to_owned(), "↑".into());
+        map.insert("behind".to_owned(), "↓".into());
+        map.insert("dirty".to_owned(), "*".into());
+        map.insert("fetch_interval".to_owned(), 0.into());
+        map.insert("repositories".to_owned(), Vec::<String>::new().into());
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("show_clean".to_owned(), false.into());
+        map.insert("template".to_owned(), "{Name}{Dirty}{Ahead}{Behind}".into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
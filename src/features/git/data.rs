@@ -0,0 +1,162 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_AHEAD;
+use super::PLACEHOLDER_BEHIND;
+use super::PLACEHOLDER_DIRTY;
+use super::PLACEHOLDER_NAME;
+use super::Status;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, statuses: &[(String, Status)]) {
+        self.cache = statuses
+            .iter()
+            .filter(|(_, status)| self.settings.show_clean || *status != Status::default())
+            .map(|(name, status)| self.render_repository(name, *status))
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        self.values = map_values!(
+            "AHEAD" => statuses.iter().map(|(_, status)| status.ahead).sum::<u32>(),
+            "BEHIND" => statuses.iter().map(|(_, status)| status.behind).sum::<u32>(),
+            "DIRTY" => statuses.iter().filter(|(_, status)| status.dirty).count(),
+        );
+    }
+
+    fn render_repository(&self, name: &str, status: Status) -> String {
+        let count = |prefix: &str, count: u32| {
+            if count > 0 {
+                format!("{}{}", prefix, count)
+            } else {
+                String::new()
+            }
+        };
+
+        let dirty = if status.dirty {
+            &self.settings.dirty[..]
+        } else {
+            ""
+        };
+
+        self.settings
+            .template
+            .replace(
+                PLACEHOLDER_AHEAD,
+                &count(&self.settings.ahead, status.ahead),
+            )
+            .replace(
+                PLACEHOLDER_BEHIND,
+                &count(&self.settings.behind, status.behind),
+            )
+            .replace(PLACEHOLDER_DIRTY, dirty)
+            .replace(PLACEHOLDER_NAME, name)
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings(show_clean: bool) -> ConfigEntry {
+        ConfigEntry {
+            ahead: "↑".to_owned(),
+            behind: "↓".to_owned(),
+            dirty: "*".to_owned(),
+            fetch_interval: 0,
+            repositories: vec![
+                "/src/dotfiles".to_owned(),
+                "/src/dwm-status".to_owned(),
+                "/src/notes".to_owned(),
+            ],
+            separator: " ".to_owned(),
+            show_clean,
+            template: "{Name}{Dirty}{Ahead}{Behind}".to_owned(),
+            update_interval: 60,
+        }
+    }
+
+    fn statuses() -> Vec<(String, Status)> {
+        vec![
+            (
+                "dotfiles".to_owned(),
+                Status {
+                    ahead: 0,
+                    behind: 0,
+                    dirty: true,
+                },
+            ),
+            (
+                "dwm-status".to_owned(),
+                Status {
+                    ahead: 2,
+                    behind: 1,
+                    dirty: false,
+                },
+            ),
+            ("notes".to_owned(), Status::default()),
+        ]
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings(false));
+
+        object.update(&statuses());
+
+        assert_that!(object.render(), is(equal_to("dotfiles* dwm-status↑2↓1")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "AHEAD" => "2",
+                "BEHIND" => "1",
+                "DIRTY" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_showing_clean() {
+        let mut object = Data::new(settings(true));
+
+        object.update(&statuses());
+
+        assert_that!(
+            object.render(),
+            is(equal_to("dotfiles* dwm-status↑2↓1 notes"))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings(false));
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
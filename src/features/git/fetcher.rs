@@ -0,0 +1,34 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+use crate::wrapper::thread;
+
+pub(super) struct Fetcher {
+    fetch_interval: u64,
+    repositories: Vec<String>,
+}
+
+impl Fetcher {
+    pub(super) const fn new(repositories: Vec<String>, fetch_interval: u64) -> Self {
+        Self {
+            fetch_interval,
+            repositories,
+        }
+    }
+}
+
+impl thread::Runnable for Fetcher {
+    fn run(&self) -> Result<()> {
+        loop {
+            for repository in &self.repositories {
+                process::Command::new("git", &["-C", repository, "fetch", "--quiet"])
+                    .env("GIT_TERMINAL_PROMPT", "0")
+                    .output()
+                    .wrap_error(FEATURE_NAME, format!("{} could not be fetched", repository))
+                    .show_error_and_ignore();
+            }
+
+            thread::sleep_secs(self.fetch_interval);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use std::path::Path;
+use std::time::Duration;
+
+pub(super) struct Notifier {
+    id: usize,
+    repositories: Vec<String>,
+    sender: channel::Sender<communication::Message>,
+    update_interval: u64,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        repositories: Vec<String>,
+        update_interval: u64,
+    ) -> Self {
+        Self {
+            id,
+            repositories,
+            sender,
+            update_interval,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            thread::sleep_secs(self.update_interval);
+
+            communication::send_message(self.id, &self.sender)?;
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.update_interval)
+    }
+
+    fn next_update(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.update_interval))
+    }
+
+    fn watched_files(&self) -> Vec<String> {
+        self.repositories
+            .iter()
+            .map(|repository| Path::new(repository).join(".git"))
+            .filter(|directory| directory.is_dir())
+            .map(|directory| directory.to_string_lossy().into_owned())
+            .collect()
+    }
+}
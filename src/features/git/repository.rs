@@ -0,0 +1,105 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+use std::path::Path;
+
+const PREFIX_AHEAD_BEHIND: &str = "# branch.ab ";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Status {
+    pub(super) ahead: u32,
+    pub(super) behind: u32,
+    pub(super) dirty: bool,
+}
+
+pub(super) fn status(path: &str) -> Result<Status> {
+    let output = process::Command::new(
+        "git",
+        &[
+            "-C",
+            path,
+            "--no-optional-locks",
+            "status",
+            "--porcelain=v2",
+            "--branch",
+        ],
+    )
+    .output()
+    .wrap_error(
+        FEATURE_NAME,
+        format!("status of {} could not be read", path),
+    )?;
+
+    Ok(parse_status(&output))
+}
+
+pub(super) fn name(path: &str) -> String {
+    Path::new(path).file_name().map_or_else(
+        || path.to_owned(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+fn parse_status(output: &str) -> Status {
+    let mut status = Status::default();
+
+    for line in output.lines() {
+        if line.starts_with(PREFIX_AHEAD_BEHIND) {
+            let mut counts = line[PREFIX_AHEAD_BEHIND.len()..]
+                .split_whitespace()
+                .map(|count| count.trim_start_matches(&['+', '-'][..]).parse().ok());
+
+            status.ahead = counts.next().flatten().unwrap_or(0);
+            status.behind = counts.next().flatten().unwrap_or(0);
+        } else if !line.starts_with('#') && !line.trim().is_empty() {
+            status.dirty = true;
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_status_of_dirty_branch() {
+        let output = "\
+# branch.oid 0123456789abcdef0123456789abcdef01234567
+# branch.head main
+# branch.upstream origin/main
+# branch.ab +2 -1
+1 .M N... 100644 100644 100644 0123456 0123456 src/main.rs
+? notes.txt";
+
+        assert_that!(
+            parse_status(output),
+            is(equal_to(Status {
+                ahead: 2,
+                behind: 1,
+                dirty: true,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_status_of_clean_branch_without_upstream() {
+        let output = "\
+# branch.oid 0123456789abcdef0123456789abcdef01234567
+# branch.head feature";
+
+        assert_that!(parse_status(output), is(equal_to(Status::default())));
+    }
+
+    #[test]
+    fn name_of_repository() {
+        assert_that!(
+            name("/home/user/src/dwm-status"),
+            is(equal_to("dwm-status"))
+        );
+        assert_that!(name("/home/user/dotfiles/"), is(equal_to("dotfiles")));
+    }
+}
@@ -0,0 +1,37 @@
+use super::Data;
+use super::repository;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    repositories: Vec<String>,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, repositories: Vec<String>) -> Self {
+        Self { data, repositories }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let statuses = self
+            .repositories
+            .iter()
+            .filter_map(|path| {
+                let status = repository::status(path).show_error().ok()?;
+
+                Some((repository::name(path), status))
+            })
+            .collect::<Vec<_>>();
+
+        self.data.update(&statuses);
+
+        Ok(())
+    }
+}
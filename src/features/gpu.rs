@@ -0,0 +1,36 @@
+mod config;
+mod data;
+mod updater;
+mod vram;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+pub(self) use self::vram::Source;
+
+pub(super) const FEATURE_NAME: &str = "gpu";
+pub(self) const PLACEHOLDER_VRAM_PERCENTAGE: &str = "{VramPercentage}";
+pub(self) const PLACEHOLDER_VRAM_TOTAL: &str = "{VramTotal}";
+pub(self) const PLACEHOLDER_VRAM_USED: &str = "{VramUsed}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let source = Source::find(&settings.card)?;
+
+    let data = Data::new(settings.template.clone(), settings.no_value.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, source),
+    )))
+}
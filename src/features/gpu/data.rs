@@ -0,0 +1,107 @@
+use super::PLACEHOLDER_VRAM_PERCENTAGE;
+use super::PLACEHOLDER_VRAM_TOTAL;
+use super::PLACEHOLDER_VRAM_USED;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, usage: Option<(u64, u64)>) {
+        let (used, total) = match usage {
+            Some(usage) => usage,
+            None => {
+                self.cache = self
+                    .template
+                    .replace(PLACEHOLDER_VRAM_PERCENTAGE, &self.no_value)
+                    .replace(PLACEHOLDER_VRAM_TOTAL, &self.no_value)
+                    .replace(PLACEHOLDER_VRAM_USED, &self.no_value);
+                self.values = Values::new();
+                return;
+            },
+        };
+
+        let percentage = if total > 0 { used * 100 / total } else { 0 };
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_VRAM_PERCENTAGE, &percentage.to_string())
+            .replace(PLACEHOLDER_VRAM_TOTAL, &utils::format_bytes(total))
+            .replace(PLACEHOLDER_VRAM_USED, &utils::format_bytes(used));
+        self.values = map_values!(
+            "VRAM_PERCENTAGE" => percentage,
+            "VRAM_TOTAL" => total,
+            "VRAM_USED" => used,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "VRAM {VramUsed}/{VramTotal} {VramPercentage}%";
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        object.update(Some((1_610_612_736, 8_589_934_592)));
+
+        assert_that!(object.render(), is(equal_to("VRAM 1.5 GiB/8.0 GiB 18%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "VRAM_PERCENTAGE" => "18",
+                "VRAM_TOTAL" => "8589934592",
+                "VRAM_USED" => "1610612736",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_usage() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("VRAM NA/NA NA%")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
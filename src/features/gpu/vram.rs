@@ -0,0 +1,104 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+use crate::wrapper::process;
+use std::fs;
+use std::path;
+
+const FILE_VRAM_TOTAL: &str = "mem_info_vram_total";
+const FILE_VRAM_USED: &str = "mem_info_vram_used";
+const PATH_DRM: &str = "/sys/class/drm";
+
+#[derive(Debug)]
+pub(super) enum Source {
+    Amdgpu(path::PathBuf),
+    Nvidia,
+}
+
+impl Source {
+    pub(super) fn find(card: &str) -> Result<Self> {
+        if !card.is_empty() {
+            let path = path::Path::new(PATH_DRM).join(card).join("device");
+
+            if path.join(FILE_VRAM_TOTAL).exists() {
+                return Ok(Self::Amdgpu(path));
+            }
+
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("card {} does not provide {}", card, FILE_VRAM_TOTAL),
+            ));
+        }
+
+        let mut paths = fs::read_dir(PATH_DRM)
+            .wrap_error(FEATURE_NAME, format!("error reading {}", PATH_DRM))?
+            .filter_map(|entry| Some(entry.ok()?.path().join("device")))
+            .filter(|path| path.join(FILE_VRAM_TOTAL).exists())
+            .collect::<Vec<_>>();
+
+        paths.sort();
+
+        Ok(match paths.into_iter().next() {
+            Some(path) => Self::Amdgpu(path),
+            None => Self::Nvidia,
+        })
+    }
+
+    pub(super) fn usage(&self) -> Result<(u64, u64)> {
+        match self {
+            Self::Amdgpu(path) => {
+                let used = file::parse_file_content(path.join(FILE_VRAM_USED))
+                    .wrap_error(FEATURE_NAME, "used VRAM could not be read")?;
+                let total = file::parse_file_content(path.join(FILE_VRAM_TOTAL))
+                    .wrap_error(FEATURE_NAME, "total VRAM could not be read")?;
+
+                Ok((used, total))
+            },
+            Self::Nvidia => {
+                let output = process::Command::new(
+                    "nvidia-smi",
+                    &[
+                        "--query-gpu=memory.used,memory.total",
+                        "--format=csv,noheader,nounits",
+                    ],
+                )
+                .output()
+                .wrap_error(FEATURE_NAME, "VRAM could not be queried with nvidia-smi")?;
+
+                parse_nvidia_smi(&output).wrap_error(
+                    FEATURE_NAME,
+                    format!("invalid output of nvidia-smi: {}", output),
+                )
+            },
+        }
+    }
+}
+
+fn parse_nvidia_smi(output: &str) -> Option<(u64, u64)> {
+    let mut columns = output.lines().next()?.split(',');
+
+    let used = columns.next()?.trim().parse::<u64>().ok()?;
+    let total = columns.next()?.trim().parse::<u64>().ok()?;
+
+    Some((used * 1024 * 1024, total * 1024 * 1024))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_nvidia_smi_with_gpus() {
+        assert_that!(
+            parse_nvidia_smi("1536, 8192\n20, 4096\n"),
+            is(equal_to(Some((1_610_612_736, 8_589_934_592))))
+        );
+    }
+
+    #[test]
+    fn parse_nvidia_smi_with_error() {
+        assert_that!(parse_nvidia_smi("[N/A], [N/A]\n"), is(equal_to(None)));
+    }
+}
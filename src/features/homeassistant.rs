@@ -0,0 +1,71 @@
+mod api;
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "homeassistant";
+
+pub(self) type SharedStates = Arc<Mutex<BTreeMap<String, Option<String>>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    if settings.url.is_empty() || settings.token.is_empty() {
+        return Err(Error::new_custom(
+            FEATURE_NAME,
+            "url and token are required",
+        ));
+    }
+
+    if settings.entities.is_empty() {
+        return Err(Error::new_custom(FEATURE_NAME, "no entities configured"));
+    }
+
+    let names = settings
+        .entities
+        .keys()
+        .map(|name| -> (String, &'static str) {
+            (
+                format!("{{{}}}", name),
+                Box::leak(name.to_uppercase().into_boxed_str()),
+            )
+        })
+        .collect();
+    let entity_ids = settings.entities.values().cloned().collect::<Vec<_>>();
+    let states = SharedStates::default();
+
+    let client = http::Client::init(FEATURE_NAME, http_settings)
+        .with_header(format!("Authorization: Bearer {}", settings.token));
+    let data = Data::new(settings.template.clone(), settings.no_value.clone(), names);
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            client,
+            entity_ids.clone(),
+            Arc::clone(&states),
+            settings.clone(),
+        ),
+        Updater::new(data, entity_ids, states),
+    )))
+}
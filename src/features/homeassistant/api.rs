@@ -0,0 +1,159 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::http;
+use serde_derive::*;
+use serde_json::json;
+
+const STATES_UNAVAILABLE: &[&str] = &["unavailable", "unknown"];
+
+#[derive(Debug, Deserialize)]
+struct EntityState {
+    state: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(super) struct EventData {
+    #[serde(default)]
+    pub(super) entity_id: String,
+    #[serde(default)]
+    new_state: Option<EntityState>,
+}
+
+impl EventData {
+    pub(super) fn state(&self) -> Option<String> {
+        self.new_state
+            .as_ref()
+            .and_then(|new_state| available(&new_state.state))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct Event {
+    #[serde(default)]
+    pub(super) data: EventData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(super) enum Message {
+    AuthInvalid {
+        #[serde(default)]
+        message: String,
+    },
+    AuthOk,
+    Event {
+        event: Event,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl Message {
+    pub(super) fn parse(line: &str) -> Result<Self> {
+        serde_json::from_str(line).wrap_error(FEATURE_NAME, "invalid message of WebSocket API")
+    }
+}
+
+pub(super) fn fetch_state(
+    client: &mut http::Client,
+    url: &str,
+    entity_id: &str,
+) -> Result<Option<String>> {
+    let body = client.get(&format!("{}/api/states/{}", base(url), entity_id), 0)?;
+
+    let entity = serde_json::from_str::<EntityState>(&body)
+        .wrap_error(FEATURE_NAME, format!("invalid state of {}", entity_id))?;
+
+    Ok(available(&entity.state))
+}
+
+pub(super) fn subscribe_messages(token: &str) -> Vec<String> {
+    vec![
+        json!({ "type": "auth", "access_token": token }).to_string(),
+        json!({ "id": 1, "type": "subscribe_events", "event_type": "state_changed" }).to_string(),
+    ]
+}
+
+pub(super) fn websocket_url(url: &str) -> String {
+    let url = base(url);
+
+    let url = if url.starts_with("https://") {
+        format!("wss://{}", &url["https://".len()..])
+    } else if url.starts_with("http://") {
+        format!("ws://{}", &url["http://".len()..])
+    } else {
+        url.to_owned()
+    };
+
+    format!("{}/api/websocket", url)
+}
+
+fn base(url: &str) -> &str {
+    url.trim_end_matches('/')
+}
+
+fn available(state: &str) -> Option<String> {
+    if STATES_UNAVAILABLE.contains(&state) {
+        None
+    } else {
+        Some(state.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn message_parse_state_changed() {
+        let line = r#"{"id":1,"type":"event","event":{"event_type":"state_changed","data":{
+            "entity_id":"cover.garage","old_state":{"state":"open"},"new_state":{"state":"closed"}
+        }}}"#;
+
+        match Message::parse(line) {
+            Ok(Message::Event { event }) => {
+                assert_that!(event.data.entity_id, is(equal_to("cover.garage")));
+                assert_that!(event.data.state(), is(equal_to(Some("closed".to_owned()))));
+            },
+            message => panic!("unexpected message {:?}", message),
+        }
+    }
+
+    #[test]
+    fn message_parse_removed_entity() {
+        let line = r#"{"id":1,"type":"event","event":{"data":{
+            "entity_id":"sensor.indoor","new_state":null
+        }}}"#;
+
+        match Message::parse(line) {
+            Ok(Message::Event { event }) => assert_that!(event.data.state(), is(none())),
+            message => panic!("unexpected message {:?}", message),
+        }
+    }
+
+    #[test]
+    fn message_parse_other_types() {
+        assert_that!(
+            Message::parse(r#"{"type":"auth_required","ha_version":"2024.1.0"}"#).is_ok(),
+            is(true)
+        );
+        assert_that!(
+            Message::parse(r#"{"id":1,"type":"result","success":true,"result":null}"#).is_ok(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn websocket_url_of_instance() {
+        assert_that!(
+            websocket_url("http://homeassistant.local:8123/"),
+            is(equal_to("ws://homeassistant.local:8123/api/websocket"))
+        );
+        assert_that!(
+            websocket_url("https://ha.example.com"),
+            is(equal_to("wss://ha.example.com/api/websocket"))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) entities: BTreeMap<String, String>,
+    pub(super) live: bool,
+    pub(super) no_value: String,
+    pub(super) template: String,
+    pub(super) token: String,
+    pub(super) update_interval: u64,
+    pub(super) url: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "entities"        => HashMap::<String, Value>::new(),
+                "live"            => false,
+                "no_value"        => "NA",
+                "template"        => "",
+                "token"           => "",
+                "update_interval" => 60,
+                "url"             => "",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("homeassistant", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("homeassistant", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert(
+            "entities".to_owned(),
+            HashMap::<String, Value>::new().into(),
+        );
+        map.insert("live".to_owned(), false.into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("template".to_owned(), "".into());
+        map.insert("token".to_owned(), "".into());
+        map.insert("update_interval".to_owned(), 60.into());
+        map.insert("url".to_owned(), "".into());
+
+        map
+    }
+}
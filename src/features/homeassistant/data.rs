@@ -0,0 +1,107 @@
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    names: Vec<(String, &'static str)>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        names: Vec<(String, &'static str)>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            names,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, states: &[Option<String>]) {
+        self.cache = self.template.clone();
+        self.values = Values::new();
+
+        for ((placeholder, key), state) in self.names.iter().zip(states) {
+            self.cache = self
+                .cache
+                .replace(placeholder, state.as_ref().unwrap_or(&self.no_value));
+
+            if let Some(state) = state {
+                self.values.insert(*key, state.clone());
+            }
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn data() -> Data {
+        Data::new(
+            "{indoor}°C Garage {garage}".to_owned(),
+            "NA".to_owned(),
+            vec![
+                ("{garage}".to_owned(), "GARAGE"),
+                ("{indoor}".to_owned(), "INDOOR"),
+            ],
+        )
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = data();
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = data();
+
+        object.update(&[Some("closed".to_owned()), Some("21.5".to_owned())]);
+
+        assert_that!(object.render(), is(equal_to("21.5°C Garage closed")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "GARAGE" => "closed",
+                "INDOOR" => "21.5",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_state() {
+        let mut object = data();
+
+        object.update(&[None, Some("21.5".to_owned())]);
+
+        assert_that!(object.render(), is(equal_to("21.5°C Garage NA")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!("INDOOR" => "21.5")))
+        );
+    }
+}
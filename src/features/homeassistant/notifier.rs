@@ -0,0 +1,125 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use super::SharedStates;
+use super::api;
+use super::api::Message;
+use crate::communication;
+use crate::error::*;
+use crate::http;
+use crate::wrapper::channel;
+use crate::wrapper::process;
+use crate::wrapper::thread;
+use log::info;
+use std::io::Write;
+use std::sync::Mutex;
+
+pub(super) struct Notifier {
+    client: Mutex<http::Client>,
+    entity_ids: Vec<String>,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    states: SharedStates,
+}
+
+impl Notifier {
+    pub(super) fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        client: http::Client,
+        entity_ids: Vec<String>,
+        states: SharedStates,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            client: Mutex::new(client),
+            entity_ids,
+            id,
+            sender,
+            settings,
+            states,
+        }
+    }
+
+    fn fetch_states(&self) {
+        let mut client = self.client.lock().unwrap();
+
+        for entity_id in &self.entity_ids {
+            let state = api::fetch_state(&mut client, &self.settings.url, entity_id)
+                .show_error()
+                .unwrap_or(None);
+
+            self.states.lock().unwrap().insert(entity_id.clone(), state);
+        }
+    }
+
+    fn listen(&self) -> Result<()> {
+        let url = api::websocket_url(&self.settings.url);
+        let command = process::Command::new("websocat", &["--text", &url]);
+        let mut connection_stdin = None;
+
+        let result = command.listen_lines(
+            |mut stdin| {
+                for message in api::subscribe_messages(&self.settings.token) {
+                    let _ = writeln!(stdin, "{}", message);
+                }
+
+                connection_stdin = Some(stdin);
+            },
+            |line| self.handle(Message::parse(line)?),
+        );
+
+        drop(connection_stdin);
+
+        result
+    }
+
+    fn handle(&self, message: Message) -> Result<()> {
+        match message {
+            Message::AuthInvalid { message } => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("authentication failed: {}", message),
+            )),
+            Message::AuthOk => {
+                info!("Connected to WebSocket API of {}", self.settings.url);
+
+                self.fetch_states();
+                communication::send_message(self.id, &self.sender)
+            },
+            Message::Event { event } if self.entity_ids.contains(&event.data.entity_id) => {
+                let state = event.data.state();
+                self.states
+                    .lock()
+                    .unwrap()
+                    .insert(event.data.entity_id, state);
+
+                communication::send_message(self.id, &self.sender)
+            },
+            Message::Event { .. } | Message::Other => Ok(()),
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        if self.settings.live {
+            return self.listen();
+        }
+
+        loop {
+            self.fetch_states();
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.settings.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        if self.settings.live {
+            None
+        } else {
+            Some(self.settings.update_interval)
+        }
+    }
+}
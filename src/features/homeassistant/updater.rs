@@ -0,0 +1,40 @@
+use super::Data;
+use super::SharedStates;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    entity_ids: Vec<String>,
+    states: SharedStates,
+}
+
+impl Updater {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(data: Data, entity_ids: Vec<String>, states: SharedStates) -> Self {
+        Self {
+            data,
+            entity_ids,
+            states,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let states = self.states.lock().unwrap().clone();
+        let states = self
+            .entity_ids
+            .iter()
+            .map(|entity_id| states.get(entity_id).cloned().flatten())
+            .collect::<Vec<_>>();
+
+        self.data.update(&states);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+mod config;
+mod data;
+mod logind;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+use crate::wrapper::dbus;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::logind::Inhibitor;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "inhibitors";
+pub(self) const PLACEHOLDER_COUNT: &str = "{Count}";
+pub(self) const PLACEHOLDER_WHO: &str = "{Who}";
+pub(self) const PLACEHOLDER_WHY: &str = "{Why}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let connection = dbus::Connection::init()?;
+    let data = Data::new(settings.template.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, connection, settings.clone()),
+    )))
+}
@@ -0,0 +1,57 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) modes: Vec<String>,
+    pub(super) template: String,
+    pub(super) types: Vec<String>,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "modes"           => vec!["block"],
+                "template"        => "INH {Count} {Who}",
+                "types"           => vec!["sleep", "idle"],
+                "update_interval" => 10,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("inhibitors", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("inhibitors", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("modes".to_owned(), vec!["block"].into());
+        map.insert("template".to_owned(), "INH {Count} {Who}".into());
+        map.insert("types".to_owned(), vec!["sleep", "idle"].into());
+        map.insert("update_interval".to_owned(), 10.into());
+
+        map
+    }
+}
@@ -0,0 +1,119 @@
+use super::Inhibitor;
+use super::PLACEHOLDER_COUNT;
+use super::PLACEHOLDER_WHO;
+use super::PLACEHOLDER_WHY;
+use super::logind;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String) -> Self {
+        Self {
+            cache: String::new(),
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, inhibitors: Option<&[Inhibitor]>) {
+        let mut values = Values::new();
+
+        self.cache = match inhibitors {
+            Some(inhibitors) => {
+                values.insert("COUNT", inhibitors.len().to_string());
+
+                match logind::top_offender(inhibitors) {
+                    Some(top) => {
+                        values.insert("WHO", top.who.clone());
+
+                        self.template
+                            .replace(PLACEHOLDER_COUNT, &inhibitors.len().to_string())
+                            .replace(PLACEHOLDER_WHO, &top.who)
+                            .replace(PLACEHOLDER_WHY, &top.why)
+                    },
+                    None => String::new(),
+                }
+            },
+            None => String::new(),
+        };
+        self.values = values;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn inhibitor(who: &str, why: &str) -> Inhibitor {
+        Inhibitor {
+            mode: "block".to_owned(),
+            what: "sleep:idle".to_owned(),
+            who: who.to_owned(),
+            why: why.to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("INH {Count} {Who} ({Why})".to_owned());
+
+        object.update(Some(&[
+            inhibitor("Firefox", "audio-playing"),
+            inhibitor("Steam", "Downloading"),
+        ]));
+
+        assert_that!(
+            object.render(),
+            is(equal_to("INH 2 Firefox (audio-playing)"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "2",
+                "WHO" => "Firefox",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_inhibitors() {
+        let mut object = Data::new("INH {Count} {Who}".to_owned());
+
+        object.update(Some(&[]));
+
+        assert_that!(object.render(), is(equal_to("")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "0",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("INH {Count} {Who}".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::error::*;
+use crate::wrapper::dbus;
+
+const INTERFACE_LOGIN_MANAGER: &str = "org.freedesktop.login1.Manager";
+const PATH_LOGIN: &str = "/org/freedesktop/login1";
+const SERVICE_LOGIN: &str = "org.freedesktop.login1";
+
+type RawInhibitor = (String, String, String, String, u32, u32);
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Inhibitor {
+    pub(super) mode: String,
+    pub(super) what: String,
+    pub(super) who: String,
+    pub(super) why: String,
+}
+
+impl Inhibitor {
+    pub(super) fn matches(&self, modes: &[String], types: &[String]) -> bool {
+        modes.contains(&self.mode)
+            && self
+                .what
+                .split(':')
+                .any(|what| types.iter().any(|kind| kind == what))
+    }
+}
+
+pub(super) fn list(connection: &dbus::Connection) -> Result<Vec<Inhibitor>> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_LOGIN,
+        PATH_LOGIN,
+        INTERFACE_LOGIN_MANAGER,
+        "ListInhibitors",
+    )?;
+
+    let inhibitors = connection
+        .send_message(message)?
+        .return_value::<Vec<RawInhibitor>>()?;
+
+    Ok(inhibitors
+        .into_iter()
+        .map(|(what, who, why, mode, ..)| Inhibitor {
+            mode,
+            what,
+            who,
+            why,
+        })
+        .collect())
+}
+
+pub(super) fn top_offender(inhibitors: &[Inhibitor]) -> Option<&Inhibitor> {
+    inhibitors.iter().rev().max_by_key(|inhibitor| {
+        inhibitors
+            .iter()
+            .filter(|other| other.who == inhibitor.who)
+            .count()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn inhibitor(who: &str, what: &str, mode: &str) -> Inhibitor {
+        Inhibitor {
+            mode: mode.to_owned(),
+            what: what.to_owned(),
+            who: who.to_owned(),
+            why: format!("{} is busy", who),
+        }
+    }
+
+    #[test]
+    fn inhibitor_matches_modes_and_types() {
+        let modes = vec!["block".to_owned()];
+        let types = vec!["sleep".to_owned(), "idle".to_owned()];
+
+        assert_that!(
+            inhibitor("firefox", "idle", "block").matches(&modes, &types),
+            is(true)
+        );
+        assert_that!(
+            inhibitor("steam", "shutdown:sleep", "block").matches(&modes, &types),
+            is(true)
+        );
+        assert_that!(
+            inhibitor("NetworkManager", "sleep", "delay").matches(&modes, &types),
+            is(false)
+        );
+        assert_that!(
+            inhibitor("logind", "handle-lid-switch", "block").matches(&modes, &types),
+            is(false)
+        );
+    }
+
+    #[test]
+    fn top_offender_with_most_inhibitors() {
+        let inhibitors = vec![
+            inhibitor("firefox", "idle", "block"),
+            inhibitor("steam", "sleep", "block"),
+            inhibitor("steam", "idle", "block"),
+        ];
+
+        assert_that!(
+            top_offender(&inhibitors).map(|inhibitor| &inhibitor.who[..]),
+            is(equal_to(Some("steam")))
+        );
+    }
+
+    #[test]
+    fn top_offender_of_ties_is_first() {
+        let inhibitors = vec![
+            inhibitor("firefox", "idle", "block"),
+            inhibitor("steam", "sleep", "block"),
+        ];
+
+        assert_that!(
+            top_offender(&inhibitors).map(|inhibitor| &inhibitor.who[..]),
+            is(equal_to(Some("firefox")))
+        );
+        assert_that!(top_offender(&[]), is(none()));
+    }
+}
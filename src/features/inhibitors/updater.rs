@@ -0,0 +1,50 @@
+use super::ConfigEntry;
+use super::Data;
+use super::logind;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::dbus;
+
+pub(super) struct Updater {
+    connection: dbus::Connection,
+    data: Data,
+    settings: ConfigEntry,
+}
+
+impl Updater {
+    pub(super) const fn new(
+        data: Data,
+        connection: dbus::Connection,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            connection,
+            data,
+            settings,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let inhibitors = logind::list(&self.connection)
+            .show_error()
+            .ok()
+            .map(|inhibitors| {
+                inhibitors
+                    .into_iter()
+                    .filter(|inhibitor| {
+                        inhibitor.matches(&self.settings.modes, &self.settings.types)
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+        self.data.update(inhibitors.as_deref());
+
+        Ok(())
+    }
+}
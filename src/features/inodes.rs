@@ -0,0 +1,34 @@
+mod config;
+mod data;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "inodes";
+pub(self) const PLACEHOLDER_FREE: &str = "{Free}";
+pub(self) const PLACEHOLDER_MOUNT: &str = "{Mount}";
+pub(self) const PLACEHOLDER_PERCENT: &str = "{Percent}";
+pub(self) const PLACEHOLDER_TOTAL: &str = "{Total}";
+pub(self) const PLACEHOLDER_USED: &str = "{Used}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, settings.clone()),
+    )))
+}
@@ -0,0 +1,120 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+const NOTIFICATION_BODY: &str = "{PERCENT}% of the inodes are used, {FREE} are free";
+const NOTIFICATION_RECOVERED_SUMMARY: &str = "Enough inodes free on {MOUNT} again";
+const NOTIFICATION_SUMMARY: &str = "Few inodes left on {MOUNT}";
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) mounts: Vec<String>,
+    pub(super) no_value: String,
+    pub(super) notification_critical: NotificationConfig,
+    pub(super) notification_recovered: NotificationConfig,
+    pub(super) notification_warning: NotificationConfig,
+    pub(super) notifier_critical: u64,
+    pub(super) notifier_recovery: bool,
+    pub(super) notifier_warning: u64,
+    pub(super) separator: String,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "mounts"                 => vec!["/"],
+                "no_value"               => "NA",
+                "notification_critical"  => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "critical",
+                ),
+                "notification_recovered" => NotificationConfig::default_map(
+                    NOTIFICATION_RECOVERED_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "low",
+                ),
+                "notification_warning"   => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "normal",
+                ),
+                "notifier_critical"      => 95,
+                "notifier_recovery"      => false,
+                "notifier_warning"       => 90,
+                "separator"              => " ",
+                "template"               => "{Mount} I {Percent}%",
+                "update_interval"        => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("inodes", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("inodes", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("mounts".to_owned(), vec!["/"].into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert(
+            "notification_critical".to_owned(),
+            notification_map(
+                "Few inodes left on {MOUNT}",
+                "{PERCENT}% of the inodes are used, {FREE} are free",
+                "critical",
+            )
+            .into(),
+        );
+        map.insert(
+            "notification_recovered".to_owned(),
+            notification_map(
+                "Enough inodes free on {MOUNT} again",
+                "{PERCENT}% of the inodes are used, {FREE} are free",
+                "low",
+            )
+            .into(),
+        );
+        map.insert(
+            "notification_warning".to_owned(),
+            notification_map(
+                "Few inodes left on {MOUNT}",
+                "{PERCENT}% of the inodes are used, {FREE} are free",
+                "normal",
+            )
+            .into(),
+        );
+        map.insert("notifier_critical".to_owned(), 95.into());
+        map.insert("notifier_recovery".to_owned(), false.into());
+        map.insert("notifier_warning".to_owned(), 90.into());
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("template".to_owned(), "{Mount} I {Percent}%".into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
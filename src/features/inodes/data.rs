@@ -0,0 +1,152 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_FREE;
+use super::PLACEHOLDER_MOUNT;
+use super::PLACEHOLDER_PERCENT;
+use super::PLACEHOLDER_TOTAL;
+use super::PLACEHOLDER_USED;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::wrapper::statvfs::Inodes;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, inodes: &[Option<Inodes>]) {
+        self.cache = self
+            .settings
+            .mounts
+            .iter()
+            .zip(inodes)
+            .map(|(mount, inodes)| self.render_mount(mount, inodes.as_ref()))
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        self.values = match inodes.first() {
+            Some(Some(inodes)) => map_values!(
+                "FREE" => inodes.free,
+                "PERCENT" => inodes.percent(),
+                "TOTAL" => inodes.total,
+                "USED" => inodes.used,
+            ),
+            _ => Values::new(),
+        };
+    }
+
+    fn render_mount(&self, mount: &str, inodes: Option<&Inodes>) -> String {
+        let value = |select: fn(&Inodes) -> u64| {
+            inodes.map_or_else(
+                || self.settings.no_value.clone(),
+                |inodes| select(inodes).to_string(),
+            )
+        };
+
+        self.settings
+            .template
+            .replace(PLACEHOLDER_FREE, &value(|inodes| inodes.free))
+            .replace(PLACEHOLDER_MOUNT, mount)
+            .replace(PLACEHOLDER_PERCENT, &value(Inodes::percent))
+            .replace(PLACEHOLDER_TOTAL, &value(|inodes| inodes.total))
+            .replace(PLACEHOLDER_USED, &value(|inodes| inodes.used))
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            mounts: vec!["/".to_owned(), "/var".to_owned(), "/home".to_owned()],
+            no_value: "NA".to_owned(),
+            notification_critical: NotificationConfig::default(),
+            notification_recovered: NotificationConfig::default(),
+            notification_warning: NotificationConfig::default(),
+            notifier_critical: 95,
+            notifier_recovery: false,
+            notifier_warning: 90,
+            separator: " · ".to_owned(),
+            template: "{Mount} {Percent}% {Free}".to_owned(),
+            update_interval: 60,
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(&[
+            Some(Inodes {
+                free: 3_000_000,
+                total: 3_276_800,
+                used: 276_800,
+            }),
+            Some(Inodes {
+                free: 1_200,
+                total: 65_536,
+                used: 64_336,
+            }),
+            None,
+        ]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("/ 9% 3000000 · /var 99% 1200 · /home NA% NA"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "FREE" => "3000000",
+                "PERCENT" => "9",
+                "TOTAL" => "3276800",
+                "USED" => "276800",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_failed_first_mount() {
+        let mut object = Data::new(settings());
+
+        object.update(&[None, None, None]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("/ NA% NA · /var NA% NA · /home NA% NA"))
+        );
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
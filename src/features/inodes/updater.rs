@@ -0,0 +1,103 @@
+use super::ConfigEntry;
+use super::Data;
+use crate::error::*;
+use crate::feature;
+use crate::threshold;
+use crate::wrapper::statvfs;
+use std::path::Path;
+use std::time::Instant;
+
+const PLACEHOLDER_FREE: &str = "{FREE}";
+const PLACEHOLDER_LIMIT: &str = "{LIMIT}";
+const PLACEHOLDER_MOUNT: &str = "{MOUNT}";
+const PLACEHOLDER_PERCENT: &str = "{PERCENT}";
+
+pub(super) struct Updater {
+    data: Data,
+    settings: ConfigEntry,
+    trackers: Vec<threshold::Tracker>,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, settings: ConfigEntry) -> Self {
+        let now = Instant::now();
+
+        Self {
+            data,
+            trackers: settings
+                .mounts
+                .iter()
+                .map(|_| threshold::Tracker::new(now))
+                .collect(),
+            settings,
+        }
+    }
+
+    fn check(&mut self, index: usize, inodes: &statvfs::Inodes, now: Instant) -> Result<()> {
+        let percent = inodes.percent();
+        let state = threshold::usage_level(
+            percent,
+            self.settings.notifier_warning,
+            self.settings.notifier_critical,
+        );
+
+        let recovered =
+            Some(&self.settings.notification_recovered).filter(|_| self.settings.notifier_recovery);
+        let tracker = &mut self.trackers[index];
+
+        let state = match tracker.update(state, 0, now) {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+
+        let limit = match state {
+            threshold::State::Critical => self.settings.notifier_critical,
+            _ => self.settings.notifier_warning,
+        };
+
+        tracker.notify(
+            state,
+            &self.settings.notification_warning,
+            &self.settings.notification_critical,
+            recovered,
+            &[
+                (PLACEHOLDER_FREE, inodes.free.to_string()),
+                (PLACEHOLDER_LIMIT, limit.to_string()),
+                (PLACEHOLDER_MOUNT, self.settings.mounts[index].clone()),
+                (PLACEHOLDER_PERCENT, percent.to_string()),
+            ],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let inodes = self
+            .settings
+            .mounts
+            .iter()
+            .map(|mount| {
+                statvfs::inodes(Path::new(mount))
+                    .show_error()
+                    .ok()
+                    .filter(|inodes| inodes.total > 0)
+            })
+            .collect::<Vec<_>>();
+
+        let now = Instant::now();
+
+        for (index, mount_inodes) in inodes.iter().enumerate() {
+            if let Some(mount_inodes) = mount_inodes {
+                self.check(index, mount_inodes, now).show_error_and_ignore();
+            }
+        }
+
+        self.data.update(&inodes);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use crate::wrapper::xkb;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "keyboard";
+pub(self) const PLACEHOLDER_LAYOUT: &str = "{Layout}";
+pub(self) const PLACEHOLDER_VARIANT: &str = "{Variant}";
+
+pub(self) type SharedLayout = Arc<Mutex<Option<xkb::Layout>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let layout = SharedLayout::default();
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone(), Arc::clone(&layout)),
+        Updater::new(data, layout),
+    )))
+}
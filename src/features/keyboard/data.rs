@@ -0,0 +1,108 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_LAYOUT;
+use super::PLACEHOLDER_VARIANT;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::wrapper::xkb::Layout;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, layout: Option<&Layout>) {
+        let (name, variant) = match layout {
+            Some(layout) => (layout.name.as_str(), layout.variant.as_str()),
+            None => (self.settings.no_value.as_str(), ""),
+        };
+
+        self.cache = self
+            .settings
+            .template
+            .replace(PLACEHOLDER_LAYOUT, name)
+            .replace(PLACEHOLDER_VARIANT, variant);
+
+        self.values = match layout {
+            Some(layout) => map_values!(
+                "GROUP" => layout.group,
+                "LAYOUT" => layout.name,
+                "VARIANT" => layout.variant,
+            ),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            no_value: "NA".to_owned(),
+            template: "K {Layout} {Variant}".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Layout {
+            group: 1,
+            name: "de".to_owned(),
+            variant: "nodeadkeys".to_owned(),
+        }));
+
+        assert_that!(object.render(), is(equal_to("K de nodeadkeys")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "GROUP" => "1",
+                "LAYOUT" => "de",
+                "VARIANT" => "nodeadkeys",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_unknown_layout() {
+        let mut object = Data::new(settings());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("K NA ")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
@@ -0,0 +1,46 @@
+use super::SharedLayout;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use crate::wrapper::xkb;
+
+pub(super) struct Notifier {
+    id: usize,
+    layout: SharedLayout,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        layout: SharedLayout,
+    ) -> Self {
+        Self { id, layout, sender }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let keyboard = xkb::Keyboard::open()?;
+
+        loop {
+            let layout = keyboard.layout();
+
+            let changed = {
+                let mut shared = self.layout.lock().unwrap();
+                let changed = *shared != layout;
+                *shared = layout;
+
+                changed
+            };
+
+            if changed {
+                communication::send_message(self.id, &self.sender)?;
+            }
+
+            keyboard.wait_for_change();
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedLayout;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    layout: SharedLayout,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, layout: SharedLayout) -> Self {
+        Self { data, layout }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let layout = self.layout.lock().unwrap().clone();
+
+        self.data.update(layout.as_ref());
+
+        Ok(())
+    }
+}
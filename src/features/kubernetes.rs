@@ -0,0 +1,37 @@
+mod cluster;
+mod config;
+mod data;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(self) use self::cluster::Kubectl;
+pub(self) use self::cluster::Nodes;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "kubernetes";
+pub(self) const PLACEHOLDER_NODES: &str = "{Nodes}";
+pub(self) const PLACEHOLDER_PODS: &str = "{Pods}";
+pub(self) const PLACEHOLDER_READY: &str = "{Ready}";
+pub(self) const PLACEHOLDER_STATE: &str = "{State}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.clone());
+    let kubectl = Kubectl::new(&settings.kubeconfig, &settings.context, settings.timeout);
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, kubectl, settings.namespaces.clone()),
+    )))
+}
@@ -0,0 +1,196 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+use serde::de::IgnoredAny;
+use serde_derive::*;
+
+const PHASE_RUNNING: &str = "Running";
+const PHASE_SUCCEEDED: &str = "Succeeded";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Nodes {
+    pub(super) ready: usize,
+    pub(super) total: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct List<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NodeCondition {
+    status: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NodeStatus {
+    #[serde(default)]
+    conditions: Vec<NodeCondition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Node {
+    #[serde(default)]
+    status: NodeStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ContainerState {
+    #[serde(default)]
+    waiting: Option<IgnoredAny>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContainerStatus {
+    #[serde(default)]
+    state: ContainerState,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PodStatus {
+    #[serde(default)]
+    container_statuses: Vec<ContainerStatus>,
+    #[serde(default)]
+    phase: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Pod {
+    #[serde(default)]
+    status: PodStatus,
+}
+
+impl Node {
+    fn is_ready(&self) -> bool {
+        self.status
+            .conditions
+            .iter()
+            .any(|condition| condition.kind == "Ready" && condition.status == "True")
+    }
+}
+
+impl Pod {
+    fn is_running(&self) -> bool {
+        match &self.status.phase[..] {
+            PHASE_SUCCEEDED => true,
+            PHASE_RUNNING => self
+                .status
+                .container_statuses
+                .iter()
+                .all(|container| container.state.waiting.is_none()),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Kubectl {
+    args: Vec<String>,
+}
+
+impl Kubectl {
+    pub(super) fn new(kubeconfig: &str, context: &str, timeout: u64) -> Self {
+        let mut args = vec![format!("--request-timeout={}s", timeout)];
+
+        if !kubeconfig.is_empty() {
+            args.push(format!("--kubeconfig={}", kubeconfig));
+        }
+
+        if !context.is_empty() {
+            args.push(format!("--context={}", context));
+        }
+
+        Self { args }
+    }
+
+    pub(super) fn nodes(&self) -> Result<Nodes> {
+        parse_nodes(&self.get(&["nodes"])?)
+    }
+
+    pub(super) fn pods_not_running(&self, namespace: Option<&str>) -> Result<usize> {
+        let output = match namespace {
+            Some(namespace) => self.get(&["pods", "--namespace", namespace])?,
+            None => self.get(&["pods", "--all-namespaces"])?,
+        };
+
+        parse_pods_not_running(&output)
+    }
+
+    fn get(&self, args: &[&str]) -> Result<String> {
+        let mut all_args = vec!["get", "--output=json"];
+        all_args.extend(self.args.iter().map(String::as_str));
+        all_args.extend(args);
+
+        process::Command::new("kubectl", &all_args)
+            .output()
+            .wrap_error(FEATURE_NAME, format!("kubectl get {} failed", args[0]))
+    }
+}
+
+fn parse_nodes(output: &str) -> Result<Nodes> {
+    let nodes = serde_json::from_str::<List<Node>>(output)
+        .wrap_error(FEATURE_NAME, "invalid list of nodes")?;
+
+    Ok(Nodes {
+        ready: nodes.items.iter().filter(|node| node.is_ready()).count(),
+        total: nodes.items.len(),
+    })
+}
+
+fn parse_pods_not_running(output: &str) -> Result<usize> {
+    let pods = serde_json::from_str::<List<Pod>>(output)
+        .wrap_error(FEATURE_NAME, "invalid list of pods")?;
+
+    Ok(pods.items.iter().filter(|pod| !pod.is_running()).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_nodes_of_list() {
+        let output = r#"{"kind":"List","items":[
+            {"status":{"conditions":[
+                {"type":"MemoryPressure","status":"False"},{"type":"Ready","status":"True"}
+            ]}},
+            {"status":{"conditions":[{"type":"Ready","status":"Unknown"}]}},
+            {"metadata":{"name":"new"}}
+        ]}"#;
+
+        assert_that!(
+            parse_nodes(output),
+            is(equal_to(Ok(Nodes { ready: 1, total: 3 })))
+        );
+    }
+
+    #[test]
+    fn parse_pods_not_running_of_list() {
+        let output = r#"{"kind":"List","items":[
+            {"status":{"phase":"Running","containerStatuses":[{"state":{"running":{}}}]}},
+            {"status":{"phase":"Running","containerStatuses":[
+                {"state":{"running":{}}},
+                {"state":{"waiting":{"reason":"CrashLoopBackOff"}}}
+            ]}},
+            {"status":{"phase":"Succeeded"}},
+            {"status":{"phase":"Pending"}},
+            {"status":{"phase":"Failed"}}
+        ]}"#;
+
+        assert_that!(parse_pods_not_running(output), is(equal_to(Ok(3))));
+    }
+
+    #[test]
+    fn parse_invalid_output() {
+        assert_that!(
+            parse_nodes("error: You must be logged in").is_err(),
+            is(true)
+        );
+    }
+}
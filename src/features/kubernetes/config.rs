@@ -0,0 +1,75 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) context: String,
+    pub(super) healthy: String,
+    pub(super) kubeconfig: String,
+    pub(super) namespaces: Vec<String>,
+    pub(super) no_value: String,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) unhealthy: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "context"         => "",
+                "healthy"         => "OK",
+                "kubeconfig"      => "",
+                "namespaces"      => vec!["default"],
+                "no_value"        => "NA",
+                "template"        => "K8S {State} N {Ready}/{Nodes} P {Pods}",
+                "timeout"         => 10,
+                "unhealthy"       => "FAIL",
+                "update_interval" => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("kubernetes", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("kubernetes", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("context".to_owned(), "".into());
+        map.insert("healthy".to_owned(), "OK".into());
+        map.insert("kubeconfig".to_owned(), "".into());
+        map.insert("namespaces".to_owned(), vec!["default"].into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert(
+            "template".to_owned(),
+            "K8S {State} N {Ready}/{Nodes} P {Pods}".into(),
+        );
+        map.insert("timeout".to_owned(), 10.into());
+        map.insert("unhealthy".to_owned(), "FAIL".into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
@@ -0,0 +1,145 @@
+use super::ConfigEntry;
+use super::Nodes;
+use super::PLACEHOLDER_NODES;
+use super::PLACEHOLDER_PODS;
+use super::PLACEHOLDER_READY;
+use super::PLACEHOLDER_STATE;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, nodes: Option<Nodes>, pods: Option<usize>) {
+        let mut values = Values::new();
+
+        if let Some(nodes) = nodes {
+            values.insert("NODES", nodes.total.to_string());
+            values.insert("READY", nodes.ready.to_string());
+        }
+
+        if let Some(pods) = pods {
+            values.insert("PODS", pods.to_string());
+        }
+
+        let problem = nodes.map_or(false, |nodes| nodes.ready < nodes.total)
+            || pods.map_or(false, |pods| pods > 0);
+
+        let state = if problem {
+            &self.settings.unhealthy
+        } else if nodes.is_some() && pods.is_some() {
+            &self.settings.healthy
+        } else {
+            &self.settings.no_value
+        };
+
+        let no_value = &self.settings.no_value;
+        let format = |value: Option<usize>| {
+            value.map_or_else(|| no_value.clone(), |value| value.to_string())
+        };
+
+        self.cache = self
+            .settings
+            .template
+            .replace(PLACEHOLDER_NODES, &format(nodes.map(|nodes| nodes.total)))
+            .replace(PLACEHOLDER_PODS, &format(pods))
+            .replace(PLACEHOLDER_READY, &format(nodes.map(|nodes| nodes.ready)))
+            .replace(PLACEHOLDER_STATE, state);
+        self.values = values;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            context: String::new(),
+            healthy: "OK".to_owned(),
+            kubeconfig: String::new(),
+            namespaces: vec!["default".to_owned()],
+            no_value: "NA".to_owned(),
+            template: "K8S {State} N {Ready}/{Nodes} P {Pods}".to_owned(),
+            timeout: 10,
+            unhealthy: "FAIL".to_owned(),
+            update_interval: 60,
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(Nodes { ready: 3, total: 3 }), Some(0));
+
+        assert_that!(object.render(), is(equal_to("K8S OK N 3/3 P 0")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "NODES" => "3",
+                "PODS" => "0",
+                "READY" => "3",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_unhealthy_cluster() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(Nodes { ready: 2, total: 3 }), None);
+
+        assert_that!(object.render(), is(equal_to("K8S FAIL N 2/3 P NA")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "NODES" => "3",
+                "READY" => "2",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_unreachable_cluster() {
+        let mut object = Data::new(settings());
+
+        object.update(None, None);
+
+        assert_that!(object.render(), is(equal_to("K8S NA N NA/NA P NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
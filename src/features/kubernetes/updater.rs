@@ -0,0 +1,51 @@
+use super::Data;
+use super::Kubectl;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    kubectl: Kubectl,
+    namespaces: Vec<String>,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, kubectl: Kubectl, namespaces: Vec<String>) -> Self {
+        Self {
+            data,
+            kubectl,
+            namespaces,
+        }
+    }
+
+    fn pods_not_running(&self) -> Result<usize> {
+        if self.namespaces.is_empty() {
+            return self.kubectl.pods_not_running(None);
+        }
+
+        self.namespaces
+            .iter()
+            .map(|namespace| self.kubectl.pods_not_running(Some(namespace)))
+            .sum()
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let nodes = self.kubectl.nodes().show_error().ok();
+
+        let pods = if nodes.is_some() {
+            self.pods_not_running().show_error().ok()
+        } else {
+            None
+        };
+
+        self.data.update(nodes, pods);
+
+        Ok(())
+    }
+}
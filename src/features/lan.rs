@@ -0,0 +1,34 @@
+mod arp;
+mod config;
+mod data;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::state;
+use crate::wrapper::channel;
+
+pub(self) use self::arp::Device;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "lan";
+pub(self) const PLACEHOLDER_COUNT: &str = "{Count}";
+pub(self) const PLACEHOLDER_UNKNOWN: &str = "{Unknown}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.template.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, settings.clone(), state::State::load(FEATURE_NAME)),
+    )))
+}
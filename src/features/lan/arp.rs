@@ -0,0 +1,89 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+
+const FLAG_COMPLETE: u32 = 0x2;
+const PATH_ARP: &str = "/proc/net/arp";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Device {
+    pub(super) interface: String,
+    pub(super) ip: String,
+    pub(super) mac: String,
+}
+
+pub(super) fn devices(interface: &str) -> Result<Vec<Device>> {
+    let content = file::read(PATH_ARP).wrap_error(
+        FEATURE_NAME,
+        format!("ARP table could not be read from {}", PATH_ARP),
+    )?;
+
+    Ok(parse_arp(&content)
+        .into_iter()
+        .filter(|device| interface.is_empty() || device.interface == interface)
+        .collect())
+}
+
+fn parse_arp(content: &str) -> Vec<Device> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns = line.split_whitespace().collect::<Vec<_>>();
+
+            let flags = columns.get(2)?.trim_start_matches("0x");
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+
+            if flags & FLAG_COMPLETE == 0 {
+                return None;
+            }
+
+            Some(Device {
+                interface: (*columns.get(5)?).to_owned(),
+                ip: (*columns.first()?).to_owned(),
+                mac: columns.get(3)?.to_lowercase(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_arp_with_complete_entries() {
+        let content = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         AA:BB:CC:00:11:22     *        wlan0
+192.168.1.23     0x1         0x0         00:00:00:00:00:00     *        wlan0
+10.0.0.5         0x1         0x6         de:ad:be:ef:00:01     *        eth0
+";
+
+        assert_that!(
+            parse_arp(content),
+            is(equal_to(vec![
+                Device {
+                    interface: "wlan0".to_owned(),
+                    ip: "192.168.1.1".to_owned(),
+                    mac: "aa:bb:cc:00:11:22".to_owned(),
+                },
+                Device {
+                    interface: "eth0".to_owned(),
+                    ip: "10.0.0.5".to_owned(),
+                    mac: "de:ad:be:ef:00:01".to_owned(),
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_arp_without_entries() {
+        let content =
+            "IP address       HW type     Flags       HW address            Mask     Device\n";
+
+        assert_that!(parse_arp(content), is(equal_to(Vec::new())));
+    }
+}
@@ -0,0 +1,77 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) interface: String,
+    pub(super) known: Vec<String>,
+    pub(super) notification: NotificationConfig,
+    pub(super) notifier: bool,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "interface"       => "",
+                "known"           => Vec::<String>::new(),
+                "notification"    => NotificationConfig::default_map(
+                    "Unknown device on the network",
+                    "{MAC} ({IP}) on {INTERFACE}",
+                    "normal",
+                ),
+                "notifier"        => false,
+                "template"        => "{Count}",
+                "update_interval" => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("lan", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("lan", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("interface".to_owned(), "".into());
+        map.insert("known".to_owned(), Vec::<String>::new().into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map(
+                "Unknown device on the network",
+                "{MAC} ({IP}) on {INTERFACE}",
+                "normal",
+            )
+            .into(),
+        );
+        map.insert("notifier".to_owned(), false.into());
+        map.insert("template".to_owned(), "{Count}".into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
@@ -0,0 +1,73 @@
+use super::PLACEHOLDER_COUNT;
+use super::PLACEHOLDER_UNKNOWN;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String) -> Self {
+        Self {
+            cache: String::new(),
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, count: usize, unknown: usize) {
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_COUNT, &count.to_string())
+            .replace(PLACEHOLDER_UNKNOWN, &unknown.to_string());
+        self.values = map_values!(
+            "COUNT" => count,
+            "UNKNOWN" => unknown,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("{Count}".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("LAN {Count} ({Unknown}?)".to_owned());
+
+        object.update(7, 2);
+
+        assert_that!(object.render(), is(equal_to("LAN 7 (2?)")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "7",
+                "UNKNOWN" => "2",
+            )))
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::ConfigEntry;
+use super::Data;
+use super::Device;
+use super::arp;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+use crate::state;
+use std::collections::BTreeSet;
+
+const PLACEHOLDER_INTERFACE: &str = "{INTERFACE}";
+const PLACEHOLDER_IP: &str = "{IP}";
+const PLACEHOLDER_MAC: &str = "{MAC}";
+const STATE_NOTIFIED: &str = "notified";
+
+pub(super) struct Updater {
+    data: Data,
+    known: BTreeSet<String>,
+    notified: BTreeSet<String>,
+    notifier: notification::LazyNotifier,
+    settings: ConfigEntry,
+    state: state::State,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, settings: ConfigEntry, state: state::State) -> Self {
+        Self {
+            data,
+            known: settings
+                .known
+                .iter()
+                .map(|mac| mac.to_lowercase())
+                .collect(),
+            notified: state.get(STATE_NOTIFIED).unwrap_or_default(),
+            notifier: notification::LazyNotifier::default(),
+            settings,
+            state,
+        }
+    }
+
+    fn notify(&mut self, device: &Device) -> Result<()> {
+        let notifier = self.notifier.get()?;
+
+        notifier.send(
+            &self.settings.notification,
+            &[
+                (PLACEHOLDER_INTERFACE, device.interface.clone()),
+                (PLACEHOLDER_IP, device.ip.clone()),
+                (PLACEHOLDER_MAC, device.mac.clone()),
+            ],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let devices = arp::devices(&self.settings.interface)?;
+
+        let macs = devices
+            .iter()
+            .map(|device| device.mac.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let unknown = devices
+            .iter()
+            .filter(|device| !self.known.contains(&device.mac))
+            .collect::<Vec<_>>();
+
+        if self.settings.notifier {
+            let mut notified = false;
+
+            for device in &unknown {
+                if self.notified.insert(device.mac.clone()) {
+                    self.notify(device).show_error_and_ignore();
+                    notified = true;
+                }
+            }
+
+            if notified {
+                self.state
+                    .set(STATE_NOTIFIED, &self.notified)
+                    .show_error_and_ignore();
+            }
+        }
+
+        let unknown_count = unknown
+            .iter()
+            .map(|device| device.mac.as_str())
+            .collect::<BTreeSet<_>>()
+            .len();
+
+        self.data.update(macs.len(), unknown_count);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+mod config;
+mod data;
+mod instance;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+use crate::wrapper::xdg;
+use std::path::PathBuf;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::instance::Instance;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "library";
+const LIBRARY_SUFFIX: &str = ".so";
+const PLUGINS_DIRECTORY: &str = "plugins";
+
+pub(super) fn create(
+    _id: usize,
+    name: &str,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Option<Box<dyn feature::Feature>>> {
+    let path = match library_path(name, settings) {
+        Some(ref path) if path.is_file() => path.clone(),
+        _ => return Ok(None),
+    };
+
+    let options = settings
+        .options
+        .get(name)
+        .map_or_else(|| "{}".to_owned(), ToString::to_string);
+
+    let instance = Instance::load(&path, &options)?;
+
+    let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+
+    Ok(Some(Box::new(feature::Composer::new(
+        name,
+        scheduler::Interval::new(instance.update_interval()),
+        Updater::new(Data::new(), instance),
+    ))))
+}
+
+fn library_path(name: &str, settings: &ConfigEntry) -> Option<PathBuf> {
+    if name.is_empty() || name.contains('/') {
+        return None;
+    }
+
+    let directory = if settings.directory.is_empty() {
+        xdg::data_dir()?.join(PLUGINS_DIRECTORY)
+    } else {
+        PathBuf::from(&settings.directory)
+    };
+
+    Some(directory.join(format!("{}{}", name, LIBRARY_SUFFIX)))
+}
@@ -0,0 +1,120 @@
+#![allow(unsafe_code)]
+
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::library;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::mem;
+use std::os::raw::c_char;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::ptr;
+
+const API_VERSION: u32 = 1;
+const SYMBOL_PLUGIN: &str = "dwm_status_plugin";
+
+#[repr(C)]
+struct Api {
+    api_version: u32,
+    update_interval: u64,
+    create: Option<extern "C" fn(options: *const c_char) -> *mut c_void>,
+    update: Option<extern "C" fn(state: *mut c_void) -> *mut c_char>,
+    free_text: Option<extern "C" fn(text: *mut c_char)>,
+    destroy: Option<extern "C" fn(state: *mut c_void)>,
+}
+
+type ApiGetter = extern "C" fn() -> *const Api;
+
+pub(super) struct Instance {
+    destroy: extern "C" fn(*mut c_void),
+    free_text: extern "C" fn(*mut c_char),
+    state: *mut c_void,
+    update: extern "C" fn(*mut c_void) -> *mut c_char,
+    update_interval: u64,
+}
+
+impl Instance {
+    pub(super) fn load(path: &Path, options: &str) -> Result<Self> {
+        let library = library::Library::open(path)?;
+        let getter = library.symbol(SYMBOL_PLUGIN)?;
+
+        let api = unsafe {
+            let getter: ApiGetter = mem::transmute(getter);
+            getter()
+                .as_ref()
+                .wrap_error(FEATURE_NAME, "plugin returned no interface")?
+        };
+
+        if api.api_version != API_VERSION {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "plugin {} implements api version {}, expected {}",
+                    path.display(),
+                    api.api_version,
+                    API_VERSION
+                ),
+            ));
+        }
+
+        if api.update_interval == 0 {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                "plugin update interval must be greater than 0",
+            ));
+        }
+
+        let create = api.create.wrap_error(FEATURE_NAME, "create is missing")?;
+
+        let mut instance = Self {
+            destroy: api.destroy.wrap_error(FEATURE_NAME, "destroy is missing")?,
+            free_text: api
+                .free_text
+                .wrap_error(FEATURE_NAME, "free_text is missing")?,
+            state: ptr::null_mut(),
+            update: api.update.wrap_error(FEATURE_NAME, "update is missing")?,
+            update_interval: api.update_interval,
+        };
+
+        let options_c =
+            CString::new(options).wrap_error(FEATURE_NAME, "options contain null byte")?;
+        instance.state = create(options_c.as_ptr());
+
+        if instance.state.is_null() {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("plugin {} failed to initialize", path.display()),
+            ));
+        }
+
+        Ok(instance)
+    }
+
+    pub(super) const fn update_interval(&self) -> u64 {
+        self.update_interval
+    }
+
+    pub(super) fn update(&mut self) -> Result<String> {
+        let text = (self.update)(self.state);
+
+        if text.is_null() {
+            return Err(Error::new_custom(FEATURE_NAME, "plugin failed to update"));
+        }
+
+        let result = unsafe { CStr::from_ptr(text) }
+            .to_string_lossy()
+            .into_owned();
+        (self.free_text)(text);
+
+        Ok(result)
+    }
+}
+
+impl Drop for Instance {
+    fn drop(&mut self) {
+        if !self.state.is_null() {
+            (self.destroy)(self.state);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::Instance;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    instance: Instance,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, instance: Instance) -> Self {
+        Self { data, instance }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let text = self.instance.update()?;
+
+        self.data.update(text);
+
+        Ok(())
+    }
+}
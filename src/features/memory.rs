@@ -0,0 +1,36 @@
+mod config;
+mod data;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+pub(self) use self::updater::Usage;
+
+pub(super) const FEATURE_NAME: &str = "memory";
+pub(self) const PLACEHOLDER_MEM_AVAILABLE: &str = "{MemAvailable}";
+pub(self) const PLACEHOLDER_MEM_FREE: &str = "{MemFree}";
+pub(self) const PLACEHOLDER_MEM_PERCENT: &str = "{MemPercent}";
+pub(self) const PLACEHOLDER_MEM_TOTAL: &str = "{MemTotal}";
+pub(self) const PLACEHOLDER_MEM_USED: &str = "{MemUsed}";
+pub(self) const PLACEHOLDER_SWAP_USED: &str = "{SwapUsed}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.template.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data),
+    )))
+}
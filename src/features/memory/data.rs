@@ -0,0 +1,141 @@
+use super::PLACEHOLDER_MEM_AVAILABLE;
+use super::PLACEHOLDER_MEM_FREE;
+use super::PLACEHOLDER_MEM_PERCENT;
+use super::PLACEHOLDER_MEM_TOTAL;
+use super::PLACEHOLDER_MEM_USED;
+use super::PLACEHOLDER_SWAP_USED;
+use super::Usage;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String) -> Self {
+        Self {
+            cache: String::new(),
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, usage: &Usage) {
+        let used = usage.mem_total.saturating_sub(usage.mem_available);
+        let percent = if usage.mem_total > 0 {
+            (used * 100 + usage.mem_total / 2) / usage.mem_total
+        } else {
+            0
+        };
+        let swap_used = usage.swap_total.saturating_sub(usage.swap_free);
+
+        self.cache = self
+            .template
+            .replace(
+                PLACEHOLDER_MEM_AVAILABLE,
+                &utils::format_bytes(usage.mem_available),
+            )
+            .replace(PLACEHOLDER_MEM_FREE, &utils::format_bytes(usage.mem_free))
+            .replace(PLACEHOLDER_MEM_PERCENT, &percent.to_string())
+            .replace(PLACEHOLDER_MEM_TOTAL, &utils::format_bytes(usage.mem_total))
+            .replace(PLACEHOLDER_MEM_USED, &utils::format_bytes(used))
+            .replace(PLACEHOLDER_SWAP_USED, &utils::format_bytes(swap_used));
+        self.values = map_values!(
+            "MEM_AVAILABLE" => usage.mem_available,
+            "MEM_FREE" => usage.mem_free,
+            "MEM_PERCENT" => percent,
+            "MEM_TOTAL" => usage.mem_total,
+            "MEM_USED" => used,
+            "SWAP_USED" => swap_used,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const USAGE: Usage = Usage {
+        mem_available: 12 * 1024 * 1024 * 1024,
+        mem_free: 2 * 1024 * 1024 * 1024,
+        mem_total: 16 * 1024 * 1024 * 1024,
+        swap_free: 7 * 1024 * 1024 * 1024,
+        swap_total: 8 * 1024 * 1024 * 1024,
+    };
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("M {MemUsed}/{MemTotal}".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(
+            "M {MemUsed}/{MemTotal} A {MemAvailable} F {MemFree} {MemPercent}% S {SwapUsed}"
+                .to_owned(),
+        );
+
+        object.update(&USAGE);
+
+        assert_that!(
+            object.render(),
+            is(equal_to(
+                "M 4.0 GiB/16.0 GiB A 12.0 GiB F 2.0 GiB 25% S 1.0 GiB"
+            ))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_memory() {
+        let mut object = Data::new("{MemPercent}% {SwapUsed}".to_owned());
+
+        object.update(&Usage {
+            mem_available: 0,
+            mem_free: 0,
+            mem_total: 0,
+            swap_free: 0,
+            swap_total: 0,
+        });
+
+        assert_that!(object.render(), is(equal_to("0% 0 B")));
+    }
+
+    #[test]
+    fn values_with_update() {
+        let mut object = Data::new("{MemPercent}".to_owned());
+
+        object.update(&USAGE);
+
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "MEM_AVAILABLE" => "12884901888",
+                "MEM_FREE" => "2147483648",
+                "MEM_PERCENT" => "25",
+                "MEM_TOTAL" => "17179869184",
+                "MEM_USED" => "4294967296",
+                "SWAP_USED" => "1073741824",
+            )))
+        );
+    }
+}
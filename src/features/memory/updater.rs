@@ -0,0 +1,128 @@
+use super::Data;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::file;
+
+const PATH_MEMINFO: &str = "/proc/meminfo";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Usage {
+    pub(super) mem_available: u64,
+    pub(super) mem_free: u64,
+    pub(super) mem_total: u64,
+    pub(super) swap_free: u64,
+    pub(super) swap_total: u64,
+}
+
+pub(super) struct Updater {
+    data: Data,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data) -> Self {
+        Self { data }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let meminfo = file::read(PATH_MEMINFO)
+            .wrap_error(FEATURE_NAME, format!("failed to read {}", PATH_MEMINFO))?;
+
+        let usage = parse_meminfo(&meminfo)
+            .wrap_error(FEATURE_NAME, format!("invalid content of {}", PATH_MEMINFO))?;
+
+        self.data.update(&usage);
+
+        Ok(())
+    }
+}
+
+fn parse_meminfo(content: &str) -> Option<Usage> {
+    let value = |key: &str| {
+        content.lines().find_map(|line| {
+            let mut parts = line.splitn(2, ':');
+
+            if parts.next()? != key {
+                return None;
+            }
+
+            let kib = parts.next()?.trim().trim_end_matches("kB").trim();
+            kib.parse::<u64>().ok().map(|kib| kib * 1024)
+        })
+    };
+
+    let mem_free = value("MemFree")?;
+
+    Some(Usage {
+        mem_available: value("MemAvailable").unwrap_or(mem_free),
+        mem_free,
+        mem_total: value("MemTotal")?,
+        swap_free: value("SwapFree").unwrap_or_default(),
+        swap_total: value("SwapTotal").unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_meminfo_with_available_memory() {
+        let content = "\
+MemTotal:       16237328 kB
+MemFree:         1203456 kB
+MemAvailable:    9876544 kB
+Buffers:          412300 kB
+SwapTotal:       8388604 kB
+SwapFree:        8126460 kB
+";
+
+        assert_that!(
+            parse_meminfo(content),
+            is(equal_to(Some(Usage {
+                mem_available: 10_113_581_056,
+                mem_free: 1_232_338_944,
+                mem_total: 16_627_023_872,
+                swap_free: 8_321_495_040,
+                swap_total: 8_589_930_496,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_meminfo_without_available_memory_and_swap() {
+        let content = "\
+MemTotal:        2048000 kB
+MemFree:          512000 kB
+";
+
+        assert_that!(
+            parse_meminfo(content),
+            is(equal_to(Some(Usage {
+                mem_available: 524_288_000,
+                mem_free: 524_288_000,
+                mem_total: 2_097_152_000,
+                swap_free: 0,
+                swap_total: 0,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_meminfo_without_free_memory() {
+        assert_that!(parse_meminfo("MemTotal:  2048000 kB\n"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn parse_meminfo_without_total_memory() {
+        assert_that!(parse_meminfo("MemFree:  512000 kB\n"), is(equal_to(None)));
+    }
+}
@@ -0,0 +1,38 @@
+mod config;
+mod data;
+mod level;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "meter";
+pub(self) const PLACEHOLDER_LEVEL: &str = "{Level}";
+pub(self) const PLACEHOLDER_METER: &str = "{Meter}";
+
+pub(self) type SharedLevel = Arc<Mutex<Option<u32>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let level = SharedLevel::default();
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone(), settings.clone(), Arc::clone(&level)),
+        Updater::new(data, level),
+    )))
+}
@@ -0,0 +1,116 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_LEVEL;
+use super::PLACEHOLDER_METER;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils::icon_by_percentage;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, level: Option<u32>) {
+        let (meter, text) = match level {
+            Some(level) => (
+                icon_by_percentage(&self.settings.symbols, level)
+                    .unwrap_or_default()
+                    .to_owned(),
+                level.to_string(),
+            ),
+            None => (
+                self.settings.no_value.clone(),
+                self.settings.no_value.clone(),
+            ),
+        };
+
+        self.cache = self
+            .settings
+            .template
+            .replace(PLACEHOLDER_LEVEL, &text)
+            .replace(PLACEHOLDER_METER, &meter);
+
+        self.values = match level {
+            Some(_) => map_values!("LEVEL" => text),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            device: "@DEFAULT_SOURCE@".to_owned(),
+            interval: 200,
+            no_value: "NA".to_owned(),
+            symbols: vec!["_".to_owned(), "-".to_owned(), "=".to_owned()],
+            template: "MIC {Meter} {Level}%".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(90));
+
+        assert_that!(object.render(), is(equal_to("MIC = 90%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "LEVEL" => "90",
+            )))
+        );
+
+        object.update(Some(0));
+
+        assert_that!(object.render(), is(equal_to("MIC _ 0%")));
+
+        object.update(Some(50));
+
+        assert_that!(object.render(), is(equal_to("MIC - 50%")));
+    }
+
+    #[test]
+    fn render_with_update_without_recording() {
+        let mut object = Data::new(settings());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("MIC NA NA%")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,64 @@
+pub(super) const SAMPLE_RATE: u64 = 8000;
+
+const RANGE_DB: f32 = 60.0;
+
+const SAMPLE_SIZE: usize = 2;
+
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn chunk_size(interval: u64) -> usize {
+    let samples = (SAMPLE_RATE * interval / 1000).max(1);
+
+    samples as usize * SAMPLE_SIZE
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(super) fn peak(chunk: &[u8]) -> u32 {
+    let peak = chunk
+        .chunks_exact(SAMPLE_SIZE)
+        .map(|sample| i32::from(i16::from_le_bytes([sample[0], sample[1]])).abs())
+        .max()
+        .unwrap_or(0);
+
+    if peak == 0 {
+        return 0;
+    }
+
+    let decibel = 20.0 * (peak as f32 / 32768.0).log10();
+
+    ((decibel + RANGE_DB) / RANGE_DB * 100.0)
+        .max(0.0)
+        .min(100.0)
+        .round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn samples(values: &[i16]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn chunk_size_of_interval() {
+        assert_that!(chunk_size(200), is(equal_to(3200)));
+        assert_that!(chunk_size(0), is(equal_to(2)));
+    }
+
+    #[test]
+    fn peak_of_samples() {
+        assert_that!(peak(&samples(&[0, 0, 0])), is(equal_to(0)));
+        assert_that!(peak(&samples(&[120, -32768, 5])), is(equal_to(100)));
+        assert_that!(peak(&samples(&[-100, 16384, 3])), is(equal_to(90)));
+        assert_that!(peak(&samples(&[16, -20])), is(equal_to(0)));
+    }
+}
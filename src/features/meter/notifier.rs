@@ -0,0 +1,67 @@
+use super::ConfigEntry;
+use super::SharedLevel;
+use super::level;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::process;
+use crate::wrapper::thread;
+
+pub(super) struct Notifier {
+    id: usize,
+    level: SharedLevel,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        settings: ConfigEntry,
+        level: SharedLevel,
+    ) -> Self {
+        Self {
+            id,
+            level,
+            sender,
+            settings,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let rate = format!("--rate={}", level::SAMPLE_RATE);
+        let latency = format!("--latency-msec={}", self.settings.interval);
+
+        let command = process::Command::new(
+            "parec",
+            &[
+                "--raw",
+                "--format=s16le",
+                "--channels=1",
+                &rate[..],
+                &latency[..],
+                "--device",
+                &self.settings.device[..],
+            ],
+        );
+
+        command.listen_chunks(level::chunk_size(self.settings.interval), |chunk| {
+            let peak = Some(level::peak(chunk));
+
+            {
+                let mut level = self.level.lock().unwrap();
+
+                if *level == peak {
+                    return Ok(());
+                }
+
+                *level = peak;
+            }
+
+            communication::send_message(self.id, &self.sender)
+        })
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedLevel;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    level: SharedLevel,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, level: SharedLevel) -> Self {
+        Self { data, level }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let level = *self.level.lock().unwrap();
+
+        self.data.update(level);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+mod client;
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(self) use self::client::Client;
+pub(self) use self::client::State;
+pub(self) use self::client::Status;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "mpd";
+pub(self) const PLACEHOLDER_ALBUM: &str = "{Album}";
+pub(self) const PLACEHOLDER_ARTIST: &str = "{Artist}";
+pub(self) const PLACEHOLDER_DURATION: &str = "{Duration}";
+pub(self) const PLACEHOLDER_ELAPSED: &str = "{Elapsed}";
+pub(self) const PLACEHOLDER_TITLE: &str = "{Title}";
+
+pub(self) type SharedStatus = Arc<Mutex<Option<Status>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let status = SharedStatus::default();
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone(), Arc::clone(&status), settings.clone()),
+        Updater::new(data, status),
+    )))
+}
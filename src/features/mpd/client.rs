@@ -0,0 +1,369 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+use std::time::Instant;
+
+const RESPONSE_GREETING: &str = "OK MPD ";
+const RESPONSE_OK: &str = "OK";
+const RESPONSE_ERROR: &str = "ACK ";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum State {
+    Pause,
+    Play,
+    Stop,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Status {
+    pub(super) album: String,
+    pub(super) artist: String,
+    pub(super) duration: Option<f64>,
+    pub(super) elapsed: f64,
+    pub(super) queried: Instant,
+    pub(super) state: State,
+    pub(super) title: String,
+}
+
+impl Status {
+    pub(super) fn elapsed_at(&self, now: Instant) -> f64 {
+        if self.state == State::Play {
+            self.elapsed + now.saturating_duration_since(self.queried).as_secs_f64()
+        } else {
+            self.elapsed
+        }
+    }
+}
+
+enum Stream {
+    Tcp(TcpStream),
+    Unix(UnixStream),
+}
+
+impl Read for Stream {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.read(buffer),
+            Self::Unix(stream) => stream.read(buffer),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.write(buffer),
+            Self::Unix(stream) => stream.write(buffer),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.flush(),
+            Self::Unix(stream) => stream.flush(),
+        }
+    }
+}
+
+impl Stream {
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.set_read_timeout(timeout),
+            Self::Unix(stream) => stream.set_read_timeout(timeout),
+        }
+    }
+}
+
+pub(super) struct Client {
+    stream: BufReader<Stream>,
+}
+
+impl Client {
+    pub(super) fn connect(address: &str, password: &str) -> Result<Self> {
+        let stream = if address.starts_with('/') {
+            UnixStream::connect(address).map(Stream::Unix)
+        } else {
+            TcpStream::connect(address).map(Stream::Tcp)
+        }
+        .wrap_error(FEATURE_NAME, format!("failed to connect to {}", address))?;
+
+        let mut client = Self {
+            stream: BufReader::new(stream),
+        };
+
+        let greeting = client.read_line()?;
+
+        if !greeting.starts_with(RESPONSE_GREETING) {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("{} is no MPD server", address),
+            ));
+        }
+
+        if !password.is_empty() {
+            client.command(&format!("password {}", quote(password)))?;
+        }
+
+        Ok(client)
+    }
+
+    pub(super) fn status(&mut self) -> Result<Status> {
+        let status = self.command("status")?;
+        let song = self.command("currentsong")?;
+
+        Ok(parse_status(&status, &song, Instant::now()))
+    }
+
+    pub(super) fn wait_for_change<F>(&mut self, interval: Option<Duration>, tick: F) -> Result<()>
+    where
+        F: Fn() -> Result<()>,
+    {
+        self.send("idle player")?;
+        self.stream
+            .get_ref()
+            .set_read_timeout(interval)
+            .wrap_error(FEATURE_NAME, "failed to set timeout")?;
+
+        let mut line = String::new();
+
+        let result = loop {
+            match self.stream.read_line(&mut line) {
+                Ok(0) => break Err(Error::new_custom(FEATURE_NAME, "connection closed")),
+                Ok(_) => {
+                    let response = line.trim_end().to_owned();
+                    line.clear();
+
+                    if response == RESPONSE_OK {
+                        break Ok(());
+                    }
+
+                    if response.starts_with(RESPONSE_ERROR) {
+                        break Err(Error::new_custom(FEATURE_NAME, response));
+                    }
+                },
+                Err(ref error)
+                    if error.kind() == io::ErrorKind::WouldBlock
+                        || error.kind() == io::ErrorKind::TimedOut =>
+                {
+                    tick()?;
+                },
+                Err(error) => break Err(Error::new_custom(FEATURE_NAME, error.to_string())),
+            }
+        };
+
+        self.stream
+            .get_ref()
+            .set_read_timeout(None)
+            .wrap_error(FEATURE_NAME, "failed to reset timeout")?;
+
+        result
+    }
+
+    fn command(&mut self, command: &str) -> Result<Vec<(String, String)>> {
+        self.send(command)?;
+
+        let mut pairs = Vec::new();
+
+        loop {
+            let line = self.read_line()?;
+
+            if line == RESPONSE_OK {
+                return Ok(pairs);
+            }
+
+            if line.starts_with(RESPONSE_ERROR) {
+                return Err(Error::new_custom(FEATURE_NAME, line));
+            }
+
+            if let Some(index) = line.find(": ") {
+                pairs.push((line[..index].to_owned(), line[index + 2..].to_owned()));
+            }
+        }
+    }
+
+    fn send(&mut self, command: &str) -> Result<()> {
+        writeln!(self.stream.get_mut(), "{}", command)
+            .wrap_error(FEATURE_NAME, "failed to send command")
+    }
+
+    fn read_line(&mut self) -> Result<String> {
+        let mut line = String::new();
+
+        let length = self
+            .stream
+            .read_line(&mut line)
+            .wrap_error(FEATURE_NAME, "failed to read response")?;
+
+        if length == 0 {
+            return Err(Error::new_custom(FEATURE_NAME, "connection closed"));
+        }
+
+        Ok(line.trim_end().to_owned())
+    }
+}
+
+fn parse_status(
+    status: &[(String, String)],
+    song: &[(String, String)],
+    queried: Instant,
+) -> Status {
+    let find = |pairs: &[(String, String)], key: &str| {
+        pairs
+            .iter()
+            .find(|(pair_key, _)| pair_key == key)
+            .map(|(_, value)| value.clone())
+    };
+
+    let state = match find(status, "state").as_deref() {
+        Some("play") => State::Play,
+        Some("pause") => State::Pause,
+        _ => State::Stop,
+    };
+
+    let time = find(status, "time").unwrap_or_default();
+    let mut time = time.split(':');
+    let time_elapsed = time.next().and_then(|elapsed| elapsed.parse().ok());
+    let time_duration = time.next().and_then(|duration| duration.parse().ok());
+
+    let elapsed = find(status, "elapsed")
+        .and_then(|elapsed| elapsed.parse().ok())
+        .or(time_elapsed)
+        .unwrap_or_default();
+    let duration = find(status, "duration")
+        .and_then(|duration| duration.parse().ok())
+        .or(time_duration)
+        .filter(|duration| *duration > 0.0);
+
+    let title = find(song, "Title")
+        .or_else(|| find(song, "Name"))
+        .or_else(|| {
+            find(song, "file").map(|file| file.rsplit('/').next().unwrap_or_default().to_owned())
+        })
+        .unwrap_or_default();
+
+    Status {
+        album: find(song, "Album").unwrap_or_default(),
+        artist: find(song, "Artist").unwrap_or_default(),
+        duration,
+        elapsed,
+        queried,
+        state,
+        title,
+    }
+}
+
+fn quote(argument: &str) -> String {
+    format!(
+        "\"{}\"",
+        argument.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_status_of_playing_song() {
+        let queried = Instant::now();
+        let status = pairs(&[
+            ("state", "play"),
+            ("time", "72:245"),
+            ("elapsed", "72.412"),
+            ("duration", "245.213"),
+        ]);
+        let song = pairs(&[
+            (
+                "file",
+                "Queen/A Night at the Opera/11 Bohemian Rhapsody.flac",
+            ),
+            ("Artist", "Queen"),
+            ("Album", "A Night at the Opera"),
+            ("Title", "Bohemian Rhapsody"),
+        ]);
+
+        assert_that!(
+            parse_status(&status, &song, queried),
+            is(equal_to(Status {
+                album: "A Night at the Opera".to_owned(),
+                artist: "Queen".to_owned(),
+                duration: Some(245.213),
+                elapsed: 72.412,
+                queried,
+                state: State::Play,
+                title: "Bohemian Rhapsody".to_owned(),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_status_of_untagged_song_of_old_version() {
+        let queried = Instant::now();
+        let status = pairs(&[("state", "pause"), ("time", "12:180")]);
+        let song = pairs(&[("file", "music/track01.mp3")]);
+
+        assert_that!(
+            parse_status(&status, &song, queried),
+            is(equal_to(Status {
+                album: "".to_owned(),
+                artist: "".to_owned(),
+                duration: Some(180.0),
+                elapsed: 12.0,
+                queried,
+                state: State::Pause,
+                title: "track01.mp3".to_owned(),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_status_when_stopped() {
+        let status = parse_status(&pairs(&[("state", "stop")]), &[], Instant::now());
+
+        assert_that!(status.state, is(equal_to(State::Stop)));
+        assert_that!(status.duration, is(none()));
+    }
+
+    #[test]
+    fn elapsed_at_advances_while_playing() {
+        let queried = Instant::now();
+        let mut status = parse_status(
+            &pairs(&[("state", "play"), ("elapsed", "10.5")]),
+            &[],
+            queried,
+        );
+
+        assert_that!(
+            status.elapsed_at(queried + Duration::from_secs(3)),
+            is(equal_to(13.5))
+        );
+
+        status.state = State::Pause;
+
+        assert_that!(
+            status.elapsed_at(queried + Duration::from_secs(3)),
+            is(equal_to(10.5))
+        );
+    }
+
+    #[test]
+    fn quote_password() {
+        assert_that!(quote(r#"se"cr\et"#), is(equal_to(r#""se\"cr\\et""#)));
+    }
+}
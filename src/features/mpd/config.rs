@@ -0,0 +1,66 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) address: String,
+    pub(super) no_value: String,
+    pub(super) password: String,
+    pub(super) paused: String,
+    pub(super) stopped: String,
+    pub(super) template: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "address"  => "localhost:6600",
+                "no_value" => "NA",
+                "password" => "",
+                "paused"   => "{Artist} - {Title} (paused)",
+                "stopped"  => "",
+                "template" => "{Artist} - {Title} {Elapsed}/{Duration}",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("mpd", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("mpd", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("address".to_owned(), "localhost:6600".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("password".to_owned(), "".into());
+        map.insert("paused".to_owned(), "{Artist} - {Title} (paused)".into());
+        map.insert("stopped".to_owned(), "".into());
+        map.insert(
+            "template".to_owned(),
+            "{Artist} - {Title} {Elapsed}/{Duration}".into(),
+        );
+
+        map
+    }
+}
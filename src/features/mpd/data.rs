@@ -0,0 +1,208 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_ALBUM;
+use super::PLACEHOLDER_ARTIST;
+use super::PLACEHOLDER_DURATION;
+use super::PLACEHOLDER_ELAPSED;
+use super::PLACEHOLDER_TITLE;
+use super::State;
+use super::Status;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use std::time::Instant;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(super) fn update(&mut self, status: Option<&Status>, now: Instant) {
+        let status = match status {
+            Some(status) if status.state != State::Stop => status,
+            _ => {
+                self.cache = self.settings.stopped.clone();
+                self.values = map_values!("STATE" => "stop");
+
+                return;
+            },
+        };
+
+        let elapsed = status.elapsed_at(now) as u64;
+        let duration = status.duration.map(|duration| duration as u64);
+
+        let (state, template) = if status.state == State::Pause {
+            ("pause", &self.settings.paused)
+        } else {
+            ("play", &self.settings.template)
+        };
+
+        self.cache = template
+            .replace(PLACEHOLDER_ALBUM, &status.album)
+            .replace(PLACEHOLDER_ARTIST, &status.artist)
+            .replace(
+                PLACEHOLDER_DURATION,
+                &duration.map_or_else(|| self.settings.no_value.clone(), format_time),
+            )
+            .replace(PLACEHOLDER_ELAPSED, &format_time(elapsed))
+            .replace(PLACEHOLDER_TITLE, &status.title);
+
+        self.values = map_values!(
+            "ARTIST" => status.artist,
+            "ELAPSED" => elapsed,
+            "STATE" => state,
+            "TITLE" => status.title,
+        );
+
+        if let Some(duration) = duration {
+            self.values.insert("DURATION", duration.to_string());
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+fn format_time(seconds: u64) -> String {
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::time::Duration;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            address: "localhost:6600".to_owned(),
+            no_value: "NA".to_owned(),
+            password: String::new(),
+            paused: "P {Title}".to_owned(),
+            stopped: "S".to_owned(),
+            template: "{Artist} - {Title} {Elapsed}/{Duration}".to_owned(),
+        }
+    }
+
+    fn status(state: State, duration: Option<f64>) -> Status {
+        Status {
+            album: "A Night at the Opera".to_owned(),
+            artist: "Queen".to_owned(),
+            duration,
+            elapsed: 72.4,
+            queried: Instant::now(),
+            state,
+            title: "Bohemian Rhapsody".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update_while_playing() {
+        let mut object = Data::new(settings());
+        let status = status(State::Play, Some(4000.0));
+
+        object.update(Some(&status), status.queried + Duration::from_secs(2));
+
+        assert_that!(
+            object.render(),
+            is(equal_to("Queen - Bohemian Rhapsody 1:14/1:06:40"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ARTIST" => "Queen",
+                "DURATION" => "4000",
+                "ELAPSED" => "74",
+                "STATE" => "play",
+                "TITLE" => "Bohemian Rhapsody",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_stream() {
+        let mut object = Data::new(settings());
+        let status = status(State::Play, None);
+
+        object.update(Some(&status), status.queried);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("Queen - Bohemian Rhapsody 1:12/NA"))
+        );
+    }
+
+    #[test]
+    fn render_with_update_while_paused() {
+        let mut object = Data::new(settings());
+        let status = status(State::Pause, Some(245.0));
+
+        object.update(Some(&status), status.queried + Duration::from_secs(2));
+
+        assert_that!(object.render(), is(equal_to("P Bohemian Rhapsody")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ARTIST" => "Queen",
+                "DURATION" => "245",
+                "ELAPSED" => "72",
+                "STATE" => "pause",
+                "TITLE" => "Bohemian Rhapsody",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_while_stopped_or_disconnected() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&status(State::Stop, None)), Instant::now());
+
+        assert_that!(object.render(), is(equal_to("S")));
+
+        object.update(None, Instant::now());
+
+        assert_that!(object.render(), is(equal_to("S")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "STATE" => "stop",
+            )))
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use super::Client;
+use super::ConfigEntry;
+use super::SharedStatus;
+use super::State;
+use super::Status;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use log::info;
+use std::time::Duration;
+
+const RECONNECT_INTERVAL: u64 = 5;
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(super) struct Notifier {
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    status: SharedStatus,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        status: SharedStatus,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            id,
+            sender,
+            settings,
+            status,
+        }
+    }
+
+    fn listen(&self, mut client: Client) -> Result<()> {
+        loop {
+            let status = client.status()?;
+            let interval = if status.state == State::Play {
+                Some(TICK_INTERVAL)
+            } else {
+                None
+            };
+
+            self.update(Some(status))?;
+
+            client.wait_for_change(interval, || {
+                communication::send_message(self.id, &self.sender)
+            })?;
+        }
+    }
+
+    fn update(&self, status: Option<Status>) -> Result<()> {
+        *self.status.lock().unwrap() = status;
+
+        communication::send_message(self.id, &self.sender)
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let mut reported = false;
+
+        loop {
+            match Client::connect(&self.settings.address, &self.settings.password) {
+                Ok(client) => {
+                    info!("Connected to MPD at {}", self.settings.address);
+                    reported = false;
+
+                    if let Err(error) = self.listen(client) {
+                        info!("Lost connection to MPD: {}", error);
+                    }
+                },
+                Err(error) if !reported => {
+                    reported = true;
+                    error.show_error();
+                },
+                Err(_) => (),
+            }
+
+            self.update(None)?;
+
+            thread::sleep_secs(RECONNECT_INTERVAL);
+        }
+    }
+}
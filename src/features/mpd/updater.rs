@@ -0,0 +1,30 @@
+use super::Data;
+use super::SharedStatus;
+use crate::error::*;
+use crate::feature;
+use std::time::Instant;
+
+pub(super) struct Updater {
+    data: Data,
+    status: SharedStatus,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, status: SharedStatus) -> Self {
+        Self { data, status }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let status = self.status.lock().unwrap().clone();
+
+        self.data.update(status.as_ref(), Instant::now());
+
+        Ok(())
+    }
+}
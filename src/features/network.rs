@@ -1,35 +1,67 @@
+mod address;
 mod config;
 mod data;
+mod interface;
 mod notifier;
+mod traffic;
 mod updater;
 
+use crate::cache;
 use crate::communication;
 use crate::error::*;
 use crate::feature;
+use crate::http;
 use crate::wrapper::channel;
 
+pub(self) use self::address::Ipv6Source;
+pub(self) use self::address::PublicIpLookup;
 pub(crate) use self::config::ConfigEntry;
 pub(self) use self::config::RenderConfig;
 pub(self) use self::config::UpdateConfig;
 pub(self) use self::data::Data;
+pub(self) use self::interface::ConnectionType;
+pub(super) use self::interface::default_route;
 pub(self) use self::notifier::Notifier;
+pub(self) use self::traffic::Traffic;
 pub(self) use self::updater::Updater;
 
 pub(super) const FEATURE_NAME: &str = "network";
+pub(self) const PLACEHOLDER_BITRATE: &str = "{Bitrate}";
+pub(self) const PLACEHOLDER_BSSID: &str = "{BSSID}";
 pub(self) const PLACEHOLDER_ESSID: &str = "{ESSID}";
 pub(self) const PLACEHOLDER_IPV4: &str = "{IPv4}";
 pub(self) const PLACEHOLDER_IPV6: &str = "{IPv6}";
+pub(self) const PLACEHOLDER_RX_TOTAL: &str = "{RxTotal}";
+pub(self) const PLACEHOLDER_TX_TOTAL: &str = "{TxTotal}";
+pub(self) const PLACEHOLDER_TYPE: &str = "{Type}";
 
 pub(super) fn create(
     id: usize,
     sender: &channel::Sender<communication::Message>,
     settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
 ) -> Result<Box<dyn feature::Feature>> {
     let data = Data::new(settings.render.clone());
 
     Ok(Box::new(feature::Composer::new(
         FEATURE_NAME,
-        Notifier::new(id, sender.clone()),
-        Updater::new(data, settings.update.clone()),
+        Notifier::new(
+            id,
+            sender.clone(),
+            if settings.update.show_traffic {
+                settings.traffic_interval
+            } else {
+                0
+            },
+        ),
+        Updater::new(
+            data,
+            settings.update.clone(),
+            Ipv6Source::init(settings)?,
+            PublicIpLookup::init(settings, http_settings)?,
+            Traffic::init(settings)?,
+            cache::Cache::init(true),
+            settings.cache_ttl,
+        ),
     )))
 }
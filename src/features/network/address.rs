@@ -0,0 +1,180 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::http;
+use std::net::Ipv6Addr;
+
+const FLAG_TEMPORARY: u32 = 0x01;
+const SCOPE_HOST: u32 = 0x10;
+const SCOPE_LINK: u32 = 0x20;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Ipv6Filter {
+    prefer_global: bool,
+    skip_link_local: bool,
+    skip_temporary: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Ipv6Source {
+    Local(Ipv6Filter),
+    Public,
+}
+
+impl Ipv6Source {
+    pub(super) fn init(settings: &ConfigEntry) -> Result<Self> {
+        match &settings.ipv6_source[..] {
+            "local" => Ok(Self::Local(Ipv6Filter {
+                prefer_global: settings.ipv6_prefer_global,
+                skip_link_local: settings.ipv6_skip_link_local,
+                skip_temporary: settings.ipv6_skip_temporary,
+            })),
+            "public" => Ok(Self::Public),
+            source => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown ipv6_source {}, expected local or public", source),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) enum PublicIpLookup {
+    Dns,
+    Http {
+        client: http::Client,
+        ipv4_url: String,
+        ipv6_url: String,
+    },
+}
+
+impl PublicIpLookup {
+    pub(super) fn init(settings: &ConfigEntry, http_settings: &http::ConfigEntry) -> Result<Self> {
+        match &settings.public_ip_lookup[..] {
+            "dns" => Ok(Self::Dns),
+            "http" => Ok(Self::Http {
+                client: http::Client::init(FEATURE_NAME, http_settings),
+                ipv4_url: settings.public_ipv4_url.clone(),
+                ipv6_url: settings.public_ipv6_url.clone(),
+            }),
+            lookup => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown public_ip_lookup {}, expected dns or http", lookup),
+            )),
+        }
+    }
+}
+
+struct LocalAddress {
+    address: Ipv6Addr,
+    flags: u32,
+    scope: u32,
+}
+
+impl LocalAddress {
+    fn parse(line: &str) -> Option<Self> {
+        let mut columns = line.split_whitespace();
+
+        let address = u128::from_str_radix(columns.next()?, 16).ok()?;
+        let scope = u32::from_str_radix(columns.nth(2)?, 16).ok()?;
+        let flags = u32::from_str_radix(columns.next()?, 16).ok()?;
+
+        Some(Self {
+            address: Ipv6Addr::from(address),
+            flags,
+            scope,
+        })
+    }
+
+    fn rank(&self) -> u8 {
+        if self.scope == SCOPE_LINK {
+            2
+        } else if self.address.segments()[0] & 0xe000 == 0x2000 {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+pub(super) fn local_ipv6(content: &str, filter: &Ipv6Filter) -> Option<String> {
+    let mut addresses = content
+        .lines()
+        .filter_map(LocalAddress::parse)
+        .filter(|address| address.scope != SCOPE_HOST)
+        .filter(|address| !filter.skip_link_local || address.scope != SCOPE_LINK)
+        .filter(|address| !filter.skip_temporary || address.flags & FLAG_TEMPORARY == 0);
+
+    let address = if filter.prefer_global {
+        addresses.min_by_key(LocalAddress::rank)
+    } else {
+        addresses.next()
+    };
+
+    address.map(|address| address.address.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const IF_INET6: &str = "\
+00000000000000000000000000000001 01 80 10 80       lo
+fe80000000000000021122fffe334455 02 40 20 80   wlp3s0
+fd00123400000000021122fffe334455 02 40 00 00   wlp3s0
+20010db8000000001a2b3c4d5e6f7081 02 40 00 01   wlp3s0
+20010db800000000021122fffe334455 02 40 00 00   wlp3s0
+";
+
+    fn filter(prefer_global: bool, skip_link_local: bool, skip_temporary: bool) -> Ipv6Filter {
+        Ipv6Filter {
+            prefer_global,
+            skip_link_local,
+            skip_temporary,
+        }
+    }
+
+    #[test]
+    fn local_ipv6_with_all_filters() {
+        assert_that!(
+            local_ipv6(IF_INET6, &filter(true, true, true)),
+            is(equal_to(Some("2001:db8::211:22ff:fe33:4455".to_owned())))
+        );
+    }
+
+    #[test]
+    fn local_ipv6_with_temporary_address() {
+        assert_that!(
+            local_ipv6(IF_INET6, &filter(true, true, false)),
+            is(equal_to(Some("2001:db8::1a2b:3c4d:5e6f:7081".to_owned())))
+        );
+    }
+
+    #[test]
+    fn local_ipv6_without_preference() {
+        assert_that!(
+            local_ipv6(IF_INET6, &filter(false, false, true)),
+            is(equal_to(Some("fe80::211:22ff:fe33:4455".to_owned())))
+        );
+        assert_that!(
+            local_ipv6(IF_INET6, &filter(false, true, true)),
+            is(equal_to(Some("fd00:1234::211:22ff:fe33:4455".to_owned())))
+        );
+    }
+
+    #[test]
+    fn local_ipv6_with_only_link_local_address() {
+        let content = "fe80000000000000021122fffe334455 02 40 20 80   wlp3s0\n";
+
+        assert_that!(
+            local_ipv6(content, &filter(true, true, true)),
+            is(equal_to(None))
+        );
+        assert_that!(
+            local_ipv6(content, &filter(true, false, true)),
+            is(equal_to(Some("fe80::211:22ff:fe33:4455".to_owned())))
+        );
+    }
+}
@@ -1,7 +1,12 @@
 use super::FEATURE_NAME;
+use super::PLACEHOLDER_BITRATE;
+use super::PLACEHOLDER_BSSID;
 use super::PLACEHOLDER_ESSID;
 use super::PLACEHOLDER_IPV4;
 use super::PLACEHOLDER_IPV6;
+use super::PLACEHOLDER_RX_TOTAL;
+use super::PLACEHOLDER_TX_TOTAL;
+use super::PLACEHOLDER_TYPE;
 use crate::error::*;
 use crate::settings::ConfigType;
 use crate::wrapper::config;
@@ -10,21 +15,37 @@ use serde_derive::*;
 
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct RenderConfig {
+    pub(super) icon_ethernet: String,
+    pub(super) icon_offline: String,
+    pub(super) icon_usb: String,
+    pub(super) icon_wifi: String,
     pub(super) no_value: String,
     pub(super) template: String,
 }
 
 #[derive(Clone, Debug, Default, Deserialize)]
 pub(super) struct UpdateConfig {
-    pub(super) show_essid: bool,
     pub(super) show_ipv4: bool,
     pub(super) show_ipv6: bool,
+    pub(super) show_traffic: bool,
+    pub(super) show_type: bool,
+    pub(super) show_wireless: bool,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ConfigEntry {
+    pub(super) cache_ttl: u64,
+    pub(super) ipv6_prefer_global: bool,
+    pub(super) ipv6_skip_link_local: bool,
+    pub(super) ipv6_skip_temporary: bool,
+    pub(super) ipv6_source: String,
+    pub(super) public_ip_lookup: String,
+    pub(super) public_ipv4_url: String,
+    pub(super) public_ipv6_url: String,
     #[serde(flatten)]
     pub(super) render: RenderConfig,
+    pub(super) traffic_interval: u64,
+    pub(super) traffic_since: String,
     #[doc(hidden)]
     pub(super) update: UpdateConfig,
 }
@@ -34,8 +55,22 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
-                "no_value" => "NA",
-                "template" => "{IPv4} · {IPv6} · {ESSID}",
+                "cache_ttl"            => 0,
+                "icon_ethernet"        => "ETH",
+                "icon_offline"         => "OFF",
+                "icon_usb"             => "USB",
+                "icon_wifi"            => "WIFI",
+                "ipv6_prefer_global"   => true,
+                "ipv6_skip_link_local" => true,
+                "ipv6_skip_temporary"  => true,
+                "ipv6_source"          => "public",
+                "no_value"             => "NA",
+                "public_ip_lookup"     => "dns",
+                "public_ipv4_url"      => "https://api.ipify.org",
+                "public_ipv6_url"      => "https://api6.ipify.org",
+                "template"             => "{IPv4} · {IPv6} · {ESSID}",
+                "traffic_interval"     => 60,
+                "traffic_since"        => "boot",
             ),
         )
     }
@@ -43,9 +78,21 @@ impl ConfigType for ConfigEntry {
     fn set_values(config: &mut config::Config) -> Result<()> {
         let template = config.get_str(&format!("{}.template", FEATURE_NAME))?;
 
-        set_update_config(config, &template, PLACEHOLDER_ESSID, "essid")?;
-        set_update_config(config, &template, PLACEHOLDER_IPV4, "ipv4")?;
-        set_update_config(config, &template, PLACEHOLDER_IPV6, "ipv6")?;
+        set_update_config(
+            config,
+            &template,
+            &[PLACEHOLDER_BITRATE, PLACEHOLDER_BSSID, PLACEHOLDER_ESSID],
+            "wireless",
+        )?;
+        set_update_config(config, &template, &[PLACEHOLDER_IPV4], "ipv4")?;
+        set_update_config(config, &template, &[PLACEHOLDER_IPV6], "ipv6")?;
+        set_update_config(config, &template, &[PLACEHOLDER_TYPE], "type")?;
+        set_update_config(
+            config,
+            &template,
+            &[PLACEHOLDER_RX_TOTAL, PLACEHOLDER_TX_TOTAL],
+            "traffic",
+        )?;
 
         Ok(())
     }
@@ -54,12 +101,14 @@ impl ConfigType for ConfigEntry {
 fn set_update_config(
     config: &mut config::Config,
     template: &str,
-    placeholder: &str,
+    placeholders: &[&str],
     key: &str,
 ) -> Result<()> {
     config.set(
         &format!("{}.update.show_{}", FEATURE_NAME, key),
-        template.contains(placeholder),
+        placeholders
+            .iter()
+            .any(|placeholder| template.contains(placeholder)),
     )
 }
 
@@ -89,8 +138,25 @@ mod tests {
 
         fn default_map() -> HashMap<String, Value> {
             let mut map = HashMap::new();
+            map.insert("cache_ttl".to_owned(), 0.into());
+            map.insert("icon_ethernet".to_owned(), "ETH".into());
+            map.insert("icon_offline".to_owned(), "OFF".into());
+            map.insert("icon_usb".to_owned(), "USB".into());
+            map.insert("icon_wifi".to_owned(), "WIFI".into());
+            map.insert("ipv6_prefer_global".to_owned(), true.into());
+            map.insert("ipv6_skip_link_local".to_owned(), true.into());
+            map.insert("ipv6_skip_temporary".to_owned(), true.into());
+            map.insert("ipv6_source".to_owned(), "public".into());
             map.insert("no_value".to_owned(), "NA".into());
+            map.insert("public_ip_lookup".to_owned(), "dns".into());
+            map.insert("public_ipv4_url".to_owned(), "https://api.ipify.org".into());
+            map.insert(
+                "public_ipv6_url".to_owned(),
+                "https://api6.ipify.org".into(),
+            );
             map.insert("template".to_owned(), "{IPv4} · {IPv6} · {ESSID}".into());
+            map.insert("traffic_interval".to_owned(), 60.into());
+            map.insert("traffic_since".to_owned(), "boot".into());
 
             map
         }
@@ -104,34 +170,58 @@ mod tests {
 
             #[test]
             fn and_all_enabled() {
-                test_config("{IPv4} · {IPv6} · {ESSID}", true, true, true);
+                test_config(
+                    "{Type} {IPv4} · {IPv6} · {ESSID} {RxTotal}",
+                    true,
+                    true,
+                    true,
+                    true,
+                    true,
+                );
+            }
+
+            #[test]
+            fn and_wireless_enabled() {
+                test_config("IPv4} · IPv6} · {ESSID}", true, false, false, false, false);
             }
 
             #[test]
-            fn and_essid_enabled() {
-                test_config("IPv4} · IPv6} · {ESSID}", true, false, false);
+            fn and_bssid_and_bitrate_enabled() {
+                test_config("{BSSID} {Bitrate}", true, false, false, false, false);
             }
 
             #[test]
             fn and_ipv4_enabled() {
-                test_config("{IPv4} · IPv6} · ESSID}", false, true, false);
+                test_config("{IPv4} · IPv6} · ESSID}", false, true, false, false, false);
             }
 
             #[test]
             fn and_ipv6_enabled() {
-                test_config("IPv4} · {IPv6} · ESSID}", false, false, true);
+                test_config("IPv4} · {IPv6} · ESSID}", false, false, true, false, false);
+            }
+
+            #[test]
+            fn and_type_enabled() {
+                test_config("{Type}", false, false, false, true, false);
+            }
+
+            #[test]
+            fn and_traffic_enabled() {
+                test_config("{TxTotal}", false, false, false, false, true);
             }
 
             #[test]
             fn and_nothing_enabled() {
-                test_config("schubidu", false, false, false);
+                test_config("schubidu", false, false, false, false, false);
             }
 
             fn test_config(
                 template: &'static str,
-                show_essid: bool,
+                show_wireless: bool,
                 show_ipv4: bool,
                 show_ipv6: bool,
+                show_type: bool,
+                show_traffic: bool,
             ) {
                 config::Config::get_str.mock_safe(move |_, key| {
                     assert_that!(key, is(equal_to("network.template")));
@@ -144,8 +234,8 @@ mod tests {
 
                     match counter {
                         1 => {
-                            assert_that!(key, is(equal_to("network.update.show_essid")));
-                            assert_that!(value, is(equal_to(show_essid)));
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
+                            assert_that!(value, is(equal_to(show_wireless)));
                             MockResult::Return(Ok(()))
                         },
                         2 => {
@@ -158,6 +248,16 @@ mod tests {
                             assert_that!(value, is(equal_to(show_ipv6)));
                             MockResult::Return(Ok(()))
                         },
+                        4 => {
+                            assert_that!(key, is(equal_to("network.update.show_type")));
+                            assert_that!(value, is(equal_to(show_type)));
+                            MockResult::Return(Ok(()))
+                        },
+                        5 => {
+                            assert_that!(key, is(equal_to("network.update.show_traffic")));
+                            assert_that!(value, is(equal_to(show_traffic)));
+                            MockResult::Return(Ok(()))
+                        },
                         _ => panic!("set called to often: {} times", counter),
                     }
                 });
@@ -199,7 +299,7 @@ mod tests {
 
                     match counter {
                         1 => {
-                            assert_that!(key, is(equal_to("network.update.show_essid")));
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
                             assert_that!(value, is(equal_to(false)));
                             MockResult::Return(Err(Error::new_custom("name", "description")))
                         },
@@ -228,7 +328,7 @@ mod tests {
 
                     match counter {
                         1 => {
-                            assert_that!(key, is(equal_to("network.update.show_essid")));
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
                             assert_that!(value, is(equal_to(false)));
                             MockResult::Return(Ok(()))
                         },
@@ -262,7 +362,7 @@ mod tests {
 
                     match counter {
                         1 => {
-                            assert_that!(key, is(equal_to("network.update.show_essid")));
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
                             assert_that!(value, is(equal_to(false)));
                             MockResult::Return(Ok(()))
                         },
@@ -287,6 +387,99 @@ mod tests {
                     is(equal_to(Err(Error::new_custom("name", "description"))))
                 );
             }
+
+            #[test]
+            fn in_fourth_set() {
+                config::Config::get_str.mock_safe(|_, key| {
+                    assert_that!(key, is(equal_to("network.template")));
+                    MockResult::Return(Ok("template".to_owned()))
+                });
+
+                let mut counter = 0;
+                config::Config::set::<bool>.mock_safe(move |_, key, value| {
+                    counter += 1;
+
+                    match counter {
+                        1 => {
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        2 => {
+                            assert_that!(key, is(equal_to("network.update.show_ipv4")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        3 => {
+                            assert_that!(key, is(equal_to("network.update.show_ipv6")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        4 => {
+                            assert_that!(key, is(equal_to("network.update.show_type")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Err(Error::new_custom("name", "description")))
+                        },
+                        _ => panic!("set called to often: {} times", counter),
+                    }
+                });
+
+                let mut config = config::Config::new();
+
+                assert_that!(
+                    ConfigEntry::set_values(&mut config),
+                    is(equal_to(Err(Error::new_custom("name", "description"))))
+                );
+            }
+
+            #[test]
+            fn in_fifth_set() {
+                config::Config::get_str.mock_safe(|_, key| {
+                    assert_that!(key, is(equal_to("network.template")));
+                    MockResult::Return(Ok("template".to_owned()))
+                });
+
+                let mut counter = 0;
+                config::Config::set::<bool>.mock_safe(move |_, key, value| {
+                    counter += 1;
+
+                    match counter {
+                        1 => {
+                            assert_that!(key, is(equal_to("network.update.show_wireless")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        2 => {
+                            assert_that!(key, is(equal_to("network.update.show_ipv4")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        3 => {
+                            assert_that!(key, is(equal_to("network.update.show_ipv6")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        4 => {
+                            assert_that!(key, is(equal_to("network.update.show_type")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Ok(()))
+                        },
+                        5 => {
+                            assert_that!(key, is(equal_to("network.update.show_traffic")));
+                            assert_that!(value, is(equal_to(false)));
+                            MockResult::Return(Err(Error::new_custom("name", "description")))
+                        },
+                        _ => panic!("set called to often: {} times", counter),
+                    }
+                });
+
+                let mut config = config::Config::new();
+
+                assert_that!(
+                    ConfigEntry::set_values(&mut config),
+                    is(equal_to(Err(Error::new_custom("name", "description"))))
+                );
+            }
         }
     }
 }
@@ -1,13 +1,23 @@
-use super::RenderConfig;
+use super::ConnectionType;
+use super::PLACEHOLDER_BITRATE;
+use super::PLACEHOLDER_BSSID;
 use super::PLACEHOLDER_ESSID;
 use super::PLACEHOLDER_IPV4;
 use super::PLACEHOLDER_IPV6;
+use super::PLACEHOLDER_RX_TOTAL;
+use super::PLACEHOLDER_TX_TOTAL;
+use super::PLACEHOLDER_TYPE;
+use super::RenderConfig;
 use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils::format_bytes;
+use crate::wrapper::nl80211;
 
 #[derive(Debug)]
 pub(super) struct Data {
     cache: String,
     config: RenderConfig,
+    values: Values,
 }
 
 impl Data {
@@ -16,21 +26,79 @@ impl Data {
         Self {
             cache: String::new(),
             config,
+            values: Values::new(),
         }
     }
 
-    pub(super) fn update<T4, T6, E>(&mut self, ipv4: T4, ipv6: T6, essid: E)
-    where
+    pub(super) fn update<T4, T6>(
+        &mut self,
+        ipv4: T4,
+        ipv6: T6,
+        wireless: Option<nl80211::Connection>,
+        connection_type: Option<ConnectionType>,
+        traffic: Option<(u64, u64)>,
+    ) where
         T4: Into<Option<String>>,
         T6: Into<Option<String>>,
-        E: Into<Option<String>>,
     {
+        let ipv4 = self.get_value(ipv4);
+        let ipv6 = self.get_value(ipv6);
+        let (essid, bssid, bitrate) = match wireless {
+            Some(wireless) => (
+                Some(wireless.essid),
+                wireless.bssid,
+                wireless.bitrate.map(|bitrate| (bitrate / 10).to_string()),
+            ),
+            None => (None, None, None),
+        };
+        let essid = self.get_value(essid);
+        let bssid = self.get_value(bssid);
+        let bitrate = self.get_value(bitrate);
+        let icon =
+            self.get_value(connection_type.map(|connection_type| self.icon(connection_type)));
+        let (rx_total, tx_total) = match traffic {
+            Some((rx, tx)) => (Some(rx), Some(tx)),
+            None => (None, None),
+        };
+        let connection_type = self
+            .get_value(connection_type.map(|connection_type| connection_type.name().to_owned()));
+
         self.cache = self
             .config
             .template
-            .replace(PLACEHOLDER_IPV4, &self.get_value(ipv4))
-            .replace(PLACEHOLDER_IPV6, &self.get_value(ipv6))
-            .replace(PLACEHOLDER_ESSID, &self.get_value(essid));
+            .replace(PLACEHOLDER_IPV4, &ipv4)
+            .replace(PLACEHOLDER_IPV6, &ipv6)
+            .replace(PLACEHOLDER_ESSID, &essid)
+            .replace(PLACEHOLDER_BSSID, &bssid)
+            .replace(PLACEHOLDER_BITRATE, &bitrate)
+            .replace(PLACEHOLDER_TYPE, &icon)
+            .replace(
+                PLACEHOLDER_RX_TOTAL,
+                &self.get_value(rx_total.map(format_bytes)),
+            )
+            .replace(
+                PLACEHOLDER_TX_TOTAL,
+                &self.get_value(tx_total.map(format_bytes)),
+            );
+        self.values = map_values!(
+            "BSSID" => bssid,
+            "Bitrate" => bitrate,
+            "ESSID" => essid,
+            "IPv4" => ipv4,
+            "IPv6" => ipv6,
+            "RxTotal" => self.get_value(rx_total.map(|rx| rx.to_string())),
+            "TxTotal" => self.get_value(tx_total.map(|tx| tx.to_string())),
+            "Type" => connection_type,
+        );
+    }
+
+    fn icon(&self, connection_type: ConnectionType) -> String {
+        match connection_type {
+            ConnectionType::Ethernet => self.config.icon_ethernet.clone(),
+            ConnectionType::Offline => self.config.icon_offline.clone(),
+            ConnectionType::Usb => self.config.icon_usb.clone(),
+            ConnectionType::Wifi => self.config.icon_wifi.clone(),
+        }
     }
 
     fn get_value<T: Into<Option<String>>>(&self, value: T) -> String {
@@ -42,6 +110,10 @@ impl Renderable for Data {
     fn render(&self) -> &str {
         &self.cache
     }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
 }
 
 #[cfg(test)]
@@ -50,27 +122,43 @@ mod tests {
     use hamcrest2::assert_that;
     use hamcrest2::prelude::*;
 
+    fn connection(essid: &str) -> nl80211::Connection {
+        nl80211::Connection {
+            bitrate: Some(8667),
+            bssid: Some("00:11:22:aa:bb:cc".to_owned()),
+            essid: essid.to_owned(),
+            interface: "wlan0".to_owned(),
+        }
+    }
+
+    fn config(template: &str) -> RenderConfig {
+        RenderConfig {
+            icon_ethernet: "ETH".to_owned(),
+            icon_offline: "OFF".to_owned(),
+            icon_usb: "USB".to_owned(),
+            icon_wifi: "WIFI".to_owned(),
+            no_value: "--".to_owned(),
+            template: template.to_owned(),
+        }
+    }
+
     #[test]
     fn render_with_default() {
-        let object = Data::new(RenderConfig {
-            no_value: "--".to_owned(),
-            template: "{IPv4} {IPv6} {ESSID}".to_owned(),
-        });
+        let object = Data::new(config("{IPv4} {IPv6} {ESSID}"));
 
         assert_that!(object.render(), is(equal_to("")));
     }
 
     #[test]
     fn render_with_update() {
-        let mut object = Data::new(RenderConfig {
-            no_value: "--".to_owned(),
-            template: "{IPv4} {IPv6} {ESSID}".to_owned(),
-        });
+        let mut object = Data::new(config("{IPv4} {IPv6} {ESSID}"));
 
         object.update(
             "127.0.0.1".to_owned(),
             "fe::1".to_owned(),
-            "WLAN".to_owned(),
+            Some(connection("WLAN")),
+            None,
+            None,
         );
 
         assert_that!(object.render(), is(equal_to("127.0.0.1 fe::1 WLAN")));
@@ -80,23 +168,90 @@ mod tests {
     fn render_with_update_and_missing_placeholder() {
         let mut object = Data::new(RenderConfig {
             no_value: "#".to_owned(),
-            template: "{IPv4} // {ESSID}".to_owned(),
+            ..config("{IPv4} // {ESSID}")
         });
 
-        object.update("127.0.0.1".to_owned(), "fe::1".to_owned(), None);
+        object.update("127.0.0.1".to_owned(), "fe::1".to_owned(), None, None, None);
 
         assert_that!(object.render(), is(equal_to("127.0.0.1 // #")));
     }
 
     #[test]
     fn render_with_update_and_none_values() {
-        let mut object = Data::new(RenderConfig {
-            no_value: "--".to_owned(),
-            template: "{IPv4} {IPv6} {ESSID}".to_owned(),
-        });
+        let mut object = Data::new(config("{IPv4} {IPv6} {ESSID}"));
 
-        object.update(None, None, None);
+        object.update(None, None, None, None, None);
 
         assert_that!(object.render(), is(equal_to("-- -- --")));
     }
+
+    #[test]
+    fn values_with_update() {
+        let mut object = Data::new(config("{IPv4}"));
+
+        object.update(
+            "127.0.0.1".to_owned(),
+            None,
+            Some(connection("WLAN")),
+            Some(ConnectionType::Wifi),
+            Some((2048, 512)),
+        );
+
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "BSSID" => "00:11:22:aa:bb:cc",
+                "Bitrate" => "866",
+                "ESSID" => "WLAN",
+                "IPv4" => "127.0.0.1",
+                "IPv6" => "--",
+                "RxTotal" => "2048",
+                "TxTotal" => "512",
+                "Type" => "wifi",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_wireless_details() {
+        let mut object = Data::new(config("{ESSID} {BSSID} {Bitrate} Mbit/s"));
+
+        object.update(None, None, Some(connection("WLAN")), None, None);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("WLAN 00:11:22:aa:bb:cc 866 Mbit/s"))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_type() {
+        let mut object = Data::new(config("{Type} {IPv4}"));
+
+        object.update(
+            "127.0.0.1".to_owned(),
+            None,
+            None,
+            Some(ConnectionType::Usb),
+        );
+
+        assert_that!(object.render(), is(equal_to("USB 127.0.0.1")));
+
+        object.update(None, None, None, Some(ConnectionType::Offline), None);
+
+        assert_that!(object.render(), is(equal_to("OFF --")));
+    }
+
+    #[test]
+    fn render_with_update_and_traffic() {
+        let mut object = Data::new(config("{RxTotal} {TxTotal}"));
+
+        object.update(None, None, None, None, Some((3_221_225_472, 52_428_800)));
+
+        assert_that!(object.render(), is(equal_to("3.0 GiB 50.0 MiB")));
+
+        object.update(None, None, None, None, None);
+
+        assert_that!(object.render(), is(equal_to("-- --")));
+    }
 }
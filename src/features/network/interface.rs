@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+const PATH_NET: &str = "/sys/class/net";
+const PATH_ROUTE: &str = "/proc/net/route";
+const ROUTE_FLAG_UP: u32 = 0x01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum ConnectionType {
+    Ethernet,
+    Offline,
+    Usb,
+    Wifi,
+}
+
+impl ConnectionType {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Ethernet => "ethernet",
+            Self::Offline => "offline",
+            Self::Usb => "usb",
+            Self::Wifi => "wifi",
+        }
+    }
+
+    pub(super) fn detect() -> Self {
+        let interface = match default_route() {
+            Some(interface) => interface,
+            None => return Self::Offline,
+        };
+
+        let path = Path::new(PATH_NET).join(&interface);
+
+        if path.join("wireless").exists() || path.join("phy80211").exists() {
+            return Self::Wifi;
+        }
+
+        let subsystem = fs::read_link(path.join("device/subsystem")).ok();
+
+        if subsystem.map_or(false, |subsystem| subsystem.ends_with("usb")) {
+            Self::Usb
+        } else {
+            Self::Ethernet
+        }
+    }
+}
+
+pub(crate) fn default_route() -> Option<String> {
+    fs::read_to_string(PATH_ROUTE)
+        .ok()
+        .and_then(|routes| default_interface(&routes))
+}
+
+fn default_interface(routes: &str) -> Option<String> {
+    routes
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns = line.split_whitespace().collect::<Vec<_>>();
+
+            let destination = columns.get(1)?;
+            let flags = u32::from_str_radix(columns.get(3)?, 16).ok()?;
+            let metric = columns.get(6)?.parse::<u32>().ok()?;
+
+            if *destination == "00000000" && flags & ROUTE_FLAG_UP != 0 {
+                Some((metric, columns[0]))
+            } else {
+                None
+            }
+        })
+        .min_by_key(|(metric, _)| *metric)
+        .map(|(_, interface)| interface.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const ROUTE: &str = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+wlp3s0\t00000000\t0100A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+enp0s31f6\t00000000\t0100A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+enp0s31f6\t0000A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+";
+
+    #[test]
+    fn default_interface_with_lowest_metric() {
+        assert_that!(
+            default_interface(ROUTE),
+            is(equal_to(Some("enp0s31f6".to_owned())))
+        );
+    }
+
+    #[test]
+    fn default_interface_without_default_route() {
+        assert_that!(
+            default_interface(
+                "Iface\tDestination\tGateway \
+                 \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n"
+            ),
+            is(equal_to(None))
+        );
+    }
+}
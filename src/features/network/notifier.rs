@@ -3,20 +3,49 @@ use crate::error::*;
 use crate::wrapper::channel;
 use crate::wrapper::process;
 use crate::wrapper::thread;
+use std::sync::Once;
 
 pub(super) struct Notifier {
     id: usize,
     sender: channel::Sender<communication::Message>,
+    traffic_interval: u64,
+    ticker: Once,
 }
 
 impl Notifier {
-    pub(super) const fn new(id: usize, sender: channel::Sender<communication::Message>) -> Self {
-        Self { id, sender }
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        traffic_interval: u64,
+    ) -> Self {
+        Self {
+            id,
+            sender,
+            traffic_interval,
+            ticker: Once::new(),
+        }
+    }
+
+    fn start_ticker(&self) -> Result<()> {
+        let mut result = Ok(());
+
+        if self.traffic_interval > 0 {
+            self.ticker.call_once(|| {
+                let ticker =
+                    communication::Ticker::new(self.id, self.sender.clone(), self.traffic_interval);
+
+                result = thread::Thread::new("network traffic", ticker).run();
+            });
+        }
+
+        result
     }
 }
 
 impl thread::Runnable for Notifier {
     fn run(&self) -> Result<()> {
+        self.start_ticker()?;
+
         let command = process::Command::new("ip", &["monitor", "address", "link"]);
 
         command.listen_stdout(|| {
@@ -0,0 +1,273 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::state;
+use crate::wrapper::file;
+use serde_derive::*;
+use std::collections::BTreeMap;
+
+const PATH_BOOT_ID: &str = "/proc/sys/kernel/random/boot_id";
+const PATH_NET_DEV: &str = "/proc/net/dev";
+const STATE_BOOT_ID: &str = "boot_id";
+const STATE_TRAFFIC: &str = "traffic";
+
+type Counters = BTreeMap<String, (u64, u64)>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Since {
+    Boot,
+    Start,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Totals {
+    interfaces: Counters,
+    rx: u64,
+    tx: u64,
+}
+
+#[derive(Debug)]
+pub(super) struct Traffic {
+    boot_id: Option<String>,
+    count_new: bool,
+    since: Since,
+    state: Option<state::State>,
+    totals: Totals,
+}
+
+impl Traffic {
+    pub(super) fn init(settings: &ConfigEntry) -> Result<Self> {
+        let since = match &settings.traffic_since[..] {
+            "boot" => Since::Boot,
+            "start" => Since::Start,
+            since => {
+                return Err(Error::new_custom(
+                    FEATURE_NAME,
+                    format!("unknown traffic_since {}, expected boot or start", since),
+                ));
+            },
+        };
+
+        let mut traffic = Self::new(since, boot_id());
+
+        if since == Since::Boot {
+            traffic.restore(state::State::load(FEATURE_NAME));
+        }
+
+        Ok(traffic)
+    }
+
+    fn new(since: Since, boot_id: Option<String>) -> Self {
+        Self {
+            boot_id,
+            count_new: since == Since::Boot,
+            since,
+            state: None,
+            totals: Totals::default(),
+        }
+    }
+
+    pub(super) fn update(&mut self) -> (u64, u64) {
+        match file::read(PATH_NET_DEV).wrap_error(
+            FEATURE_NAME,
+            format!("traffic could not be read from {}", PATH_NET_DEV),
+        ) {
+            Ok(content) => self.add(parse_counters(&content)),
+            Err(error) => error.show_error(),
+        }
+
+        (self.totals.rx, self.totals.tx)
+    }
+
+    fn restore(&mut self, state: state::State) {
+        if self.boot_id.is_some() && state.get::<String>(STATE_BOOT_ID) == self.boot_id {
+            self.totals = state.get(STATE_TRAFFIC).unwrap_or_default();
+        }
+
+        self.state = Some(state);
+    }
+
+    fn add(&mut self, counters: Counters) {
+        for (interface, (rx, tx)) in &counters {
+            let (last_rx, last_tx) = match self.totals.interfaces.get(interface) {
+                Some(last) => *last,
+                None if self.count_new => (0, 0),
+                None => (*rx, *tx),
+            };
+
+            self.totals.rx += rx.checked_sub(last_rx).unwrap_or(*rx);
+            self.totals.tx += tx.checked_sub(last_tx).unwrap_or(*tx);
+        }
+
+        self.totals.interfaces = counters;
+        self.count_new = true;
+
+        self.persist().show_error_and_ignore();
+    }
+
+    fn persist(&mut self) -> Result<()> {
+        if self.since != Since::Boot {
+            return Ok(());
+        }
+
+        let state = match self.state {
+            Some(ref mut state) => state,
+            None => return Ok(()),
+        };
+
+        state.set(STATE_BOOT_ID, &self.boot_id)?;
+        state.set(STATE_TRAFFIC, &self.totals)
+    }
+}
+
+fn boot_id() -> Option<String> {
+    file::read(PATH_BOOT_ID)
+        .ok()
+        .map(|boot_id| boot_id.trim().to_owned())
+}
+
+fn parse_counters(content: &str) -> Counters {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let interface = parts.next()?.trim();
+            let columns = parts.next()?.split_whitespace().collect::<Vec<_>>();
+
+            if interface == "lo" {
+                return None;
+            }
+
+            let rx = columns.first()?.parse().ok()?;
+            let tx = columns.get(8)?.parse().ok()?;
+
+            Some((interface.to_owned(), (rx, tx)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:  123456     100    0    0    0     0          0         0   123456     100    0    0    0     0       0          0
+wlp3s0: 5000000    4000    0    0    0     0          0         0  1000000    2000    0    0    0     0       0          0
+";
+
+    fn counters(counters: &[(&str, u64, u64)]) -> Counters {
+        counters
+            .iter()
+            .map(|(interface, rx, tx)| ((*interface).to_owned(), (*rx, *tx)))
+            .collect()
+    }
+
+    fn traffic(since: Since) -> Traffic {
+        let mut traffic = Traffic::new(since, Some("boot".to_owned()));
+        traffic.restore(state::State::in_memory(FEATURE_NAME));
+        traffic
+    }
+
+    #[test]
+    fn parse_counters_without_loopback() {
+        assert_that!(
+            parse_counters(NET_DEV),
+            is(equal_to(counters(&[("wlp3s0", 5_000_000, 1_000_000)])))
+        );
+    }
+
+    #[test]
+    fn add_since_boot() {
+        let mut traffic = traffic(Since::Boot);
+
+        traffic.add(counters(&[("wlan0", 500, 100)]));
+        traffic.add(counters(&[("wlan0", 800, 150), ("usb0", 50, 10)]));
+
+        assert_that!(
+            (traffic.totals.rx, traffic.totals.tx),
+            is(equal_to((850, 160)))
+        );
+    }
+
+    #[test]
+    fn add_since_start() {
+        let mut traffic = traffic(Since::Start);
+
+        traffic.add(counters(&[("wlan0", 500, 100)]));
+        traffic.add(counters(&[("wlan0", 800, 150), ("usb0", 50, 10)]));
+
+        assert_that!(
+            (traffic.totals.rx, traffic.totals.tx),
+            is(equal_to((350, 60)))
+        );
+    }
+
+    #[test]
+    fn add_keeps_traffic_of_removed_interfaces() {
+        let mut traffic = traffic(Since::Boot);
+
+        traffic.add(counters(&[("wlan0", 500, 100), ("usb0", 50, 10)]));
+        traffic.add(counters(&[("wlan0", 600, 100)]));
+        traffic.add(counters(&[("wlan0", 600, 100), ("usb0", 20, 5)]));
+
+        assert_that!(
+            (traffic.totals.rx, traffic.totals.tx),
+            is(equal_to((670, 115)))
+        );
+    }
+
+    #[test]
+    fn restore_with_same_boot_id() {
+        let mut state = state::State::in_memory(FEATURE_NAME);
+        state.set(STATE_BOOT_ID, "boot").unwrap();
+        state
+            .set(
+                STATE_TRAFFIC,
+                Totals {
+                    interfaces: counters(&[("wlan0", 500, 100)]),
+                    rx: 500,
+                    tx: 100,
+                },
+            )
+            .unwrap();
+
+        let mut traffic = Traffic::new(Since::Boot, Some("boot".to_owned()));
+        traffic.restore(state);
+        traffic.add(counters(&[("wlan0", 700, 200)]));
+
+        assert_that!(
+            (traffic.totals.rx, traffic.totals.tx),
+            is(equal_to((700, 200)))
+        );
+    }
+
+    #[test]
+    fn restore_with_other_boot_id() {
+        let mut state = state::State::in_memory(FEATURE_NAME);
+        state.set(STATE_BOOT_ID, "other").unwrap();
+        state
+            .set(
+                STATE_TRAFFIC,
+                Totals {
+                    interfaces: counters(&[("wlan0", 500, 100)]),
+                    rx: 500,
+                    tx: 100,
+                },
+            )
+            .unwrap();
+
+        let mut traffic = Traffic::new(Since::Boot, Some("boot".to_owned()));
+        traffic.restore(state);
+        traffic.add(counters(&[("wlan0", 300, 50)]));
+
+        assert_that!(
+            (traffic.totals.rx, traffic.totals.tx),
+            is(equal_to((300, 50)))
+        );
+    }
+}
@@ -1,14 +1,24 @@
 #![allow(clippy::use_self)] // is experimental in stable rust
 
+use super::ConnectionType;
 use super::Data;
-use super::UpdateConfig;
 use super::FEATURE_NAME;
+use super::Ipv6Source;
+use super::PublicIpLookup;
+use super::Traffic;
+use super::UpdateConfig;
+use super::address;
+use crate::cache;
 use crate::error::*;
 use crate::feature;
+use crate::wrapper::file;
+use crate::wrapper::nl80211;
 use crate::wrapper::process;
 use log::info;
 use std::fmt;
 
+const PATH_IF_INET6: &str = "/proc/net/if_inet6";
+
 enum IpAddress {
     V4,
     V6,
@@ -30,19 +40,62 @@ impl fmt::Display for IpAddress {
 pub(super) struct Updater {
     data: Data,
     config: UpdateConfig,
+    ipv6_source: Ipv6Source,
+    public_ip_lookup: PublicIpLookup,
+    traffic: Traffic,
+    cache: cache::Cache,
+    cache_ttl: u64,
 }
 
 impl Updater {
-    pub(super) const fn new(data: Data, config: UpdateConfig) -> Self {
-        Self { data, config }
+    pub(super) const fn new(
+        data: Data,
+        config: UpdateConfig,
+        ipv6_source: Ipv6Source,
+        public_ip_lookup: PublicIpLookup,
+        traffic: Traffic,
+        cache: cache::Cache,
+        cache_ttl: u64,
+    ) -> Self {
+        Self {
+            data,
+            config,
+            ipv6_source,
+            public_ip_lookup,
+            traffic,
+            cache,
+            cache_ttl,
+        }
     }
 
-    fn get_if_enabled<F: Fn() -> Result<Option<String>>>(
-        &self,
-        enabled: bool,
-        builder: F,
-    ) -> Result<Option<String>> {
-        if enabled { builder() } else { Ok(None) }
+    fn cached_ip_address(&mut self, enabled: bool, address_type: &IpAddress) -> Option<String> {
+        if !enabled {
+            return None;
+        }
+
+        match self.public_ip_lookup {
+            PublicIpLookup::Dns => {
+                let key = format!("{}.{}", FEATURE_NAME, address_type);
+
+                self.cache
+                    .get_or_update(&key, self.cache_ttl, || ip_address(address_type))
+            },
+            PublicIpLookup::Http {
+                ref mut client,
+                ref ipv4_url,
+                ref ipv6_url,
+            } => {
+                let url = match address_type {
+                    IpAddress::V4 => ipv4_url,
+                    IpAddress::V6 => ipv6_url,
+                };
+
+                normalize_output(client.get(url, self.cache_ttl).wrap_error(
+                    FEATURE_NAME,
+                    format!("ip address {} could not be fetched", address_type),
+                ))
+            },
+        }
     }
 }
 
@@ -52,26 +105,59 @@ impl feature::Updatable for Updater {
     }
 
     fn update(&mut self) -> Result<()> {
-        let ipv4 = self.get_if_enabled(self.config.show_ipv4, || ip_address(&IpAddress::V4))?;
-        let ipv6 = self.get_if_enabled(self.config.show_ipv6, || ip_address(&IpAddress::V6))?;
-        let essid = self.get_if_enabled(self.config.show_essid, essid)?;
+        let ipv4 = self.cached_ip_address(self.config.show_ipv4, &IpAddress::V4);
+        let ipv6 = match self.ipv6_source {
+            Ipv6Source::Local(ref filter) if self.config.show_ipv6 => local_ipv6(filter),
+            Ipv6Source::Local(_) => None,
+            Ipv6Source::Public => self.cached_ip_address(self.config.show_ipv6, &IpAddress::V6),
+        };
+        let wireless = if self.config.show_wireless {
+            wireless()
+        } else {
+            None
+        };
+        let connection_type = if self.config.show_type {
+            Some(ConnectionType::detect())
+        } else {
+            None
+        };
+
+        let traffic = if self.config.show_traffic {
+            Some(self.traffic.update())
+        } else {
+            None
+        };
 
-        self.data.update(ipv4, ipv6, essid);
+        self.data
+            .update(ipv4, ipv6, wireless, connection_type, traffic);
 
         Ok(())
     }
 }
 
-fn essid() -> Result<Option<String>> {
-    let command = process::Command::new("iwgetid", &["-r"]);
-    let output = command
-        .output()
-        .wrap_error(FEATURE_NAME, "essid {} could not be fetched");
+fn wireless() -> Option<nl80211::Connection> {
+    match nl80211::connections() {
+        Ok(connections) => connections.into_iter().next(),
+        Err(error) => {
+            info!("{}", error);
+            None
+        },
+    }
+}
+
+fn local_ipv6(filter: &address::Ipv6Filter) -> Option<String> {
+    let content = file::read(PATH_IF_INET6).wrap_error(
+        FEATURE_NAME,
+        format!(
+            "local ipv6 address could not be read from {}",
+            PATH_IF_INET6
+        ),
+    );
 
-    Ok(normalize_output(output))
+    normalize_output(content).and_then(|content| address::local_ipv6(&content, filter))
 }
 
-fn ip_address(address_type: &IpAddress) -> Result<Option<String>> {
+fn ip_address(address_type: &IpAddress) -> Option<String> {
     let command = process::Command::new(
         "dig",
         &[
@@ -94,7 +180,7 @@ fn ip_address(address_type: &IpAddress) -> Result<Option<String>> {
         format!("ip address {} could not be fetched", address_type),
     );
 
-    Ok(normalize_output(output))
+    normalize_output(output)
 }
 
 fn normalize_output(output: Result<String>) -> Option<String> {
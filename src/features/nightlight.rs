@@ -0,0 +1,34 @@
+mod color;
+mod config;
+mod data;
+mod sun;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+use crate::wrapper::xgamma;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "nightlight";
+pub(self) const PLACEHOLDER_TEMPERATURE: &str = "{Temperature}";
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let gamma = xgamma::Gamma::open()?;
+    let data = Data::new(settings.template.clone(), settings.disabled.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, gamma, id, sender.clone(), settings.clone()),
+    )))
+}
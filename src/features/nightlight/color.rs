@@ -0,0 +1,64 @@
+const TEMPERATURE_NEUTRAL: u32 = 6500;
+
+pub(super) fn factors(temperature: u32) -> [f64; 3] {
+    let neutral = blackbody(TEMPERATURE_NEUTRAL);
+    let color = blackbody(temperature.min(TEMPERATURE_NEUTRAL));
+
+    [
+        (color[0] / neutral[0]).min(1.0),
+        (color[1] / neutral[1]).min(1.0),
+        (color[2] / neutral[2]).min(1.0),
+    ]
+}
+
+fn blackbody(temperature: u32) -> [f64; 3] {
+    let temperature = f64::from(temperature.max(1000)) / 100.0;
+
+    let (red, green) = if temperature <= 66.0 {
+        (
+            255.0,
+            99.470_802_586_1 * temperature.ln() - 161.119_568_166_1,
+        )
+    } else {
+        (
+            329.698_727_446 * (temperature - 60.0).powf(-0.133_204_759_2),
+            288.122_169_528_3 * (temperature - 60.0).powf(-0.075_514_849_2),
+        )
+    };
+
+    let blue = if temperature >= 66.0 {
+        255.0
+    } else if temperature <= 19.0 {
+        0.0
+    } else {
+        138.517_731_223_1 * (temperature - 10.0).ln() - 305.044_792_730_7
+    };
+
+    [
+        red.max(0.0).min(255.0) / 255.0,
+        green.max(0.0).min(255.0) / 255.0,
+        blue.max(0.0).min(255.0) / 255.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn factors_of_neutral_temperatures() {
+        assert_that!(factors(6500), is(equal_to([1.0, 1.0, 1.0])));
+        assert_that!(factors(9000), is(equal_to([1.0, 1.0, 1.0])));
+    }
+
+    #[test]
+    fn factors_of_warm_temperature() {
+        let [red, green, blue] = factors(3500);
+
+        assert_that!(red, is(equal_to(1.0)));
+        assert_that!((green - 0.76).abs() < 0.01, is(true));
+        assert_that!((blue - 0.56).abs() < 0.01, is(true));
+    }
+}
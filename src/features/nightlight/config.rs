@@ -0,0 +1,66 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) day_temperature: u32,
+    pub(super) disabled: String,
+    pub(super) latitude: f64,
+    pub(super) longitude: f64,
+    pub(super) night_temperature: u32,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "day_temperature"   => 6500,
+                "disabled"          => "NL OFF",
+                "latitude"          => 0.0,
+                "longitude"         => 0.0,
+                "night_temperature" => 3500,
+                "template"          => "NL {Temperature}K",
+                "update_interval"   => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("nightlight", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("nightlight", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("day_temperature".to_owned(), 6500.into());
+        map.insert("disabled".to_owned(), "NL OFF".into());
+        map.insert("latitude".to_owned(), 0.0.into());
+        map.insert("longitude".to_owned(), 0.0.into());
+        map.insert("night_temperature".to_owned(), 3500.into());
+        map.insert("template".to_owned(), "NL {Temperature}K".into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
@@ -0,0 +1,96 @@
+use super::PLACEHOLDER_TEMPERATURE;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    disabled: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, disabled: String) -> Self {
+        Self {
+            cache: String::new(),
+            disabled,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, temperature: Option<u32>) {
+        match temperature {
+            Some(temperature) => {
+                self.cache = self
+                    .template
+                    .replace(PLACEHOLDER_TEMPERATURE, &temperature.to_string());
+                self.values = map_values!(
+                    "ENABLED" => true,
+                    "TEMPERATURE" => temperature,
+                );
+            },
+            None => {
+                self.cache = self.disabled.clone();
+                self.values = map_values!("ENABLED" => false);
+            },
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("NL {Temperature}K".to_owned(), "NL OFF".to_owned());
+
+        object.update(Some(4200));
+
+        assert_that!(object.render(), is(equal_to("NL 4200K")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ENABLED" => "true",
+                "TEMPERATURE" => "4200",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_when_disabled() {
+        let mut object = Data::new("NL {Temperature}K".to_owned(), "NL OFF".to_owned());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("NL OFF")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ENABLED" => "false",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("NL {Temperature}K".to_owned(), "NL OFF".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
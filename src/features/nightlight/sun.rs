@@ -0,0 +1,69 @@
+const ELEVATION_DAY: f64 = 3.0;
+const ELEVATION_NIGHT: f64 = -6.0;
+const JULIAN_DATE_J2000: f64 = 2_451_545.0;
+const JULIAN_DATE_UNIX_EPOCH: f64 = 2_440_587.5;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn elevation(timestamp: i64, latitude: f64, longitude: f64) -> f64 {
+    let days = timestamp as f64 / SECONDS_PER_DAY + JULIAN_DATE_UNIX_EPOCH - JULIAN_DATE_J2000;
+
+    let mean_longitude = (280.460 + 0.985_647_4 * days) % 360.0;
+    let mean_anomaly = ((357.528 + 0.985_600_3 * days) % 360.0).to_radians();
+    let ecliptic_longitude =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin())
+            .to_radians();
+    let obliquity = (23.439 - 0.000_000_4 * days).to_radians();
+
+    let declination = (obliquity.sin() * ecliptic_longitude.sin()).asin();
+    let right_ascension =
+        (obliquity.cos() * ecliptic_longitude.sin()).atan2(ecliptic_longitude.cos());
+
+    let sidereal_time = ((18.697_374_558 + 24.065_709_824_419_08 * days) % 24.0) * 15.0;
+    let hour_angle = (sidereal_time + longitude).to_radians() - right_ascension;
+    let latitude = latitude.to_radians();
+
+    (latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(super) fn temperature(elevation: f64, day: u32, night: u32) -> u32 {
+    let progress = ((elevation - ELEVATION_NIGHT) / (ELEVATION_DAY - ELEVATION_NIGHT))
+        .max(0.0)
+        .min(1.0);
+
+    (f64::from(night) + (f64::from(day) - f64::from(night)) * progress).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const SUMMER_NOON: i64 = 1_718_968_200;
+
+    #[test]
+    fn elevation_at_noon_and_midnight() {
+        let noon = elevation(SUMMER_NOON, 52.52, 13.40);
+        assert_that!((noon - 60.9).abs() < 0.5, is(true));
+
+        let midnight = elevation(SUMMER_NOON + 12 * 3600, 52.52, 13.40);
+        assert_that!((midnight + 14.0).abs() < 0.5, is(true));
+    }
+
+    #[test]
+    fn elevation_at_southern_hemisphere() {
+        let noon = elevation(SUMMER_NOON - 9 * 3600 - 10 * 60, -33.87, 151.21);
+        assert_that!((noon - 32.7).abs() < 0.5, is(true));
+    }
+
+    #[test]
+    fn temperature_during_twilight() {
+        assert_that!(temperature(30.0, 6500, 3500), is(equal_to(6500)));
+        assert_that!(temperature(-1.5, 6500, 3500), is(equal_to(5000)));
+        assert_that!(temperature(-20.0, 6500, 3500), is(equal_to(3500)));
+    }
+}
@@ -0,0 +1,83 @@
+use super::ConfigEntry;
+use super::Data;
+use super::color;
+use super::sun;
+use crate::click;
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use crate::wrapper::xgamma;
+
+pub(super) struct Updater {
+    data: Data,
+    enabled: bool,
+    gamma: xgamma::Gamma,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+}
+
+impl Updater {
+    pub(super) const fn new(
+        data: Data,
+        gamma: xgamma::Gamma,
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            data,
+            enabled: true,
+            gamma,
+            id,
+            sender,
+            settings,
+        }
+    }
+
+    fn temperature(&self) -> u32 {
+        let elevation = sun::elevation(
+            chrono::Utc::now().timestamp(),
+            self.settings.latitude,
+            self.settings.longitude,
+        );
+
+        sun::temperature(
+            elevation,
+            self.settings.day_temperature,
+            self.settings.night_temperature,
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let temperature = if self.enabled {
+            Some(self.temperature())
+        } else {
+            None
+        };
+
+        self.gamma
+            .set(temperature.map_or([1.0, 1.0, 1.0], color::factors))?;
+
+        self.data.update(temperature);
+
+        Ok(())
+    }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        if click::Button::from_number(button)? != click::Button::Left {
+            return Ok(());
+        }
+
+        self.enabled = !self.enabled;
+
+        communication::send_message(self.id, &self.sender)
+    }
+}
@@ -0,0 +1,32 @@
+mod config;
+mod data;
+mod packages;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "pacman";
+pub(self) const PLACEHOLDER_CACHE: &str = "{Cache}";
+pub(self) const PLACEHOLDER_ORPHANS: &str = "{Orphans}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let data = Data::new(settings.template.clone(), settings.no_value.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, settings.cache_path.clone()),
+    )))
+}
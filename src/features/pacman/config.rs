@@ -0,0 +1,57 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) cache_path: String,
+    pub(super) no_value: String,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "cache_path"      => "/var/cache/pacman/pkg",
+                "no_value"        => "NA",
+                "template"        => "O {Orphans} C {Cache}",
+                "update_interval" => 3600,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("pacman", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("pacman", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("cache_path".to_owned(), "/var/cache/pacman/pkg".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("template".to_owned(), "O {Orphans} C {Cache}".into());
+        map.insert("update_interval".to_owned(), 3600.into());
+
+        map
+    }
+}
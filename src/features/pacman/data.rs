@@ -0,0 +1,106 @@
+use super::PLACEHOLDER_CACHE;
+use super::PLACEHOLDER_ORPHANS;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils::format_bytes;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, orphans: Option<usize>, cache_size: Option<u64>) {
+        let mut values = Values::new();
+
+        let orphans = match orphans {
+            Some(orphans) => {
+                values.insert("ORPHANS", orphans.to_string());
+                orphans.to_string()
+            },
+            None => self.no_value.clone(),
+        };
+
+        let cache_size = match cache_size {
+            Some(cache_size) => {
+                values.insert("CACHE", cache_size.to_string());
+                format_bytes(cache_size)
+            },
+            None => self.no_value.clone(),
+        };
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_CACHE, &cache_size)
+            .replace(PLACEHOLDER_ORPHANS, &orphans);
+        self.values = values;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("O {Orphans} C {Cache}".to_owned(), "NA".to_owned());
+
+        object.update(Some(3), Some(5 * 1024 * 1024 * 1024));
+
+        assert_that!(object.render(), is(equal_to("O 3 C 5.0 GiB")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CACHE" => "5368709120",
+                "ORPHANS" => "3",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_pacman() {
+        let mut object = Data::new("O {Orphans} C {Cache}".to_owned(), "NA".to_owned());
+
+        object.update(None, Some(1024));
+
+        assert_that!(object.render(), is(equal_to("O NA C 1.0 KiB")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CACHE" => "1024",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("O {Orphans} C {Cache}".to_owned(), "NA".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
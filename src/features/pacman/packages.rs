@@ -0,0 +1,68 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+use std::fs;
+
+const SCRIPT_ORPHANS: &str = "pacman -Qdtq; test $? -le 1";
+
+pub(super) fn orphans() -> Result<usize> {
+    let output = process::Command::new("sh", &["-c", SCRIPT_ORPHANS])
+        .output()
+        .wrap_error(FEATURE_NAME, "orphaned packages could not be listed")?;
+
+    Ok(count_lines(&output))
+}
+
+pub(super) fn cache_size(path: &str) -> Result<u64> {
+    let entries = fs::read_dir(path).wrap_error(
+        FEATURE_NAME,
+        format!("package cache {} could not be read", path),
+    )?;
+
+    Ok(entries
+        .filter_map(|entry| entry.ok()?.metadata().ok())
+        .filter(fs::Metadata::is_file)
+        .map(|metadata| metadata.len())
+        .sum())
+}
+
+fn count_lines(output: &str) -> usize {
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::env;
+
+    #[test]
+    fn count_lines_of_orphans() {
+        assert_that!(count_lines("gtk2\npython-pip\n"), is(equal_to(2)));
+        assert_that!(count_lines(""), is(equal_to(0)));
+    }
+
+    #[test]
+    fn cache_size_of_files() {
+        let directory = env::temp_dir().join("dwm-status-test-pacman-cache");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("download-abc")).unwrap();
+        fs::write(directory.join("a-1.0-1-x86_64.pkg.tar.zst"), vec![0; 100]).unwrap();
+        fs::write(directory.join("b-2.0-1-any.pkg.tar.zst"), vec![0; 20]).unwrap();
+        fs::write(directory.join("download-abc").join("c.part"), vec![0; 5]).unwrap();
+
+        assert_that!(
+            cache_size(&directory.to_string_lossy()),
+            is(equal_to(Ok(120)))
+        );
+    }
+
+    #[test]
+    fn cache_size_of_missing_directory() {
+        assert_that!(cache_size("/nonexistent/dwm-status").is_err(), is(true));
+    }
+}
@@ -0,0 +1,30 @@
+use super::Data;
+use super::packages;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    cache_path: String,
+    data: Data,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, cache_path: String) -> Self {
+        Self { cache_path, data }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let orphans = packages::orphans().show_error().ok();
+        let cache = packages::cache_size(&self.cache_path).show_error().ok();
+
+        self.data.update(orphans, cache);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+mod config;
+mod connection;
+mod data;
+mod message;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::connection::Connection;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "plugin";
+pub(self) const PROTOCOL_VERSION: u32 = 1;
+
+pub(super) fn create(
+    id: usize,
+    name: &str,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Option<Box<dyn feature::Feature>>> {
+    let command = match settings.commands.get(name) {
+        Some(command) => command.clone(),
+        None => return Ok(None),
+    };
+
+    let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    let connection = Arc::new(Mutex::new(Connection::new()));
+
+    Ok(Some(Box::new(feature::Composer::new(
+        name,
+        Notifier::new(id, name, sender.clone(), command, Arc::clone(&connection)),
+        Updater::new(Data::new(), connection),
+    ))))
+}
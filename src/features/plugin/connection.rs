@@ -0,0 +1,47 @@
+use super::FEATURE_NAME;
+use super::message::Input;
+use crate::error::*;
+use std::io::Write;
+use std::process::ChildStdin;
+
+#[derive(Debug)]
+pub(super) struct Connection {
+    stdin: Option<ChildStdin>,
+    text: Option<String>,
+}
+
+impl Connection {
+    pub(super) const fn new() -> Self {
+        Self {
+            stdin: None,
+            text: None,
+        }
+    }
+
+    pub(super) fn connect(&mut self, stdin: ChildStdin) {
+        self.stdin = Some(stdin);
+    }
+
+    pub(super) fn disconnect(&mut self) {
+        self.stdin = None;
+    }
+
+    pub(super) fn push_text(&mut self, text: String) {
+        self.text = Some(text);
+    }
+
+    pub(super) fn take_text(&mut self) -> Option<String> {
+        self.text.take()
+    }
+
+    pub(super) fn send(&mut self, input: &Input) -> Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .wrap_error(FEATURE_NAME, "plugin process is not running")?;
+
+        writeln!(stdin, "{}", input.serialize()?)
+            .and_then(|_| stdin.flush())
+            .wrap_error(FEATURE_NAME, "failed to write to plugin process")
+    }
+}
@@ -0,0 +1,47 @@
+use crate::feature::Renderable;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+}
+
+impl Data {
+    pub(super) const fn new() -> Self {
+        Self {
+            cache: String::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, text: String) {
+        self.cache = text;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new();
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new();
+
+        object.update("text".to_owned());
+
+        assert_that!(object.render(), is(equal_to("text")));
+    }
+}
@@ -0,0 +1,77 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use serde_derive::*;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(super) enum Output {
+    Register { name: String, protocol: u32 },
+    Update { text: String },
+}
+
+impl Output {
+    pub(super) fn parse(line: &str) -> Result<Self> {
+        serde_json::from_str(line).wrap_error(
+            FEATURE_NAME,
+            format!("invalid message from plugin process: {}", line),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(super) enum Input {
+    Click { button: u8 },
+}
+
+impl Input {
+    pub(super) fn serialize(&self) -> Result<String> {
+        serde_json::to_string(self).wrap_error(FEATURE_NAME, "failed to serialize message")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn output_parse_register() {
+        assert_that!(
+            Output::parse(r#"{"type": "register", "name": "weather", "protocol": 1}"#),
+            is(equal_to(Ok(Output::Register {
+                name: "weather".to_owned(),
+                protocol: 1,
+            })))
+        );
+    }
+
+    #[test]
+    fn output_parse_update() {
+        assert_that!(
+            Output::parse(r#"{"type": "update", "text": "12°C"}"#),
+            is(equal_to(Ok(Output::Update {
+                text: "12°C".to_owned(),
+            })))
+        );
+    }
+
+    #[test]
+    fn output_parse_unknown_type() {
+        assert_that!(Output::parse(r#"{"type": "unknown"}"#).is_err(), is(true));
+    }
+
+    #[test]
+    fn output_parse_invalid_json() {
+        assert_that!(Output::parse("update").is_err(), is(true));
+    }
+
+    #[test]
+    fn input_serialize_click() {
+        assert_that!(
+            Input::Click { button: 1 }.serialize(),
+            is(equal_to(Ok(r#"{"type":"click","button":1}"#.to_owned())))
+        );
+    }
+}
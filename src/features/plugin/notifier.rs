@@ -0,0 +1,89 @@
+use super::Connection;
+use super::FEATURE_NAME;
+use super::PROTOCOL_VERSION;
+use super::message::Output;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::process;
+use crate::wrapper::thread;
+use log::info;
+use log::warn;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(super) struct Notifier {
+    command: String,
+    connection: Arc<Mutex<Connection>>,
+    id: usize,
+    name: &'static str,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        name: &'static str,
+        sender: channel::Sender<communication::Message>,
+        command: String,
+        connection: Arc<Mutex<Connection>>,
+    ) -> Self {
+        Self {
+            command,
+            connection,
+            id,
+            name,
+            sender,
+        }
+    }
+
+    fn handle(&self, output: Output, registered: &mut bool) -> Result<()> {
+        match output {
+            Output::Register { name, protocol } => {
+                if protocol != PROTOCOL_VERSION {
+                    return Err(Error::new_custom(
+                        FEATURE_NAME,
+                        format!(
+                            "plugin {} uses protocol version {}, expected {}",
+                            self.name, protocol, PROTOCOL_VERSION
+                        ),
+                    ));
+                }
+
+                if name != self.name {
+                    warn!("Plugin {} registered with name {}", self.name, name);
+                }
+
+                info!("Plugin {} registered", self.name);
+                *registered = true;
+
+                Ok(())
+            },
+            Output::Update { .. } if !*registered => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("plugin {} sent update before register", self.name),
+            )),
+            Output::Update { text } => {
+                self.connection.lock().unwrap().push_text(text);
+
+                communication::send_message(self.id, &self.sender)
+            },
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let command = process::Command::new("sh", &["-c", &self.command]);
+        let mut registered = false;
+
+        let result = command.listen_lines(
+            |stdin| self.connection.lock().unwrap().connect(stdin),
+            |line| self.handle(Output::parse(line)?, &mut registered),
+        );
+
+        self.connection.lock().unwrap().disconnect();
+
+        result
+    }
+}
@@ -0,0 +1,39 @@
+use super::Connection;
+use super::Data;
+use super::message::Input;
+use crate::error::*;
+use crate::feature;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(super) struct Updater {
+    connection: Arc<Mutex<Connection>>,
+    data: Data,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, connection: Arc<Mutex<Connection>>) -> Self {
+        Self { connection, data }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        if let Some(text) = self.connection.lock().unwrap().take_text() {
+            self.data.update(text);
+        }
+
+        Ok(())
+    }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        self.connection
+            .lock()
+            .unwrap()
+            .send(&Input::Click { button })
+    }
+}
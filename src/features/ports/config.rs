@@ -0,0 +1,84 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct TargetConfig {
+    pub(super) host: String,
+    #[serde(default)]
+    pub(super) name: Option<String>,
+    pub(super) port: u16,
+    #[serde(default)]
+    pub(super) timeout: Option<u64>,
+}
+
+impl TargetConfig {
+    pub(super) fn name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.host, self.port))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) closed: String,
+    pub(super) open: String,
+    pub(super) separator: String,
+    pub(super) targets: Vec<TargetConfig>,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "closed"          => "CLOSED",
+                "open"            => "OPEN",
+                "separator"       => " ",
+                "targets"         => Vec::<Value>::new(),
+                "template"        => "{Name} {State}",
+                "timeout"         => 1000,
+                "update_interval" => 30,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("ports", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("ports", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("closed".to_owned(), "CLOSED".into());
+        map.insert("open".to_owned(), "OPEN".into());
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("targets".to_owned(), Vec::<Value>::new().into());
+        map.insert("template".to_owned(), "{Name} {State}".into());
+        map.insert("timeout".to_owned(), 1000.into());
+        map.insert("update_interval".to_owned(), 30.into());
+
+        map
+    }
+}
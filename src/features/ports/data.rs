@@ -0,0 +1,125 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_NAME;
+use super::PLACEHOLDER_STATE;
+use super::TargetConfig;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    names: Vec<String>,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            names: settings
+                .targets
+                .iter()
+                .map(|target| target.name())
+                .collect(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, states: &[Option<bool>]) {
+        self.cache = self
+            .names
+            .iter()
+            .zip(states)
+            .filter_map(|(name, state)| {
+                let state = if (*state)? {
+                    &self.settings.open
+                } else {
+                    &self.settings.closed
+                };
+
+                Some(
+                    self.settings
+                        .template
+                        .replace(PLACEHOLDER_NAME, name)
+                        .replace(PLACEHOLDER_STATE, state),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        let open = states.iter().filter(|state| **state == Some(true)).count();
+        let closed = states.iter().filter(|state| **state == Some(false)).count();
+
+        self.values = map_values!(
+            "CLOSED" => closed,
+            "OPEN" => open,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            closed: "CLOSED".to_owned(),
+            open: "OPEN".to_owned(),
+            separator: " · ".to_owned(),
+            targets: vec![
+                target("nas.lan", None, 22, None),
+                target("db.lan", Some("db"), 5432, Some(3000)),
+                target("nas.lan", None, 80, None),
+            ],
+            template: "{Name} {State}".to_owned(),
+            timeout: 1000,
+            update_interval: 30,
+        }
+    }
+
+    fn target(host: &str, name: Option<&str>, port: u16, timeout: Option<u64>) -> TargetConfig {
+        TargetConfig {
+            host: host.to_owned(),
+            name: name.map(str::to_owned),
+            port,
+            timeout,
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(&[Some(true), Some(false), None]);
+
+        assert_that!(object.render(), is(equal_to("nas.lan:22 OPEN · db CLOSED")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CLOSED" => "1",
+                "OPEN" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
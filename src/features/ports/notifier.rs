@@ -0,0 +1,114 @@
+use super::ConfigEntry;
+use super::SharedStates;
+use super::TargetConfig;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use log::debug;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+
+pub(super) struct Notifier {
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    states: SharedStates,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        settings: ConfigEntry,
+        states: SharedStates,
+    ) -> Self {
+        Self {
+            id,
+            sender,
+            settings,
+            states,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            let states = self
+                .settings
+                .targets
+                .iter()
+                .map(|target| Some(is_open(target, self.settings.timeout)))
+                .collect();
+
+            *self.states.lock().unwrap() = states;
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.settings.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.settings.update_interval)
+    }
+}
+
+fn is_open(target: &TargetConfig, timeout: u64) -> bool {
+    let timeout = Duration::from_millis(target.timeout.unwrap_or(timeout));
+
+    let addresses = match (&target.host[..], target.port).to_socket_addrs() {
+        Ok(addresses) => addresses,
+        Err(error) => {
+            debug!("failed to resolve {}: {}", target.host, error);
+            return false;
+        },
+    };
+
+    for address in addresses {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(_) => return true,
+            Err(error) => debug!("failed to connect to {}: {}", address, error),
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::net::TcpListener;
+
+    fn target(port: u16) -> TargetConfig {
+        TargetConfig {
+            host: "127.0.0.1".to_owned(),
+            name: None,
+            port,
+            timeout: None,
+        }
+    }
+
+    #[test]
+    fn is_open_with_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        assert_that!(is_open(&target(port), 1000), is(true));
+    }
+
+    #[test]
+    fn is_open_without_listener() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        assert_that!(is_open(&target(port), 1000), is(false));
+    }
+}
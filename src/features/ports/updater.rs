@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedStates;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    states: SharedStates,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, states: SharedStates) -> Self {
+        Self { data, states }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let states = self.states.lock().unwrap().clone();
+
+        self.data.update(&states);
+
+        Ok(())
+    }
+}
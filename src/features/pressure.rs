@@ -0,0 +1,42 @@
+mod config;
+mod data;
+mod psi;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::locale;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::psi::Pressure;
+pub(self) use self::psi::Query;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "pressure";
+pub(self) const PLACEHOLDER_CPU: &str = "{Cpu}";
+pub(self) const PLACEHOLDER_IO: &str = "{Io}";
+pub(self) const PLACEHOLDER_MEMORY: &str = "{Memory}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let query = Query::init(&settings.kind, settings.window)?;
+
+    let data = Data::new(
+        settings.template.clone(),
+        settings.no_value.clone(),
+        locale::decimal_separator(),
+    );
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, query),
+    )))
+}
@@ -0,0 +1,60 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) kind: String,
+    pub(super) no_value: String,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+    pub(super) window: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "kind"            => "some",
+                "no_value"        => "NA",
+                "template"        => "PSI {Cpu} {Memory} {Io}",
+                "update_interval" => 5,
+                "window"          => 10,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("pressure", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("pressure", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("kind".to_owned(), "some".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("template".to_owned(), "PSI {Cpu} {Memory} {Io}".into());
+        map.insert("update_interval".to_owned(), 5.into());
+        map.insert("window".to_owned(), 10.into());
+
+        map
+    }
+}
@@ -0,0 +1,112 @@
+use super::PLACEHOLDER_CPU;
+use super::PLACEHOLDER_IO;
+use super::PLACEHOLDER_MEMORY;
+use super::Pressure;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    decimal_separator: Option<String>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        decimal_separator: Option<String>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            decimal_separator,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub(super) fn update(&mut self, pressure: &Pressure) {
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let format = |percentage: Option<f64>| {
+            percentage.map(|percentage| locale::format_decimal(percentage as f32, 1, separator))
+        };
+
+        let cpu = format(pressure.cpu);
+        let io = format(pressure.io);
+        let memory = format(pressure.memory);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_CPU, cpu.as_ref().unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_IO, io.as_ref().unwrap_or(&self.no_value))
+            .replace(
+                PLACEHOLDER_MEMORY,
+                memory.as_ref().unwrap_or(&self.no_value),
+            );
+
+        self.values = Values::new();
+
+        for (key, value) in vec![
+            ("CPU", pressure.cpu),
+            ("IO", pressure.io),
+            ("MEMORY", pressure.memory),
+        ] {
+            if let Some(value) = value {
+                self.values.insert(key, format!("{:.2}", value));
+            }
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "PSI {Cpu} {Memory} {Io}";
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), Some(",".to_owned()));
+
+        object.update(&Pressure {
+            cpu: Some(12.34),
+            io: None,
+            memory: Some(0.5),
+        });
+
+        assert_that!(object.render(), is(equal_to("PSI 12,3 0,5 NA")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CPU" => "12.34",
+                "MEMORY" => "0.50",
+            )))
+        );
+    }
+}
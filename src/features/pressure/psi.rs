@@ -0,0 +1,129 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+
+const PATH_PRESSURE: &str = "/proc/pressure";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Pressure {
+    pub(super) cpu: Option<f64>,
+    pub(super) io: Option<f64>,
+    pub(super) memory: Option<f64>,
+}
+
+#[derive(Debug)]
+pub(super) struct Query {
+    average: String,
+    kind: &'static str,
+}
+
+impl Query {
+    pub(super) fn init(kind: &str, window: u64) -> Result<Self> {
+        let kind = match kind {
+            "full" => "full",
+            "some" => "some",
+            _ => {
+                return Err(Error::new_custom(
+                    FEATURE_NAME,
+                    format!("unknown kind {}, expected some or full", kind),
+                ));
+            },
+        };
+
+        if window != 10 && window != 60 && window != 300 {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown window {}, expected 10, 60 or 300", window),
+            ));
+        }
+
+        Ok(Self {
+            average: format!("avg{}", window),
+            kind,
+        })
+    }
+
+    pub(super) fn read(&self) -> Result<Pressure> {
+        let pressure = Pressure {
+            cpu: self.read_resource("cpu"),
+            io: self.read_resource("io"),
+            memory: self.read_resource("memory"),
+        };
+
+        if pressure == Pressure::default() {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "pressure could not be read from {}, is PSI enabled in the kernel?",
+                    PATH_PRESSURE
+                ),
+            ));
+        }
+
+        Ok(pressure)
+    }
+
+    fn read_resource(&self, resource: &str) -> Option<f64> {
+        let content = file::read(format!("{}/{}", PATH_PRESSURE, resource)).ok()?;
+
+        self.parse(&content)
+    }
+
+    fn parse(&self, content: &str) -> Option<f64> {
+        let line = content
+            .lines()
+            .find(|line| line.split_whitespace().next() == Some(self.kind))?;
+
+        line.split_whitespace().skip(1).find_map(|field| {
+            let mut parts = field.splitn(2, '=');
+
+            if parts.next()? == self.average {
+                parts.next()?.parse().ok()
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const CONTENT: &str = "\
+some avg10=1.53 avg60=0.87 avg300=0.25 total=123456
+full avg10=0.40 avg60=0.20 avg300=0.05 total=45678
+";
+
+    #[test]
+    fn query_init_with_unknown_values() {
+        assert_that!(Query::init("partial", 10).is_err(), is(true));
+        assert_that!(Query::init("some", 30).is_err(), is(true));
+    }
+
+    #[test]
+    fn query_parse_some() {
+        let query = Query::init("some", 10).unwrap();
+
+        assert_that!(query.parse(CONTENT), is(equal_to(Some(1.53))));
+    }
+
+    #[test]
+    fn query_parse_full() {
+        let query = Query::init("full", 300).unwrap();
+
+        assert_that!(query.parse(CONTENT), is(equal_to(Some(0.05))));
+    }
+
+    #[test]
+    fn query_parse_without_line() {
+        let query = Query::init("full", 10).unwrap();
+
+        assert_that!(
+            query.parse("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n"),
+            is(equal_to(None))
+        );
+    }
+}
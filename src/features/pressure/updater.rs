@@ -0,0 +1,29 @@
+use super::Data;
+use super::Query;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    query: Query,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, query: Query) -> Self {
+        Self { data, query }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let pressure = self.query.read()?;
+
+        self.data.update(&pressure);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+mod config;
+mod data;
+mod updater;
+mod zone;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::locale;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+pub(self) use self::zone::Power;
+pub(self) use self::zone::Zone;
+
+pub(super) const FEATURE_NAME: &str = "rapl";
+pub(self) const PLACEHOLDER_CORE: &str = "{Core}";
+pub(self) const PLACEHOLDER_DRAM: &str = "{Dram}";
+pub(self) const PLACEHOLDER_PACKAGE: &str = "{Package}";
+pub(self) const PLACEHOLDER_UNCORE: &str = "{Uncore}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let zones = Zone::find()?;
+
+    let data = Data::new(
+        settings.template.clone(),
+        settings.no_value.clone(),
+        locale::decimal_separator(),
+    );
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, zones),
+    )))
+}
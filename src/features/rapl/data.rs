@@ -0,0 +1,133 @@
+use super::PLACEHOLDER_CORE;
+use super::PLACEHOLDER_DRAM;
+use super::PLACEHOLDER_PACKAGE;
+use super::PLACEHOLDER_UNCORE;
+use super::Power;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    decimal_separator: Option<String>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        decimal_separator: Option<String>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            decimal_separator,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, power: Option<Power>) {
+        let power = power.unwrap_or_default();
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let format = |watts: Option<f64>| watts.map(|watts| format_watts(watts, separator));
+
+        let core = format(power.core);
+        let dram = format(power.dram);
+        let package = format(power.package);
+        let uncore = format(power.uncore);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_CORE, core.as_ref().unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_DRAM, dram.as_ref().unwrap_or(&self.no_value))
+            .replace(
+                PLACEHOLDER_PACKAGE,
+                package.as_ref().unwrap_or(&self.no_value),
+            )
+            .replace(
+                PLACEHOLDER_UNCORE,
+                uncore.as_ref().unwrap_or(&self.no_value),
+            );
+
+        self.values = Values::new();
+
+        for (key, value) in vec![
+            ("CORE", core),
+            ("DRAM", dram),
+            ("PACKAGE", package),
+            ("UNCORE", uncore),
+        ] {
+            if let Some(value) = value {
+                self.values.insert(key, value);
+            }
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn format_watts(watts: f64, separator: Option<&str>) -> String {
+    locale::format_decimal(watts as f32, 1, separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "P {Package}W C {Core}W D {Dram}W";
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), Some(",".to_owned()));
+
+        object.update(Some(Power {
+            core: Some(4.26),
+            dram: None,
+            package: Some(12.04),
+            uncore: None,
+        }));
+
+        assert_that!(object.render(), is(equal_to("P 12,0W C 4,3W D NAW")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CORE" => "4,3",
+                "PACKAGE" => "12,0",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_power() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("P NAW C NAW D NAW")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
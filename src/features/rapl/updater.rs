@@ -0,0 +1,46 @@
+use super::Data;
+use super::Zone;
+use super::zone;
+use crate::error::*;
+use crate::feature;
+use std::time::Instant;
+
+pub(super) struct Updater {
+    data: Data,
+    last: Option<(Vec<u64>, Instant)>,
+    zones: Vec<Zone>,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, zones: Vec<Zone>) -> Self {
+        Self {
+            data,
+            last: None,
+            zones,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let energies = self
+            .zones
+            .iter()
+            .map(Zone::energy)
+            .collect::<Result<Vec<_>>>()?;
+
+        let power = self.last.as_ref().map(|(last, time)| {
+            zone::power(&self.zones, last, &energies, now.duration_since(*time))
+        });
+
+        self.data.update(power);
+        self.last = Some((energies, now));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+use std::fs;
+use std::path;
+use std::time::Duration;
+
+const PATH_POWERCAP: &str = "/sys/class/powercap";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Power {
+    pub(super) core: Option<f64>,
+    pub(super) dram: Option<f64>,
+    pub(super) package: Option<f64>,
+    pub(super) uncore: Option<f64>,
+}
+
+impl Power {
+    fn add(&mut self, domain: Domain, watts: f64) {
+        let power = match domain {
+            Domain::Core => &mut self.core,
+            Domain::Dram => &mut self.dram,
+            Domain::Package => &mut self.package,
+            Domain::Uncore => &mut self.uncore,
+        };
+
+        *power = Some(power.unwrap_or(0.) + watts);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Domain {
+    Core,
+    Dram,
+    Package,
+    Uncore,
+}
+
+impl Domain {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "core" => Some(Self::Core),
+            "dram" => Some(Self::Dram),
+            "uncore" => Some(Self::Uncore),
+            _ if name.starts_with("package-") => Some(Self::Package),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Zone {
+    domain: Domain,
+    max_energy: u64,
+    path: path::PathBuf,
+}
+
+impl Zone {
+    pub(super) fn find() -> Result<Vec<Self>> {
+        let mut zones = fs::read_dir(PATH_POWERCAP)
+            .wrap_error(FEATURE_NAME, format!("error reading {}", PATH_POWERCAP))?
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| name.starts_with("intel-rapl:"))
+            })
+            .filter_map(|path| {
+                let name = file::read(path.join("name")).ok()?;
+                let domain = Domain::parse(name.trim())?;
+                let max_energy = file::parse_file_content(path.join("max_energy_range_uj")).ok()?;
+
+                Some(Self {
+                    domain,
+                    max_energy,
+                    path,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if zones.is_empty() {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("no RAPL zones found in {}", PATH_POWERCAP),
+            ));
+        }
+
+        zones.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(zones)
+    }
+
+    pub(super) fn energy(&self) -> Result<u64> {
+        let path = self.path.join("energy_uj");
+
+        file::parse_file_content(&path).wrap_error(
+            FEATURE_NAME,
+            format!(
+                "energy could not be read from {}, is it readable by the user?",
+                path.display()
+            ),
+        )
+    }
+}
+
+pub(super) fn power(zones: &[Zone], last: &[u64], current: &[u64], elapsed: Duration) -> Power {
+    let mut power = Power::default();
+
+    for ((zone, last), current) in zones.iter().zip(last).zip(current) {
+        power.add(
+            zone.domain,
+            watts(*last, *current, zone.max_energy, elapsed),
+        );
+    }
+
+    power
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn watts(last: u64, current: u64, max_energy: u64, elapsed: Duration) -> f64 {
+    let energy = if current >= last {
+        current - last
+    } else {
+        max_energy.saturating_sub(last) + current
+    };
+
+    let seconds = elapsed.as_secs_f64();
+
+    if seconds > 0. {
+        energy as f64 / 1_000_000. / seconds
+    } else {
+        0.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn zone(domain: Domain) -> Zone {
+        Zone {
+            domain,
+            max_energy: 262_143_328_850,
+            path: path::PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn domain_parse() {
+        assert_that!(
+            Domain::parse("package-1"),
+            is(equal_to(Some(Domain::Package)))
+        );
+        assert_that!(Domain::parse("core"), is(equal_to(Some(Domain::Core))));
+        assert_that!(Domain::parse("psys"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn watts_with_increased_counter() {
+        assert_that!(
+            watts(1_000_000, 31_000_000, 100_000_000, Duration::from_secs(2)),
+            is(equal_to(15.))
+        );
+    }
+
+    #[test]
+    fn watts_with_wrapped_counter() {
+        assert_that!(
+            watts(90_000_000, 5_000_000, 100_000_000, Duration::from_secs(3)),
+            is(equal_to(5.))
+        );
+    }
+
+    #[test]
+    fn power_sums_packages() {
+        let zones = vec![
+            zone(Domain::Package),
+            zone(Domain::Core),
+            zone(Domain::Package),
+        ];
+
+        assert_that!(
+            power(
+                &zones,
+                &[0, 0, 0],
+                &[10_000_000, 4_000_000, 6_000_000],
+                Duration::from_secs(2)
+            ),
+            is(equal_to(Power {
+                core: Some(2.),
+                dram: None,
+                package: Some(8.),
+                uncore: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+mod config;
+mod data;
+mod probe;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::locale;
+use crate::scheduler;
+use crate::wrapper::channel;
+use crate::wrapper::xdg;
+use std::fs;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::probe::Probe;
+pub(self) use self::probe::Vitals;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "remote";
+pub(self) const PLACEHOLDER_DISK: &str = "{Disk}";
+pub(self) const PLACEHOLDER_HOST: &str = "{Host}";
+pub(self) const PLACEHOLDER_LOAD: &str = "{Load}";
+pub(self) const PLACEHOLDER_UPTIME: &str = "{Uptime}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    if settings.host.is_empty() {
+        return Err(Error::new_custom(FEATURE_NAME, "no host configured"));
+    }
+
+    let probes = settings
+        .probes
+        .iter()
+        .map(|probe| Probe::parse(probe))
+        .collect::<Result<Vec<_>>>()?;
+
+    let data = Data::new(
+        settings.host.clone(),
+        settings.template.clone(),
+        settings.no_value.clone(),
+        locale::decimal_separator(),
+    );
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, probes, control_path(), settings.clone()),
+    )))
+}
+
+fn control_path() -> Option<String> {
+    let directory = xdg::runtime_dir()?;
+
+    fs::create_dir_all(&directory)
+        .wrap_error(FEATURE_NAME, "failed to create runtime directory")
+        .show_error()
+        .ok()?;
+
+    directory.join("ssh-%C").to_str().map(ToOwned::to_owned)
+}
@@ -0,0 +1,69 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) disk_path: String,
+    pub(super) host: String,
+    pub(super) no_value: String,
+    pub(super) probes: Vec<String>,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "disk_path"       => "/",
+                "host"            => "",
+                "no_value"        => "NA",
+                "probes"          => vec!["load", "disk", "uptime"],
+                "template"        => "{Host} L {Load} D {Disk}% U {Uptime}",
+                "timeout"         => 10,
+                "update_interval" => 60,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("remote", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("remote", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("disk_path".to_owned(), "/".into());
+        map.insert("host".to_owned(), "".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("probes".to_owned(), vec!["load", "disk", "uptime"].into());
+        map.insert(
+            "template".to_owned(),
+            "{Host} L {Load} D {Disk}% U {Uptime}".into(),
+        );
+        map.insert("timeout".to_owned(), 10.into());
+        map.insert("update_interval".to_owned(), 60.into());
+
+        map
+    }
+}
@@ -0,0 +1,157 @@
+use super::PLACEHOLDER_DISK;
+use super::PLACEHOLDER_HOST;
+use super::PLACEHOLDER_LOAD;
+use super::PLACEHOLDER_UPTIME;
+use super::Vitals;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    decimal_separator: Option<String>,
+    host: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        host: String,
+        template: String,
+        no_value: String,
+        decimal_separator: Option<String>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            decimal_separator,
+            host,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, vitals: &Vitals) {
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let disk = vitals.disk.map(|disk| disk.to_string());
+        let load = vitals
+            .load
+            .map(|load| locale::format_decimal(load, 2, separator));
+        let uptime = vitals.uptime.map(format_uptime);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_DISK, disk.as_ref().unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_HOST, &self.host)
+            .replace(PLACEHOLDER_LOAD, load.as_ref().unwrap_or(&self.no_value))
+            .replace(
+                PLACEHOLDER_UPTIME,
+                uptime.as_ref().unwrap_or(&self.no_value),
+            );
+
+        self.values = Values::new();
+
+        if let Some(disk) = disk {
+            self.values.insert("DISK", disk);
+        }
+
+        if let Some(load) = vitals.load {
+            self.values.insert("LOAD", format!("{:.2}", load));
+        }
+
+        if let Some(uptime) = vitals.uptime {
+            self.values.insert("UPTIME", uptime.to_string());
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let time = format!("{:02}:{:02}", seconds % 86400 / 3600, seconds % 3600 / 60);
+
+    if days > 0 {
+        format!("{}d {}", days, time)
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "{Host} L {Load} D {Disk}% U {Uptime}";
+
+    fn data(decimal_separator: Option<&str>) -> Data {
+        Data::new(
+            "server".to_owned(),
+            TEMPLATE.to_owned(),
+            "NA".to_owned(),
+            decimal_separator.map(ToOwned::to_owned),
+        )
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = data(None);
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = data(Some(","));
+
+        object.update(&Vitals {
+            disk: Some(45),
+            load: Some(0.42),
+            uptime: Some(3 * 86400 + 4 * 3600 + 5 * 60 + 6),
+        });
+
+        assert_that!(
+            object.render(),
+            is(equal_to("server L 0,42 D 45% U 3d 04:05"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "DISK" => "45",
+                "LOAD" => "0.42",
+                "UPTIME" => "273906",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_vitals() {
+        let mut object = data(None);
+
+        object.update(&Vitals {
+            disk: None,
+            load: None,
+            uptime: Some(3600),
+        });
+
+        assert_that!(object.render(), is(equal_to("server L NA D NA% U 01:00")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!("UPTIME" => "3600")))
+        );
+    }
+}
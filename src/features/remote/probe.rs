@@ -0,0 +1,145 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+
+const SEPARATOR: &str = "--dwm-status--";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Probe {
+    Disk,
+    Load,
+    Uptime,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Vitals {
+    pub(super) disk: Option<u64>,
+    pub(super) load: Option<f32>,
+    pub(super) uptime: Option<u64>,
+}
+
+impl Probe {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "disk" => Ok(Self::Disk),
+            "load" => Ok(Self::Load),
+            "uptime" => Ok(Self::Uptime),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown probe {}, expected disk, load or uptime", name),
+            )),
+        }
+    }
+
+    fn command(self, disk_path: &str) -> String {
+        match self {
+            Self::Disk => format!("df -P {}", quote(disk_path)),
+            Self::Load => "cat /proc/loadavg".to_owned(),
+            Self::Uptime => "cat /proc/uptime".to_owned(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn read(self, output: &str, vitals: &mut Vitals) {
+        match self {
+            Self::Disk => vitals.disk = parse_df(output),
+            Self::Load => vitals.load = parse_first_number(output),
+            Self::Uptime => {
+                vitals.uptime = parse_first_number::<f64>(output).map(|uptime| uptime as u64);
+            },
+        }
+    }
+}
+
+pub(super) fn command(probes: &[Probe], disk_path: &str) -> String {
+    let commands = probes
+        .iter()
+        .map(|probe| probe.command(disk_path))
+        .collect::<Vec<_>>()
+        .join(&format!("; echo {}; ", SEPARATOR));
+
+    format!("{}; true", commands)
+}
+
+pub(super) fn parse(probes: &[Probe], output: &str) -> Vitals {
+    let mut vitals = Vitals::default();
+
+    for (probe, output) in probes.iter().zip(output.split(SEPARATOR)) {
+        probe.read(output, &mut vitals);
+    }
+
+    vitals
+}
+
+fn parse_df(output: &str) -> Option<u64> {
+    output
+        .trim()
+        .lines()
+        .last()?
+        .split_whitespace()
+        .nth(4)?
+        .trim_end_matches('%')
+        .parse()
+        .ok()
+}
+
+fn parse_first_number<T: std::str::FromStr>(output: &str) -> Option<T> {
+    output.split_whitespace().next()?.parse().ok()
+}
+
+fn quote(argument: &str) -> String {
+    format!("'{}'", argument.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const OUTPUT: &str = "\
+0.42 0.30 0.25 1/234 5678
+--dwm-status--
+Filesystem     1024-blocks      Used Available Capacity Mounted on
+/dev/sda2        479670976 201461640 253780928      45% /srv
+--dwm-status--
+1234567.89 2345678.90";
+
+    #[test]
+    fn command_of_probes() {
+        assert_that!(
+            command(&[Probe::Load, Probe::Disk], "/srv/it's"),
+            is(equal_to(
+                "cat /proc/loadavg; echo --dwm-status--; df -P '/srv/it'\\''s'; true"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_output() {
+        assert_that!(
+            parse(&[Probe::Load, Probe::Disk, Probe::Uptime], OUTPUT),
+            is(equal_to(Vitals {
+                disk: Some(45),
+                load: Some(0.42),
+                uptime: Some(1_234_567),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_output_with_failed_probe() {
+        assert_that!(
+            parse(&[Probe::Uptime, Probe::Disk], "1234.5\n--dwm-status--\n"),
+            is(equal_to(Vitals {
+                disk: None,
+                load: None,
+                uptime: Some(1234),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_unknown_probe() {
+        assert_that!(Probe::parse("memory").is_err(), is(true));
+    }
+}
@@ -0,0 +1,88 @@
+use super::ConfigEntry;
+use super::Data;
+use super::FEATURE_NAME;
+use super::Probe;
+use super::Vitals;
+use super::probe;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::process;
+
+pub(super) struct Updater {
+    control_path: Option<String>,
+    data: Data,
+    probes: Vec<Probe>,
+    settings: ConfigEntry,
+}
+
+impl Updater {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        data: Data,
+        probes: Vec<Probe>,
+        control_path: Option<String>,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            control_path,
+            data,
+            probes,
+            settings,
+        }
+    }
+
+    fn run_probes(&self) -> Result<Vitals> {
+        let timeout = format!("ConnectTimeout={}", self.settings.timeout);
+        let command = probe::command(&self.probes, &self.settings.disk_path);
+        let mut args = vec!["-o", "BatchMode=yes", "-o", &timeout[..]];
+
+        let control_path = self
+            .control_path
+            .as_ref()
+            .map(|path| format!("ControlPath={}", path));
+        let control_persist = format!(
+            "ControlPersist={}",
+            self.settings.update_interval.saturating_mul(2)
+        );
+
+        if let Some(ref control_path) = control_path {
+            args.extend(&[
+                "-o",
+                "ControlMaster=auto",
+                "-o",
+                &control_path[..],
+                "-o",
+                &control_persist[..],
+            ]);
+        }
+
+        args.extend(&[&self.settings.host[..], "--", &command[..]]);
+
+        let output = process::Command::new("ssh", &args).output().wrap_error(
+            FEATURE_NAME,
+            format!("probes could not be run on {}", self.settings.host),
+        )?;
+
+        Ok(probe::parse(&self.probes, &output))
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let vitals = match self.run_probes() {
+            Ok(vitals) => vitals,
+            Err(error) => {
+                error.show_error();
+                Vitals::default()
+            },
+        };
+
+        self.data.update(&vitals);
+
+        Ok(())
+    }
+}
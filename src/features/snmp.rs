@@ -0,0 +1,51 @@
+mod config;
+mod data;
+mod query;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::query::Reading;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "snmp";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    if settings.host.is_empty() {
+        return Err(Error::new_custom(FEATURE_NAME, "no host configured"));
+    }
+
+    if settings.oids.is_empty() {
+        return Err(Error::new_custom(FEATURE_NAME, "no OIDs configured"));
+    }
+
+    let names = settings
+        .oids
+        .keys()
+        .map(|name| -> (String, &'static str) {
+            (
+                format!("{{{}}}", name),
+                Box::leak(name.to_uppercase().into_boxed_str()),
+            )
+        })
+        .collect();
+    let oids = settings.oids.values().cloned().collect();
+
+    let data = Data::new(settings.template.clone(), settings.no_value.clone(), names);
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, oids, settings.clone()),
+    )))
+}
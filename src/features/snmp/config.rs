@@ -0,0 +1,70 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) community: String,
+    pub(super) host: String,
+    pub(super) no_value: String,
+    pub(super) oids: BTreeMap<String, String>,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) update_interval: u64,
+    pub(super) version: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "community"       => "public",
+                "host"            => "",
+                "no_value"        => "NA",
+                "oids"            => HashMap::<String, Value>::new(),
+                "template"        => "",
+                "timeout"         => 5,
+                "update_interval" => 60,
+                "version"         => "2c",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("snmp", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("snmp", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("community".to_owned(), "public".into());
+        map.insert("host".to_owned(), "".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("oids".to_owned(), HashMap::<String, Value>::new().into());
+        map.insert("template".to_owned(), "".into());
+        map.insert("timeout".to_owned(), 5.into());
+        map.insert("update_interval".to_owned(), 60.into());
+        map.insert("version".to_owned(), "2c".into());
+
+        map
+    }
+}
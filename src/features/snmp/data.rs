@@ -0,0 +1,104 @@
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    names: Vec<(String, &'static str)>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        names: Vec<(String, &'static str)>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            names,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, values: &[Option<String>]) {
+        self.cache = self.template.clone();
+        self.values = Values::new();
+
+        for ((placeholder, key), value) in self.names.iter().zip(values) {
+            self.cache = self
+                .cache
+                .replace(placeholder, value.as_ref().unwrap_or(&self.no_value));
+
+            if let Some(value) = value {
+                self.values.insert(*key, value.clone());
+            }
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn data() -> Data {
+        Data::new(
+            "WAN {wan_in} Toner {toner}%".to_owned(),
+            "NA".to_owned(),
+            vec![
+                ("{toner}".to_owned(), "TONER"),
+                ("{wan_in}".to_owned(), "WAN_IN"),
+            ],
+        )
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = data();
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = data();
+
+        object.update(&[Some("63".to_owned()), Some("125000".to_owned())]);
+
+        assert_that!(object.render(), is(equal_to("WAN 125000 Toner 63%")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "TONER" => "63",
+                "WAN_IN" => "125000",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_value() {
+        let mut object = data();
+
+        object.update(&[Some("63".to_owned()), None]);
+
+        assert_that!(object.render(), is(equal_to("WAN NA Toner 63%")));
+        assert_that!(object.values(), is(equal_to(map_values!("TONER" => "63"))));
+    }
+}
@@ -0,0 +1,90 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Reading {
+    Counter(u64),
+    Value(String),
+}
+
+pub(super) fn get(settings: &ConfigEntry, oids: &[String]) -> Result<Vec<Option<Reading>>> {
+    let timeout = settings.timeout.to_string();
+    let mut args = vec![
+        "-v",
+        &settings.version[..],
+        "-c",
+        &settings.community[..],
+        "-t",
+        &timeout[..],
+        "-r",
+        "0",
+        "-Ov",
+        "-Oe",
+        "-Ot",
+        &settings.host[..],
+    ];
+    args.extend(oids.iter().map(String::as_str));
+
+    let output = process::Command::new("snmpget", &args)
+        .output()
+        .wrap_error(
+            FEATURE_NAME,
+            format!("OIDs of {} could not be queried", settings.host),
+        )?;
+
+    Ok(parse_output(&output, oids.len()))
+}
+
+fn parse_output(output: &str, count: usize) -> Vec<Option<Reading>> {
+    let mut readings = output.lines().map(parse_line).collect::<Vec<_>>();
+    readings.resize(count, None);
+
+    readings
+}
+
+fn parse_line(line: &str) -> Option<Reading> {
+    let mut parts = line.splitn(2, ": ");
+    let kind = parts.next()?.trim();
+    let value = parts.next()?.trim();
+
+    match kind {
+        "Counter32" | "Counter64" => value.parse().ok().map(Reading::Counter),
+        _ => Some(Reading::Value(value.trim_matches('"').to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_output_with_values() {
+        let output = "\
+Counter64: 123456789
+Gauge32: 42
+STRING: \"Black Toner\"
+No Such Object available on this agent at this OID";
+
+        assert_that!(
+            parse_output(output, 4),
+            is(equal_to(vec![
+                Some(Reading::Counter(123_456_789)),
+                Some(Reading::Value("42".to_owned())),
+                Some(Reading::Value("Black Toner".to_owned())),
+                None,
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_output_with_missing_lines() {
+        assert_that!(
+            parse_output("INTEGER: 3", 2),
+            is(equal_to(vec![Some(Reading::Value("3".to_owned())), None]))
+        );
+    }
+}
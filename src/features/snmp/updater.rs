@@ -0,0 +1,95 @@
+use super::ConfigEntry;
+use super::Data;
+use super::Reading;
+use super::query;
+use crate::error::*;
+use crate::feature;
+use std::time::Instant;
+
+pub(super) struct Updater {
+    counters: Vec<Option<(u64, Instant)>>,
+    data: Data,
+    oids: Vec<String>,
+    settings: ConfigEntry,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, oids: Vec<String>, settings: ConfigEntry) -> Self {
+        Self {
+            counters: vec![None; oids.len()],
+            data,
+            oids,
+            settings,
+        }
+    }
+
+    fn value(&mut self, index: usize, reading: Option<Reading>, now: Instant) -> Option<String> {
+        match reading? {
+            Reading::Counter(counter) => {
+                let last = self.counters[index].replace((counter, now));
+                let (last_counter, last_time) = last?;
+
+                rate(
+                    last_counter,
+                    counter,
+                    now.duration_since(last_time).as_secs_f64(),
+                )
+                .map(|rate| rate.to_string())
+            },
+            Reading::Value(value) => Some(value),
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let readings = query::get(&self.settings, &self.oids)?;
+        let now = Instant::now();
+
+        let values = readings
+            .into_iter()
+            .enumerate()
+            .map(|(index, reading)| self.value(index, reading, now))
+            .collect::<Vec<_>>();
+
+        self.data.update(&values);
+
+        Ok(())
+    }
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn rate(last: u64, current: u64, seconds: f64) -> Option<u64> {
+    let increase = current.checked_sub(last)?;
+
+    if seconds > 0.0 {
+        Some((increase as f64 / seconds).round() as u64)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn rate_per_second() {
+        assert_that!(rate(1000, 61000, 60.0), is(equal_to(Some(1000))));
+    }
+
+    #[test]
+    fn rate_of_wrapped_counter() {
+        assert_that!(rate(4_294_967_000, 500, 60.0), is(none()));
+    }
+}
@@ -0,0 +1,60 @@
+mod config;
+mod data;
+mod measurement;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::locale;
+use crate::state;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::measurement::Backend;
+pub(self) use self::measurement::Measurement;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "speedtest";
+pub(self) const PLACEHOLDER_AGE: &str = "{Age}";
+pub(self) const PLACEHOLDER_DOWN: &str = "{Down}";
+pub(self) const PLACEHOLDER_UP: &str = "{Up}";
+pub(self) const STATE_LAST: &str = "last";
+
+pub(self) type SharedMeasurement = Arc<Mutex<Option<Measurement>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let backend = Backend::init(settings, http::Client::init(FEATURE_NAME, http_settings))?;
+
+    let state = state::State::load(FEATURE_NAME);
+    let measurement = Arc::new(Mutex::new(state.get::<Measurement>(STATE_LAST)));
+
+    let data = Data::new(
+        settings.template.clone(),
+        settings.no_value.clone(),
+        locale::decimal_separator(),
+    );
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            backend,
+            Arc::clone(&measurement),
+            settings.update_interval,
+        ),
+        Updater::new(data, measurement, state),
+    )))
+}
@@ -0,0 +1,66 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) backend: String,
+    pub(super) no_value: String,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) update_interval: u64,
+    pub(super) url: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "backend"         => "download",
+                "no_value"        => "NA",
+                "template"        => "D {Down} U {Up} ({Age})",
+                "timeout"         => 60,
+                "update_interval" => 3600,
+                "url"             => "https://speed.cloudflare.com/__down?bytes=25000000",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("speedtest", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("speedtest", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("backend".to_owned(), "download".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("template".to_owned(), "D {Down} U {Up} ({Age})".into());
+        map.insert("timeout".to_owned(), 60.into());
+        map.insert("update_interval".to_owned(), 3600.into());
+        map.insert(
+            "url".to_owned(),
+            "https://speed.cloudflare.com/__down?bytes=25000000".into(),
+        );
+
+        map
+    }
+}
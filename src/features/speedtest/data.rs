@@ -0,0 +1,170 @@
+use super::Measurement;
+use super::PLACEHOLDER_AGE;
+use super::PLACEHOLDER_DOWN;
+use super::PLACEHOLDER_UP;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    decimal_separator: Option<String>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        decimal_separator: Option<String>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            decimal_separator,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, measurement: Option<Measurement>, now: i64) {
+        let measurement = match measurement {
+            Some(measurement) => measurement,
+            None => {
+                self.cache = self
+                    .template
+                    .replace(PLACEHOLDER_AGE, &self.no_value)
+                    .replace(PLACEHOLDER_DOWN, &self.no_value)
+                    .replace(PLACEHOLDER_UP, &self.no_value);
+                self.values = Values::new();
+                return;
+            },
+        };
+
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let age = format_age(now - measurement.timestamp);
+        let down = format_megabits(measurement.down, separator);
+        let up = measurement.up.map(|up| format_megabits(up, separator));
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_AGE, &age)
+            .replace(PLACEHOLDER_DOWN, &down)
+            .replace(PLACEHOLDER_UP, up.as_ref().unwrap_or(&self.no_value));
+
+        self.values = map_values!(
+            "AGE" => age,
+            "DOWN" => down,
+        );
+
+        if let Some(up) = up {
+            self.values.insert("UP", up);
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn format_megabits(bits: f64, separator: Option<&str>) -> String {
+    locale::format_decimal((bits / 1_000_000.) as f32, 1, separator)
+}
+
+fn format_age(seconds: i64) -> String {
+    let minutes = seconds.max(0) / 60;
+
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else if minutes < 24 * 60 {
+        format!("{}h", minutes / 60)
+    } else {
+        format!("{}d", minutes / (24 * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "D {Down} U {Up} ({Age})";
+
+    fn measurement(up: Option<f64>) -> Measurement {
+        Measurement {
+            down: 93_456_789.,
+            timestamp: 1_000,
+            up,
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), Some(",".to_owned()));
+
+        object.update(Some(measurement(Some(12_345_678.))), 1_000 + 2 * 3600);
+
+        assert_that!(object.render(), is(equal_to("D 93,5 U 12,3 (2h)")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "AGE" => "2h",
+                "DOWN" => "93,5",
+                "UP" => "12,3",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_upload() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        object.update(Some(measurement(None)), 1_000 + 90);
+
+        assert_that!(object.render(), is(equal_to("D 93.5 U NA (1m)")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "AGE" => "1m",
+                "DOWN" => "93.5",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_measurement() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        object.update(None, 1_000);
+
+        assert_that!(object.render(), is(equal_to("D NA U NA (NA)")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+
+    #[test]
+    fn format_age_in_units() {
+        assert_that!(format_age(-5), is(equal_to("0m")));
+        assert_that!(format_age(59 * 60), is(equal_to("59m")));
+        assert_that!(format_age(3 * 3600 + 59 * 60), is(equal_to("3h")));
+        assert_that!(format_age(2 * 86400 + 3600), is(equal_to("2d")));
+    }
+}
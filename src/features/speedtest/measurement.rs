@@ -0,0 +1,99 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::http;
+use crate::wrapper::process;
+use serde_derive::*;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(super) struct Measurement {
+    pub(super) down: f64,
+    pub(super) timestamp: i64,
+    pub(super) up: Option<f64>,
+}
+
+#[derive(Debug)]
+pub(super) enum Backend {
+    Download {
+        client: http::Client,
+        timeout: u64,
+        url: String,
+    },
+    SpeedtestCli,
+}
+
+impl Backend {
+    pub(super) fn init(settings: &ConfigEntry, client: http::Client) -> Result<Self> {
+        match &settings.backend[..] {
+            "download" => Ok(Self::Download {
+                client,
+                timeout: settings.timeout,
+                url: settings.url.clone(),
+            }),
+            "speedtest-cli" => Ok(Self::SpeedtestCli),
+            backend => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "unknown backend {}, expected download or speedtest-cli",
+                    backend
+                ),
+            )),
+        }
+    }
+
+    pub(super) fn measure(&self) -> Result<(f64, Option<f64>)> {
+        match self {
+            Self::Download {
+                client,
+                timeout,
+                url,
+            } => Ok((client.download_speed(url, *timeout)? * 8., None)),
+            Self::SpeedtestCli => {
+                let output = process::Command::new("speedtest-cli", &["--json"])
+                    .output()
+                    .wrap_error(FEATURE_NAME, "speedtest-cli failed")?;
+
+                let (down, up) = parse_speedtest_cli(&output).wrap_error(
+                    FEATURE_NAME,
+                    format!("invalid output of speedtest-cli: {}", output),
+                )?;
+
+                Ok((down, Some(up)))
+            },
+        }
+    }
+}
+
+fn parse_speedtest_cli(output: &str) -> Option<(f64, f64)> {
+    let result = serde_json::from_str::<Value>(output).ok()?;
+
+    Some((result["download"].as_f64()?, result["upload"].as_f64()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_speedtest_cli_with_result() {
+        let output = r#"{"download": 93456789.5, "upload": 12345678.25, "ping": 12.3,
+            "server": {"name": "Berlin"}, "bytes_sent": 15466496}"#;
+
+        assert_that!(
+            parse_speedtest_cli(output),
+            is(equal_to(Some((93_456_789.5, 12_345_678.25))))
+        );
+    }
+
+    #[test]
+    fn parse_speedtest_cli_with_invalid_output() {
+        assert_that!(
+            parse_speedtest_cli("Cannot retrieve speedtest configuration"),
+            is(none())
+        );
+        assert_that!(parse_speedtest_cli(r#"{"download": 1}"#), is(none()));
+    }
+}
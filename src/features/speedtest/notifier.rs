@@ -0,0 +1,77 @@
+use super::Backend;
+use super::Measurement;
+use super::SharedMeasurement;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::date_time;
+use crate::wrapper::thread;
+use std::convert::TryFrom;
+
+const AGE_INTERVAL: u64 = 60;
+
+pub(super) struct Notifier {
+    backend: Backend,
+    id: usize,
+    measurement: SharedMeasurement,
+    sender: channel::Sender<communication::Message>,
+    update_interval: u64,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        backend: Backend,
+        measurement: SharedMeasurement,
+        update_interval: u64,
+    ) -> Self {
+        Self {
+            backend,
+            id,
+            measurement,
+            sender,
+            update_interval,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let mut last_attempt = None;
+        let update_interval = i64::try_from(self.update_interval).unwrap_or(i64::max_value());
+
+        loop {
+            let now = date_time::DateTime::now().timestamp();
+            let last = self
+                .measurement
+                .lock()
+                .unwrap()
+                .map(|measurement| measurement.timestamp)
+                .max(last_attempt);
+
+            if last.map_or(true, |last| now >= last.saturating_add(update_interval)) {
+                last_attempt = Some(now);
+
+                match self.backend.measure() {
+                    Ok((down, up)) => {
+                        *self.measurement.lock().unwrap() = Some(Measurement {
+                            down,
+                            timestamp: now,
+                            up,
+                        });
+                    },
+                    Err(error) => error.show_error(),
+                }
+            }
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(AGE_INTERVAL);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(AGE_INTERVAL)
+    }
+}
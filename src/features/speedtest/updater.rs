@@ -0,0 +1,51 @@
+use super::Data;
+use super::Measurement;
+use super::STATE_LAST;
+use super::SharedMeasurement;
+use crate::error::*;
+use crate::feature;
+use crate::state;
+use crate::wrapper::date_time;
+
+pub(super) struct Updater {
+    data: Data,
+    measurement: SharedMeasurement,
+    state: state::State,
+}
+
+impl Updater {
+    pub(super) const fn new(
+        data: Data,
+        measurement: SharedMeasurement,
+        state: state::State,
+    ) -> Self {
+        Self {
+            data,
+            measurement,
+            state,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let measurement = *self.measurement.lock().unwrap();
+
+        if let Some(measurement) = measurement {
+            if self.state.get::<Measurement>(STATE_LAST) != Some(measurement) {
+                self.state
+                    .set(STATE_LAST, measurement)
+                    .show_error_and_ignore();
+            }
+        }
+
+        self.data
+            .update(measurement, date_time::DateTime::now().timestamp());
+
+        Ok(())
+    }
+}
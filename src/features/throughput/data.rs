@@ -0,0 +1,115 @@
+use super::PLACEHOLDER_DOWN;
+use super::PLACEHOLDER_INTERFACE;
+use super::PLACEHOLDER_UP;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::utils;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, interface: Option<&str>, rates: Option<(u64, u64)>) {
+        let format = |rate: u64| format!("{}/s", utils::format_bytes(rate));
+
+        let (down, up) = rates.map_or_else(
+            || (self.no_value.clone(), self.no_value.clone()),
+            |(down, up)| (format(down), format(up)),
+        );
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_DOWN, &down)
+            .replace(PLACEHOLDER_INTERFACE, interface.unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_UP, &up);
+
+        self.values = Values::new();
+
+        if let Some(interface) = interface {
+            self.values.insert("INTERFACE", interface.to_owned());
+        }
+
+        if let Some((down, up)) = rates {
+            self.values.insert("DOWN", down.to_string());
+            self.values.insert("UP", up.to_string());
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("D {Down} U {Up}".to_owned(), "NA".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("{Interface} D {Down} U {Up}".to_owned(), "NA".to_owned());
+
+        object.update(Some("wlp3s0"), Some((1_572_864, 512)));
+
+        assert_that!(
+            object.render(),
+            is(equal_to("wlp3s0 D 1.5 MiB/s U 512 B/s"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "DOWN" => "1572864",
+                "INTERFACE" => "wlp3s0",
+                "UP" => "512",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_first_sample() {
+        let mut object = Data::new("{Interface} D {Down} U {Up}".to_owned(), "NA".to_owned());
+
+        object.update(Some("wlp3s0"), None);
+
+        assert_that!(object.render(), is(equal_to("wlp3s0 D NA U NA")));
+    }
+
+    #[test]
+    fn render_with_update_and_offline() {
+        let mut object = Data::new("{Interface} D {Down} U {Up}".to_owned(), "NA".to_owned());
+
+        object.update(None, None);
+
+        assert_that!(object.render(), is(equal_to("NA D NA U NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
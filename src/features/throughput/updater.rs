@@ -0,0 +1,142 @@
+use super::Data;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::feature;
+use crate::features::network;
+use crate::wrapper::file;
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+const PATH_NET: &str = "/sys/class/net";
+
+struct Sample {
+    interface: String,
+    rx: u64,
+    time: Instant,
+    tx: u64,
+}
+
+pub(super) struct Updater {
+    data: Data,
+    interface: String,
+    last: Option<Sample>,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, interface: String) -> Self {
+        Self {
+            data,
+            interface,
+            last: None,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let interface = if self.interface.is_empty() {
+            network::default_route()
+        } else {
+            Some(self.interface.clone())
+        };
+
+        let interface = match interface {
+            Some(interface) => interface,
+            None => {
+                self.last = None;
+                self.data.update(None, None);
+
+                return Ok(());
+            },
+        };
+
+        let statistics = Path::new(PATH_NET).join(&interface).join("statistics");
+        let rx = file::parse_file_content(statistics.join("rx_bytes")).wrap_error(
+            FEATURE_NAME,
+            format!("received bytes of {} could not be read", interface),
+        )?;
+        let tx = file::parse_file_content(statistics.join("tx_bytes")).wrap_error(
+            FEATURE_NAME,
+            format!("transmitted bytes of {} could not be read", interface),
+        )?;
+        let time = Instant::now();
+
+        let rates = self
+            .last
+            .as_ref()
+            .filter(|last| last.interface == interface)
+            .map(|last| {
+                let elapsed = time.duration_since(last.time);
+
+                (rate(last.rx, rx, elapsed), rate(last.tx, tx, elapsed))
+            });
+
+        self.data.update(Some(&interface), rates);
+        self.last = Some(Sample {
+            interface,
+            rx,
+            time,
+            tx,
+        });
+
+        Ok(())
+    }
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn rate(previous: u64, current: u64, elapsed: Duration) -> u64 {
+    let seconds = elapsed.as_secs_f64();
+
+    if seconds <= 0. {
+        return 0;
+    }
+
+    let bytes = if current < previous {
+        current
+    } else {
+        current - previous
+    };
+
+    (bytes as f64 / seconds).round() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn rate_per_second() {
+        assert_that!(
+            rate(1_000, 11_000, Duration::from_millis(2_000)),
+            is(equal_to(5_000))
+        );
+        assert_that!(
+            rate(1_000, 2_000, Duration::from_millis(500)),
+            is(equal_to(2_000))
+        );
+    }
+
+    #[test]
+    fn rate_with_reset_counter() {
+        assert_that!(
+            rate(50_000, 3_000, Duration::from_secs(1)),
+            is(equal_to(3_000))
+        );
+    }
+
+    #[test]
+    fn rate_without_elapsed_time() {
+        assert_that!(rate(1_000, 2_000, Duration::from_secs(0)), is(equal_to(0)));
+    }
+}
@@ -6,7 +6,9 @@ mod updater;
 use crate::communication;
 use crate::error::*;
 use crate::feature;
+use crate::locale;
 use crate::wrapper::channel;
+use std::iter;
 
 pub(crate) use self::config::ConfigEntry;
 pub(self) use self::data::Data;
@@ -20,11 +22,15 @@ pub(super) fn create(
     sender: &channel::Sender<communication::Message>,
     settings: &ConfigEntry,
 ) -> Result<Box<dyn feature::Feature>> {
-    let data = Data::new(settings.format.clone());
+    let formats = iter::once(&settings.format)
+        .chain(&settings.formats)
+        .cloned()
+        .collect();
+    let data = Data::new(formats, locale::is_enabled());
 
     Ok(Box::new(feature::Composer::new(
         FEATURE_NAME,
-        Notifier::new(id, sender.clone(), settings.update_seconds),
-        Updater::new(data),
+        Notifier::new(settings.update_seconds, settings.cycle_seconds),
+        Updater::new(data, id, sender.clone(), settings.cycle_seconds),
     )))
 }
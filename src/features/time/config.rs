@@ -7,7 +7,9 @@ use serde_derive::*;
 
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ConfigEntry {
+    pub(super) cycle_seconds: u64,
     pub(super) format: String,
+    pub(super) formats: Vec<String>,
     pub(super) update_seconds: bool,
 }
 
@@ -16,7 +18,9 @@ impl ConfigType for ConfigEntry {
         config.set_default(
             FEATURE_NAME,
             map!(
+                "cycle_seconds"  => 0,
                 "format"         => "%Y-%m-%d %H:%M",
+                "formats"        => Vec::<String>::new(),
                 "update_seconds" => false,
             ),
         )
@@ -24,14 +28,20 @@ impl ConfigType for ConfigEntry {
 
     fn set_values(config: &mut config::Config) -> Result<()> {
         // dynamically set time.update_seconds
-        let time_format = config
-            .get_str(&format!("{}.format", FEATURE_NAME))?
-            .replace("%%", "");
-
-        if ["%f", "%r", "%S", "%s", "%T"]
-            .iter()
-            .any(|specifier| time_format.contains(specifier))
-        {
+        let mut time_formats = vec![config.get_str(&format!("{}.format", FEATURE_NAME))?];
+        time_formats.extend(
+            config
+                .get_str_list_option(&format!("{}.formats", FEATURE_NAME))?
+                .unwrap_or_default(),
+        );
+
+        if time_formats.iter().any(|time_format| {
+            let time_format = time_format.replace("%%", "");
+
+            ["%f", "%r", "%S", "%s", "%T"]
+                .iter()
+                .any(|specifier| time_format.contains(specifier))
+        }) {
             config.set_default(&format!("{}.update_seconds", FEATURE_NAME), true)?;
         }
 
@@ -65,7 +75,9 @@ mod tests {
 
         fn default_map() -> HashMap<String, Value> {
             let mut map = HashMap::new();
+            map.insert("cycle_seconds".to_owned(), 0.into());
             map.insert("format".to_owned(), "%Y-%m-%d %H:%M".into());
+            map.insert("formats".to_owned(), Vec::<String>::new().into());
             map.insert("update_seconds".to_owned(), false.into());
 
             map
@@ -113,11 +125,35 @@ mod tests {
                 test_builder("%Y-%m-%d %H:%M", false);
             }
 
+            #[test]
+            fn and_matching_placeholder_in_formats() {
+                test_builder_with_formats("%H:%M", &["%Y-%m-%d", "%T"], true);
+            }
+
+            #[test]
+            fn and_not_matching_placeholder_in_formats() {
+                test_builder_with_formats("%H:%M", &["%Y-%m-%d", "%%S"], false);
+            }
+
             fn test_builder(format: &'static str, is_match: bool) {
+                test_builder_with_formats(format, &[], is_match);
+            }
+
+            fn test_builder_with_formats(
+                format: &'static str,
+                formats: &'static [&'static str],
+                is_match: bool,
+            ) {
                 config::Config::get_str.mock_safe(move |_, key| {
                     assert_that!(key, is(equal_to("time.format")));
                     MockResult::Return(Ok(format.to_owned()))
                 });
+                config::Config::get_str_list_option.mock_safe(move |_, key| {
+                    assert_that!(key, is(equal_to("time.formats")));
+                    MockResult::Return(Ok(Some(
+                        formats.iter().map(|format| (*format).to_owned()).collect(),
+                    )))
+                });
 
                 let mut counter = 0;
                 unsafe {
@@ -154,12 +190,32 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn in_get_str_list_option() {
+                config::Config::get_str.mock_safe(|_, key| {
+                    assert_that!(key, is(equal_to("time.format")));
+                    MockResult::Return(Ok("%s".to_owned()))
+                });
+                config::Config::get_str_list_option.mock_safe(|_, key| {
+                    assert_that!(key, is(equal_to("time.formats")));
+                    MockResult::Return(Err(Error::new_custom("name", "description")))
+                });
+
+                let mut config = config::Config::new();
+
+                assert_that!(
+                    ConfigEntry::set_values(&mut config),
+                    is(equal_to(Err(Error::new_custom("name", "description"))))
+                );
+            }
+
             #[test]
             fn in_set_default() {
                 config::Config::get_str.mock_safe(|_, key| {
                     assert_that!(key, is(equal_to("time.format")));
                     MockResult::Return(Ok("%s".to_owned()))
                 });
+                config::Config::get_str_list_option.mock_safe(|_, _| MockResult::Return(Ok(None)));
                 config::Config::set_default.mock_safe(|_, key, value: bool| {
                     assert_that!(key, is(equal_to("time.update_seconds")));
                     assert_that!(value, is(equal_to(true)));
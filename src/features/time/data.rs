@@ -1,23 +1,43 @@
+use crate::error::*;
 use crate::feature::Renderable;
 use crate::wrapper::date_time;
 
 #[derive(Debug)]
 pub(super) struct Data {
     cache: String,
-    format: String,
+    formats: Vec<String>,
+    localized: bool,
+    offset: usize,
 }
 
 impl Data {
     #[allow(clippy::missing_const_for_fn)]
-    pub(super) fn new(format: String) -> Self {
+    pub(super) fn new(formats: Vec<String>, localized: bool) -> Self {
         Self {
             cache: String::new(),
-            format,
+            formats,
+            localized,
+            offset: 0,
         }
     }
 
-    pub(super) fn update(&mut self, date_time: &date_time::DateTime) {
-        self.cache = date_time.format(&self.format);
+    pub(super) fn next_format(&mut self) {
+        self.offset = (self.offset + 1) % self.formats.len().max(1);
+    }
+
+    pub(super) fn update(&mut self, date_time: &date_time::DateTime, cycle: usize) -> Result<()> {
+        let format: &str = match self.formats.len() {
+            0 => "",
+            length => &self.formats[(cycle % length + self.offset) % length],
+        };
+
+        self.cache = if self.localized {
+            date_time.format_localized(format)?
+        } else {
+            date_time.format(format)
+        };
+
+        Ok(())
     }
 }
 
@@ -37,7 +57,7 @@ mod tests {
 
     #[test]
     fn render_with_default() {
-        let object = Data::new("format".to_owned());
+        let object = Data::new(vec!["format".to_owned()], false);
 
         assert_that!(object.render(), is(equal_to("")));
     }
@@ -45,7 +65,7 @@ mod tests {
     #[cfg(feature = "mocking")]
     #[test]
     fn render_with_update() {
-        let mut object = Data::new("format".to_owned());
+        let mut object = Data::new(vec!["format".to_owned()], false);
 
         date_time::DateTime::format.mock_safe(|_, format| {
             assert_that!(format, is(equal_to("format")));
@@ -53,8 +73,53 @@ mod tests {
             MockResult::Return("formatted date time".to_owned())
         });
 
-        object.update(&date_time::DateTime::now());
+        assert_that!(
+            object.update(&date_time::DateTime::now(), 0),
+            is(equal_to(Ok(())))
+        );
 
         assert_that!(object.render(), is(equal_to("formatted date time")));
     }
+
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_localized_update() {
+        let mut object = Data::new(vec!["format".to_owned()], true);
+
+        date_time::DateTime::format_localized.mock_safe(|_, format| {
+            assert_that!(format, is(equal_to("format")));
+
+            MockResult::Return(Ok("lokalisierte Zeit".to_owned()))
+        });
+
+        assert_that!(
+            object.update(&date_time::DateTime::now(), 0),
+            is(equal_to(Ok(())))
+        );
+
+        assert_that!(object.render(), is(equal_to("lokalisierte Zeit")));
+    }
+    #[cfg(feature = "mocking")]
+    #[test]
+    fn render_with_formats() {
+        let mut object = Data::new(
+            vec!["time".to_owned(), "date".to_owned(), "week".to_owned()],
+            false,
+        );
+
+        date_time::DateTime::format.mock_safe(|_, format| MockResult::Return(format.to_owned()));
+
+        let date_time = date_time::DateTime::now();
+
+        object.update(&date_time, 4).unwrap();
+        assert_that!(object.render(), is(equal_to("date")));
+
+        object.next_format();
+        object.update(&date_time, 4).unwrap();
+        assert_that!(object.render(), is(equal_to("week")));
+
+        object.next_format();
+        object.update(&date_time, 4).unwrap();
+        assert_that!(object.render(), is(equal_to("time")));
+    }
 }
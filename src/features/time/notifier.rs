@@ -1,24 +1,18 @@
-use crate::communication;
 use crate::error::*;
-use crate::wrapper::channel;
 use crate::wrapper::thread;
 use chrono::Timelike;
+use std::convert::TryFrom;
+use std::time::Duration;
 
 pub(super) struct Notifier {
-    id: usize,
-    sender: channel::Sender<communication::Message>,
+    cycle_seconds: u64,
     update_seconds: bool,
 }
 
 impl Notifier {
-    pub(super) const fn new(
-        id: usize,
-        sender: channel::Sender<communication::Message>,
-        update_seconds: bool,
-    ) -> Self {
+    pub(super) const fn new(update_seconds: bool, cycle_seconds: u64) -> Self {
         Self {
-            id,
-            sender,
+            cycle_seconds,
             update_seconds,
         }
     }
@@ -26,16 +20,28 @@ impl Notifier {
 
 impl thread::Runnable for Notifier {
     fn run(&self) -> Result<()> {
-        loop {
-            let update_interval = if self.update_seconds {
-                1
-            } else {
-                60 - u64::from(chrono::Local::now().second())
-            };
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(if self.update_seconds { 1 } else { 60 })
+    }
 
-            thread::sleep_secs(update_interval);
+    fn next_update(&self) -> Option<Duration> {
+        let now = chrono::Local::now();
+        let mut seconds = if self.update_seconds {
+            0
+        } else {
+            59 - u64::from(now.second().min(59))
+        };
 
-            communication::send_message(self.id, &self.sender)?;
+        if self.cycle_seconds > 0 {
+            let elapsed = u64::try_from(now.timestamp()).unwrap_or_default() % self.cycle_seconds;
+            seconds = seconds.min(self.cycle_seconds - 1 - elapsed);
         }
+
+        let nanoseconds = 1_000_000_000 - now.nanosecond().min(999_999_999);
+
+        Some(Duration::from_secs(seconds) + Duration::from_nanos(u64::from(nanoseconds)))
     }
 }
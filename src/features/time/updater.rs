@@ -1,15 +1,32 @@
 use super::Data;
+use crate::click;
+use crate::communication;
 use crate::error::*;
 use crate::feature;
+use crate::wrapper::channel;
 use crate::wrapper::date_time;
+use std::convert::TryFrom;
 
 pub(super) struct Updater {
+    cycle_seconds: u64,
     data: Data,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
 }
 
 impl Updater {
-    pub(super) const fn new(data: Data) -> Self {
-        Self { data }
+    pub(super) const fn new(
+        data: Data,
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        cycle_seconds: u64,
+    ) -> Self {
+        Self {
+            cycle_seconds,
+            data,
+            id,
+            sender,
+        }
     }
 }
 
@@ -19,8 +36,26 @@ impl feature::Updatable for Updater {
     }
 
     fn update(&mut self) -> Result<()> {
-        self.data.update(&date_time::DateTime::now());
+        let date_time = date_time::DateTime::now();
 
-        Ok(())
+        let cycle = match self.cycle_seconds {
+            0 => 0,
+            cycle_seconds => {
+                let timestamp = u64::try_from(date_time.timestamp()).unwrap_or_default();
+                usize::try_from(timestamp / cycle_seconds).unwrap_or_default()
+            },
+        };
+
+        self.data.update(&date_time, cycle)
+    }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        if click::Button::from_number(button)? != click::Button::Left {
+            return Ok(());
+        }
+
+        self.data.next_format();
+
+        communication::send_message(self.id, &self.sender)
     }
 }
@@ -0,0 +1,39 @@
+mod certificate;
+mod config;
+mod data;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(self) use self::certificate::Target;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "tls";
+pub(self) const PLACEHOLDER_DAYS: &str = "{Days}";
+pub(self) const PLACEHOLDER_HOST: &str = "{Host}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let targets = settings
+        .hosts
+        .iter()
+        .map(|host| Target::parse(host))
+        .collect::<Result<Vec<_>>>()?;
+
+    let data = Data::new(settings.template.clone(), settings.no_value.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, targets, settings.clone()),
+    )))
+}
@@ -0,0 +1,137 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::process;
+
+const DEFAULT_PORT: u16 = 443;
+const SECONDS_PER_DAY: i64 = 86400;
+
+const SCRIPT: &str = "timeout \"$3\" openssl s_client -connect \"$1:$2\" -servername \"$1\" \
+                      </dev/null 2>/dev/null | openssl x509 -noout -enddate";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Target {
+    pub(super) host: String,
+    pub(super) port: u16,
+}
+
+impl Target {
+    pub(super) fn parse(target: &str) -> Result<Self> {
+        let mut parts = target.rsplitn(2, ':');
+        let last = parts.next().unwrap_or_default();
+
+        let (host, port) = match parts.next() {
+            Some(host) => (
+                host,
+                last.parse()
+                    .wrap_error(FEATURE_NAME, format!("invalid port of host {}", target))?,
+            ),
+            None => (last, DEFAULT_PORT),
+        };
+
+        if host.is_empty() {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("invalid host {}", target),
+            ));
+        }
+
+        Ok(Self {
+            host: host.to_owned(),
+            port,
+        })
+    }
+
+    pub(super) fn expiry(&self, timeout: u64) -> Result<i64> {
+        let port = self.port.to_string();
+        let timeout = timeout.to_string();
+
+        let output =
+            process::Command::new("sh", &["-c", SCRIPT, "sh", &self.host, &port, &timeout])
+                .output()
+                .wrap_error(
+                    FEATURE_NAME,
+                    format!("certificate of {} could not be fetched", self.host),
+                )?;
+
+        parse_not_after(&output).wrap_error(
+            FEATURE_NAME,
+            format!("invalid expiry of certificate of {}: {}", self.host, output),
+        )
+    }
+}
+
+pub(super) fn days_left(expiry: i64, now: i64) -> i64 {
+    (expiry - now).div_euclid(SECONDS_PER_DAY)
+}
+
+fn parse_not_after(output: &str) -> Option<i64> {
+    let mut parts = output.trim().splitn(2, '=');
+
+    if parts.next()? != "notAfter" {
+        return None;
+    }
+
+    let date = parts
+        .next()?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    chrono::NaiveDateTime::parse_from_str(&date, "%b %d %H:%M:%S %Y GMT")
+        .ok()
+        .map(|date_time| date_time.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn target_parse_with_port() {
+        assert_that!(
+            Target::parse("example.com:8443"),
+            is(equal_to(Ok(Target {
+                host: "example.com".to_owned(),
+                port: 8443,
+            })))
+        );
+    }
+
+    #[test]
+    fn target_parse_without_port() {
+        assert_that!(
+            Target::parse("example.com"),
+            is(equal_to(Ok(Target {
+                host: "example.com".to_owned(),
+                port: 443,
+            })))
+        );
+    }
+
+    #[test]
+    fn target_parse_with_invalid_port() {
+        assert_that!(Target::parse("example.com:https").is_err(), is(true));
+        assert_that!(Target::parse(":443").is_err(), is(true));
+    }
+
+    #[test]
+    fn parse_not_after_with_padded_day() {
+        assert_that!(
+            parse_not_after("notAfter=Jan  5 12:00:00 2025 GMT\n"),
+            is(equal_to(Some(1_736_078_400)))
+        );
+    }
+
+    #[test]
+    fn parse_not_after_with_invalid_output() {
+        assert_that!(parse_not_after("unable to load certificate"), is(none()));
+    }
+
+    #[test]
+    fn days_left_rounds_down() {
+        assert_that!(days_left(10 * SECONDS_PER_DAY - 1, 0), is(equal_to(9)));
+        assert_that!(days_left(0, 1), is(equal_to(-1)));
+    }
+}
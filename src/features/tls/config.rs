@@ -0,0 +1,80 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) hosts: Vec<String>,
+    pub(super) no_value: String,
+    pub(super) notification: NotificationConfig,
+    pub(super) notifier_days: i64,
+    pub(super) template: String,
+    pub(super) timeout: u64,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "hosts"           => Vec::<String>::new(),
+                "no_value"        => "NA",
+                "notification"    => NotificationConfig::default_map(
+                    "Certificate of {HOST} expires soon",
+                    "{DAYS} days left",
+                    "normal",
+                ),
+                "notifier_days"   => 14,
+                "template"        => "{Host} {Days}d",
+                "timeout"         => 10,
+                "update_interval" => 3600,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("tls", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("tls", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("hosts".to_owned(), Vec::<String>::new().into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map(
+                "Certificate of {HOST} expires soon",
+                "{DAYS} days left",
+                "normal",
+            )
+            .into(),
+        );
+        map.insert("notifier_days".to_owned(), 14.into());
+        map.insert("template".to_owned(), "{Host} {Days}d".into());
+        map.insert("timeout".to_owned(), 10.into());
+        map.insert("update_interval".to_owned(), 3600.into());
+
+        map
+    }
+}
@@ -0,0 +1,87 @@
+use super::PLACEHOLDER_DAYS;
+use super::PLACEHOLDER_HOST;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String, no_value: String) -> Self {
+        Self {
+            cache: String::new(),
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, soonest: Option<(&str, i64)>) {
+        let (host, days) = match soonest {
+            Some((host, days)) => (host.to_owned(), days.to_string()),
+            None => (self.no_value.clone(), self.no_value.clone()),
+        };
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_DAYS, &days)
+            .replace(PLACEHOLDER_HOST, &host);
+
+        self.values = match soonest {
+            Some(_) => map_values!(
+                "DAYS" => days,
+                "HOST" => host,
+            ),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("{Host} {Days}d".to_owned(), "NA".to_owned());
+
+        object.update(Some(("example.com", 12)));
+
+        assert_that!(object.render(), is(equal_to("example.com 12d")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "DAYS" => "12",
+                "HOST" => "example.com",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_certificate() {
+        let mut object = Data::new("{Host} {Days}d".to_owned(), "NA".to_owned());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("NA NAd")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
@@ -0,0 +1,82 @@
+use super::ConfigEntry;
+use super::Data;
+use super::Target;
+use super::certificate;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+use crate::wrapper::date_time;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+const PLACEHOLDER_DAYS: &str = "{DAYS}";
+const PLACEHOLDER_HOST: &str = "{HOST}";
+
+pub(super) struct Updater {
+    data: Data,
+    notified: HashSet<String>,
+    notifiers: HashMap<String, notification::LazyNotifier>,
+    settings: ConfigEntry,
+    targets: Vec<Target>,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, targets: Vec<Target>, settings: ConfigEntry) -> Self {
+        Self {
+            data,
+            notified: HashSet::new(),
+            notifiers: HashMap::new(),
+            settings,
+            targets,
+        }
+    }
+
+    fn notify(&mut self, host: &str, days: i64) -> Result<()> {
+        let notifier = self.notifiers.entry(host.to_owned()).or_default().get()?;
+
+        notifier.send(
+            &self.settings.notification,
+            &[
+                (PLACEHOLDER_DAYS, days.to_string()),
+                (PLACEHOLDER_HOST, host.to_owned()),
+            ],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let now = date_time::DateTime::now().timestamp();
+
+        let days = self
+            .targets
+            .iter()
+            .filter_map(|target| match target.expiry(self.settings.timeout) {
+                Ok(expiry) => Some((target.host.clone(), certificate::days_left(expiry, now))),
+                Err(error) => {
+                    error.show_error();
+                    None
+                },
+            })
+            .collect::<Vec<_>>();
+
+        for (host, days) in &days {
+            if *days >= self.settings.notifier_days {
+                self.notified.remove(host);
+            } else if self.settings.notifier_days > 0 && self.notified.insert(host.clone()) {
+                self.notify(host, *days).show_error_and_ignore();
+            }
+        }
+
+        let soonest = days.iter().min_by_key(|(_, days)| *days);
+
+        self.data
+            .update(soonest.map(|(host, days)| (&host[..], *days)));
+
+        Ok(())
+    }
+}
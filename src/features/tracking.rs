@@ -0,0 +1,54 @@
+mod activitywatch;
+mod backend;
+mod config;
+mod data;
+mod notifier;
+mod timewarrior;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+
+pub(self) use self::backend::Activity;
+pub(self) use self::backend::Backend;
+pub(self) use self::backend::Interval;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "tracking";
+pub(self) const PLACEHOLDER_ELAPSED: &str = "{Elapsed}";
+pub(self) const PLACEHOLDER_TAG: &str = "{Tag}";
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let backend = Backend::parse(&settings.backend)?;
+    let client = http::Client::init(FEATURE_NAME, http_settings);
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            backend.watched_files(),
+            settings.update_interval,
+        ),
+        Updater::new(
+            data,
+            backend,
+            client,
+            settings.url.clone(),
+            id,
+            sender.clone(),
+        ),
+    )))
+}
@@ -0,0 +1,110 @@
+use super::Activity;
+use super::FEATURE_NAME;
+use super::Interval;
+use crate::error::*;
+use crate::http;
+use crate::settings;
+use serde_derive::*;
+
+const BUCKET_AFK: &str = "aw-watcher-afk";
+const BUCKET_WINDOW: &str = "aw-watcher-window";
+const STATUS_NOT_AFK: &str = "not-afk";
+
+#[derive(Debug, Default, Deserialize)]
+struct EventData {
+    #[serde(default)]
+    app: String,
+    #[serde(default)]
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Event {
+    #[serde(default)]
+    data: EventData,
+    timestamp: String,
+}
+
+pub(super) fn activity(client: &mut http::Client, url: &str) -> Result<Activity> {
+    let hostname = settings::hostname().wrap_error(FEATURE_NAME, "hostname is unknown")?;
+
+    let start = match latest_event(client, url, &bucket(BUCKET_AFK, &hostname))? {
+        Some(ref event) if event.data.status == STATUS_NOT_AFK => {
+            parse_timestamp(&event.timestamp)?
+        },
+        _ => return Ok(Activity::Idle),
+    };
+
+    let tag = latest_event(client, url, &bucket(BUCKET_WINDOW, &hostname))
+        .show_error()
+        .ok()
+        .flatten()
+        .map(|event| event.data.app)
+        .unwrap_or_default();
+
+    Ok(Activity::Tracking(Interval { start, tag }))
+}
+
+fn bucket(watcher: &str, hostname: &str) -> String {
+    format!("{}_{}", watcher, hostname)
+}
+
+fn latest_event(client: &mut http::Client, url: &str, bucket: &str) -> Result<Option<Event>> {
+    let body = client.get(
+        &format!(
+            "{}/api/0/buckets/{}/events?limit=1",
+            url.trim_end_matches('/'),
+            bucket
+        ),
+        0,
+    )?;
+
+    parse_events(&body, bucket)
+}
+
+fn parse_events(body: &str, bucket: &str) -> Result<Option<Event>> {
+    let events = serde_json::from_str::<Vec<Event>>(body)
+        .wrap_error(FEATURE_NAME, format!("invalid events of bucket {}", bucket))?;
+
+    Ok(events.into_iter().next())
+}
+
+fn parse_timestamp(timestamp: &str) -> Result<i64> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|date_time| date_time.timestamp())
+        .wrap_error(
+            FEATURE_NAME,
+            format!("invalid timestamp {} of ActivityWatch", timestamp),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_events_of_afk_watcher() {
+        let body = r#"[{"id":42,"timestamp":"2024-01-02T08:30:00.123000+00:00","duration":93.5,
+            "data":{"status":"not-afk"}}]"#;
+
+        let event = parse_events(body, "aw-watcher-afk_laptop")
+            .unwrap()
+            .unwrap();
+
+        assert_that!(event.data.status, is(equal_to(STATUS_NOT_AFK)));
+        assert_that!(
+            parse_timestamp(&event.timestamp),
+            is(equal_to(Ok(1_704_184_200)))
+        );
+    }
+
+    #[test]
+    fn parse_events_of_empty_bucket() {
+        assert_that!(
+            parse_events("[]", "aw-watcher-afk_laptop").map(|event| event.is_none()),
+            is(equal_to(Ok(true)))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use super::FEATURE_NAME;
+use super::activitywatch;
+use super::timewarrior;
+use crate::error::*;
+use crate::http;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Backend {
+    ActivityWatch,
+    Timewarrior,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Interval {
+    pub(super) start: i64,
+    pub(super) tag: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Activity {
+    Idle,
+    Tracking(Interval),
+}
+
+impl Backend {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "activitywatch" => Ok(Self::ActivityWatch),
+            "timewarrior" => Ok(Self::Timewarrior),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "unknown backend {}, expected activitywatch or timewarrior",
+                    name
+                ),
+            )),
+        }
+    }
+
+    pub(super) fn activity(self, client: &mut http::Client, url: &str) -> Result<Activity> {
+        match self {
+            Self::ActivityWatch => activitywatch::activity(client, url),
+            Self::Timewarrior => timewarrior::activity(),
+        }
+    }
+
+    pub(super) fn toggle(self, tracking: bool) -> Result<()> {
+        match self {
+            Self::ActivityWatch => Ok(()),
+            Self::Timewarrior => timewarrior::toggle(tracking),
+        }
+    }
+
+    pub(super) fn watched_files(self) -> Vec<String> {
+        match self {
+            Self::ActivityWatch => Vec::new(),
+            Self::Timewarrior => timewarrior::data_directory()
+                .map(|directory| directory.to_string_lossy().into_owned())
+                .into_iter()
+                .collect(),
+        }
+    }
+}
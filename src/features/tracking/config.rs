@@ -0,0 +1,63 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) backend: String,
+    pub(super) no_value: String,
+    pub(super) not_tracking: String,
+    pub(super) template: String,
+    pub(super) update_interval: u64,
+    pub(super) url: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "backend"         => "timewarrior",
+                "no_value"        => "NA",
+                "not_tracking"    => "NOT TRACKING",
+                "template"        => "{Tag} {Elapsed}",
+                "update_interval" => 60,
+                "url"             => "http://localhost:5600",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("tracking", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("tracking", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("backend".to_owned(), "timewarrior".into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("not_tracking".to_owned(), "NOT TRACKING".into());
+        map.insert("template".to_owned(), "{Tag} {Elapsed}".into());
+        map.insert("update_interval".to_owned(), 60.into());
+        map.insert("url".to_owned(), "http://localhost:5600".into());
+
+        map
+    }
+}
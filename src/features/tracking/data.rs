@@ -0,0 +1,155 @@
+use super::Activity;
+use super::ConfigEntry;
+use super::PLACEHOLDER_ELAPSED;
+use super::PLACEHOLDER_TAG;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, activity: Option<&Activity>, now: i64) {
+        let mut values = Values::new();
+
+        self.cache = match activity {
+            Some(Activity::Tracking(interval)) => {
+                let elapsed = now.saturating_sub(interval.start).max(0);
+
+                values.insert("ELAPSED", elapsed.to_string());
+                values.insert("TAG", interval.tag.clone());
+                values.insert("TRACKING", true.to_string());
+
+                self.settings
+                    .template
+                    .replace(PLACEHOLDER_ELAPSED, &format_elapsed(elapsed))
+                    .replace(PLACEHOLDER_TAG, &interval.tag)
+                    .trim()
+                    .to_owned()
+            },
+            Some(Activity::Idle) => {
+                values.insert("TRACKING", false.to_string());
+
+                self.settings.not_tracking.clone()
+            },
+            None => self.settings.no_value.clone(),
+        };
+        self.values = values;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+fn format_elapsed(elapsed: i64) -> String {
+    format!("{}:{:02}", elapsed / 3600, elapsed / 60 % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::tracking::Interval;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            backend: "timewarrior".to_owned(),
+            no_value: "NA".to_owned(),
+            not_tracking: "NOT TRACKING".to_owned(),
+            template: "{Tag} {Elapsed}".to_owned(),
+            update_interval: 60,
+            url: "http://localhost:5600".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(
+            Some(&Activity::Tracking(Interval {
+                start: 1000,
+                tag: "review".to_owned(),
+            })),
+            1000 + 3900,
+        );
+
+        assert_that!(object.render(), is(equal_to("review 1:05")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ELAPSED" => "3900",
+                "TAG" => "review",
+                "TRACKING" => "true",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_tag() {
+        let mut object = Data::new(settings());
+
+        object.update(
+            Some(&Activity::Tracking(Interval {
+                start: 1000,
+                tag: String::new(),
+            })),
+            1000 + 59,
+        );
+
+        assert_that!(object.render(), is(equal_to("0:00")));
+    }
+
+    #[test]
+    fn render_with_update_when_idle() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Activity::Idle), 1000);
+
+        assert_that!(object.render(), is(equal_to("NOT TRACKING")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "TRACKING" => "false",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_when_unknown() {
+        let mut object = Data::new(settings());
+
+        object.update(None, 1000);
+
+        assert_that!(object.render(), is(equal_to("NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -2,8 +2,10 @@ use crate::communication;
 use crate::error::*;
 use crate::wrapper::channel;
 use crate::wrapper::thread;
+use std::time::Duration;
 
 pub(super) struct Notifier {
+    files: Vec<String>,
     id: usize,
     sender: channel::Sender<communication::Message>,
     update_interval: u64,
@@ -13,9 +15,11 @@ impl Notifier {
     pub(super) const fn new(
         id: usize,
         sender: channel::Sender<communication::Message>,
+        files: Vec<String>,
         update_interval: u64,
     ) -> Self {
         Self {
+            files,
             id,
             sender,
             update_interval,
@@ -31,4 +35,16 @@ impl thread::Runnable for Notifier {
             communication::send_message(self.id, &self.sender)?;
         }
     }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.update_interval)
+    }
+
+    fn next_update(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.update_interval))
+    }
+
+    fn watched_files(&self) -> Vec<String> {
+        self.files.clone()
+    }
 }
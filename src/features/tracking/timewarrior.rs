@@ -0,0 +1,110 @@
+use super::Activity;
+use super::FEATURE_NAME;
+use super::Interval;
+use crate::error::*;
+use crate::wrapper::process;
+use serde_derive::*;
+use std::env;
+use std::path::PathBuf;
+
+const SCRIPT_ACTIVE: &str =
+    "active=$(timew get dom.active) && if [ \"$active\" = 1 ]; then timew get dom.active.json; fi";
+
+#[derive(Debug, Deserialize)]
+struct ActiveInterval {
+    start: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+pub(super) fn activity() -> Result<Activity> {
+    let output = process::Command::new("sh", &["-c", SCRIPT_ACTIVE])
+        .output()
+        .wrap_error(
+            FEATURE_NAME,
+            "active interval of Timewarrior could not be read",
+        )?;
+
+    parse_active(&output)
+}
+
+pub(super) fn toggle(tracking: bool) -> Result<()> {
+    let command = if tracking { "stop" } else { "continue" };
+
+    process::Command::new("timew", &[command])
+        .output()
+        .wrap_error(FEATURE_NAME, format!("timew {} failed", command))?;
+
+    Ok(())
+}
+
+pub(super) fn data_directory() -> Option<PathBuf> {
+    if let Some(database) = env::var_os("TIMEWARRIORDB") {
+        return Some(PathBuf::from(database).join("data")).filter(|directory| directory.is_dir());
+    }
+
+    let home = env::var_os("HOME").map(PathBuf::from)?;
+    let legacy = home.join(".timewarrior").join("data");
+    if legacy.is_dir() {
+        return Some(legacy);
+    }
+
+    let directory = env::var_os("XDG_DATA_HOME")
+        .filter(|value| !value.is_empty())
+        .map_or_else(|| home.join(".local").join("share"), PathBuf::from)
+        .join("timewarrior")
+        .join("data");
+
+    Some(directory).filter(|directory| directory.is_dir())
+}
+
+fn parse_active(output: &str) -> Result<Activity> {
+    if output.is_empty() {
+        return Ok(Activity::Idle);
+    }
+
+    let interval = serde_json::from_str::<ActiveInterval>(output)
+        .wrap_error(FEATURE_NAME, "invalid active interval of Timewarrior")?;
+
+    let start = chrono::NaiveDateTime::parse_from_str(&interval.start, "%Y%m%dT%H%M%SZ")
+        .wrap_error(
+            FEATURE_NAME,
+            format!("invalid start {} of Timewarrior", interval.start),
+        )?;
+
+    Ok(Activity::Tracking(Interval {
+        start: start.timestamp(),
+        tag: interval.tags.join(" "),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_active_interval() {
+        assert_that!(
+            parse_active(r#"{"id":1,"start":"20240102T083000Z","tags":["dwm-status","review"]}"#),
+            is(equal_to(Ok(Activity::Tracking(Interval {
+                start: 1_704_184_200,
+                tag: "dwm-status review".to_owned(),
+            }))))
+        );
+    }
+
+    #[test]
+    fn parse_active_without_interval() {
+        assert_that!(parse_active(""), is(equal_to(Ok(Activity::Idle))));
+    }
+
+    #[test]
+    fn parse_active_with_invalid_start() {
+        assert_that!(
+            parse_active(r#"{"id":1,"start":"yesterday"}"#).is_err(),
+            is(true)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use super::Activity;
+use super::Backend;
+use super::Data;
+use crate::click;
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+
+pub(super) struct Updater {
+    activity: Option<Activity>,
+    backend: Backend,
+    client: http::Client,
+    data: Data,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    url: String,
+}
+
+impl Updater {
+    pub(super) const fn new(
+        data: Data,
+        backend: Backend,
+        client: http::Client,
+        url: String,
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+    ) -> Self {
+        Self {
+            activity: None,
+            backend,
+            client,
+            data,
+            id,
+            sender,
+            url,
+        }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        self.activity = self
+            .backend
+            .activity(&mut self.client, &self.url)
+            .show_error()
+            .ok();
+
+        self.data
+            .update(self.activity.as_ref(), chrono::Utc::now().timestamp());
+
+        Ok(())
+    }
+
+    fn click(&mut self, button: u8) -> Result<()> {
+        if click::Button::from_number(button)? != click::Button::Left {
+            return Ok(());
+        }
+
+        let tracking = self
+            .activity
+            .as_ref()
+            .map_or(false, |activity| *activity != Activity::Idle);
+
+        self.backend.toggle(tracking)?;
+
+        communication::send_message(self.id, &self.sender)
+    }
+}
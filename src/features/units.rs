@@ -0,0 +1,39 @@
+mod config;
+mod data;
+mod manager;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::config::UnitConfig;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "units";
+pub(self) const PLACEHOLDER_ICON: &str = "{Icon}";
+pub(self) const PLACEHOLDER_STATE: &str = "{State}";
+
+pub(self) type SharedStates = Arc<Mutex<Vec<Option<String>>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let states = Arc::new(Mutex::new(vec![None; settings.units.len()]));
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone(), settings.clone(), Arc::clone(&states)),
+        Updater::new(data, states),
+    )))
+}
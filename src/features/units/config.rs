@@ -0,0 +1,74 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct UnitConfig {
+    #[serde(default)]
+    pub(super) icon: Option<String>,
+    pub(super) name: String,
+}
+
+impl UnitConfig {
+    pub(super) fn icon(&self) -> &str {
+        if let Some(ref icon) = self.icon {
+            return icon;
+        }
+
+        match self.name.rfind('.') {
+            Some(index) => &self.name[..index],
+            None => &self.name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) separator: String,
+    pub(super) template: String,
+    pub(super) units: Vec<UnitConfig>,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "separator" => " ",
+                "template"  => "{Icon} {State}",
+                "units"     => Vec::<Value>::new(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("units", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("units", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("template".to_owned(), "{Icon} {State}".into());
+        map.insert("units".to_owned(), Vec::<Value>::new().into());
+
+        map
+    }
+}
@@ -0,0 +1,115 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_ICON;
+use super::PLACEHOLDER_STATE;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+const STATE_ACTIVE: &str = "active";
+const STATE_FAILED: &str = "failed";
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, states: &[Option<String>]) {
+        self.cache = self
+            .settings
+            .units
+            .iter()
+            .zip(states)
+            .filter_map(|(unit, state)| {
+                Some(
+                    self.settings
+                        .template
+                        .replace(PLACEHOLDER_ICON, unit.icon())
+                        .replace(PLACEHOLDER_STATE, state.as_ref()?),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        let count = |expected: &str| {
+            states
+                .iter()
+                .filter(|state| state.as_ref().map_or(false, |state| state == expected))
+                .count()
+        };
+
+        self.values = map_values!(
+            "ACTIVE" => count(STATE_ACTIVE),
+            "FAILED" => count(STATE_FAILED),
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::units::UnitConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            separator: " · ".to_owned(),
+            template: "{Icon} {State}".to_owned(),
+            units: vec![
+                unit(None, "syncthing.service"),
+                unit(Some("M"), "mbsync.timer"),
+                unit(None, "backup.service"),
+            ],
+        }
+    }
+
+    fn unit(icon: Option<&str>, name: &str) -> UnitConfig {
+        UnitConfig {
+            icon: icon.map(str::to_owned),
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(&[Some("active".to_owned()), Some("failed".to_owned()), None]);
+
+        assert_that!(object.render(), is(equal_to("syncthing active · M failed")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "ACTIVE" => "1",
+                "FAILED" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::error::*;
+use crate::wrapper::dbus;
+
+const INTERFACE_DBUS_PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+const INTERFACE_MANAGER: &str = "org.freedesktop.systemd1.Manager";
+const INTERFACE_UNIT: &str = "org.freedesktop.systemd1.Unit";
+const MEMBER_PROPERTIES_CHANGED: &str = "PropertiesChanged";
+const PATH_SYSTEMD: &str = "/org/freedesktop/systemd1";
+const PROPERTY_ACTIVE_STATE: &str = "ActiveState";
+const SERVICE_SYSTEMD: &str = "org.freedesktop.systemd1";
+
+pub(super) fn subscribe(connection: &dbus::Connection) -> Result<()> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_SYSTEMD,
+        PATH_SYSTEMD,
+        INTERFACE_MANAGER,
+        "Subscribe",
+    )?;
+
+    connection.send_message(message)?;
+
+    Ok(())
+}
+
+pub(super) fn load_unit(connection: &dbus::Connection, name: &str) -> Result<String> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_SYSTEMD,
+        PATH_SYSTEMD,
+        INTERFACE_MANAGER,
+        "LoadUnit",
+    )?
+    .append(name);
+
+    let response = connection.send_message(message)?;
+    let path = response.return_value::<dbus::Path<'_>>()?;
+
+    Ok((*path).to_owned())
+}
+
+pub(super) fn watch_unit(connection: &dbus::Connection, path: &str) -> Result<()> {
+    connection.add_match(dbus::Match::new(
+        INTERFACE_DBUS_PROPERTIES,
+        MEMBER_PROPERTIES_CHANGED,
+        path,
+    ))
+}
+
+pub(super) fn active_state(connection: &dbus::Connection, path: &str) -> Result<String> {
+    let message =
+        dbus::Message::init_method_call(SERVICE_SYSTEMD, path, INTERFACE_DBUS_PROPERTIES, "Get")?
+            .append(INTERFACE_UNIT)
+            .append(PROPERTY_ACTIVE_STATE);
+
+    let response = connection.send_message(message)?;
+    let state = response.return_value::<dbus::Variant<String>>()?;
+
+    Ok(state.0)
+}
@@ -0,0 +1,72 @@
+use super::ConfigEntry;
+use super::SharedStates;
+use super::manager;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::dbus;
+use crate::wrapper::thread;
+use log::debug;
+
+pub(super) struct Notifier {
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    states: SharedStates,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        settings: ConfigEntry,
+        states: SharedStates,
+    ) -> Self {
+        Self {
+            id,
+            sender,
+            settings,
+            states,
+        }
+    }
+
+    fn refresh(&self, connection: &dbus::Connection, paths: &[String]) -> Result<()> {
+        let states = paths
+            .iter()
+            .map(|path| match manager::active_state(connection, path) {
+                Ok(state) => Some(state),
+                Err(error) => {
+                    debug!("{}", error);
+                    None
+                },
+            })
+            .collect();
+
+        *self.states.lock().unwrap() = states;
+
+        communication::send_message(self.id, &self.sender)
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let connection = dbus::Connection::init_session()?;
+
+        manager::subscribe(&connection)?;
+
+        let paths = self
+            .settings
+            .units
+            .iter()
+            .map(|unit| manager::load_unit(&connection, &unit.name))
+            .collect::<Result<Vec<_>>>()?;
+
+        for path in &paths {
+            manager::watch_unit(&connection, path)?;
+        }
+
+        self.refresh(&connection, &paths)?;
+
+        connection.listen_for_signals(|_| self.refresh(&connection, &paths))
+    }
+}
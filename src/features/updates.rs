@@ -0,0 +1,52 @@
+mod backend;
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(self) use self::backend::Backend;
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "updates";
+pub(self) const PLACEHOLDER_COUNT: &str = "{Count}";
+pub(self) const PLACEHOLDER_FLATPAK: &str = "{Flatpak}";
+pub(self) const PLACEHOLDER_SNAP: &str = "{Snap}";
+
+pub(self) type SharedCounts = Arc<Mutex<Vec<Option<usize>>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let backends = settings
+        .backends
+        .iter()
+        .map(|backend| Backend::parse(backend))
+        .collect::<Result<Vec<_>>>()?;
+
+    let counts = Arc::new(Mutex::new(vec![None; backends.len()]));
+    let data = Data::new(backends.clone(), settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            backends,
+            settings.update_interval,
+            Arc::clone(&counts),
+        ),
+        Updater::new(data, counts),
+    )))
+}
@@ -0,0 +1,110 @@
+use super::FEATURE_NAME;
+use super::PLACEHOLDER_FLATPAK;
+use super::PLACEHOLDER_SNAP;
+use crate::error::*;
+use crate::wrapper::process;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Backend {
+    Flatpak,
+    Snap,
+}
+
+impl Backend {
+    pub(super) const ALL: &'static [Self] = &[Self::Flatpak, Self::Snap];
+
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "flatpak" => Ok(Self::Flatpak),
+            "snap" => Ok(Self::Snap),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown backend {}, expected flatpak or snap", name),
+            )),
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Flatpak => "FLATPAK",
+            Self::Snap => "SNAP",
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn placeholder(self) -> &'static str {
+        match self {
+            Self::Flatpak => PLACEHOLDER_FLATPAK,
+            Self::Snap => PLACEHOLDER_SNAP,
+        }
+    }
+
+    pub(super) fn pending(self) -> Result<usize> {
+        let command = match self {
+            Self::Flatpak => {
+                process::Command::new("flatpak", &["remote-ls", "--updates", "--columns=ref"])
+            },
+            Self::Snap => process::Command::new("snap", &["refresh", "--list"]),
+        };
+
+        let output = command.output().wrap_error(
+            FEATURE_NAME,
+            format!("pending updates of {:?} could not be fetched", self),
+        )?;
+
+        Ok(match self {
+            Self::Flatpak => count_flatpak(&output),
+            Self::Snap => count_snap(&output),
+        })
+    }
+}
+
+fn count_flatpak(output: &str) -> usize {
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count()
+}
+
+fn count_snap(output: &str) -> usize {
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .skip(1)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn count_flatpak_refs() {
+        let output = "\
+app/org.mozilla.firefox/x86_64/stable
+runtime/org.freedesktop.Platform/x86_64/23.08
+app/org.gimp.GIMP/x86_64/stable";
+
+        assert_that!(count_flatpak(output), is(equal_to(3)));
+        assert_that!(count_flatpak(""), is(equal_to(0)));
+    }
+
+    #[test]
+    fn count_snap_table() {
+        let output = "\
+Name     Version  Rev   Size   Publisher   Notes
+firefox  121.0    3504  252MB  mozilla✓    -
+core22   20231123 1033  77MB   canonical✓  base";
+
+        assert_that!(count_snap(output), is(equal_to(2)));
+        assert_that!(count_snap(""), is(equal_to(0)));
+    }
+
+    #[test]
+    fn parse_unknown_backend() {
+        assert_that!(Backend::parse("apt").is_err(), is(true));
+    }
+}
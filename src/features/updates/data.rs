@@ -0,0 +1,136 @@
+use super::Backend;
+use super::ConfigEntry;
+use super::PLACEHOLDER_COUNT;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    backends: Vec<Backend>,
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    pub(super) fn new(backends: Vec<Backend>, settings: ConfigEntry) -> Self {
+        Self {
+            backends,
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, counts: &[Option<usize>]) {
+        let mut values = Values::new();
+        let mut cache = self.settings.template.clone();
+
+        for backend in Backend::ALL {
+            let count = self
+                .backends
+                .iter()
+                .position(|configured| configured == backend)
+                .and_then(|index| *counts.get(index)?);
+
+            let text = match count {
+                Some(count) => {
+                    values.insert(backend.name(), count.to_string());
+                    count.to_string()
+                },
+                None => self.settings.no_value.clone(),
+            };
+
+            cache = cache.replace(backend.placeholder(), &text);
+        }
+
+        let known = counts.iter().filter_map(|count| *count).collect::<Vec<_>>();
+
+        let total = if known.is_empty() {
+            self.settings.no_value.clone()
+        } else {
+            let total = known.iter().sum::<usize>().to_string();
+            values.insert("COUNT", total.clone());
+            total
+        };
+
+        self.cache = cache.replace(PLACEHOLDER_COUNT, &total);
+        self.values = values;
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            backends: vec!["flatpak".to_owned(), "snap".to_owned()],
+            no_value: "NA".to_owned(),
+            template: "{Count} ({Flatpak}/{Snap})".to_owned(),
+            update_interval: 3600,
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(vec![Backend::Flatpak, Backend::Snap], settings());
+
+        object.update(&[Some(4), Some(1)]);
+
+        assert_that!(object.render(), is(equal_to("5 (4/1)")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "5",
+                "FLATPAK" => "4",
+                "SNAP" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_snap() {
+        let mut object = Data::new(vec![Backend::Flatpak], settings());
+
+        object.update(&[Some(2)]);
+
+        assert_that!(object.render(), is(equal_to("2 (2/NA)")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "2",
+                "FLATPAK" => "2",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_failed_backends() {
+        let mut object = Data::new(vec![Backend::Flatpak, Backend::Snap], settings());
+
+        object.update(&[None, None]);
+
+        assert_that!(object.render(), is(equal_to("NA (NA/NA)")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(vec![Backend::Flatpak, Backend::Snap], settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,54 @@
+use super::Backend;
+use super::SharedCounts;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+
+pub(super) struct Notifier {
+    backends: Vec<Backend>,
+    counts: SharedCounts,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    update_interval: u64,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        backends: Vec<Backend>,
+        update_interval: u64,
+        counts: SharedCounts,
+    ) -> Self {
+        Self {
+            backends,
+            counts,
+            id,
+            sender,
+            update_interval,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            let counts = self
+                .backends
+                .iter()
+                .map(|backend| backend.pending().show_error().ok())
+                .collect();
+
+            *self.counts.lock().unwrap() = counts;
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.update_interval)
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedCounts;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    counts: SharedCounts,
+    data: Data,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, counts: SharedCounts) -> Self {
+        Self { counts, data }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let counts = self.counts.lock().unwrap().clone();
+
+        self.data.update(&counts);
+
+        Ok(())
+    }
+}
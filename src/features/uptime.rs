@@ -0,0 +1,59 @@
+mod config;
+mod data;
+mod notifier;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::data::Status;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "uptime";
+pub(self) const PLACEHOLDER_LATENCY: &str = "{Latency}";
+pub(self) const PLACEHOLDER_NAME: &str = "{Name}";
+pub(self) const PLACEHOLDER_STATE: &str = "{State}";
+
+pub(self) type SharedStatuses = Arc<Mutex<Vec<Option<Status>>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let head = match &settings.method[..] {
+        "get" => false,
+        "head" => true,
+        method => {
+            return Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown method {}, expected get or head", method),
+            ));
+        },
+    };
+
+    let statuses = Arc::new(Mutex::new(vec![None; settings.urls.len()]));
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            http::Client::init(FEATURE_NAME, http_settings),
+            head,
+            settings.clone(),
+            Arc::clone(&statuses),
+        ),
+        Updater::new(data, settings.clone(), statuses),
+    )))
+}
@@ -0,0 +1,91 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) down: String,
+    pub(super) method: String,
+    pub(super) notification_down: NotificationConfig,
+    pub(super) notification_up: NotificationConfig,
+    pub(super) notifier: bool,
+    pub(super) separator: String,
+    pub(super) template: String,
+    pub(super) up: String,
+    pub(super) update_interval: u64,
+    pub(super) urls: Vec<String>,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "down"              => "DOWN",
+                "method"            => "head",
+                "notification_down" => NotificationConfig::default_map(
+                    "{NAME} is down",
+                    "{URL}",
+                    "critical",
+                ),
+                "notification_up"   => NotificationConfig::default_map(
+                    "{NAME} is up again",
+                    "{URL}",
+                    "normal",
+                ),
+                "notifier"          => true,
+                "separator"         => " ",
+                "template"          => "{Name} {State}",
+                "up"                => "UP",
+                "update_interval"   => 60,
+                "urls"              => Vec::<String>::new(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("uptime", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("uptime", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("down".to_owned(), "DOWN".into());
+        map.insert("method".to_owned(), "head".into());
+        map.insert(
+            "notification_down".to_owned(),
+            notification_map("{NAME} is down", "{URL}", "critical").into(),
+        );
+        map.insert(
+            "notification_up".to_owned(),
+            notification_map("{NAME} is up again", "{URL}", "normal").into(),
+        );
+        map.insert("notifier".to_owned(), true.into());
+        map.insert("separator".to_owned(), " ".into());
+        map.insert("template".to_owned(), "{Name} {State}".into());
+        map.insert("up".to_owned(), "UP".into());
+        map.insert("update_interval".to_owned(), 60.into());
+        map.insert("urls".to_owned(), Vec::<String>::new().into());
+
+        map
+    }
+}
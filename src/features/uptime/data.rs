@@ -0,0 +1,118 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_LATENCY;
+use super::PLACEHOLDER_NAME;
+use super::PLACEHOLDER_STATE;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Status {
+    Down,
+    Up(u128),
+}
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, targets: &[(&str, Status)]) {
+        self.cache = targets
+            .iter()
+            .map(|(name, status)| {
+                let (state, latency) = match status {
+                    Status::Down => (&self.settings.down, String::new()),
+                    Status::Up(latency) => (&self.settings.up, latency.to_string()),
+                };
+
+                self.settings
+                    .template
+                    .replace(PLACEHOLDER_NAME, name)
+                    .replace(PLACEHOLDER_STATE, state)
+                    .replace(PLACEHOLDER_LATENCY, &latency)
+            })
+            .collect::<Vec<_>>()
+            .join(&self.settings.separator);
+
+        let down = targets
+            .iter()
+            .filter(|(_, status)| *status == Status::Down)
+            .count();
+
+        self.values = map_values!(
+            "DOWN" => down,
+            "UP" => targets.len() - down,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings(template: &str) -> ConfigEntry {
+        ConfigEntry {
+            down: "DOWN".to_owned(),
+            method: "head".to_owned(),
+            notification_down: NotificationConfig::default(),
+            notification_up: NotificationConfig::default(),
+            notifier: false,
+            separator: " · ".to_owned(),
+            template: template.to_owned(),
+            up: "UP".to_owned(),
+            update_interval: 60,
+            urls: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings("{Name} {State} {Latency}"));
+
+        object.update(&[("example.com", Status::Up(42)), ("nas.lan", Status::Down)]);
+
+        assert_that!(
+            object.render(),
+            is(equal_to("example.com UP 42 · nas.lan DOWN "))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "DOWN" => "1",
+                "UP" => "1",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings("{Name} {State}"));
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,75 @@
+use super::ConfigEntry;
+use super::SharedStatuses;
+use super::Status;
+use crate::communication;
+use crate::error::*;
+use crate::http;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use log::info;
+
+pub(super) struct Notifier {
+    client: http::Client,
+    head: bool,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    statuses: SharedStatuses,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        client: http::Client,
+        head: bool,
+        settings: ConfigEntry,
+        statuses: SharedStatuses,
+    ) -> Self {
+        Self {
+            client,
+            head,
+            id,
+            sender,
+            settings,
+            statuses,
+        }
+    }
+
+    fn status(&self, url: &str) -> Status {
+        match self.client.probe(url, self.head) {
+            Ok(probe) if probe.status < 400 => Status::Up(probe.latency.as_millis()),
+            Ok(probe) => {
+                info!("{} responded with status {}", url, probe.status);
+                Status::Down
+            },
+            Err(error) => {
+                info!("{}", error);
+                Status::Down
+            },
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            let statuses = self
+                .settings
+                .urls
+                .iter()
+                .map(|url| Some(self.status(url)))
+                .collect();
+
+            *self.statuses.lock().unwrap() = statuses;
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.settings.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.settings.update_interval)
+    }
+}
@@ -0,0 +1,132 @@
+use super::ConfigEntry;
+use super::Data;
+use super::SharedStatuses;
+use super::Status;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+
+const PLACEHOLDER_NAME: &str = "{NAME}";
+const PLACEHOLDER_URL: &str = "{URL}";
+
+struct Target {
+    name: String,
+    notifier: notification::LazyNotifier,
+    status: Option<Status>,
+    url: String,
+}
+
+pub(super) struct Updater {
+    data: Data,
+    settings: ConfigEntry,
+    statuses: SharedStatuses,
+    targets: Vec<Target>,
+}
+
+impl Updater {
+    pub(super) fn new(data: Data, settings: ConfigEntry, statuses: SharedStatuses) -> Self {
+        let targets = settings
+            .urls
+            .iter()
+            .map(|url| Target {
+                name: host(url).to_owned(),
+                notifier: notification::LazyNotifier::default(),
+                status: None,
+                url: url.clone(),
+            })
+            .collect();
+
+        Self {
+            data,
+            settings,
+            statuses,
+            targets,
+        }
+    }
+
+    fn notify(&mut self, index: usize) -> Result<()> {
+        let target = &mut self.targets[index];
+        let config = match target.status {
+            Some(Status::Down) => &self.settings.notification_down,
+            _ => &self.settings.notification_up,
+        };
+
+        target.notifier.get()?.send(
+            config,
+            &[
+                (PLACEHOLDER_NAME, target.name.clone()),
+                (PLACEHOLDER_URL, target.url.clone()),
+            ],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let statuses = self.statuses.lock().unwrap().clone();
+
+        for (index, status) in statuses.into_iter().enumerate() {
+            let status = match status {
+                Some(status) => status,
+                None => continue,
+            };
+            let last = self.targets[index].status.replace(status);
+
+            let changed = match last {
+                Some(Status::Down) => status != Status::Down,
+                Some(Status::Up(_)) => status == Status::Down,
+                None => false,
+            };
+
+            if self.settings.notifier && changed {
+                self.notify(index).show_error_and_ignore();
+            }
+        }
+
+        let statuses = self
+            .targets
+            .iter()
+            .filter_map(|target| Some((&target.name[..], target.status?)))
+            .collect::<Vec<_>>();
+
+        self.data.update(&statuses);
+
+        Ok(())
+    }
+}
+
+fn host(url: &str) -> &str {
+    let without_scheme = url.splitn(2, "://").last().unwrap_or(url);
+
+    without_scheme
+        .split(|character| character == '/' || character == '?' || character == '#')
+        .next()
+        .unwrap_or(without_scheme)
+        .rsplitn(2, '@')
+        .next()
+        .unwrap_or(without_scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn host_of_url() {
+        assert_that!(
+            host("https://example.com/health"),
+            is(equal_to("example.com"))
+        );
+        assert_that!(
+            host("http://user@nas.lan:8080?check"),
+            is(equal_to("nas.lan:8080"))
+        );
+        assert_that!(host("example.com"), is(equal_to("example.com")));
+    }
+}
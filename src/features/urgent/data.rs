@@ -0,0 +1,89 @@
+use super::PLACEHOLDER_COUNT;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(template: String) -> Self {
+        Self {
+            cache: String::new(),
+            template,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, count: Option<usize>) {
+        self.cache = match count {
+            Some(count) if count > 0 => {
+                self.template.replace(PLACEHOLDER_COUNT, &count.to_string())
+            },
+            _ => String::new(),
+        };
+
+        self.values = match count {
+            Some(count) => map_values!("COUNT" => count),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new("! {Count}".to_owned());
+
+        object.update(Some(2));
+
+        assert_that!(object.render(), is(equal_to("! 2")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "2",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_urgent_windows() {
+        let mut object = Data::new("! {Count}".to_owned());
+
+        object.update(Some(0));
+
+        assert_that!(object.render(), is(equal_to("")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "0",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new("! {Count}".to_owned());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+}
@@ -0,0 +1,46 @@
+use super::SharedCount;
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use crate::wrapper::xclients;
+
+pub(super) struct Notifier {
+    count: SharedCount,
+    id: usize,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl Notifier {
+    pub(super) const fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        count: SharedCount,
+    ) -> Self {
+        Self { count, id, sender }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        let clients = xclients::Clients::open()?;
+
+        loop {
+            let count = Some(clients.urgent_count());
+
+            let changed = {
+                let mut shared = self.count.lock().unwrap();
+                let changed = *shared != count;
+                *shared = count;
+
+                changed
+            };
+
+            if changed {
+                communication::send_message(self.id, &self.sender)?;
+            }
+
+            clients.wait_for_change();
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedCount;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    count: SharedCount,
+    data: Data,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, count: SharedCount) -> Self {
+        Self { count, data }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let count = *self.count.lock().unwrap();
+
+        self.data.update(count);
+
+        Ok(())
+    }
+}
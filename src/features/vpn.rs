@@ -0,0 +1,39 @@
+mod config;
+mod data;
+mod notifier;
+mod tunnel;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use crate::wrapper::dbus;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "vpn";
+pub(self) const PLACEHOLDER_COUNT: &str = "{Count}";
+pub(self) const PLACEHOLDER_NAME: &str = "{Name}";
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let connection = if settings.networkmanager {
+        Some(dbus::Connection::init()?)
+    } else {
+        None
+    };
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(id, sender.clone()),
+        Updater::new(data, connection, settings.clone()),
+    )))
+}
@@ -0,0 +1,78 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) interfaces: Vec<String>,
+    pub(super) networkmanager: bool,
+    pub(super) no_value: String,
+    pub(super) no_vpn: String,
+    pub(super) notification: NotificationConfig,
+    pub(super) notifier: bool,
+    pub(super) separator: String,
+    pub(super) template: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "interfaces"     => Vec::<String>::new(),
+                "networkmanager" => false,
+                "no_value"       => "NA",
+                "no_vpn"         => "NO VPN",
+                "notification"   => NotificationConfig::default_map(
+                    "VPN disconnected",
+                    "The tunnel {NAME} is down",
+                    "normal",
+                ),
+                "notifier"       => true,
+                "separator"      => ", ",
+                "template"       => "VPN {Name}",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use crate::wrapper::config::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("vpn", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("vpn", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("interfaces".to_owned(), Vec::<String>::new().into());
+        map.insert("networkmanager".to_owned(), false.into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("no_vpn".to_owned(), "NO VPN".into());
+        map.insert(
+            "notification".to_owned(),
+            notification_map("VPN disconnected", "The tunnel {NAME} is down", "normal").into(),
+        );
+        map.insert("notifier".to_owned(), true.into());
+        map.insert("separator".to_owned(), ", ".into());
+        map.insert("template".to_owned(), "VPN {Name}".into());
+
+        map
+    }
+}
@@ -0,0 +1,127 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_COUNT;
+use super::PLACEHOLDER_NAME;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, tunnels: Option<&[String]>) {
+        self.cache = match tunnels {
+            Some(tunnels) if tunnels.is_empty() => self.settings.no_vpn.clone(),
+            Some(tunnels) => self
+                .settings
+                .template
+                .replace(PLACEHOLDER_COUNT, &tunnels.len().to_string())
+                .replace(PLACEHOLDER_NAME, &tunnels.join(&self.settings.separator)),
+            None => self
+                .settings
+                .template
+                .replace(PLACEHOLDER_COUNT, &self.settings.no_value)
+                .replace(PLACEHOLDER_NAME, &self.settings.no_value),
+        };
+
+        self.values = match tunnels {
+            Some(tunnels) => map_values!(
+                "COUNT" => tunnels.len(),
+                "NAME" => tunnels.join(&self.settings.separator),
+            ),
+            None => Values::new(),
+        };
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notification::NotificationConfig;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn settings() -> ConfigEntry {
+        ConfigEntry {
+            interfaces: Vec::new(),
+            networkmanager: false,
+            no_value: "NA".to_owned(),
+            no_vpn: "NO VPN".to_owned(),
+            notification: NotificationConfig::default(),
+            notifier: true,
+            separator: ", ".to_owned(),
+            template: "VPN {Count} {Name}".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&["tun0".to_owned(), "wg0".to_owned()]));
+
+        assert_that!(object.render(), is(equal_to("VPN 2 tun0, wg0")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "2",
+                "NAME" => "tun0, wg0",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_tunnels() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&[]));
+
+        assert_that!(object.render(), is(equal_to("NO VPN")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "COUNT" => "0",
+                "NAME" => "",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_of_unknown_tunnels() {
+        let mut object = Data::new(settings());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("VPN NA NA")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
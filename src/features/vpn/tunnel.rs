@@ -0,0 +1,129 @@
+use crate::error::*;
+use crate::wrapper::dbus;
+use std::fs;
+use std::path::Path;
+
+const INTERFACE_ACTIVE_CONNECTION: &str = "org.freedesktop.NetworkManager.Connection.Active";
+const INTERFACE_DBUS_PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+const INTERFACE_NETWORK_MANAGER: &str = "org.freedesktop.NetworkManager";
+const PATH_NET: &str = "/sys/class/net";
+const PATH_NETWORK_MANAGER: &str = "/org/freedesktop/NetworkManager";
+const SERVICE_NETWORK_MANAGER: &str = "org.freedesktop.NetworkManager";
+
+const FLAG_UP: u32 = 0x1;
+const STATE_ACTIVATED: u32 = 2;
+const TYPES_VPN: &[&str] = &["vpn", "wireguard"];
+
+pub(super) fn interfaces(interfaces: &[String]) -> Vec<String> {
+    let entries = match fs::read_dir(PATH_NET) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut names = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| interfaces.is_empty() || interfaces.contains(name))
+        .filter(|name| {
+            let path = Path::new(PATH_NET).join(name);
+
+            let uevent = fs::read_to_string(path.join("uevent")).unwrap_or_default();
+            let flags = fs::read_to_string(path.join("flags")).unwrap_or_default();
+
+            is_tunnel(&uevent, path.join("tun_flags").exists()) && is_up(&flags)
+        })
+        .collect::<Vec<_>>();
+
+    names.sort();
+
+    names
+}
+
+pub(super) fn connections(connection: &dbus::Connection) -> Result<Vec<String>> {
+    let response = get(
+        connection,
+        PATH_NETWORK_MANAGER,
+        INTERFACE_NETWORK_MANAGER,
+        "ActiveConnections",
+    )?;
+    let paths = response.return_value::<dbus::Variant<Vec<dbus::Path<'_>>>>()?;
+
+    let mut names = Vec::new();
+
+    for path in paths.0.iter() {
+        let kind = get(connection, path, INTERFACE_ACTIVE_CONNECTION, "Type")?
+            .return_value::<dbus::Variant<String>>()?;
+        let state = get(connection, path, INTERFACE_ACTIVE_CONNECTION, "State")?
+            .return_value::<dbus::Variant<u32>>()?;
+
+        if !TYPES_VPN.contains(&kind.0.as_str()) || state.0 != STATE_ACTIVATED {
+            continue;
+        }
+
+        let name = get(connection, path, INTERFACE_ACTIVE_CONNECTION, "Id")?
+            .return_value::<dbus::Variant<String>>()?;
+
+        names.push(name.0);
+    }
+
+    names.sort();
+
+    Ok(names)
+}
+
+fn get(
+    connection: &dbus::Connection,
+    path: &str,
+    interface: &'static str,
+    property: &'static str,
+) -> Result<dbus::Message> {
+    let message = dbus::Message::init_method_call(
+        SERVICE_NETWORK_MANAGER,
+        path,
+        INTERFACE_DBUS_PROPERTIES,
+        "Get",
+    )?
+    .append(interface)
+    .append(property);
+
+    connection.send_message(message)
+}
+
+fn is_tunnel(uevent: &str, tun_flags: bool) -> bool {
+    tun_flags
+        || uevent
+            .lines()
+            .any(|line| line.trim() == "DEVTYPE=wireguard")
+}
+
+fn is_up(flags: &str) -> bool {
+    u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16)
+        .map_or(false, |flags| flags & FLAG_UP != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn is_tunnel_of_interfaces() {
+        assert_that!(
+            is_tunnel("DEVTYPE=wireguard\nINTERFACE=wg0\nIFINDEX=5\n", false),
+            is(true)
+        );
+        assert_that!(is_tunnel("INTERFACE=tun0\nIFINDEX=6\n", true), is(true));
+        assert_that!(
+            is_tunnel("DEVTYPE=wlan\nINTERFACE=wlp3s0\nIFINDEX=3\n", false),
+            is(false)
+        );
+    }
+
+    #[test]
+    fn is_up_of_flags() {
+        assert_that!(is_up("0x1091\n"), is(true));
+        assert_that!(is_up("0x1090\n"), is(false));
+        assert_that!(is_up(""), is(false));
+    }
+}
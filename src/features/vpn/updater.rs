@@ -0,0 +1,76 @@
+use super::ConfigEntry;
+use super::Data;
+use super::tunnel;
+use crate::error::*;
+use crate::feature;
+use crate::notification;
+use crate::wrapper::dbus;
+
+const PLACEHOLDER_NAME: &str = "{NAME}";
+
+pub(super) struct Updater {
+    connection: Option<dbus::Connection>,
+    data: Data,
+    notifier: notification::LazyNotifier,
+    settings: ConfigEntry,
+    tunnels: Vec<String>,
+}
+
+impl Updater {
+    pub(super) fn new(
+        data: Data,
+        connection: Option<dbus::Connection>,
+        settings: ConfigEntry,
+    ) -> Self {
+        Self {
+            connection,
+            data,
+            notifier: notification::LazyNotifier::default(),
+            settings,
+            tunnels: Vec::new(),
+        }
+    }
+
+    fn notify(&mut self, name: &str) -> Result<()> {
+        let notifier = self.notifier.get()?;
+
+        notifier.send(
+            &self.settings.notification,
+            &[(PLACEHOLDER_NAME, name.to_owned())],
+        )
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let tunnels = match &self.connection {
+            Some(connection) => tunnel::connections(connection).show_error().ok(),
+            None => Some(tunnel::interfaces(&self.settings.interfaces)),
+        };
+
+        if let Some(tunnels) = &tunnels {
+            let dropped = self
+                .tunnels
+                .iter()
+                .filter(|name| !tunnels.contains(name))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if self.settings.notifier {
+                for name in &dropped {
+                    self.notify(name).show_error_and_ignore();
+                }
+            }
+
+            self.tunnels = tunnels.clone();
+        }
+
+        self.data.update(tunnels.as_deref());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+mod config;
+mod data;
+mod notifier;
+mod provider;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::http;
+use crate::wrapper::channel;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::notifier::Notifier;
+pub(self) use self::provider::Provider;
+pub(self) use self::provider::Units;
+pub(self) use self::provider::Weather;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "weather";
+pub(self) const PLACEHOLDER_CONDITION: &str = "{Condition}";
+pub(self) const PLACEHOLDER_HUMIDITY: &str = "{Humidity}";
+pub(self) const PLACEHOLDER_TEMP: &str = "{Temp}";
+pub(self) const PLACEHOLDER_WIND: &str = "{Wind}";
+
+pub(self) type SharedWeather = Arc<Mutex<Option<Weather>>>;
+
+pub(super) fn create(
+    id: usize,
+    sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+    http_settings: &http::ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let provider = Provider::parse(&settings.provider)?;
+    let units = Units::parse(&settings.units)?;
+
+    if settings.latitude == 0.0 && settings.longitude == 0.0 {
+        return Err(Error::new_custom(
+            FEATURE_NAME,
+            "latitude and longitude are required",
+        ));
+    }
+
+    if provider == Provider::OpenWeatherMap && settings.api_key.is_empty() {
+        return Err(Error::new_custom(
+            FEATURE_NAME,
+            "api_key is required for openweathermap",
+        ));
+    }
+
+    let client = http::Client::init(FEATURE_NAME, http_settings);
+    let weather = SharedWeather::default();
+    let data = Data::new(settings.clone());
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        Notifier::new(
+            id,
+            sender.clone(),
+            client,
+            provider,
+            units,
+            settings.clone(),
+            Arc::clone(&weather),
+        ),
+        Updater::new(data, weather),
+    )))
+}
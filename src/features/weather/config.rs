@@ -0,0 +1,78 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) api_key: String,
+    pub(super) conditions: HashMap<String, String>,
+    pub(super) latitude: f64,
+    pub(super) longitude: f64,
+    pub(super) max_age: u64,
+    pub(super) no_value: String,
+    pub(super) provider: String,
+    pub(super) template: String,
+    pub(super) units: String,
+    pub(super) update_interval: u64,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            FEATURE_NAME,
+            map!(
+                "api_key"         => "",
+                "conditions"      => HashMap::<String, Value>::new(),
+                "latitude"        => 0.0,
+                "longitude"       => 0.0,
+                "max_age"         => 600,
+                "no_value"        => "NA",
+                "provider"        => "open-meteo",
+                "template"        => "{Condition} {Temp}°",
+                "units"           => "metric",
+                "update_interval" => 300,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("weather", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("weather", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("api_key".to_owned(), "".into());
+        map.insert(
+            "conditions".to_owned(),
+            HashMap::<String, Value>::new().into(),
+        );
+        map.insert("latitude".to_owned(), 0.0.into());
+        map.insert("longitude".to_owned(), 0.0.into());
+        map.insert("max_age".to_owned(), 600.into());
+        map.insert("no_value".to_owned(), "NA".into());
+        map.insert("provider".to_owned(), "open-meteo".into());
+        map.insert("template".to_owned(), "{Condition} {Temp}°".into());
+        map.insert("units".to_owned(), "metric".into());
+        map.insert("update_interval".to_owned(), 300.into());
+
+        map
+    }
+}
@@ -0,0 +1,171 @@
+use super::ConfigEntry;
+use super::PLACEHOLDER_CONDITION;
+use super::PLACEHOLDER_HUMIDITY;
+use super::PLACEHOLDER_TEMP;
+use super::PLACEHOLDER_WIND;
+use super::Weather;
+use crate::feature::Renderable;
+use crate::feature::Values;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    settings: ConfigEntry,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(settings: ConfigEntry) -> Self {
+        Self {
+            cache: String::new(),
+            settings,
+            values: Values::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, weather: Option<&Weather>) {
+        let weather = match weather {
+            Some(weather) => weather,
+            None => {
+                self.cache = [
+                    PLACEHOLDER_CONDITION,
+                    PLACEHOLDER_HUMIDITY,
+                    PLACEHOLDER_TEMP,
+                    PLACEHOLDER_WIND,
+                ]
+                .iter()
+                .fold(self.settings.template.clone(), |cache, placeholder| {
+                    cache.replace(placeholder, &self.settings.no_value)
+                });
+                self.values = Values::new();
+
+                return;
+            },
+        };
+
+        let name = weather.condition.name();
+        let condition = self
+            .settings
+            .conditions
+            .get(name)
+            .map_or(name, String::as_str);
+        let humidity = round(weather.humidity);
+        let temperature = round(weather.temperature);
+        let wind = round(weather.wind);
+
+        self.cache = self
+            .settings
+            .template
+            .replace(PLACEHOLDER_CONDITION, condition)
+            .replace(PLACEHOLDER_HUMIDITY, &humidity.to_string())
+            .replace(PLACEHOLDER_TEMP, &temperature.to_string())
+            .replace(PLACEHOLDER_WIND, &wind.to_string());
+
+        self.values = map_values!(
+            "CONDITION" => name,
+            "HUMIDITY" => humidity,
+            "TEMP" => temperature,
+            "WIND" => wind,
+        );
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn round(value: f64) -> i64 {
+    value.round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::weather::provider::Condition;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::collections::HashMap;
+
+    fn settings() -> ConfigEntry {
+        let mut conditions = HashMap::new();
+        conditions.insert("rain".to_owned(), "☂".to_owned());
+
+        ConfigEntry {
+            api_key: String::new(),
+            conditions,
+            latitude: 52.52,
+            longitude: 13.41,
+            max_age: 600,
+            no_value: "NA".to_owned(),
+            provider: "open-meteo".to_owned(),
+            template: "{Condition} {Temp}° {Humidity}% {Wind} km/h".to_owned(),
+            units: "metric".to_owned(),
+            update_interval: 300,
+        }
+    }
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(settings());
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Weather {
+            condition: Condition::PartlyCloudy,
+            humidity: 52.0,
+            temperature: -0.3,
+            wind: 11.6,
+        }));
+
+        assert_that!(
+            object.render(),
+            is(equal_to("partly_cloudy 0° 52% 12 km/h"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "CONDITION" => "partly_cloudy",
+                "HUMIDITY" => "52",
+                "TEMP" => "0",
+                "WIND" => "12",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_and_configured_condition() {
+        let mut object = Data::new(settings());
+
+        object.update(Some(&Weather {
+            condition: Condition::Rain,
+            humidity: 87.0,
+            temperature: 12.5,
+            wind: 20.2,
+        }));
+
+        assert_that!(object.render(), is(equal_to("☂ 13° 87% 20 km/h")));
+    }
+
+    #[test]
+    fn render_with_update_without_weather() {
+        let mut object = Data::new(settings());
+
+        object.update(None);
+
+        assert_that!(object.render(), is(equal_to("NA NA° NA% NA km/h")));
+        assert_that!(object.values(), is(equal_to(Values::new())));
+    }
+}
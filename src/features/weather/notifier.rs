@@ -0,0 +1,68 @@
+use super::ConfigEntry;
+use super::Provider;
+use super::SharedWeather;
+use super::Units;
+use super::provider;
+use crate::communication;
+use crate::error::*;
+use crate::http;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use std::sync::Mutex;
+
+pub(super) struct Notifier {
+    client: Mutex<http::Client>,
+    id: usize,
+    provider: Provider,
+    sender: channel::Sender<communication::Message>,
+    settings: ConfigEntry,
+    units: Units,
+    weather: SharedWeather,
+}
+
+impl Notifier {
+    pub(super) fn new(
+        id: usize,
+        sender: channel::Sender<communication::Message>,
+        client: http::Client,
+        provider: Provider,
+        units: Units,
+        settings: ConfigEntry,
+        weather: SharedWeather,
+    ) -> Self {
+        Self {
+            client: Mutex::new(client),
+            id,
+            provider,
+            sender,
+            settings,
+            units,
+            weather,
+        }
+    }
+}
+
+impl thread::Runnable for Notifier {
+    fn run(&self) -> Result<()> {
+        loop {
+            let weather = provider::current(
+                &mut self.client.lock().unwrap(),
+                self.provider,
+                self.units,
+                &self.settings,
+            )
+            .show_error()
+            .ok();
+
+            *self.weather.lock().unwrap() = weather;
+
+            communication::send_message(self.id, &self.sender)?;
+
+            thread::sleep_secs(self.settings.update_interval);
+        }
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.settings.update_interval)
+    }
+}
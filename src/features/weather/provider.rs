@@ -0,0 +1,316 @@
+use super::ConfigEntry;
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::http;
+use serde_derive::*;
+
+const URL_OPEN_METEO: &str = "https://api.open-meteo.com/v1/forecast";
+const URL_OPENWEATHERMAP: &str = "https://api.openweathermap.org/data/2.5/weather";
+
+const KMH_PER_MPS: f64 = 3.6;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Provider {
+    OpenMeteo,
+    OpenWeatherMap,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Units {
+    Imperial,
+    Metric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Condition {
+    Clear,
+    Cloudy,
+    Drizzle,
+    Fog,
+    PartlyCloudy,
+    Rain,
+    Snow,
+    Thunderstorm,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Weather {
+    pub(super) condition: Condition,
+    pub(super) humidity: f64,
+    pub(super) temperature: f64,
+    pub(super) wind: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoCurrent {
+    relative_humidity_2m: f64,
+    temperature_2m: f64,
+    weather_code: u32,
+    wind_speed_10m: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoResponse {
+    current: OpenMeteoCurrent,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenWeatherMapCondition {
+    id: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenWeatherMapMain {
+    humidity: f64,
+    temp: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenWeatherMapWind {
+    speed: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenWeatherMapResponse {
+    main: OpenWeatherMapMain,
+    weather: Vec<OpenWeatherMapCondition>,
+    wind: OpenWeatherMapWind,
+}
+
+impl Condition {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Clear => "clear",
+            Self::Cloudy => "cloudy",
+            Self::Drizzle => "drizzle",
+            Self::Fog => "fog",
+            Self::PartlyCloudy => "partly_cloudy",
+            Self::Rain => "rain",
+            Self::Snow => "snow",
+            Self::Thunderstorm => "thunderstorm",
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn from_wmo(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Clear),
+            1 | 2 => Some(Self::PartlyCloudy),
+            3 => Some(Self::Cloudy),
+            45 | 48 => Some(Self::Fog),
+            51..=57 => Some(Self::Drizzle),
+            61..=67 | 80..=82 => Some(Self::Rain),
+            71..=77 | 85 | 86 => Some(Self::Snow),
+            95..=99 => Some(Self::Thunderstorm),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn from_openweathermap(id: u32) -> Option<Self> {
+        match id {
+            200..=299 => Some(Self::Thunderstorm),
+            300..=399 => Some(Self::Drizzle),
+            500..=599 => Some(Self::Rain),
+            600..=699 => Some(Self::Snow),
+            700..=799 => Some(Self::Fog),
+            800 => Some(Self::Clear),
+            801 | 802 => Some(Self::PartlyCloudy),
+            803 | 804 => Some(Self::Cloudy),
+            _ => None,
+        }
+    }
+}
+
+impl Provider {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "open-meteo" => Ok(Self::OpenMeteo),
+            "openweathermap" => Ok(Self::OpenWeatherMap),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!(
+                    "unknown provider {}, expected open-meteo or openweathermap",
+                    name
+                ),
+            )),
+        }
+    }
+
+    fn url(self, settings: &ConfigEntry, units: Units) -> String {
+        match self {
+            Self::OpenMeteo => {
+                let units = match units {
+                    Units::Imperial => "&temperature_unit=fahrenheit&wind_speed_unit=mph",
+                    Units::Metric => "",
+                };
+
+                format!(
+                    "{}?latitude={}&longitude={}&current=temperature_2m,relative_humidity_2m,\
+                     weather_code,wind_speed_10m{}",
+                    URL_OPEN_METEO, settings.latitude, settings.longitude, units
+                )
+            },
+            Self::OpenWeatherMap => format!(
+                "{}?lat={}&lon={}&units={}&appid={}",
+                URL_OPENWEATHERMAP,
+                settings.latitude,
+                settings.longitude,
+                units.name(),
+                settings.api_key
+            ),
+        }
+    }
+
+    fn parse_weather(self, body: &str, units: Units) -> Result<Weather> {
+        match self {
+            Self::OpenMeteo => {
+                let current = serde_json::from_str::<OpenMeteoResponse>(body)
+                    .wrap_error(FEATURE_NAME, "invalid response of Open-Meteo")?
+                    .current;
+
+                Ok(Weather {
+                    condition: Condition::from_wmo(current.weather_code).wrap_error(
+                        FEATURE_NAME,
+                        format!("unknown weather code {}", current.weather_code),
+                    )?,
+                    humidity: current.relative_humidity_2m,
+                    temperature: current.temperature_2m,
+                    wind: current.wind_speed_10m,
+                })
+            },
+            Self::OpenWeatherMap => {
+                let response = serde_json::from_str::<OpenWeatherMapResponse>(body)
+                    .wrap_error(FEATURE_NAME, "invalid response of OpenWeatherMap")?;
+
+                let id = response
+                    .weather
+                    .first()
+                    .map(|condition| condition.id)
+                    .wrap_error(FEATURE_NAME, "missing condition of OpenWeatherMap")?;
+
+                let wind = match units {
+                    Units::Imperial => response.wind.speed,
+                    Units::Metric => response.wind.speed * KMH_PER_MPS,
+                };
+
+                Ok(Weather {
+                    condition: Condition::from_openweathermap(id)
+                        .wrap_error(FEATURE_NAME, format!("unknown condition id {}", id))?,
+                    humidity: response.main.humidity,
+                    temperature: response.main.temp,
+                    wind,
+                })
+            },
+        }
+    }
+}
+
+impl Units {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name {
+            "imperial" => Ok(Self::Imperial),
+            "metric" => Ok(Self::Metric),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown units {}, expected imperial or metric", name),
+            )),
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    fn name(self) -> &'static str {
+        match self {
+            Self::Imperial => "imperial",
+            Self::Metric => "metric",
+        }
+    }
+}
+
+pub(super) fn current(
+    client: &mut http::Client,
+    provider: Provider,
+    units: Units,
+    settings: &ConfigEntry,
+) -> Result<Weather> {
+    let body = client.get(&provider.url(settings, units), settings.max_age)?;
+
+    provider.parse_weather(&body, units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_weather_of_open_meteo() {
+        let body = r#"{"latitude":52.52,"longitude":13.419998,"current_units":{"temperature_2m":"°C"},
+            "current":{"time":"2024-05-01T12:00","interval":900,"temperature_2m":18.4,
+            "relative_humidity_2m":52,"weather_code":61,"wind_speed_10m":11.2}}"#;
+
+        assert_that!(
+            Provider::OpenMeteo.parse_weather(body, Units::Metric),
+            is(equal_to(Ok(Weather {
+                condition: Condition::Rain,
+                humidity: 52.0,
+                temperature: 18.4,
+                wind: 11.2,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_weather_of_openweathermap_in_metric_units() {
+        let body = r#"{"weather":[{"id":803,"main":"Clouds","description":"broken clouds"}],
+            "main":{"temp":18.4,"feels_like":17.9,"humidity":52},"wind":{"speed":2.5,"deg":240},
+            "name":"Berlin"}"#;
+
+        assert_that!(
+            Provider::OpenWeatherMap.parse_weather(body, Units::Metric),
+            is(equal_to(Ok(Weather {
+                condition: Condition::Cloudy,
+                humidity: 52.0,
+                temperature: 18.4,
+                wind: 9.0,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_weather_with_invalid_response() {
+        assert_that!(
+            Provider::OpenMeteo
+                .parse_weather(
+                    r#"{"error":true,"reason":"Latitude must be in range"}"#,
+                    Units::Metric
+                )
+                .is_err(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn conditions_of_codes() {
+        assert_that!(
+            Condition::from_wmo(2),
+            is(equal_to(Some(Condition::PartlyCloudy)))
+        );
+        assert_that!(
+            Condition::from_wmo(96),
+            is(equal_to(Some(Condition::Thunderstorm)))
+        );
+        assert_that!(
+            Condition::from_openweathermap(741),
+            is(equal_to(Some(Condition::Fog)))
+        );
+        assert_that!(Condition::from_openweathermap(100), is(none()));
+    }
+
+    #[test]
+    fn parse_unknown_provider() {
+        assert_that!(Provider::parse("wttr.in").is_err(), is(true));
+    }
+}
@@ -0,0 +1,29 @@
+use super::Data;
+use super::SharedWeather;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    weather: SharedWeather,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, weather: SharedWeather) -> Self {
+        Self { data, weather }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let weather = self.weather.lock().unwrap().clone();
+
+        self.data.update(weather.as_ref());
+
+        Ok(())
+    }
+}
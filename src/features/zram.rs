@@ -0,0 +1,43 @@
+mod config;
+mod data;
+mod stats;
+mod updater;
+
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::locale;
+use crate::scheduler;
+use crate::wrapper::channel;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::data::Data;
+pub(self) use self::stats::Source;
+pub(self) use self::stats::Stats;
+pub(self) use self::updater::Updater;
+
+pub(super) const FEATURE_NAME: &str = "zram";
+pub(self) const PLACEHOLDER_MEMORY: &str = "{Memory}";
+pub(self) const PLACEHOLDER_RATIO: &str = "{Ratio}";
+pub(self) const PLACEHOLDER_SIZE: &str = "{Size}";
+pub(self) const PLACEHOLDER_USED: &str = "{Used}";
+
+pub(super) fn create(
+    _id: usize,
+    _sender: &channel::Sender<communication::Message>,
+    settings: &ConfigEntry,
+) -> Result<Box<dyn feature::Feature>> {
+    let source = Source::init(&settings.source)?;
+
+    let data = Data::new(
+        settings.template.clone(),
+        settings.no_value.clone(),
+        locale::decimal_separator(),
+    );
+
+    Ok(Box::new(feature::Composer::new(
+        FEATURE_NAME,
+        scheduler::Interval::new(settings.update_interval),
+        Updater::new(data, source),
+    )))
+}
@@ -0,0 +1,141 @@
+use super::PLACEHOLDER_MEMORY;
+use super::PLACEHOLDER_RATIO;
+use super::PLACEHOLDER_SIZE;
+use super::PLACEHOLDER_USED;
+use super::Stats;
+use crate::feature::Renderable;
+use crate::feature::Values;
+use crate::locale;
+use crate::utils;
+
+#[derive(Debug)]
+pub(super) struct Data {
+    cache: String,
+    decimal_separator: Option<String>,
+    no_value: String,
+    template: String,
+    values: Values,
+}
+
+impl Data {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(super) fn new(
+        template: String,
+        no_value: String,
+        decimal_separator: Option<String>,
+    ) -> Self {
+        Self {
+            cache: String::new(),
+            decimal_separator,
+            no_value,
+            template,
+            values: Values::new(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub(super) fn update(&mut self, stats: &Stats) {
+        let separator = self.decimal_separator.as_ref().map(String::as_str);
+        let ratio = if stats.memory > 0 {
+            Some(locale::format_decimal(
+                (stats.used as f64 / stats.memory as f64) as f32,
+                2,
+                separator,
+            ))
+        } else {
+            None
+        };
+        let size = stats.size.map(utils::format_bytes);
+
+        self.cache = self
+            .template
+            .replace(PLACEHOLDER_MEMORY, &utils::format_bytes(stats.memory))
+            .replace(PLACEHOLDER_RATIO, ratio.as_ref().unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_SIZE, size.as_ref().unwrap_or(&self.no_value))
+            .replace(PLACEHOLDER_USED, &utils::format_bytes(stats.used));
+
+        self.values = map_values!(
+            "MEMORY" => stats.memory,
+            "USED" => stats.used,
+        );
+
+        if let Some(ratio) = ratio {
+            self.values.insert("RATIO", ratio);
+        }
+
+        if let Some(size) = stats.size {
+            self.values.insert("SIZE", size.to_string());
+        }
+    }
+}
+
+impl Renderable for Data {
+    fn render(&self) -> &str {
+        &self.cache
+    }
+
+    fn values(&self) -> Values {
+        self.values.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const TEMPLATE: &str = "Z {Used}/{Size} {Memory} x{Ratio}";
+
+    #[test]
+    fn render_with_default() {
+        let object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        assert_that!(object.render(), is(equal_to("")));
+    }
+
+    #[test]
+    fn render_with_update() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), None);
+
+        object.update(&Stats {
+            memory: 256 * 1024 * 1024,
+            size: Some(4 * 1024 * 1024 * 1024),
+            used: 1024 * 1024 * 1024,
+        });
+
+        assert_that!(
+            object.render(),
+            is(equal_to("Z 1.0 GiB/4.0 GiB 256.0 MiB x4.00"))
+        );
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "MEMORY" => "268435456",
+                "RATIO" => "4.00",
+                "SIZE" => "4294967296",
+                "USED" => "1073741824",
+            )))
+        );
+    }
+
+    #[test]
+    fn render_with_update_without_data() {
+        let mut object = Data::new(TEMPLATE.to_owned(), "NA".to_owned(), Some(",".to_owned()));
+
+        object.update(&Stats {
+            memory: 0,
+            size: None,
+            used: 0,
+        });
+
+        assert_that!(object.render(), is(equal_to("Z 0 B/NA 0 B xNA")));
+        assert_that!(
+            object.values(),
+            is(equal_to(map_values!(
+                "MEMORY" => "0",
+                "USED" => "0",
+            )))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use super::FEATURE_NAME;
+use crate::error::*;
+use crate::wrapper::file;
+use std::fs;
+use std::path;
+
+const PATH_BLOCK: &str = "/sys/block";
+const PATH_MEMINFO: &str = "/proc/meminfo";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Stats {
+    pub(super) memory: u64,
+    pub(super) size: Option<u64>,
+    pub(super) used: u64,
+}
+
+#[derive(Debug)]
+pub(super) enum Source {
+    Zram,
+    Zswap,
+}
+
+impl Source {
+    pub(super) fn init(source: &str) -> Result<Self> {
+        match source {
+            "auto" if zram_devices().next().is_some() => Ok(Self::Zram),
+            "auto" | "zswap" => Ok(Self::Zswap),
+            "zram" => Ok(Self::Zram),
+            _ => Err(Error::new_custom(
+                FEATURE_NAME,
+                format!("unknown source {}, expected auto, zram or zswap", source),
+            )),
+        }
+    }
+
+    pub(super) fn stats(&self) -> Result<Stats> {
+        match self {
+            Self::Zram => {
+                let mut stats = Stats {
+                    memory: 0,
+                    size: Some(0),
+                    used: 0,
+                };
+
+                for device in zram_devices() {
+                    let size = file::parse_file_content::<_, u64>(device.join("disksize"))
+                        .unwrap_or_default();
+
+                    if size == 0 {
+                        continue;
+                    }
+
+                    let mm_stat = file::read(device.join("mm_stat")).wrap_error(
+                        FEATURE_NAME,
+                        format!("mm_stat of {} could not be read", device.display()),
+                    )?;
+
+                    let (used, memory) = parse_mm_stat(&mm_stat).wrap_error(
+                        FEATURE_NAME,
+                        format!("invalid mm_stat of {}", device.display()),
+                    )?;
+
+                    stats.memory += memory;
+                    stats.size = stats.size.map(|total| total + size);
+                    stats.used += used;
+                }
+
+                Ok(stats)
+            },
+            Self::Zswap => {
+                let meminfo = file::read(PATH_MEMINFO).wrap_error(
+                    FEATURE_NAME,
+                    format!("memory info could not be read from {}", PATH_MEMINFO),
+                )?;
+
+                parse_meminfo(&meminfo)
+                    .wrap_error(FEATURE_NAME, "zswap is not supported by the kernel")
+            },
+        }
+    }
+}
+
+fn zram_devices() -> impl Iterator<Item = path::PathBuf> {
+    fs::read_dir(PATH_BLOCK)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+
+            if name.starts_with("zram") {
+                Some(entry.path())
+            } else {
+                None
+            }
+        })
+}
+
+fn parse_mm_stat(content: &str) -> Option<(u64, u64)> {
+    let columns = content.split_whitespace().collect::<Vec<_>>();
+
+    let used = columns.first()?.parse().ok()?;
+    let memory = columns.get(2)?.parse().ok()?;
+
+    Some((used, memory))
+}
+
+fn parse_meminfo(content: &str) -> Option<Stats> {
+    let value = |key: &str| {
+        content.lines().find_map(|line| {
+            let mut parts = line.splitn(2, ':');
+
+            if parts.next()? != key {
+                return None;
+            }
+
+            let kib = parts.next()?.trim().trim_end_matches("kB").trim();
+            kib.parse::<u64>().ok().map(|kib| kib * 1024)
+        })
+    };
+
+    Some(Stats {
+        memory: value("Zswap")?,
+        size: None,
+        used: value("Zswapped")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_mm_stat_with_columns() {
+        let content =
+            "1056768000  293601280  301989888        0  310378496    12345     0     42\n";
+
+        assert_that!(
+            parse_mm_stat(content),
+            is(equal_to(Some((1_056_768_000, 301_989_888))))
+        );
+    }
+
+    #[test]
+    fn parse_meminfo_with_zswap() {
+        let content = "\
+MemTotal:       16237328 kB
+SwapFree:        8388604 kB
+Zswap:             51200 kB
+Zswapped:         204800 kB
+Dirty:               320 kB
+";
+
+        assert_that!(
+            parse_meminfo(content),
+            is(equal_to(Some(Stats {
+                memory: 52_428_800,
+                size: None,
+                used: 209_715_200,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_meminfo_without_zswap() {
+        assert_that!(
+            parse_meminfo("MemTotal:       16237328 kB\n"),
+            is(equal_to(None))
+        );
+    }
+}
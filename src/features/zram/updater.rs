@@ -0,0 +1,29 @@
+use super::Data;
+use super::Source;
+use crate::error::*;
+use crate::feature;
+
+pub(super) struct Updater {
+    data: Data,
+    source: Source,
+}
+
+impl Updater {
+    pub(super) const fn new(data: Data, source: Source) -> Self {
+        Self { data, source }
+    }
+}
+
+impl feature::Updatable for Updater {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        &self.data
+    }
+
+    fn update(&mut self) -> Result<()> {
+        let stats = self.source.stats()?;
+
+        self.data.update(&stats);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,494 @@
+mod config;
+
+use crate::cache;
+use crate::error::*;
+use crate::wrapper::process;
+use log::warn;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub(crate) use self::config::ConfigEntry;
+
+const CACHE_DIRECTORY: &str = "http";
+const ERROR_NAME: &str = "http";
+const PROXY_DIRECT: &str = "direct";
+const STATUS_NOT_MODIFIED: u16 = 304;
+const STATUS_OK: u16 = 200;
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    status: u16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Probe {
+    pub(crate) latency: Duration,
+    pub(crate) status: u16,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Proxy {
+    Direct,
+    Environment,
+    Url(String),
+}
+
+impl Proxy {
+    fn parse(proxy: &str) -> Self {
+        match proxy {
+            "" => Self::Environment,
+            PROXY_DIRECT => Self::Direct,
+            url => Self::Url(url.to_owned()),
+        }
+    }
+
+    fn args<F>(&self, url: &str, variable: F) -> Vec<String>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let proxy = match self {
+            Self::Direct => return vec!["--noproxy".to_owned(), "*".to_owned()],
+            Self::Environment => {
+                let variables = if url.starts_with("https://") {
+                    ["https_proxy", "HTTPS_PROXY"]
+                } else {
+                    ["http_proxy", "HTTP_PROXY"]
+                };
+
+                match variables
+                    .iter()
+                    .filter_map(|name| variable(name))
+                    .find(|value| !value.is_empty())
+                {
+                    Some(proxy) => proxy,
+                    None => return Vec::new(),
+                }
+            },
+            Self::Url(proxy) => proxy.clone(),
+        };
+
+        vec!["--proxy".to_owned(), proxy]
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Client {
+    ca_bundle: String,
+    cache: cache::Cache,
+    headers: Vec<String>,
+    proxy: Proxy,
+    settings: ConfigEntry,
+}
+
+impl Client {
+    pub(crate) fn init(name: &str, settings: &ConfigEntry) -> Self {
+        let feature = settings
+            .features
+            .iter()
+            .find(|(feature, _)| feature.eq_ignore_ascii_case(name))
+            .map(|(_, feature)| feature.clone())
+            .unwrap_or_default();
+
+        Self {
+            ca_bundle: feature
+                .ca_bundle
+                .unwrap_or_else(|| settings.ca_bundle.clone()),
+            cache: cache::Cache::init(settings.cache),
+            headers: Vec::new(),
+            proxy: Proxy::parse(feature.proxy.as_ref().unwrap_or(&settings.proxy)),
+            settings: settings.clone(),
+        }
+    }
+
+    pub(crate) fn with_header<H: Into<String>>(mut self, header: H) -> Self {
+        self.headers.push(header.into());
+        self
+    }
+
+    pub(crate) fn get(&mut self, url: &str, max_age: u64) -> Result<String> {
+        let now = cache::now();
+        let key = cache_key(url);
+        let cached = self.cache.entry(&key);
+
+        if let Some(ref entry) = cached {
+            if entry.expires_at > now {
+                return Ok(entry.value.clone());
+            }
+        }
+
+        let response = match self.request(url, cached.as_ref()) {
+            Ok(response) => response,
+            Err(error) => {
+                return match cached {
+                    Some(entry) => {
+                        warn!("{}, using cached response", error);
+                        Ok(entry.value)
+                    },
+                    None => Err(error),
+                };
+            },
+        };
+
+        let entry = match (response.status, cached) {
+            (STATUS_NOT_MODIFIED, Some(entry)) => cache::Entry {
+                expires_at: now.saturating_add(max_age),
+                ..entry
+            },
+            (STATUS_OK, _) => cache::Entry {
+                etag: response.etag,
+                expires_at: now.saturating_add(max_age),
+                last_modified: response.last_modified,
+                value: response.body,
+            },
+            (status, _) => {
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    format!("request of {} failed with status {}", url, status),
+                ));
+            },
+        };
+
+        let body = entry.value.clone();
+        self.cache.set(&key, entry);
+
+        Ok(body)
+    }
+
+    pub(crate) fn probe(&self, url: &str, head: bool) -> Result<Probe> {
+        let mut args = vec![
+            "--output",
+            "/dev/null",
+            "--write-out",
+            "%{http_code} %{time_total}",
+        ];
+
+        if head {
+            args.push("--head");
+        }
+
+        let output = self.curl(url, self.settings.timeout, &args)?;
+
+        parse_probe(&output).wrap_error(ERROR_NAME, format!("invalid response of {}", url))
+    }
+
+    pub(crate) fn download_speed(&self, url: &str, timeout: u64) -> Result<f64> {
+        let output = self.curl(
+            url,
+            timeout,
+            &[
+                "--output",
+                "/dev/null",
+                "--write-out",
+                "%{http_code} %{speed_download}",
+            ],
+        )?;
+
+        let (status, speed) = parse_download(&output)
+            .wrap_error(ERROR_NAME, format!("invalid response of {}", url))?;
+
+        if status >= 400 {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!("download of {} failed with status {}", url, status),
+            ));
+        }
+
+        Ok(speed)
+    }
+
+    fn request(&self, url: &str, cached: Option<&cache::Entry>) -> Result<Response> {
+        let mut args = vec!["--include"];
+
+        let etag = cached
+            .and_then(|entry| entry.etag.as_ref())
+            .map(|etag| format!("If-None-Match: {}", etag));
+        let last_modified = cached
+            .and_then(|entry| entry.last_modified.as_ref())
+            .map(|last_modified| format!("If-Modified-Since: {}", last_modified));
+
+        for header in etag.iter().chain(last_modified.iter()) {
+            args.push("--header");
+            args.push(header);
+        }
+
+        let output = self.curl(url, self.settings.timeout, &args)?;
+
+        parse_response(&output).wrap_error(ERROR_NAME, format!("invalid response of {}", url))
+    }
+
+    fn curl(&self, url: &str, timeout: u64, args: &[&str]) -> Result<String> {
+        let timeout = timeout.to_string();
+        let proxy = self.proxy.args(url, |name| env::var(name).ok());
+
+        let mut curl_args = vec![
+            "--silent",
+            "--show-error",
+            "--location",
+            "--max-time",
+            timeout.as_str(),
+            "--user-agent",
+            self.settings.user_agent.as_str(),
+        ];
+        curl_args.extend(proxy.iter().map(String::as_str));
+
+        if !self.ca_bundle.is_empty() {
+            curl_args.push("--cacert");
+            curl_args.push(&self.ca_bundle);
+        }
+
+        if !self.headers.is_empty() {
+            curl_args.push("--header");
+            curl_args.push("@-");
+        }
+
+        curl_args.extend_from_slice(args);
+        curl_args.push(url);
+
+        let mut command = process::Command::new("curl", &curl_args);
+
+        if !self.headers.is_empty() {
+            command = command.input(self.headers.join("\n"));
+        }
+
+        command
+            .output()
+            .wrap_error(ERROR_NAME, format!("request of {} failed", url))
+    }
+}
+
+fn cache_key(url: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+
+    format!("{}/{:016x}", CACHE_DIRECTORY, hasher.finish())
+}
+
+fn parse_response(output: &str) -> Option<Response> {
+    let mut parts = output.splitn(2, "\r\n\r\n");
+    let head = parts.next()?;
+    let body = parts.next().unwrap_or("");
+
+    if body.starts_with("HTTP/") {
+        return parse_response(body);
+    }
+
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let headers = lines
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let name = parts.next()?.trim().to_ascii_lowercase();
+            let value = parts.next()?.trim().to_owned();
+
+            Some((name, value))
+        })
+        .collect::<HashMap<_, _>>();
+
+    Some(Response {
+        body: body.to_owned(),
+        etag: headers.get("etag").cloned(),
+        last_modified: headers.get("last-modified").cloned(),
+        status,
+    })
+}
+
+fn parse_probe(output: &str) -> Option<Probe> {
+    let mut parts = output.split_whitespace();
+
+    let status = parts.next()?.parse().ok()?;
+    let latency = parts.next()?.parse::<f64>().ok()?;
+
+    Some(Probe {
+        latency: Duration::from_secs_f64(latency),
+        status,
+    })
+}
+
+fn parse_download(output: &str) -> Option<(u16, f64)> {
+    let mut parts = output.split_whitespace();
+
+    let status = parts.next()?.parse().ok()?;
+    let speed = parts.next()?.parse().ok()?;
+
+    Some((status, speed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_response_with_headers() {
+        let output = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nETag: \
+                      \"abc\"\r\nLast-Modified: Wed, 21 Oct 2015 07:28:00 GMT\r\n\r\n{\"key\": 1}";
+
+        assert_that!(
+            parse_response(output),
+            is(equal_to(Some(Response {
+                body: "{\"key\": 1}".to_owned(),
+                etag: Some("\"abc\"".to_owned()),
+                last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_owned()),
+                status: 200,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_response_with_redirect() {
+        let output =
+            "HTTP/1.1 301 Moved Permanently\r\nLocation: /\r\n\r\nHTTP/2 304\r\netag: \"abc\"";
+
+        assert_that!(
+            parse_response(output),
+            is(equal_to(Some(Response {
+                body: "".to_owned(),
+                etag: Some("\"abc\"".to_owned()),
+                last_modified: None,
+                status: 304,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_response_with_invalid_output() {
+        assert_that!(
+            parse_response("curl: (6) Could not resolve host"),
+            is(none())
+        );
+    }
+
+    #[test]
+    fn parse_probe_with_status_and_time() {
+        assert_that!(
+            parse_probe("204 0.125000"),
+            is(equal_to(Some(Probe {
+                latency: Duration::from_millis(125),
+                status: 204,
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_probe_with_invalid_output() {
+        assert_that!(parse_probe("204"), is(none()));
+    }
+
+    #[test]
+    fn parse_download_with_status_and_speed() {
+        assert_that!(
+            parse_download("200 1250000"),
+            is(equal_to(Some((200, 1_250_000.))))
+        );
+        assert_that!(parse_download("200"), is(none()));
+    }
+
+    #[test]
+    fn proxy_args_from_environment() {
+        let variable = |name: &str| match name {
+            "HTTP_PROXY" => Some("http://proxy:3128".to_owned()),
+            "https_proxy" => Some("".to_owned()),
+            _ => None,
+        };
+
+        assert_that!(
+            Proxy::Environment.args("http://example.com", variable),
+            is(equal_to(vec![
+                "--proxy".to_owned(),
+                "http://proxy:3128".to_owned()
+            ]))
+        );
+        assert_that!(
+            Proxy::Environment.args("https://example.com", variable),
+            is(equal_to(Vec::<String>::new()))
+        );
+    }
+
+    #[test]
+    fn proxy_args_configured() {
+        let variable = |_: &str| Some("http://proxy:3128".to_owned());
+
+        assert_that!(
+            Proxy::parse("direct").args("https://example.com", variable),
+            is(equal_to(vec!["--noproxy".to_owned(), "*".to_owned()]))
+        );
+        assert_that!(
+            Proxy::parse("socks5h://localhost:1080").args("https://example.com", variable),
+            is(equal_to(vec![
+                "--proxy".to_owned(),
+                "socks5h://localhost:1080".to_owned()
+            ]))
+        );
+    }
+
+    #[test]
+    fn client_init_with_feature_overrides() {
+        let mut features = HashMap::new();
+        features.insert(
+            "Weather".to_owned(),
+            config::FeatureConfig {
+                ca_bundle: None,
+                proxy: Some("direct".to_owned()),
+            },
+        );
+        let settings = ConfigEntry {
+            ca_bundle: "/etc/ssl/internal.pem".to_owned(),
+            cache: false,
+            features,
+            proxy: "http://proxy:3128".to_owned(),
+            timeout: 1,
+            user_agent: "dwm-status".to_owned(),
+        };
+
+        let client = Client::init("weather", &settings);
+
+        assert_that!(
+            client.ca_bundle,
+            is(equal_to("/etc/ssl/internal.pem".to_owned()))
+        );
+        assert_that!(client.proxy, is(equal_to(Proxy::Direct)));
+
+        let client = Client::init("network", &settings);
+
+        assert_that!(
+            client.proxy,
+            is(equal_to(Proxy::Url("http://proxy:3128".to_owned())))
+        );
+    }
+
+    #[test]
+    fn get_returns_fresh_entry_without_request() {
+        let settings = ConfigEntry {
+            ca_bundle: "".to_owned(),
+            cache: false,
+            features: HashMap::new(),
+            proxy: "".to_owned(),
+            timeout: 1,
+            user_agent: "dwm-status".to_owned(),
+        };
+        let mut client = Client::init("network", &settings);
+
+        client.cache.set(
+            &cache_key("http://localhost/"),
+            cache::Entry {
+                etag: None,
+                expires_at: cache::now() + 60,
+                last_modified: None,
+                value: "value".to_owned(),
+            },
+        );
+
+        assert_that!(
+            client.get("http://localhost/", 60),
+            is(equal_to(Ok("value".to_owned())))
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct FeatureConfig {
+    #[serde(default)]
+    pub(super) ca_bundle: Option<String>,
+    #[serde(default)]
+    pub(super) proxy: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) ca_bundle: String,
+    pub(super) cache: bool,
+    pub(super) features: HashMap<String, FeatureConfig>,
+    pub(super) proxy: String,
+    pub(super) timeout: u64,
+    pub(super) user_agent: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "http",
+            map!(
+                "ca_bundle"  => "",
+                "cache"      => true,
+                "features"   => HashMap::<String, Value>::new(),
+                "proxy"      => "",
+                "timeout"    => 10,
+                "user_agent" => "dwm-status",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("http", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("http", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("ca_bundle".to_owned(), "".into());
+        map.insert("cache".to_owned(), true.into());
+        map.insert(
+            "features".to_owned(),
+            HashMap::<String, Value>::new().into(),
+        );
+        map.insert("proxy".to_owned(), "".into());
+        map.insert("timeout".to_owned(), 10.into());
+        map.insert("user_agent".to_owned(), "dwm-status".into());
+
+        map
+    }
+}
@@ -0,0 +1,307 @@
+mod config;
+
+use crate::error::*;
+use std::ops::Range;
+use std::slice;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::config::GroupConfig;
+
+const ERROR_NAME: &str = "layout";
+
+#[derive(Debug, PartialEq)]
+struct Group {
+    features: Vec<usize>,
+    prefix: String,
+    separator: Option<String>,
+    suffix: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum Item {
+    Feature(usize),
+    Group(Group),
+}
+
+impl Item {
+    fn features(&self) -> &[usize] {
+        match self {
+            Self::Feature(id) => slice::from_ref(id),
+            Self::Group(group) => &group.features,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Layout {
+    items: Vec<Item>,
+}
+
+impl Layout {
+    pub(crate) fn new(names: &[&'static str], settings: &ConfigEntry) -> Result<Self> {
+        let mut group_ids = vec![None; names.len()];
+
+        for (group_id, (group_name, group)) in settings.groups.iter().enumerate() {
+            for feature in &group.features {
+                let id = names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(feature))
+                    .wrap_error(
+                        ERROR_NAME,
+                        format!("feature {} of group {} is not enabled", feature, group_name),
+                    )?;
+
+                if group_ids[id].is_some() {
+                    return Err(Error::new_custom(
+                        ERROR_NAME,
+                        format!("feature {} is part of more than one group", feature),
+                    ));
+                }
+
+                group_ids[id] = Some(group_id);
+            }
+        }
+
+        let groups = settings.groups.values().collect::<Vec<_>>();
+        let mut items = Vec::new();
+
+        for (id, group_id) in group_ids.iter().enumerate() {
+            match *group_id {
+                None => items.push(Item::Feature(id)),
+                Some(group_id) if !group_ids[..id].contains(&Some(group_id)) => items.push(
+                    Item::Group(new_group(groups[group_id], &group_ids, group_id)),
+                ),
+                Some(_) => (),
+            }
+        }
+
+        Ok(Self { items })
+    }
+
+    pub(crate) fn join(
+        &self,
+        values: &[Option<String>],
+        separator: &str,
+    ) -> (String, Vec<Range<usize>>) {
+        let mut writer = Writer::default();
+        let mut positions = vec![0..0; values.len()];
+        let mut first = true;
+
+        for item in &self.items {
+            let visible = item
+                .features()
+                .iter()
+                .filter_map(|&id| values.get(id)?.as_ref().map(|value| (id, value)))
+                .collect::<Vec<_>>();
+
+            if visible.is_empty() {
+                continue;
+            }
+
+            if !first {
+                writer.push(separator);
+            }
+            first = false;
+
+            match item {
+                Item::Feature(_) => {
+                    for (id, value) in visible {
+                        positions[id] = writer.push(value);
+                    }
+                },
+                Item::Group(group) => {
+                    let group_separator = group.separator.as_deref().unwrap_or(separator);
+
+                    writer.push(&group.prefix);
+                    for (index, (id, value)) in visible.into_iter().enumerate() {
+                        if index > 0 {
+                            writer.push(group_separator);
+                        }
+                        positions[id] = writer.push(value);
+                    }
+                    writer.push(&group.suffix);
+                },
+            }
+        }
+
+        (writer.text, positions)
+    }
+
+    pub(crate) fn length(&self, values: &[Option<String>], separator: &str) -> usize {
+        self.join(values, separator).0.chars().count()
+    }
+}
+
+fn new_group(settings: &GroupConfig, group_ids: &[Option<usize>], group_id: usize) -> Group {
+    Group {
+        features: group_ids
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| **id == Some(group_id))
+            .map(|(id, _)| id)
+            .collect(),
+        prefix: settings.prefix.clone(),
+        separator: settings.separator.clone(),
+        suffix: settings.suffix.clone(),
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    length: usize,
+    text: String,
+}
+
+impl Writer {
+    fn push(&mut self, text: &str) -> Range<usize> {
+        let start = self.length;
+
+        self.length += text.chars().count();
+        self.text.push_str(text);
+
+        start..self.length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::collections::BTreeMap;
+
+    const NAMES: &[&str] = &["audio", "cpu_load", "battery", "time"];
+
+    fn settings(groups: &[(&str, &[&str], Option<&str>)]) -> ConfigEntry {
+        ConfigEntry {
+            groups: groups
+                .iter()
+                .map(|(name, features, separator)| {
+                    (
+                        (*name).to_owned(),
+                        GroupConfig {
+                            features: features
+                                .iter()
+                                .map(|feature| (*feature).to_owned())
+                                .collect(),
+                            prefix: "[".to_owned(),
+                            separator: separator.map(ToOwned::to_owned),
+                            suffix: "]".to_owned(),
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    fn values(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values
+            .iter()
+            .map(|value| value.map(ToOwned::to_owned))
+            .collect()
+    }
+
+    #[test]
+    fn new_with_unknown_feature() {
+        assert_that!(
+            Layout::new(NAMES, &settings(&[("system", &["memory"], None)])).is_err(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn new_with_feature_in_two_groups() {
+        assert_that!(
+            Layout::new(
+                NAMES,
+                &settings(&[("a", &["audio"], None), ("b", &["Audio"], None)])
+            )
+            .is_err(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn join_without_groups() {
+        let layout = Layout::new(NAMES, &settings(&[])).unwrap();
+
+        assert_that!(
+            layout.join(
+                &values(&[Some("S 50%"), None, Some(""), Some("12:00")]),
+                " / "
+            ),
+            is(equal_to((
+                "S 50% /  / 12:00".to_owned(),
+                vec![0..5, 0..0, 8..8, 11..16]
+            )))
+        );
+    }
+
+    #[test]
+    fn join_with_group() {
+        let layout = Layout::new(
+            NAMES,
+            &settings(&[("system", &["battery", "cpu_load"], Some(" "))]),
+        )
+        .unwrap();
+
+        assert_that!(
+            layout.join(
+                &values(&[Some("S 50%"), Some("0.5"), Some("80%"), Some("12:00")]),
+                " / "
+            ),
+            is(equal_to((
+                "S 50% / [0.5 80%] / 12:00".to_owned(),
+                vec![0..5, 9..12, 13..16, 20..25]
+            )))
+        );
+    }
+
+    #[test]
+    fn join_with_group_separator_fallback() {
+        let layout = Layout::new(
+            NAMES,
+            &settings(&[("system", &["cpu_load", "battery"], None)]),
+        )
+        .unwrap();
+
+        assert_that!(
+            layout.join(&values(&[None, Some("0.5"), Some("80%"), None]), " / "),
+            is(equal_to((
+                "[0.5 / 80%]".to_owned(),
+                vec![0..0, 1..4, 7..10, 0..0]
+            )))
+        );
+    }
+
+    #[test]
+    fn join_with_hidden_group() {
+        let layout = Layout::new(
+            NAMES,
+            &settings(&[("system", &["cpu_load", "battery"], None)]),
+        )
+        .unwrap();
+
+        assert_that!(
+            layout.join(&values(&[Some("S 50%"), None, None, Some("12:00")]), " / "),
+            is(equal_to((
+                "S 50% / 12:00".to_owned(),
+                vec![0..5, 0..0, 0..0, 8..13]
+            )))
+        );
+    }
+
+    #[test]
+    fn length_with_group() {
+        let layout = Layout::new(
+            NAMES,
+            &settings(&[("system", &["audio", "time"], Some(" "))]),
+        )
+        .unwrap();
+
+        assert_that!(
+            layout.length(&values(&[Some("S 50%"), None, None, Some("12:00")]), " · "),
+            is(equal_to(13))
+        );
+    }
+}
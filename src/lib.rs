@@ -33,74 +33,90 @@
 
 #[macro_use]
 mod macros;
+mod allocator;
+mod bench;
+mod builder;
+mod cache;
+mod click;
 mod communication;
+mod condition;
+mod control;
 mod error;
 mod feature;
 mod features;
+mod http;
+mod layout;
+mod locale;
+mod logging;
+mod marquee;
+mod metrics;
+mod migration;
+mod mock;
+mod notification;
+pub mod output;
+mod overflow;
+#[cfg(feature = "plugin-api")]
+pub mod plugin_api;
+mod recording;
+mod registry;
 mod resume;
+mod sanitize;
+mod scheduler;
+mod script;
 mod settings;
+mod snapshot;
+mod state;
 mod status_bar;
+mod systemd;
 #[cfg(test)]
 mod test_utils;
+mod threshold;
 mod utils;
+mod watcher;
 mod wrapper;
 
 use crate::error::*;
-use crate::status_bar::StatusBar;
-use crate::wrapper::channel;
-use crate::wrapper::termination;
-use std::collections::HashSet;
-use std::iter::FromIterator;
+use log::LevelFilter;
 
-fn validate_settings(settings: &settings::Settings) -> Result<()> {
-    if settings.general.order.is_empty() {
-        return Err(Error::new_custom("settings", "no features enabled"));
-    }
-
-    let set: HashSet<&String> = HashSet::from_iter(settings.general.order.iter());
-    if set.len() < settings.general.order.len() {
-        return Err(Error::new_custom(
-            "settings",
-            "order must not have more than one entry of one feature",
-        ));
-    }
-
-    Ok(())
-}
+pub use crate::allocator::CountingAllocator;
+pub use crate::builder::Builder;
+pub use crate::error::Error;
 
+/// Runs the status bar with the given config file, see [`Builder`](struct.Builder.html) to
+/// configure the status bar programmatically.
 pub fn run(config_path: &str) -> Result<()> {
-    let settings = settings::Settings::init(config_path)?;
-
-    validate_settings(&settings)?;
+    run_with_log_level(config_path, None)
+}
 
-    let (sender, receiver) = channel::create();
-    let mut features = Vec::new();
+/// Runs the status bar with the given config file and log level overriding the configured one.
+pub fn run_with_log_level(config_path: &str, log_level: Option<LevelFilter>) -> Result<()> {
+    let mut builder = Builder::new().config_file(config_path).logging(true);
 
-    for (index, feature_name) in settings.general.order.iter().enumerate() {
-        let mut feature = features::create_feature(index, feature_name, &sender, &settings)?;
-        feature.init_notifier()?;
-        features.push(feature);
+    if let Some(log_level) = log_level {
+        builder = builder.log_level(log_level);
     }
 
-    resume::init_resume_notifier(&sender)?;
-
-    sender.send(communication::Message::UpdateAll)?;
+    builder.run()
+}
 
-    termination::register_handler(move || {
-        sender
-            .send(communication::Message::Kill)
-            .show_error()
-            .unwrap()
-    })?;
+/// Clicks with the given button number on a feature name or `@<position>` in characters of the
+/// status text of the running instance.
+pub fn click(button: u8, target: &str) -> Result<String> {
+    control::send_command(&format!("click {} {}", button, target))
+}
 
-    let mut status_bar = StatusBar::init(features)?;
+/// Returns update and render timings of the running instance.
+pub fn stats() -> Result<String> {
+    control::send_command("stats")
+}
 
-    while let Ok(message) = receiver.read_blocking() {
-        match message {
-            communication::Message::Kill => break,
-            _ => status_bar.update(&message, &settings.general)?,
-        }
-    }
+/// Returns the current value, values, last update time and error of every feature of the running
+/// instance as JSON.
+pub fn status() -> Result<String> {
+    control::send_command("status")
+}
 
-    Ok(())
+/// Converts a legacy config file with one feature per line into the current config format.
+pub fn migrate_config(path: &str) -> Result<String> {
+    migration::migrate_config(path)
 }
@@ -0,0 +1,54 @@
+use crate::error::*;
+use crate::wrapper::locale;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn init(enabled: bool) -> Result<()> {
+    if enabled {
+        locale::init_from_environment()?;
+    }
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+
+    Ok(())
+}
+
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub(crate) fn decimal_separator() -> Option<String> {
+    if is_enabled() {
+        Some(locale::decimal_point())
+    } else {
+        None
+    }
+}
+
+pub(crate) fn format_decimal(value: f32, precision: usize, separator: Option<&str>) -> String {
+    let formatted = format!("{:.*}", precision, value);
+
+    match separator {
+        Some(separator) if separator != "." => formatted.replace('.', separator),
+        _ => formatted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn format_decimal_without_separator() {
+        assert_that!(format_decimal(1.234, 2, None), is(equal_to("1.23")));
+    }
+
+    #[test]
+    fn format_decimal_with_separator() {
+        assert_that!(format_decimal(1.234, 2, Some(",")), is(equal_to("1,23")));
+    }
+}
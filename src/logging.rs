@@ -0,0 +1,352 @@
+mod config;
+mod rotating_file;
+
+use crate::error::*;
+use crate::wrapper::date_time;
+use log::Level;
+use log::LevelFilter;
+use log::Log;
+use log::Metadata;
+use log::Record;
+use log::debug;
+use std::cell::RefCell;
+use std::env;
+use std::io;
+use std::io::Write;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::time::Duration;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::config::FileConfig;
+pub(self) use self::rotating_file::RotatingFile;
+
+const ENV_LOG_LEVEL: &str = "DWM_STATUS_LOG";
+pub(self) const ERROR_NAME: &str = "logging";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+thread_local!(
+    static CONTEXT: RefCell<Context> = RefCell::new(Context::default());
+);
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Context {
+    pub(crate) duration: Option<Duration>,
+    pub(crate) feature: Option<&'static str>,
+}
+
+pub(crate) fn with_context<T, F>(context: Context, function: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = CONTEXT.with(|current| current.replace(context));
+    let result = function();
+    CONTEXT.with(|current| current.replace(previous));
+
+    result
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(self) enum Format {
+    Json,
+    Text,
+}
+
+struct Entry<'a> {
+    context: Context,
+    level: Level,
+    message: &'a str,
+    target: &'a str,
+    timestamp: &'a str,
+}
+
+impl Entry<'_> {
+    fn feature(&self) -> Option<&str> {
+        self.context
+            .feature
+            .or_else(|| feature_from_module(self.target))
+    }
+
+    fn format(&self, format: Format) -> String {
+        match format {
+            Format::Json => serde_json::json!({
+                "duration_us": self.context.duration.map(duration_micros),
+                "feature": self.feature(),
+                "level": self.level.to_string(),
+                "message": self.message,
+                "module": self.target,
+                "timestamp": self.timestamp,
+            })
+            .to_string(),
+            Format::Text => format!(
+                "{} [{:5}] {}: {}",
+                self.timestamp, self.level, self.target, self.message
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Filters {
+    level: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Filters {
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| is_module_or_submodule(target, module))
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.level, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, LevelFilter::max)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Logger {
+    file: Mutex<Option<RotatingFile>>,
+    filters: RwLock<Filters>,
+    format: RwLock<Format>,
+}
+
+impl Logger {
+    pub(crate) fn init() -> Option<&'static Self> {
+        let logger: &'static Self = Box::leak(Box::new(Self {
+            file: Mutex::new(None),
+            filters: RwLock::new(Filters {
+                level: LevelFilter::Info,
+                modules: Vec::new(),
+            }),
+            format: RwLock::new(Format::Text),
+        }));
+
+        if log::set_logger(logger).is_err() {
+            debug!("Keeping the already registered logger");
+
+            return None;
+        }
+
+        log::set_max_level(LevelFilter::Info);
+
+        Some(logger)
+    }
+
+    pub(crate) fn configure(
+        &self,
+        settings: &ConfigEntry,
+        level_override: Option<LevelFilter>,
+    ) -> Result<()> {
+        let filters = match level_override.map_or_else(level_from_env, |level| Ok(Some(level)))? {
+            Some(level) => Filters {
+                level,
+                modules: Vec::new(),
+            },
+            None => Filters {
+                level: settings.level_filter()?,
+                modules: settings.module_filters()?,
+            },
+        };
+
+        let file = if settings.file.file.is_empty() {
+            None
+        } else {
+            Some(RotatingFile::open(settings.file.clone())?)
+        };
+
+        let format = settings.log_format()?;
+
+        log::set_max_level(filters.max_level());
+
+        *self.filters.write().unwrap() = filters;
+        *self.file.lock().unwrap() = file;
+        *self.format.write().unwrap() = format;
+
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.filters.read().unwrap().level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = Entry {
+            context: CONTEXT.with(|context| *context.borrow()),
+            level: record.level(),
+            message: &record.args().to_string(),
+            target: record.target(),
+            timestamp: &date_time::DateTime::now().format(TIMESTAMP_FORMAT),
+        }
+        .format(*self.format.read().unwrap());
+
+        let _ = writeln!(io::stderr(), "{}", line);
+
+        if let Some(ref mut file) = *self.file.lock().unwrap() {
+            if let Err(error) = file.write_line(&line) {
+                let _ = writeln!(io::stderr(), "{}", error);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        let _ = io::stderr().flush();
+    }
+}
+
+fn level_from_env() -> Result<Option<LevelFilter>> {
+    match env::var(ENV_LOG_LEVEL) {
+        Ok(level) if !level.is_empty() => config::parse_level(&level).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn duration_micros(duration: Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_mul(1_000_000)
+        .saturating_add(u64::from(duration.subsec_micros()))
+}
+
+fn feature_from_module(module: &str) -> Option<&str> {
+    let mut parts = module.split("::").skip_while(|part| *part != "features");
+
+    parts.next()?;
+    parts.next()
+}
+
+fn is_module_or_submodule(target: &str, module: &str) -> bool {
+    target == module || target.starts_with(&format!("{}::", module))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn filters() -> Filters {
+        Filters {
+            level: LevelFilter::Info,
+            modules: vec![
+                ("dwm_status::features".to_owned(), LevelFilter::Warn),
+                (
+                    "dwm_status::features::network".to_owned(),
+                    LevelFilter::Debug,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn filters_level_for_unfiltered_module() {
+        assert_that!(
+            filters().level_for("dwm_status::status_bar"),
+            is(equal_to(LevelFilter::Info))
+        );
+    }
+
+    #[test]
+    fn filters_level_for_filtered_module() {
+        assert_that!(
+            filters().level_for("dwm_status::features::battery::notifier"),
+            is(equal_to(LevelFilter::Warn))
+        );
+    }
+
+    #[test]
+    fn filters_level_for_most_specific_module() {
+        assert_that!(
+            filters().level_for("dwm_status::features::network::updater"),
+            is(equal_to(LevelFilter::Debug))
+        );
+    }
+
+    #[test]
+    fn filters_level_for_module_with_same_prefix() {
+        assert_that!(
+            filters().level_for("dwm_status::features_list"),
+            is(equal_to(LevelFilter::Info))
+        );
+    }
+
+    #[test]
+    fn filters_max_level() {
+        assert_that!(filters().max_level(), is(equal_to(LevelFilter::Debug)));
+    }
+
+    fn entry(target: &'static str, context: Context) -> Entry<'static> {
+        Entry {
+            context,
+            level: Level::Info,
+            message: "message",
+            target,
+            timestamp: "2020-05-19 12:00:00.000",
+        }
+    }
+
+    #[test]
+    fn entry_format_text_with_padded_level() {
+        assert_that!(
+            entry("dwm_status", Context::default()).format(Format::Text),
+            is(equal_to(
+                "2020-05-19 12:00:00.000 [INFO ] dwm_status: message"
+            ))
+        );
+    }
+
+    #[test]
+    fn entry_format_json_without_context() {
+        assert_that!(
+            entry("dwm_status::status_bar", Context::default()).format(Format::Json),
+            is(equal_to(
+                "{\"duration_us\":null,\"feature\":null,\"level\":\"INFO\",\"message\":\"message\"\
+                 ,\"module\":\"dwm_status::status_bar\",\"timestamp\":\"2020-05-19 12:00:00.000\"}"
+            ))
+        );
+    }
+
+    #[test]
+    fn entry_format_json_with_context() {
+        let context = Context {
+            duration: Some(Duration::from_millis(12)),
+            feature: Some("battery"),
+        };
+
+        assert_that!(
+            entry("dwm_status::status_bar", context).format(Format::Json),
+            is(equal_to(
+                "{\"duration_us\":12000,\"feature\":\"battery\",\"level\":\"INFO\",\"message\":\"\
+                 message\",\"module\":\"dwm_status::status_bar\",\"timestamp\":\"2020-05-19 \
+                 12:00:00.000\"}"
+            ))
+        );
+    }
+
+    #[test]
+    fn entry_feature_from_module() {
+        assert_that!(
+            entry("dwm_status::features::network::updater", Context::default()).feature(),
+            is(equal_to(Some("network")))
+        );
+    }
+
+    #[test]
+    fn entry_feature_without_feature_module() {
+        assert_that!(
+            entry("dwm_status::features", Context::default()).feature(),
+            is(equal_to(None))
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::ERROR_NAME;
+use super::Format;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use log::LevelFilter;
+use serde_derive::*;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct FileConfig {
+    pub(super) file: String,
+    pub(super) max_file_size: u64,
+    pub(super) max_files: usize,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    #[serde(flatten)]
+    pub(super) file: FileConfig,
+    pub(super) filters: HashMap<String, String>,
+    pub(super) format: String,
+    pub(super) level: String,
+}
+
+impl ConfigEntry {
+    pub(super) fn log_format(&self) -> Result<Format> {
+        match &self.format[..] {
+            "json" => Ok(Format::Json),
+            "text" => Ok(Format::Text),
+            _ => Err(Error::new_custom(
+                ERROR_NAME,
+                format!("invalid log format '{}'", self.format),
+            )),
+        }
+    }
+
+    pub(super) fn level_filter(&self) -> Result<LevelFilter> {
+        parse_level(&self.level)
+    }
+
+    pub(super) fn module_filters(&self) -> Result<Vec<(String, LevelFilter)>> {
+        self.filters
+            .iter()
+            .map(|(module, level)| Ok((module.clone(), parse_level(level)?)))
+            .collect()
+    }
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "logging",
+            map!(
+                "file"          => "",
+                "filters"       => HashMap::<String, Value>::new(),
+                "format"        => "text",
+                "level"         => "info",
+                "max_file_size" => 1_048_576,
+                "max_files"     => 3,
+            ),
+        )
+    }
+}
+
+pub(super) fn parse_level(level: &str) -> Result<LevelFilter> {
+    LevelFilter::from_str(level).wrap_error(ERROR_NAME, format!("invalid log level '{}'", level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_level_when_valid() {
+        assert_that!(parse_level("debug"), is(equal_to(Ok(LevelFilter::Debug))));
+        assert_that!(parse_level("WARN"), is(equal_to(Ok(LevelFilter::Warn))));
+        assert_that!(parse_level("off"), is(equal_to(Ok(LevelFilter::Off))));
+    }
+
+    #[test]
+    fn parse_level_when_invalid() {
+        assert_that!(parse_level("verbose").is_err(), is(true));
+    }
+
+    #[cfg(feature = "mocking")]
+    mod config_type_set_default {
+        use super::*;
+        use crate::test_utils::config::test_set_default_err;
+        use crate::test_utils::config::test_set_default_ok;
+
+        #[test]
+        fn when_ok() {
+            test_set_default_ok::<ConfigEntry>("logging", default_map);
+        }
+
+        #[test]
+        fn when_err() {
+            test_set_default_err::<ConfigEntry>("logging", default_map);
+        }
+
+        fn default_map() -> HashMap<String, Value> {
+            let mut map = HashMap::new();
+            map.insert("file".to_owned(), "".into());
+            map.insert("filters".to_owned(), HashMap::<String, Value>::new().into());
+            map.insert("format".to_owned(), "text".into());
+            map.insert("level".to_owned(), "info".into());
+            map.insert("max_file_size".to_owned(), 1_048_576.into());
+            map.insert("max_files".to_owned(), 3.into());
+
+            map
+        }
+    }
+}
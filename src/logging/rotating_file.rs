@@ -0,0 +1,135 @@
+use super::ERROR_NAME;
+use super::FileConfig;
+use crate::error::*;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub(super) struct RotatingFile {
+    config: FileConfig,
+    file: fs::File,
+    size: u64,
+}
+
+impl RotatingFile {
+    pub(super) fn open(config: FileConfig) -> Result<Self> {
+        let file = open_append(&config.file)?;
+        let size = file
+            .metadata()
+            .wrap_error(ERROR_NAME, "failed to read log file metadata")?
+            .len();
+
+        Ok(Self { config, file, size })
+    }
+
+    pub(super) fn write_line(&mut self, line: &str) -> Result<()> {
+        let length = line.len() as u64 + 1;
+
+        if self.size > 0 && self.size + length > self.config.max_file_size {
+            self.rotate()?;
+        }
+
+        writeln!(self.file, "{}", line).wrap_error(ERROR_NAME, "failed to write log file")?;
+        self.size += length;
+
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        for index in (1..self.config.max_files).rev() {
+            let source = self.rotated_path(index);
+
+            if source.exists() {
+                fs::rename(&source, self.rotated_path(index + 1))
+                    .wrap_error(ERROR_NAME, "failed to rotate log files")?;
+            }
+        }
+
+        if self.config.max_files > 0 {
+            fs::rename(&self.config.file, self.rotated_path(1))
+                .wrap_error(ERROR_NAME, "failed to rotate log files")?;
+        } else {
+            fs::remove_file(&self.config.file)
+                .wrap_error(ERROR_NAME, "failed to remove log file")?;
+        }
+
+        self.file = open_append(&self.config.file)?;
+        self.size = 0;
+
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        PathBuf::from(format!("{}.{}", self.config.file, index))
+    }
+}
+
+fn open_append(path: &str) -> Result<fs::File> {
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .wrap_error(ERROR_NAME, format!("failed to open log file {}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrapper::file;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use std::env;
+
+    fn test_file(name: &str) -> String {
+        let directory = env::temp_dir().join(format!("dwm-status-test-{}", name));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+
+        directory.join("log").to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn write_line_without_rotation() {
+        let path = test_file("write-line");
+
+        let mut file = RotatingFile::open(FileConfig {
+            file: path.clone(),
+            max_file_size: 100,
+            max_files: 2,
+        })
+        .unwrap();
+
+        assert_that!(file.write_line("first"), is(equal_to(Ok(()))));
+        assert_that!(file.write_line("second"), is(equal_to(Ok(()))));
+
+        assert_that!(file::read(&path).unwrap(), is(equal_to("first\nsecond\n")));
+    }
+
+    #[test]
+    fn write_line_with_rotation() {
+        let path = test_file("rotation");
+
+        let mut file = RotatingFile::open(FileConfig {
+            file: path.clone(),
+            max_file_size: 10,
+            max_files: 2,
+        })
+        .unwrap();
+
+        for line in &["first", "second", "third", "fourth"] {
+            assert_that!(file.write_line(line), is(equal_to(Ok(()))));
+        }
+
+        assert_that!(file::read(&path).unwrap(), is(equal_to("fourth\n")));
+        assert_that!(
+            file::read(format!("{}.1", path)).unwrap(),
+            is(equal_to("third\n"))
+        );
+        assert_that!(
+            file::read(format!("{}.2", path)).unwrap(),
+            is(equal_to("second\n"))
+        );
+        assert_that!(PathBuf::from(format!("{}.3", path)).exists(), is(false));
+    }
+}
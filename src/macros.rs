@@ -7,3 +7,11 @@ macro_rules! map {
         map
     }}
 }
+
+macro_rules! map_values {
+    ( $( $k: expr => $v: expr ),* $(,)? ) => {{
+        let mut values = crate::feature::Values::new();
+        $( values.insert($k, $v.to_string()); )*
+        values
+    }}
+}
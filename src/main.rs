@@ -31,25 +31,206 @@
 
 use clap::Arg;
 use clap::*;
-use simplelog::Config;
-use simplelog::LevelFilter;
-use simplelog::SimpleLogger;
+use log::LevelFilter;
 use std::process;
 
-fn main() {
-    let _ = SimpleLogger::init(LevelFilter::Info, Config::default());
+#[global_allocator]
+static ALLOCATOR: dwm_status::CountingAllocator = dwm_status::CountingAllocator;
+
+fn log_level(matches: &ArgMatches<'_>) -> Option<LevelFilter> {
+    if matches.is_present("quiet") {
+        return Some(LevelFilter::Error);
+    }
+
+    match matches.occurrences_of("verbose") {
+        0 => None,
+        1 => Some(LevelFilter::Debug),
+        _ => Some(LevelFilter::Trace),
+    }
+}
 
+fn main() {
     let matches = app_from_crate!()
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(
             Arg::with_name("config-file")
                 .help("Path to config file")
                 .required(true),
         )
+        .arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .long("verbose")
+                .multiple(true)
+                .help("Increases log verbosity (-v for debug, -vv for trace)"),
+        )
+        .arg(
+            Arg::with_name("mock")
+                .long("mock")
+                .value_name("fixtures")
+                .help("Renders the fixtures of the given file once to stdout"),
+        )
+        .arg(
+            Arg::with_name("record")
+                .long("record")
+                .value_name("file")
+                .conflicts_with_all(&["mock", "replay"])
+                .help("Records every feature update to the given file"),
+        )
+        .arg(
+            Arg::with_name("replay")
+                .long("replay")
+                .value_name("file")
+                .conflicts_with("mock")
+                .help("Replays the feature updates of the given recording"),
+        )
+        .arg(
+            Arg::with_name("quiet")
+                .short("q")
+                .long("quiet")
+                .conflicts_with("verbose")
+                .help("Logs errors only"),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Updates every enabled feature repeatedly and prints update durations")
+                .arg(
+                    Arg::with_name("config-file")
+                        .help("Path to config file")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("iterations")
+                        .short("n")
+                        .long("iterations")
+                        .value_name("n")
+                        .default_value("100")
+                        .help("Number of updates per feature"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("click")
+                .about("Clicks on a feature of the running instance, e.g. from a patched dwm")
+                .arg(
+                    Arg::with_name("button")
+                        .help("Button number, 1 (left), 2 (middle) or 3 (right)")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("target")
+                        .help("Feature name or @<position> in characters of the status text")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("migrate-config")
+                .about("Prints the given legacy config file with one feature per line as TOML")
+                .arg(
+                    Arg::with_name("old")
+                        .help("Path to legacy config file")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("stats")
+                .about("Prints update and render timings of the running instance"),
+        )
+        .subcommand(
+            SubCommand::with_name("status")
+                .about("Prints the current state of every feature of the running instance as JSON"),
+        )
         .get_matches();
 
+    if let Some(matches) = matches.subcommand_matches("bench") {
+        let iterations = value_t_or_exit!(matches, "iterations", usize);
+        let config = matches.value_of("config-file").unwrap();
+
+        match dwm_status::Builder::new()
+            .config_file(config)
+            .logging(true)
+            .bench(iterations)
+        {
+            Ok(report) => print!("{}", report),
+            Err(error) => {
+                error.show_error();
+                process::exit(1);
+            },
+        }
+
+        return;
+    }
+
+    if let Some(matches) = matches.subcommand_matches("click") {
+        let button = value_t_or_exit!(matches, "button", u8);
+
+        match dwm_status::click(button, matches.value_of("target").unwrap()) {
+            Ok(answer) => print!("{}", answer),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            },
+        }
+
+        return;
+    }
+
+    if let Some(matches) = matches.subcommand_matches("migrate-config") {
+        match dwm_status::migrate_config(matches.value_of("old").unwrap()) {
+            Ok(config) => print!("{}", config),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            },
+        }
+
+        return;
+    }
+
+    if matches.subcommand_matches("stats").is_some() {
+        match dwm_status::stats() {
+            Ok(stats) => print!("{}", stats),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            },
+        }
+
+        return;
+    }
+
+    if matches.subcommand_matches("status").is_some() {
+        match dwm_status::status() {
+            Ok(status) => print!("{}", status),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            },
+        }
+
+        return;
+    }
+
     let config = matches.value_of("config-file").unwrap();
 
-    if let Err(error) = dwm_status::run(config) {
+    let mut builder = dwm_status::Builder::new().config_file(config).logging(true);
+
+    if let Some(log_level) = log_level(&matches) {
+        builder = builder.log_level(log_level);
+    }
+
+    if let Some(fixtures) = matches.value_of("mock") {
+        builder = builder.mock(fixtures);
+    }
+
+    if let Some(path) = matches.value_of("record") {
+        builder = builder.record(path);
+    }
+
+    if let Some(path) = matches.value_of("replay") {
+        builder = builder.replay(path);
+    }
+
+    if let Err(error) = builder.run() {
         error.show_error();
         process::exit(1);
     }
@@ -0,0 +1,160 @@
+mod config;
+
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub(crate) use self::config::ConfigEntry;
+
+const ERROR_NAME: &str = "marquee";
+
+#[derive(Debug)]
+pub(crate) struct Marquee {
+    gap: String,
+    tick: usize,
+    widths: HashMap<String, usize>,
+}
+
+impl Marquee {
+    pub(crate) fn new(settings: &ConfigEntry) -> Self {
+        Self {
+            gap: settings.gap.clone(),
+            tick: 0,
+            widths: settings
+                .widths
+                .iter()
+                .map(|(feature, width)| (feature.to_lowercase(), *width))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn advance(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+    }
+
+    pub(crate) fn apply(&self, feature: &str, value: String) -> String {
+        let width = match self.widths.get(feature) {
+            Some(&width) if width > 0 => width,
+            _ => return value,
+        };
+
+        if value.chars().count() <= width {
+            return value;
+        }
+
+        let text = value.chars().chain(self.gap.chars()).collect::<Vec<_>>();
+
+        text.iter()
+            .cycle()
+            .skip(self.tick % text.len())
+            .take(width)
+            .collect()
+    }
+}
+
+pub(crate) fn init_ticker(
+    settings: &ConfigEntry,
+    sender: &channel::Sender<communication::Message>,
+) -> Result<()> {
+    if settings.widths.is_empty() {
+        return Ok(());
+    }
+
+    if settings.interval == 0 {
+        return Err(Error::new_custom(
+            ERROR_NAME,
+            "interval must be greater than 0",
+        ));
+    }
+
+    let ticker = Ticker {
+        interval: Duration::from_millis(settings.interval),
+        sender: sender.clone(),
+    };
+
+    thread::Thread::new(ERROR_NAME, ticker).run()
+}
+
+struct Ticker {
+    interval: Duration,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl thread::Runnable for Ticker {
+    fn run(&self) -> Result<()> {
+        loop {
+            thread::sleep(self.interval);
+
+            self.sender.send(communication::Message::Tick)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn marquee(width: usize) -> Marquee {
+        let mut widths = HashMap::new();
+        widths.insert("MPRIS".to_owned(), width);
+
+        Marquee::new(&ConfigEntry {
+            gap: " | ".to_owned(),
+            interval: 500,
+            widths,
+        })
+    }
+
+    #[test]
+    fn apply_without_width() {
+        assert_that!(
+            marquee(5).apply("time", "2020-05-19 12:00".to_owned()),
+            is(equal_to("2020-05-19 12:00"))
+        );
+    }
+
+    #[test]
+    fn apply_when_value_fits() {
+        assert_that!(
+            marquee(5).apply("mpris", "song".to_owned()),
+            is(equal_to("song"))
+        );
+    }
+
+    #[test]
+    fn apply_when_value_is_too_long() {
+        let mut marquee = marquee(5);
+
+        assert_that!(
+            marquee.apply("mpris", "a song".to_owned()),
+            is(equal_to("a son"))
+        );
+
+        marquee.advance();
+        assert_that!(
+            marquee.apply("mpris", "a song".to_owned()),
+            is(equal_to(" song"))
+        );
+
+        for _ in 0..5 {
+            marquee.advance();
+        }
+        assert_that!(
+            marquee.apply("mpris", "a song".to_owned()),
+            is(equal_to(" | a "))
+        );
+
+        for _ in 0..3 {
+            marquee.advance();
+        }
+        assert_that!(
+            marquee.apply("mpris", "a song".to_owned()),
+            is(equal_to("a son"))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub(crate) type SharedMetrics = Arc<Mutex<Metrics>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Timing {
+    count: u64,
+    max: Duration,
+    min: Duration,
+    total: Duration,
+}
+
+impl Timing {
+    pub(crate) fn record(&mut self, duration: Duration) {
+        self.min = if self.count == 0 {
+            duration
+        } else {
+            self.min.min(duration)
+        };
+        self.count += 1;
+        self.max = self.max.max(duration);
+        self.total += duration;
+    }
+
+    pub(crate) fn average(&self) -> Duration {
+        u32::try_from(self.count)
+            .ok()
+            .and_then(|count| self.total.checked_div(count))
+            .unwrap_or_default()
+    }
+
+    pub(crate) const fn max(&self) -> Duration {
+        self.max
+    }
+
+    pub(crate) const fn min(&self) -> Duration {
+        self.min
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "avg {:?}, max {:?}, total {:?}",
+            self.average(),
+            self.max,
+            self.total
+        )
+    }
+}
+
+#[derive(Debug)]
+struct FeatureMetrics {
+    failures: u64,
+    name: &'static str,
+    updates: Timing,
+}
+
+#[derive(Debug)]
+pub(crate) struct Metrics {
+    features: Vec<FeatureMetrics>,
+    renders: Timing,
+}
+
+impl Metrics {
+    pub(crate) fn new(names: &[&'static str]) -> Self {
+        Self {
+            features: names
+                .iter()
+                .map(|&name| FeatureMetrics {
+                    failures: 0,
+                    name,
+                    updates: Timing::default(),
+                })
+                .collect(),
+            renders: Timing::default(),
+        }
+    }
+
+    pub(crate) fn shared(self) -> SharedMetrics {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub(crate) fn record_update(&mut self, id: usize, duration: Duration, success: bool) {
+        if let Some(feature) = self.features.get_mut(id) {
+            feature.updates.record(duration);
+
+            if !success {
+                feature.failures += 1;
+            }
+        }
+    }
+
+    pub(crate) fn record_render(&mut self, duration: Duration) {
+        self.renders.record(duration);
+    }
+
+    pub(crate) fn summary(&self) -> String {
+        let mut summary = String::new();
+
+        for feature in &self.features {
+            let _ = writeln!(
+                summary,
+                "{}: {} updates, {} failures, {}",
+                feature.name,
+                feature.updates.count,
+                feature.failures,
+                feature.updates.summary()
+            );
+        }
+
+        let _ = writeln!(
+            summary,
+            "render: {} renders, {}",
+            self.renders.count,
+            self.renders.summary()
+        );
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn timing_average_without_records() {
+        assert_that!(
+            Timing::default().average(),
+            is(equal_to(Duration::from_secs(0)))
+        );
+    }
+
+    #[test]
+    fn timing_record() {
+        let mut timing = Timing::default();
+
+        timing.record(Duration::from_millis(10));
+        timing.record(Duration::from_millis(30));
+
+        assert_that!(
+            timing,
+            is(equal_to(Timing {
+                count: 2,
+                max: Duration::from_millis(30),
+                min: Duration::from_millis(10),
+                total: Duration::from_millis(40),
+            }))
+        );
+        assert_that!(timing.average(), is(equal_to(Duration::from_millis(20))));
+    }
+
+    #[test]
+    fn metrics_summary() {
+        let mut metrics = Metrics::new(&["battery", "time"]);
+
+        metrics.record_update(0, Duration::from_millis(4), true);
+        metrics.record_update(0, Duration::from_millis(2), false);
+        metrics.record_update(2, Duration::from_millis(2), true);
+        metrics.record_render(Duration::from_millis(1));
+
+        assert_that!(
+            metrics.summary(),
+            is(equal_to(
+                "battery: 2 updates, 1 failures, avg 3ms, max 4ms, total 6ms\ntime: 0 updates, 0 \
+                 failures, avg 0ns, max 0ns, total 0ns\nrender: 1 renders, avg 1ms, max 1ms, \
+                 total 1ms\n"
+            ))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::error::*;
+use crate::wrapper::file;
+
+const ERROR_NAME: &str = "migration";
+
+pub(super) fn migrate_config(path: &str) -> Result<String> {
+    let content =
+        file::read(path).wrap_error(ERROR_NAME, format!("failed to read config {}", path))?;
+
+    convert_legacy_config(&content)
+}
+
+fn convert_legacy_config(content: &str) -> Result<String> {
+    let features = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|feature| {
+            if feature
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                Ok(format!("\"{}\"", feature))
+            } else {
+                Err(Error::new_custom(
+                    ERROR_NAME,
+                    format!(
+                        "invalid feature name '{}', is this a legacy config?",
+                        feature
+                    ),
+                ))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if features.is_empty() {
+        return Err(Error::new_custom(ERROR_NAME, "no features found"));
+    }
+
+    Ok(format!("features = [{}]\n", features.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn convert_legacy_config_when_valid() {
+        assert_that!(
+            convert_legacy_config("audio\n\n  battery \n# comment\ntime\n"),
+            is(equal_to(Ok("features = [\"audio\", \"battery\", \
+                            \"time\"]\n"
+                .to_owned())))
+        );
+    }
+
+    #[test]
+    fn convert_legacy_config_when_empty() {
+        assert_that!(convert_legacy_config("\n# comment\n").is_err(), is(true));
+    }
+
+    #[test]
+    fn convert_legacy_config_when_structured() {
+        assert_that!(
+            convert_legacy_config("separator = \" / \"\n").is_err(),
+            is(true)
+        );
+    }
+}
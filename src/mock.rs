@@ -0,0 +1,172 @@
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::config;
+use serde_derive::*;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+const ERROR_NAME: &str = "mock";
+
+#[derive(Clone, Debug, Deserialize)]
+pub(super) struct Fixture {
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    values: HashMap<String, String>,
+}
+
+pub(super) fn create_features(
+    fixtures_path: &str,
+    names: &[String],
+) -> Result<Vec<Box<dyn feature::Feature>>> {
+    let mut config = config::Config::new();
+    config.set_path(fixtures_path)?;
+    let mut fixtures: HashMap<String, Fixture> = config.try_into()?;
+
+    names
+        .iter()
+        .map(|name| {
+            let fixture = fixtures.remove(name).wrap_error(
+                ERROR_NAME,
+                format!("no fixture for feature {} in {}", name, fixtures_path),
+            )?;
+
+            let feature: Box<dyn feature::Feature> =
+                Box::new(MockFeature::new(name, fixture.text, fixture.values));
+
+            Ok(feature)
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub(crate) struct MockFeature {
+    keys: HashMap<String, &'static str>,
+    name: &'static str,
+    queue: VecDeque<(String, feature::Values)>,
+    text: String,
+    values: feature::Values,
+}
+
+impl MockFeature {
+    pub(crate) fn new<V>(name: &str, text: String, values: V) -> Self
+    where
+        V: IntoIterator<Item = (String, String)>,
+    {
+        let mut feature = Self {
+            keys: HashMap::new(),
+            name: leak(name.to_owned()),
+            queue: VecDeque::new(),
+            text,
+            values: feature::Values::new(),
+        };
+        feature.values = feature.intern_values(values);
+
+        feature
+    }
+
+    pub(crate) fn push<V>(&mut self, text: String, values: V)
+    where
+        V: IntoIterator<Item = (String, String)>,
+    {
+        let values = self.intern_values(values);
+        self.queue.push_back((text, values));
+    }
+
+    fn intern_values<V>(&mut self, values: V) -> feature::Values
+    where
+        V: IntoIterator<Item = (String, String)>,
+    {
+        let keys = &mut self.keys;
+
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                let key = *keys.entry(key.clone()).or_insert_with(|| leak(key));
+
+                (key, value)
+            })
+            .collect()
+    }
+}
+
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+impl feature::Renderable for MockFeature {
+    fn render(&self) -> &str {
+        &self.text
+    }
+
+    fn values(&self) -> feature::Values {
+        self.values.clone()
+    }
+}
+
+impl feature::Updatable for MockFeature {
+    fn renderable(&self) -> &dyn feature::Renderable {
+        self
+    }
+
+    fn update(&mut self) -> Result<()> {
+        if let Some((text, values)) = self.queue.pop_front() {
+            self.text = text;
+            self.values = values;
+        }
+
+        Ok(())
+    }
+}
+
+impl feature::Feature for MockFeature {
+    fn init_notifier(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::Feature;
+    use crate::feature::Renderable;
+    use crate::feature::Updatable;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn mock_feature_renders_fixture() {
+        let mut values = HashMap::new();
+        values.insert("CAP".to_owned(), "42".to_owned());
+
+        let object = MockFeature::new("battery", "▼ 42%".to_owned(), values);
+
+        assert_that!(object.name(), is(equal_to("battery")));
+        assert_that!(object.render(), is(equal_to("▼ 42%")));
+        assert_that!(object.values(), is(equal_to(map_values!("CAP" => "42"))));
+    }
+
+    #[test]
+    fn mock_feature_updates_queued_data() {
+        let mut object = MockFeature::new("battery", "▼ 42%".to_owned(), HashMap::new());
+        object.push(
+            "▼ 41%".to_owned(),
+            vec![("CAP".to_owned(), "41".to_owned())],
+        );
+
+        assert_that!(object.update(), is(equal_to(Ok(()))));
+        assert_that!(object.render(), is(equal_to("▼ 41%")));
+        assert_that!(object.values(), is(equal_to(map_values!("CAP" => "41"))));
+
+        assert_that!(object.update(), is(equal_to(Ok(()))));
+        assert_that!(object.render(), is(equal_to("▼ 41%")));
+    }
+}
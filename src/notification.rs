@@ -0,0 +1,253 @@
+mod config;
+mod dbus;
+
+use crate::error::*;
+#[cfg(feature = "libnotify")]
+use crate::wrapper::libnotify;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+pub(crate) use self::config::ConfigEntry;
+pub(crate) use self::config::NotificationConfig;
+pub(self) use self::dbus::DbusNotifications;
+pub(self) use self::dbus::Notification;
+
+pub(self) const ERROR_NAME: &str = "notification";
+
+static USE_LIBNOTIFY: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Urgency {
+    Critical,
+    Low,
+    Normal,
+}
+
+impl Urgency {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(crate) fn raised(self) -> Self {
+        match self {
+            Self::Low => Self::Normal,
+            Self::Normal | Self::Critical => Self::Critical,
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Low => "low",
+            Self::Normal => "normal",
+        }
+    }
+}
+
+pub(crate) fn init(settings: &ConfigEntry) -> Result<()> {
+    let use_libnotify = match &settings.backend[..] {
+        "dbus" => false,
+        #[cfg(feature = "libnotify")]
+        "libnotify" => true,
+        backend => {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!("unsupported backend '{}'", backend),
+            ));
+        },
+    };
+
+    USE_LIBNOTIFY.store(use_libnotify, Ordering::Relaxed);
+
+    Ok(())
+}
+
+enum Backend {
+    Dbus(DbusNotifications),
+    #[cfg(feature = "libnotify")]
+    LibNotify(libnotify::LibNotify),
+}
+
+pub(crate) struct Notifier {
+    backend: Backend,
+    last: Option<Sent>,
+}
+
+enum Sent {
+    Dbus(u32),
+    #[cfg(feature = "libnotify")]
+    LibNotify(libnotify::SentNotification),
+}
+
+impl Notifier {
+    pub(crate) fn init() -> Result<Self> {
+        let backend = if USE_LIBNOTIFY.load(Ordering::Relaxed) {
+            init_libnotify()?
+        } else {
+            Backend::Dbus(DbusNotifications::init()?)
+        };
+
+        Ok(Self {
+            backend,
+            last: None,
+        })
+    }
+
+    pub(crate) fn close(&mut self) -> Result<()> {
+        match (&self.backend, self.last.take()) {
+            (Backend::Dbus(notifications), Some(Sent::Dbus(id))) => notifications.close(id),
+            #[cfg(feature = "libnotify")]
+            (_, Some(Sent::LibNotify(notification))) => notification.close(),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn last_id(&self) -> Option<u32> {
+        match self.last {
+            Some(Sent::Dbus(id)) => Some(id),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn send(
+        &mut self,
+        config: &NotificationConfig,
+        placeholders: &[(&str, String)],
+    ) -> Result<()> {
+        self.send_with_actions(config, placeholders, &[])
+    }
+
+    pub(crate) fn send_with_actions(
+        &mut self,
+        config: &NotificationConfig,
+        placeholders: &[(&str, String)],
+        actions: &[(&str, &str)],
+    ) -> Result<()> {
+        self.notify(config, placeholders, actions, None)
+    }
+
+    pub(crate) fn send_with_progress(
+        &mut self,
+        config: &NotificationConfig,
+        placeholders: &[(&str, String)],
+        progress: u32,
+    ) -> Result<()> {
+        self.notify(config, placeholders, &[], Some(progress))
+    }
+
+    fn notify(
+        &mut self,
+        config: &NotificationConfig,
+        placeholders: &[(&str, String)],
+        actions: &[(&str, &str)],
+        progress: Option<u32>,
+    ) -> Result<()> {
+        let summary = render(&config.summary, placeholders);
+        let body = render(&config.body, placeholders);
+        let icon = if config.icon.is_empty() {
+            None
+        } else {
+            Some(&config.icon[..])
+        };
+        let urgency = config::parse_urgency(&config.urgency)?;
+
+        let last_id = self.last_id();
+
+        self.last = Some(match self.backend {
+            Backend::Dbus(ref notifications) => Sent::Dbus(notifications.notify(
+                last_id,
+                &Notification {
+                    actions,
+                    body: &body,
+                    icon,
+                    progress,
+                    summary: &summary,
+                    timeout: config.timeout,
+                    urgency,
+                },
+            )?),
+            #[cfg(feature = "libnotify")]
+            Backend::LibNotify(ref libnotify) => {
+                let notification = libnotify::Notification {
+                    body: &body,
+                    icon,
+                    summary: &summary,
+                    timeout: config.timeout,
+                    urgency: match urgency {
+                        Urgency::Critical => libnotify::Urgency::Critical,
+                        Urgency::Low => libnotify::Urgency::Low,
+                        Urgency::Normal => libnotify::Urgency::Normal,
+                    },
+                };
+
+                match (progress, self.last.take()) {
+                    (Some(_), Some(Sent::LibNotify(sent))) => {
+                        sent.update(&notification)?;
+                        Sent::LibNotify(sent)
+                    },
+                    _ => Sent::LibNotify(libnotify.send_notification(&notification)?),
+                }
+            },
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct LazyNotifier {
+    notifier: Option<Notifier>,
+}
+
+impl LazyNotifier {
+    pub(crate) fn get(&mut self) -> Result<&mut Notifier> {
+        if self.notifier.is_none() {
+            self.notifier = Some(Notifier::init()?);
+        }
+
+        self.notifier
+            .as_mut()
+            .wrap_error(ERROR_NAME, "notifier is missing")
+    }
+}
+
+pub(crate) fn listen_for_actions<F>(handle_action: F) -> Result<()>
+where
+    F: FnMut(u32, &str) -> Result<()>,
+{
+    DbusNotifications::listen_for_actions(handle_action)
+}
+
+#[cfg(feature = "libnotify")]
+fn init_libnotify() -> Result<Backend> {
+    Ok(Backend::LibNotify(libnotify::LibNotify::init()?))
+}
+
+#[cfg(not(feature = "libnotify"))]
+fn init_libnotify() -> Result<Backend> {
+    Ok(Backend::Dbus(DbusNotifications::init()?))
+}
+
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_owned(), |text, (placeholder, value)| {
+            text.replace(placeholder, value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn render_with_placeholders() {
+        assert_that!(
+            render(
+                "Battery under {LEVEL}% ({LEVEL})",
+                &[("{LEVEL}", "10".to_owned()), ("{TIME}", "00:20".to_owned())]
+            ),
+            is(equal_to("Battery under 10% (10)"))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use super::ERROR_NAME;
+use super::Urgency;
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::HashMap;
+
+#[cfg(feature = "libnotify")]
+const DEFAULT_BACKEND: &str = "libnotify";
+#[cfg(not(feature = "libnotify"))]
+const DEFAULT_BACKEND: &str = "dbus";
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) backend: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "notification",
+            map!(
+                "backend" => DEFAULT_BACKEND,
+            ),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct NotificationConfig {
+    pub(super) body: String,
+    pub(super) icon: String,
+    pub(super) summary: String,
+    pub(super) timeout: i32,
+    pub(super) urgency: String,
+}
+
+impl NotificationConfig {
+    pub(crate) fn default_map(summary: &str, body: &str, urgency: &str) -> HashMap<String, Value> {
+        map!(
+            "body"    => body,
+            "icon"    => "",
+            "summary" => summary,
+            "timeout" => -1,
+            "urgency" => urgency,
+        )
+    }
+
+    pub(crate) fn with_raised_urgency(&self, steps: usize) -> Result<Self> {
+        let urgency = (0..steps).fold(parse_urgency(&self.urgency)?, |urgency, _| urgency.raised());
+
+        Ok(Self {
+            urgency: urgency.name().to_owned(),
+            ..self.clone()
+        })
+    }
+}
+
+pub(super) fn parse_urgency(urgency: &str) -> Result<Urgency> {
+    match urgency {
+        "critical" => Ok(Urgency::Critical),
+        "low" => Ok(Urgency::Low),
+        "normal" => Ok(Urgency::Normal),
+        _ => Err(Error::new_custom(
+            ERROR_NAME,
+            format!("invalid urgency '{}'", urgency),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_urgency_when_valid() {
+        assert_that!(parse_urgency("low"), is(equal_to(Ok(Urgency::Low))));
+        assert_that!(parse_urgency("normal"), is(equal_to(Ok(Urgency::Normal))));
+        assert_that!(
+            parse_urgency("critical"),
+            is(equal_to(Ok(Urgency::Critical)))
+        );
+    }
+
+    #[test]
+    fn parse_urgency_when_invalid() {
+        assert_that!(parse_urgency("urgent").is_err(), is(true));
+    }
+
+    #[test]
+    fn with_raised_urgency() {
+        let config = NotificationConfig {
+            body: "body".to_owned(),
+            icon: String::new(),
+            summary: "summary".to_owned(),
+            timeout: -1,
+            urgency: "low".to_owned(),
+        };
+
+        let urgency = |steps| config.with_raised_urgency(steps).unwrap().urgency;
+
+        assert_that!(urgency(0), is(equal_to("low".to_owned())));
+        assert_that!(urgency(1), is(equal_to("normal".to_owned())));
+        assert_that!(urgency(2), is(equal_to("critical".to_owned())));
+        assert_that!(urgency(5), is(equal_to("critical".to_owned())));
+    }
+
+    #[cfg(feature = "mocking")]
+    mod config_type_set_default {
+        use super::*;
+        use crate::test_utils::config::test_set_default_err;
+        use crate::test_utils::config::test_set_default_ok;
+
+        #[test]
+        fn when_ok() {
+            test_set_default_ok::<ConfigEntry>("notification", default_map);
+        }
+
+        #[test]
+        fn when_err() {
+            test_set_default_err::<ConfigEntry>("notification", default_map);
+        }
+
+        fn default_map() -> HashMap<String, Value> {
+            let mut map = HashMap::new();
+            map.insert("backend".to_owned(), DEFAULT_BACKEND.into());
+
+            map
+        }
+    }
+}
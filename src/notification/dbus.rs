@@ -0,0 +1,125 @@
+use super::Urgency;
+use crate::error::*;
+use crate::wrapper::dbus;
+use std::collections::HashMap;
+
+const HINT_URGENCY: &str = "urgency";
+const HINT_VALUE: &str = "value";
+const INTERFACE_NOTIFICATIONS: &str = "org.freedesktop.Notifications";
+const MEMBER_ACTION_INVOKED: &str = "ActionInvoked";
+const MEMBER_CLOSE_NOTIFICATION: &str = "CloseNotification";
+const MEMBER_NOTIFY: &str = "Notify";
+const PATH_NOTIFICATIONS: &str = "/org/freedesktop/Notifications";
+
+pub(super) struct Notification<'a> {
+    pub(super) actions: &'a [(&'a str, &'a str)],
+    pub(super) body: &'a str,
+    pub(super) icon: Option<&'a str>,
+    pub(super) progress: Option<u32>,
+    pub(super) summary: &'a str,
+    pub(super) timeout: i32,
+    pub(super) urgency: Urgency,
+}
+
+pub(super) struct DbusNotifications {
+    connection: dbus::Connection,
+}
+
+impl DbusNotifications {
+    pub(super) fn init() -> Result<Self> {
+        Ok(Self {
+            connection: dbus::Connection::init_session()?,
+        })
+    }
+
+    pub(super) fn close(&self, id: u32) -> Result<()> {
+        let message = dbus::Message::init_method_call(
+            INTERFACE_NOTIFICATIONS,
+            PATH_NOTIFICATIONS,
+            INTERFACE_NOTIFICATIONS,
+            MEMBER_CLOSE_NOTIFICATION,
+        )?
+        .append(id);
+
+        self.connection.send_message(message)?;
+
+        Ok(())
+    }
+
+    pub(super) fn notify(
+        &self,
+        replaces_id: Option<u32>,
+        notification: &Notification<'_>,
+    ) -> Result<u32> {
+        let mut hints: HashMap<&str, dbus::Variant<Box<dyn dbus::RefArg>>> = HashMap::new();
+        hints.insert(
+            HINT_URGENCY,
+            dbus::Variant(Box::new(urgency_byte(notification.urgency))),
+        );
+        if let Some(progress) = notification.progress {
+            hints.insert(
+                HINT_VALUE,
+                dbus::Variant(Box::new(progress_value(progress))),
+            );
+        }
+
+        let actions = notification
+            .actions
+            .iter()
+            .flat_map(|(key, label)| vec![*key, *label])
+            .collect::<Vec<_>>();
+
+        let message = dbus::Message::init_method_call(
+            INTERFACE_NOTIFICATIONS,
+            PATH_NOTIFICATIONS,
+            INTERFACE_NOTIFICATIONS,
+            MEMBER_NOTIFY,
+        )?
+        .append("dwm-status")
+        .append(replaces_id.unwrap_or(0))
+        .append(notification.icon.unwrap_or(""))
+        .append(notification.summary)
+        .append(notification.body)
+        .append(actions)
+        .append(hints)
+        .append(notification.timeout);
+
+        self.connection.send_message(message)?.return_value::<u32>()
+    }
+
+    pub(super) fn listen_for_actions<F>(mut handle_action: F) -> Result<()>
+    where
+        F: FnMut(u32, &str) -> Result<()>,
+    {
+        let connection = dbus::Connection::init_session()?;
+
+        connection.add_match(dbus::Match::new(
+            INTERFACE_NOTIFICATIONS,
+            MEMBER_ACTION_INVOKED,
+            PATH_NOTIFICATIONS,
+        ))?;
+
+        connection.listen_for_signals(|signal| {
+            if signal.is_member(MEMBER_ACTION_INVOKED)? {
+                let (id, key) = signal.return_values::<u32, String>()?;
+
+                handle_action(id, &key)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn progress_value(progress: u32) -> i32 {
+    progress.min(100) as i32
+}
+
+fn urgency_byte(urgency: Urgency) -> u8 {
+    match urgency {
+        Urgency::Low => 0,
+        Urgency::Normal => 1,
+        Urgency::Critical => 2,
+    }
+}
@@ -0,0 +1,334 @@
+mod config;
+
+use crate::error::*;
+use crate::wrapper::xft;
+use crate::wrapper::xsetroot;
+use log::info;
+use log::warn;
+use std::cmp;
+use std::io;
+use std::io::Write;
+use std::os::raw::c_ulong;
+use std::time::Duration;
+use std::time::Instant;
+
+pub(crate) use self::config::ConfigEntry;
+
+const PROPERTY_DEFAULT: &str = "WM_NAME";
+const RECONNECT_DELAY_INITIAL: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+const WINDOW_ROOT: &str = "root";
+
+/// Sink of the rendered status text, written after every update of a feature.
+pub trait Output {
+    fn write(&mut self, status: &str) -> Result<()>;
+}
+
+/// Sets the status text as name of the X11 root window, which is shown by dwm.
+///
+/// The status is set on the display of `$DISPLAY` or on every display configured in
+/// `output.displays`. Instead of `WM_NAME` of the root window the status can be set as another
+/// property in `output.property` or on another window in `output.window`. If the X server restarts,
+/// the display is reopened with increasing delays between the attempts and rendering resumes with
+/// the next status text.
+#[derive(Debug)]
+pub struct RootWindow {
+    displays: Vec<Display>,
+}
+
+impl RootWindow {
+    pub fn init() -> Result<Self> {
+        Ok(Self {
+            displays: vec![Display::open(None, Target::default())?],
+        })
+    }
+
+    pub(crate) fn init_with_settings(settings: &ConfigEntry) -> Result<Self> {
+        let target = Target {
+            property: settings.property.clone(),
+            window: parse_window(&settings.window)?,
+        };
+
+        if settings.displays.is_empty() {
+            return Ok(Self {
+                displays: vec![Display::open(None, target)?],
+            });
+        }
+
+        Ok(Self {
+            displays: settings
+                .displays
+                .iter()
+                .map(|name| Display::open(Some(name.clone()), target.clone()))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl Output for RootWindow {
+    fn write(&mut self, status: &str) -> Result<()> {
+        let now = Instant::now();
+        let mut result = Ok(());
+
+        for display in &mut self.displays {
+            if let Some(xsetroot) = display.connect(now) {
+                let display_result = xsetroot.render(status.to_owned());
+                result = result.and(display_result);
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Target {
+    property: String,
+    window: Option<c_ulong>,
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self {
+            property: PROPERTY_DEFAULT.to_owned(),
+            window: None,
+        }
+    }
+}
+
+fn parse_window(window: &str) -> Result<Option<c_ulong>> {
+    if window == WINDOW_ROOT {
+        return Ok(None);
+    }
+
+    let id = if window.starts_with("0x") {
+        c_ulong::from_str_radix(&window[2..], 16)
+    } else {
+        window.parse()
+    };
+
+    id.map(Some).wrap_error(
+        "output",
+        format!("invalid window {}, expected root or a window id", window),
+    )
+}
+
+#[derive(Debug)]
+struct Display {
+    name: Option<String>,
+    reconnect: Backoff,
+    target: Target,
+    xsetroot: Option<xsetroot::XSetRoot>,
+}
+
+impl Display {
+    fn open(name: Option<String>, target: Target) -> Result<Self> {
+        let mut display = Self {
+            name,
+            reconnect: Backoff::default(),
+            target,
+            xsetroot: None,
+        };
+        display.xsetroot = Some(display.init_xsetroot()?);
+
+        Ok(display)
+    }
+
+    fn init_xsetroot(&self) -> Result<xsetroot::XSetRoot> {
+        xsetroot::XSetRoot::init(
+            self.name.as_deref(),
+            self.target.window,
+            &self.target.property,
+        )
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or("$DISPLAY")
+    }
+
+    fn connect(&mut self, now: Instant) -> Option<&xsetroot::XSetRoot> {
+        match self.xsetroot.as_ref().map(xsetroot::XSetRoot::is_connected) {
+            Some(true) => return self.xsetroot.as_ref(),
+            Some(false) => {
+                warn!(
+                    "Lost connection to X server of display {}, reopen display",
+                    self.name()
+                );
+                self.xsetroot = None;
+            },
+            None => (),
+        }
+
+        if !self.reconnect.is_due(now) {
+            return None;
+        }
+
+        match self.init_xsetroot() {
+            Ok(xsetroot) => {
+                info!("Reopened display {}", self.name());
+                self.reconnect = Backoff::default();
+                self.xsetroot = Some(xsetroot);
+            },
+            Err(_) => self.reconnect.fail(now),
+        }
+
+        self.xsetroot.as_ref()
+    }
+}
+
+#[derive(Debug)]
+struct Backoff {
+    delay: Duration,
+    next_attempt: Option<Instant>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            delay: RECONNECT_DELAY_INITIAL,
+            next_attempt: None,
+        }
+    }
+}
+
+impl Backoff {
+    fn is_due(&self, now: Instant) -> bool {
+        self.next_attempt
+            .map_or(true, |next_attempt| now >= next_attempt)
+    }
+
+    fn fail(&mut self, now: Instant) {
+        self.next_attempt = Some(now + self.delay);
+        self.delay = cmp::min(self.delay * 2, RECONNECT_DELAY_MAX);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TextWidth {
+    font: Option<xft::Font>,
+    max_width: usize,
+    name: String,
+    reopen: Backoff,
+}
+
+impl TextWidth {
+    pub(crate) fn init(settings: &ConfigEntry) -> Result<Option<Self>> {
+        if settings.font.is_empty() || settings.max_width == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            font: Some(xft::Font::open(&settings.font)?),
+            max_width: settings.max_width,
+            name: settings.font.clone(),
+            reopen: Backoff::default(),
+        }))
+    }
+
+    pub(crate) const fn max_width(&self) -> usize {
+        self.max_width
+    }
+
+    pub(crate) fn connect(&mut self, now: Instant) -> bool {
+        if self.font.as_ref().map_or(false, xft::Font::is_connected) {
+            return true;
+        }
+
+        self.font = None;
+
+        if !self.reopen.is_due(now) {
+            return false;
+        }
+
+        match xft::Font::open(&self.name) {
+            Ok(font) => {
+                info!("Reopened font {}", self.name);
+                self.reopen = Backoff::default();
+                self.font = Some(font);
+                true
+            },
+            Err(_) => {
+                self.reopen.fail(now);
+                false
+            },
+        }
+    }
+
+    pub(crate) fn width(&self, text: &str) -> usize {
+        self.font.as_ref().map_or(0, |font| font.width(text))
+    }
+}
+
+/// Prints every status text as line on stdout, e.g. for bars reading their input from a pipe.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stdout;
+
+impl Output for Stdout {
+    fn write(&mut self, status: &str) -> Result<()> {
+        let stdout = io::stdout();
+        let mut handle = stdout.lock();
+
+        writeln!(handle, "{}", status)
+            .and_then(|_| handle.flush())
+            .wrap_error("render", "failed to write status to stdout")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_window_with_root() {
+        assert_that!(parse_window("root"), is(equal_to(Ok(None))));
+    }
+
+    #[test]
+    fn parse_window_with_id() {
+        assert_that!(parse_window("23068679"), is(equal_to(Ok(Some(23_068_679)))));
+        assert_that!(
+            parse_window("0x1600007"),
+            is(equal_to(Ok(Some(0x0160_0007))))
+        );
+    }
+
+    #[test]
+    fn parse_window_with_invalid_id() {
+        assert_that!(parse_window("bar").is_err(), is(true));
+        assert_that!(parse_window("0xbar").is_err(), is(true));
+    }
+
+    #[test]
+    fn backoff_is_due_initially() {
+        assert_that!(Backoff::default().is_due(Instant::now()), is(true));
+    }
+
+    #[test]
+    fn backoff_doubles_delay() {
+        let now = Instant::now();
+        let mut backoff = Backoff::default();
+
+        backoff.fail(now);
+        assert_that!(backoff.is_due(now), is(false));
+        assert_that!(backoff.is_due(now + Duration::from_secs(1)), is(true));
+
+        backoff.fail(now);
+        assert_that!(backoff.is_due(now + Duration::from_secs(1)), is(false));
+        assert_that!(backoff.is_due(now + Duration::from_secs(2)), is(true));
+    }
+
+    #[test]
+    fn backoff_limits_delay() {
+        let now = Instant::now();
+        let mut backoff = Backoff::default();
+
+        for _ in 0..10 {
+            backoff.fail(now);
+        }
+
+        assert_that!(backoff.delay, is(equal_to(RECONNECT_DELAY_MAX)));
+        assert_that!(backoff.is_due(now + RECONNECT_DELAY_MAX), is(true));
+    }
+}
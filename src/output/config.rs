@@ -0,0 +1,59 @@
+use crate::error::*;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) displays: Vec<String>,
+    pub(super) font: String,
+    pub(super) max_width: usize,
+    pub(super) property: String,
+    pub(super) window: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "output",
+            map!(
+                "displays"  => Vec::<String>::new(),
+                "font"      => "",
+                "max_width" => 0,
+                "property"  => "WM_NAME",
+                "window"    => "root",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+    use std::collections::HashMap;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("output", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("output", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("displays".to_owned(), Vec::<String>::new().into());
+        map.insert("font".to_owned(), "".into());
+        map.insert("max_width".to_owned(), 0.into());
+        map.insert("property".to_owned(), "WM_NAME".into());
+        map.insert("window".to_owned(), "root".into());
+
+        map
+    }
+}
@@ -0,0 +1,195 @@
+use std::cmp::Reverse;
+use std::iter;
+
+const ELLIPSIS: char = '…';
+
+pub(crate) fn fit<F>(
+    mut values: Vec<Option<String>>,
+    priorities: &[i64],
+    max_length: usize,
+    length: F,
+) -> Vec<Option<String>>
+where
+    F: Fn(&[Option<String>]) -> usize,
+{
+    if max_length == 0 {
+        return values;
+    }
+
+    loop {
+        let length = length(&values);
+
+        if length <= max_length {
+            break;
+        }
+
+        let index = match lowest_priority(&values, priorities) {
+            Some(index) => index,
+            None => break,
+        };
+
+        values[index] = shorten_to_fit(&mut values, index, max_length, &length);
+    }
+
+    values
+}
+
+fn shorten_to_fit<F>(
+    values: &mut [Option<String>],
+    index: usize,
+    max_length: usize,
+    length: &F,
+) -> Option<String>
+where
+    F: Fn(&[Option<String>]) -> usize,
+{
+    let value = values[index].take()?;
+    let mut shortened = None;
+
+    let mut low = 2;
+    let mut high = value.chars().count().saturating_sub(1);
+
+    while low <= high {
+        let middle = (low + high) / 2;
+        values[index] = Some(shorten(&value, middle));
+
+        if length(values) <= max_length {
+            shortened = values[index].take();
+            low = middle + 1;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    shortened
+}
+
+fn lowest_priority(values: &[Option<String>], priorities: &[i64]) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| value.as_ref().map(|value| (index, value)))
+        .min_by_key(|(index, value)| {
+            (
+                priorities.get(*index).copied().unwrap_or_default(),
+                Reverse(value.chars().count()),
+            )
+        })
+        .map(|(index, _)| index)
+}
+
+fn shorten(value: &str, length: usize) -> String {
+    value
+        .chars()
+        .take(length - 1)
+        .chain(iter::once(ELLIPSIS))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    fn values(values: &[&str]) -> Vec<Option<String>> {
+        values
+            .iter()
+            .map(|value| Some((*value).to_owned()))
+            .collect()
+    }
+
+    fn length(values: &[Option<String>]) -> usize {
+        values
+            .iter()
+            .filter_map(Option::as_deref)
+            .collect::<Vec<_>>()
+            .join(" / ")
+            .chars()
+            .count()
+    }
+
+    #[test]
+    fn fit_without_limit() {
+        assert_that!(
+            fit(values(&["a long title", "12:00"]), &[0, 1], 0, length),
+            is(equal_to(vec![
+                Some("a long title".to_owned()),
+                Some("12:00".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_when_short_enough() {
+        assert_that!(
+            fit(values(&["title", "12:00"]), &[0, 1], 13, length),
+            is(equal_to(vec![
+                Some("title".to_owned()),
+                Some("12:00".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_shortens_lowest_priority() {
+        assert_that!(
+            fit(values(&["12:00", "a long title"]), &[1, 0], 17, length),
+            is(equal_to(vec![
+                Some("12:00".to_owned()),
+                Some("a long t…".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_shortens_longest_value_of_same_priority() {
+        assert_that!(
+            fit(values(&["80%", "a long title"]), &[], 14, length),
+            is(equal_to(vec![
+                Some("80%".to_owned()),
+                Some("a long …".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_hides_lowest_priority() {
+        assert_that!(
+            fit(values(&["80%", "title", "12:00"]), &[1, 0, 2], 12, length),
+            is(equal_to(vec![
+                Some("80%".to_owned()),
+                None,
+                Some("12:00".to_owned())
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_shortens_with_wide_characters() {
+        let width = |values: &[Option<String>]| {
+            values
+                .iter()
+                .filter_map(Option::as_deref)
+                .flat_map(str::chars)
+                .map(|c| if c.is_ascii() { 1 } else { 2 })
+                .sum()
+        };
+
+        assert_that!(
+            fit(values(&["12:00", "東京都の天気"]), &[1, 0], 12, width),
+            is(equal_to(vec![
+                Some("12:00".to_owned()),
+                Some("東京…".to_owned()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fit_hides_all_values() {
+        assert_that!(
+            fit(values(&["80%", "12:00"]), &[0, 1], 1, length),
+            is(equal_to(vec![None, None]))
+        );
+    }
+}
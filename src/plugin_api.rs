@@ -0,0 +1,13 @@
+//! Types to implement features in other crates, available with the cargo feature `plugin-api`.
+//!
+//! The types follow semantic versioning of dwm-status. Features are registered with
+//! [`Builder::custom_feature`](../struct.Builder.html#method.custom_feature) and get updated
+//! after they send `Message::FeatureUpdate` with their id.
+
+pub use crate::communication::Message;
+pub use crate::error::Error;
+pub use crate::feature::Feature;
+pub use crate::feature::Renderable;
+pub use crate::feature::Updatable;
+pub use crate::feature::Values;
+pub use crate::wrapper::channel::Sender;
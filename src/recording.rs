@@ -0,0 +1,183 @@
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::mock;
+use crate::settings;
+use crate::status_bar::StatusBar;
+use crate::wrapper::file;
+use crate::wrapper::thread;
+use serde_derive::*;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+const ERROR_NAME: &str = "recording";
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum Entry {
+    Features {
+        names: Vec<String>,
+    },
+    Update {
+        elapsed_ms: u64,
+        feature: usize,
+        text: String,
+        values: BTreeMap<String, String>,
+    },
+}
+
+pub(crate) struct Recorder {
+    start: Instant,
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    pub(crate) fn init(path: &str, names: &[&'static str]) -> Result<Self> {
+        let file =
+            File::create(path).wrap_error(ERROR_NAME, format!("failed to create {}", path))?;
+
+        let mut recorder = Self {
+            start: Instant::now(),
+            writer: BufWriter::new(file),
+        };
+
+        recorder.write(&Entry::Features {
+            names: names.iter().map(|name| (*name).to_owned()).collect(),
+        })?;
+
+        Ok(recorder)
+    }
+
+    pub(crate) fn record(&mut self, id: usize, renderable: &dyn feature::Renderable) -> Result<()> {
+        self.write(&Entry::Update {
+            elapsed_ms: u64::try_from(self.start.elapsed().as_millis()).unwrap_or(u64::max_value()),
+            feature: id,
+            text: renderable.render().to_owned(),
+            values: renderable
+                .values()
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        })
+    }
+
+    fn write(&mut self, entry: &Entry) -> Result<()> {
+        let line = serde_json::to_string(entry).wrap_error(ERROR_NAME, "failed to serialize")?;
+
+        writeln!(self.writer, "{}", line)
+            .and_then(|_| self.writer.flush())
+            .wrap_error(ERROR_NAME, "failed to write recording")
+    }
+}
+
+pub(crate) fn replay<F>(path: &str, settings: &settings::General, init: F) -> Result<()>
+where
+    F: FnOnce(Vec<Box<dyn feature::Feature>>) -> Result<StatusBar>,
+{
+    let content = file::read(path).wrap_error(ERROR_NAME, format!("failed to read {}", path))?;
+    let mut entries = parse(&content)?.into_iter();
+
+    let names = match entries.next() {
+        Some(Entry::Features { names }) => names,
+        _ => {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                "recording does not start with the list of features",
+            ));
+        },
+    };
+
+    let mut features = names
+        .iter()
+        .map(|name| mock::MockFeature::new(name, String::new(), BTreeMap::new()))
+        .collect::<Vec<_>>();
+    let mut updates = Vec::new();
+
+    for entry in entries {
+        if let Entry::Update {
+            elapsed_ms,
+            feature,
+            text,
+            values,
+        } = entry
+        {
+            features
+                .get_mut(feature)
+                .wrap_error(ERROR_NAME, format!("feature id {} does not exist", feature))?
+                .push(text, values);
+            updates.push((elapsed_ms, feature));
+        }
+    }
+
+    let mut status_bar = init(
+        features
+            .into_iter()
+            .map(|replayed| -> Box<dyn feature::Feature> { Box::new(replayed) })
+            .collect(),
+    )?;
+
+    let start = Instant::now();
+
+    for (elapsed_ms, id) in updates {
+        let elapsed = Duration::from_millis(elapsed_ms);
+        if let Some(delay) = elapsed.checked_sub(start.elapsed()) {
+            thread::sleep(delay);
+        }
+
+        status_bar.update(&communication::Message::FeatureUpdate(id), settings)?;
+    }
+
+    Ok(())
+}
+
+fn parse(content: &str) -> Result<Vec<Entry>> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .wrap_error(ERROR_NAME, format!("invalid recording entry: {}", line))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn parse_entries() {
+        let mut values = BTreeMap::new();
+        values.insert("CAP".to_owned(), "42".to_owned());
+
+        assert_that!(
+            parse(
+                "{\"type\":\"features\",\"names\":[\"battery\"]}\n{\"type\":\"update\",\"\
+                 elapsed_ms\":10,\"feature\":0,\"text\":\"42%\",\"values\":{\"CAP\":\"42\"}}\n"
+            ),
+            is(equal_to(Ok(vec![
+                Entry::Features {
+                    names: vec!["battery".to_owned()],
+                },
+                Entry::Update {
+                    elapsed_ms: 10,
+                    feature: 0,
+                    text: "42%".to_owned(),
+                    values,
+                },
+            ])))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_entry() {
+        assert_that!(parse("{\"type\":\"unknown\"}").is_err(), is(true));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::feature;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) type SharedRegistry = Arc<Mutex<Registry>>;
+
+#[derive(Debug)]
+struct FeatureValues {
+    name: String,
+    values: feature::Values,
+}
+
+#[derive(Debug)]
+pub(crate) struct Registry {
+    features: Vec<FeatureValues>,
+}
+
+impl Registry {
+    pub(crate) fn new<S: AsRef<str>>(names: &[S]) -> Self {
+        Self {
+            features: names
+                .iter()
+                .map(|name| FeatureValues {
+                    name: name.as_ref().to_lowercase(),
+                    values: feature::Values::new(),
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn shared(self) -> SharedRegistry {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub(crate) fn record(&mut self, id: usize, values: feature::Values) -> bool {
+        match self.features.get_mut(id) {
+            Some(feature) if feature.values != values => {
+                feature.values = values;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    pub(crate) fn value(&self, name: &str, key: &str) -> Option<&str> {
+        self.features
+            .iter()
+            .find(|feature| feature.name.eq_ignore_ascii_case(name))?
+            .values
+            .iter()
+            .find(|(value_key, _)| value_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| &value[..])
+    }
+}
+
+pub(crate) fn subscribers(names: &[&str], subscriptions: &[Vec<String>]) -> Vec<Vec<usize>> {
+    let mut subscribers = vec![Vec::new(); names.len()];
+
+    for (subscriber, names_subscribed) in subscriptions.iter().enumerate() {
+        for name in names_subscribed {
+            for (id, _) in names
+                .iter()
+                .enumerate()
+                .filter(|(_, feature)| feature.eq_ignore_ascii_case(name))
+            {
+                if id != subscriber && !subscribers[id].contains(&subscriber) {
+                    subscribers[id].push(subscriber);
+                }
+            }
+        }
+    }
+
+    subscribers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn value_after_record() {
+        let mut registry = Registry::new(&["Network", "time"]);
+
+        registry.record(
+            0,
+            map_values!(
+                "ESSID" => "home",
+                "IPv4" => "192.168.1.23",
+            ),
+        );
+
+        assert_that!(
+            registry.value("network", "ESSID"),
+            is(equal_to(Some("home")))
+        );
+        assert_that!(
+            registry.value("NETWORK", "ipv4"),
+            is(equal_to(Some("192.168.1.23")))
+        );
+    }
+
+    #[test]
+    fn value_of_unknown_feature_or_key() {
+        let mut registry = Registry::new(&["network"]);
+
+        registry.record(0, map_values!("ESSID" => "home"));
+
+        assert_that!(registry.value("battery", "CAP"), is(none()));
+        assert_that!(registry.value("network", "BSSID"), is(none()));
+    }
+
+    #[test]
+    fn record_returns_whether_changed() {
+        let mut registry = Registry::new(&["network"]);
+
+        assert_that!(registry.record(0, map_values!("ESSID" => "home")), is(true));
+        assert_that!(
+            registry.record(0, map_values!("ESSID" => "home")),
+            is(false)
+        );
+        assert_that!(registry.record(0, map_values!("ESSID" => "work")), is(true));
+    }
+
+    #[test]
+    fn subscribers_by_name() {
+        let subscriptions = vec![
+            Vec::new(),
+            vec!["Network".to_owned(), "unknown".to_owned()],
+            vec!["network".to_owned(), "composite".to_owned()],
+        ];
+
+        assert_that!(
+            subscribers(&["network", "composite", "speedtest"], &subscriptions),
+            is(equal_to(vec![vec![1, 2], vec![2], Vec::new()]))
+        );
+    }
+
+    #[test]
+    fn subscribers_ignores_self() {
+        let subscriptions = vec![vec!["network".to_owned()]];
+
+        assert_that!(
+            subscribers(&["network"], &subscriptions),
+            is(equal_to(vec![Vec::<usize>::new()]))
+        );
+    }
+
+    #[test]
+    fn record_with_unknown_id() {
+        let mut registry = Registry::new(&["network"]);
+
+        registry.record(3, map_values!("ESSID" => "home"));
+
+        assert_that!(registry.value("network", "ESSID"), is(none()));
+    }
+}
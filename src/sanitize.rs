@@ -0,0 +1,72 @@
+const REPLACEMENT_WHITESPACE: char = ' ';
+
+pub(crate) fn sanitize(value: String, strip_control_characters: bool) -> String {
+    let is_line_break = |c: char| c == '\n' || c == '\r';
+    let is_unsafe =
+        |c: char| c.is_control() && (strip_control_characters || is_control_whitespace(c));
+
+    if !value.contains(is_unsafe) {
+        return value;
+    }
+
+    value
+        .trim_end_matches(is_line_break)
+        .chars()
+        .filter_map(|c| {
+            if is_control_whitespace(c) {
+                Some(REPLACEMENT_WHITESPACE)
+            } else if is_unsafe(c) {
+                None
+            } else {
+                Some(c)
+            }
+        })
+        .collect()
+}
+
+fn is_control_whitespace(c: char) -> bool {
+    c.is_control() && c.is_whitespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn sanitize_without_control_characters() {
+        assert_that!(
+            sanitize("S 50% · 12:00".to_owned(), true),
+            is(equal_to("S 50% · 12:00".to_owned()))
+        );
+    }
+
+    #[test]
+    fn sanitize_with_line_breaks() {
+        assert_that!(
+            sanitize("first\nsecond\r\n".to_owned(), true),
+            is(equal_to("first second".to_owned()))
+        );
+        assert_that!(
+            sanitize("a\tb\n".to_owned(), false),
+            is(equal_to("a b".to_owned()))
+        );
+    }
+
+    #[test]
+    fn sanitize_with_control_characters() {
+        assert_that!(
+            sanitize("\u{1b}[1mbold\u{7}".to_owned(), true),
+            is(equal_to("[1mbold".to_owned()))
+        );
+    }
+
+    #[test]
+    fn sanitize_keeping_control_characters() {
+        assert_that!(
+            sanitize("\u{1}normal\u{4}urgent\n".to_owned(), false),
+            is(equal_to("\u{1}normal\u{4}urgent".to_owned()))
+        );
+    }
+}
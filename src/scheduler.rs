@@ -0,0 +1,116 @@
+use crate::error::*;
+use crate::wrapper::thread;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::time::Duration;
+use std::time::Instant;
+
+#[derive(Debug, Default)]
+pub(crate) struct Scheduler {
+    queue: BinaryHeap<Reverse<(Instant, usize)>>,
+}
+
+impl Scheduler {
+    pub(crate) fn schedule(&mut self, id: usize, due: Instant) {
+        self.queue.push(Reverse((due, id)));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub(crate) fn timeout(&self, now: Instant) -> Option<Duration> {
+        self.queue
+            .peek()
+            .map(|Reverse((due, _))| due.saturating_duration_since(now))
+    }
+
+    pub(crate) fn pop_due(&mut self, now: Instant) -> Vec<usize> {
+        let mut ids = Vec::new();
+
+        while let Some(Reverse((due, id))) = self.queue.peek() {
+            if *due > now {
+                break;
+            }
+
+            ids.push(*id);
+            self.queue.pop();
+        }
+
+        ids
+    }
+}
+
+pub(crate) struct Interval {
+    update_interval: u64,
+}
+
+impl Interval {
+    pub(crate) const fn new(update_interval: u64) -> Self {
+        Self { update_interval }
+    }
+}
+
+impl thread::Runnable for Interval {
+    fn run(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<u64> {
+        Some(self.update_interval)
+    }
+
+    fn next_update(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.update_interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn timeout_without_schedule() {
+        assert_that!(Scheduler::default().timeout(Instant::now()), is(none()));
+    }
+
+    #[test]
+    fn timeout_of_next_update() {
+        let now = Instant::now();
+        let mut scheduler = Scheduler::default();
+
+        scheduler.schedule(0, now + Duration::from_secs(20));
+        scheduler.schedule(1, now + Duration::from_secs(5));
+
+        assert_that!(
+            scheduler.timeout(now),
+            is(equal_to(Some(Duration::from_secs(5))))
+        );
+        assert_that!(
+            scheduler.timeout(now + Duration::from_secs(10)),
+            is(equal_to(Some(Duration::from_secs(0))))
+        );
+    }
+
+    #[test]
+    fn pop_due_in_order() {
+        let now = Instant::now();
+        let mut scheduler = Scheduler::default();
+
+        scheduler.schedule(0, now + Duration::from_secs(20));
+        scheduler.schedule(1, now + Duration::from_secs(5));
+        scheduler.schedule(2, now + Duration::from_secs(1));
+
+        assert_that!(
+            scheduler.pop_due(now + Duration::from_secs(5)),
+            is(equal_to(vec![2, 1]))
+        );
+        assert_that!(scheduler.pop_due(now + Duration::from_secs(5)), is(empty()));
+        assert_that!(
+            scheduler.timeout(now + Duration::from_secs(5)),
+            is(equal_to(Some(Duration::from_secs(15))))
+        );
+    }
+}
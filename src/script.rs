@@ -0,0 +1,277 @@
+mod config;
+
+use crate::error::*;
+use crate::feature::Values;
+use rhai::AST;
+use rhai::Dynamic;
+use rhai::Engine;
+use rhai::Map;
+use rhai::Scope;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+pub(crate) use self::config::ConfigEntry;
+
+const ERROR_NAME: &str = "script";
+const VARIABLE_DATA: &str = "data";
+const VARIABLE_STATE: &str = "state";
+const VARIABLE_VALUE: &str = "value";
+
+pub(crate) struct Hooks {
+    conditions: HashMap<String, AST>,
+    engine: Engine,
+    scripts: HashMap<String, AST>,
+}
+
+impl Hooks {
+    pub(crate) fn init(settings: &ConfigEntry) -> Result<Self> {
+        let engine = Engine::new();
+
+        let scripts = settings
+            .hooks
+            .iter()
+            .map(|(feature, path)| {
+                let ast = engine.compile_file(PathBuf::from(path)).wrap_error(
+                    ERROR_NAME,
+                    format!("failed to compile script {} of feature {}", path, feature),
+                )?;
+
+                Ok((feature.to_lowercase(), ast))
+            })
+            .collect::<Result<_>>()?;
+
+        let conditions = settings
+            .hide_when
+            .iter()
+            .map(|(feature, expression)| {
+                let ast = engine.compile_expression(expression).wrap_error(
+                    ERROR_NAME,
+                    format!(
+                        "failed to compile hide_when expression of feature {}",
+                        feature
+                    ),
+                )?;
+
+                Ok((feature.to_lowercase(), ast))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            conditions,
+            engine,
+            scripts,
+        })
+    }
+
+    #[cfg(test)]
+    fn from_script(feature: &str, script: &str) -> Self {
+        let engine = Engine::new();
+
+        let mut scripts = HashMap::new();
+        scripts.insert(feature.to_owned(), engine.compile(script).unwrap());
+
+        Self {
+            conditions: HashMap::new(),
+            engine,
+            scripts,
+        }
+    }
+
+    #[cfg(test)]
+    fn from_condition(feature: &str, expression: &str) -> Self {
+        let engine = Engine::new();
+
+        let mut conditions = HashMap::new();
+        conditions.insert(
+            feature.to_owned(),
+            engine.compile_expression(expression).unwrap(),
+        );
+
+        Self {
+            conditions,
+            engine,
+            scripts: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn apply<F>(
+        &self,
+        feature: &str,
+        value: &str,
+        state: &str,
+        values: F,
+    ) -> Option<Result<String>>
+    where
+        F: FnOnce() -> Values,
+    {
+        let ast = self.scripts.get(feature)?;
+        let mut scope = scope(value, state, values);
+
+        Some(
+            self.engine
+                .eval_ast_with_scope::<Dynamic>(&mut scope, ast)
+                .map(|result| result.to_string())
+                .wrap_error(ERROR_NAME, format!("script of feature {} failed", feature)),
+        )
+    }
+
+    pub(crate) fn is_hidden<F>(
+        &self,
+        feature: &str,
+        value: &str,
+        state: &str,
+        values: F,
+    ) -> Option<Result<bool>>
+    where
+        F: FnOnce() -> Values,
+    {
+        let ast = self.conditions.get(feature)?;
+        let mut scope = scope(value, state, values);
+
+        Some(
+            self.engine
+                .eval_ast_with_scope::<bool>(&mut scope, ast)
+                .wrap_error(
+                    ERROR_NAME,
+                    format!("hide_when expression of feature {} failed", feature),
+                ),
+        )
+    }
+}
+
+fn scope<F>(value: &str, state: &str, values: F) -> Scope<'static>
+where
+    F: FnOnce() -> Values,
+{
+    let data = values()
+        .into_iter()
+        .map(|(key, value)| (key.to_owned(), Dynamic::from(value)))
+        .collect::<Map>();
+
+    let mut scope = Scope::new();
+    scope.push(VARIABLE_DATA, data);
+    scope.push(VARIABLE_STATE, state.to_owned());
+    scope.push(VARIABLE_VALUE, value.to_owned());
+
+    scope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn apply_without_script() {
+        let hooks = Hooks::from_script("battery", "value");
+
+        assert_that!(
+            hooks.apply("time", "12:00", "ok", Values::new),
+            is(equal_to(None))
+        );
+    }
+
+    #[test]
+    fn apply_with_value() {
+        let hooks = Hooks::from_script("time", r#"value + " o'clock""#);
+
+        assert_that!(
+            hooks.apply("time", "12", "ok", Values::new),
+            is(equal_to(Some(Ok("12 o'clock".to_owned()))))
+        );
+    }
+
+    #[test]
+    fn apply_with_data() {
+        let hooks = Hooks::from_script(
+            "battery",
+            r#"if data.STATUS == "charging" { "+" + data.CAP } else { value }"#,
+        );
+
+        assert_that!(
+            hooks.apply("battery", "80%", "ok", || {
+                map_values!("CAP" => "80", "STATUS" => "charging")
+            }),
+            is(equal_to(Some(Ok("+80".to_owned()))))
+        );
+    }
+
+    #[test]
+    fn apply_with_failing_script() {
+        let hooks = Hooks::from_script("battery", "data.CAP.unknown()");
+
+        assert_that!(
+            hooks
+                .apply("battery", "80%", "ok", Values::new)
+                .unwrap()
+                .is_err(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn is_hidden_without_condition() {
+        let hooks = Hooks::from_condition("audio", "true");
+
+        assert_that!(
+            hooks.is_hidden("time", "12:00", "ok", Values::new),
+            is(equal_to(None))
+        );
+    }
+
+    #[test]
+    fn is_hidden_with_data() {
+        let hooks = Hooks::from_condition("audio", r#"data.MUTE == "false" && data.VOL == "50""#);
+
+        assert_that!(
+            hooks.is_hidden("audio", "S 50%", "ok", || {
+                map_values!("MUTE" => "false", "VOL" => "50")
+            }),
+            is(equal_to(Some(Ok(true))))
+        );
+        assert_that!(
+            hooks.is_hidden("audio", "S 60%", "ok", || {
+                map_values!("MUTE" => "false", "VOL" => "60")
+            }),
+            is(equal_to(Some(Ok(false))))
+        );
+    }
+
+    #[test]
+    fn is_hidden_with_value() {
+        let hooks = Hooks::from_condition("network", r#"value == "NA""#);
+
+        assert_that!(
+            hooks.is_hidden("network", "NA", "ok", Values::new),
+            is(equal_to(Some(Ok(true))))
+        );
+    }
+
+    #[test]
+    fn is_hidden_with_state() {
+        let hooks = Hooks::from_condition("cpu_load", r#"state == "ok""#);
+
+        assert_that!(
+            hooks.is_hidden("cpu_load", "0.50", "ok", Values::new),
+            is(equal_to(Some(Ok(true))))
+        );
+        assert_that!(
+            hooks.is_hidden("cpu_load", "3.50", "critical", Values::new),
+            is(equal_to(Some(Ok(false))))
+        );
+    }
+
+    #[test]
+    fn is_hidden_with_non_boolean_result() {
+        let hooks = Hooks::from_condition("audio", "value");
+
+        assert_that!(
+            hooks
+                .is_hidden("audio", "S 50%", "ok", Values::new)
+                .unwrap()
+                .is_err(),
+            is(true)
+        );
+    }
+}
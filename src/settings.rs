@@ -1,13 +1,68 @@
+use crate::click;
 use crate::error::*;
 use crate::features::audio;
 use crate::features::backlight;
 use crate::features::battery;
+use crate::features::bluetooth;
+use crate::features::ci;
+use crate::features::composite;
 use crate::features::cpu_load;
+use crate::features::cpu_temp;
+use crate::features::dhcp;
+use crate::features::disk_usage;
+use crate::features::git;
+use crate::features::gpu;
+use crate::features::homeassistant;
+use crate::features::inhibitors;
+use crate::features::inodes;
+use crate::features::keyboard;
+use crate::features::kubernetes;
+use crate::features::lan;
+use crate::features::library;
+use crate::features::memory;
+use crate::features::meter;
+use crate::features::mpd;
 use crate::features::network;
+use crate::features::nightlight;
+use crate::features::pacman;
+use crate::features::plugin;
+use crate::features::ports;
+use crate::features::pressure;
+use crate::features::rapl;
+use crate::features::remote;
+use crate::features::snmp;
+use crate::features::speedtest;
+use crate::features::throughput;
 use crate::features::time;
+use crate::features::tls;
+use crate::features::tracking;
+use crate::features::units;
+use crate::features::updates;
+use crate::features::uptime;
+use crate::features::urgent;
+use crate::features::vpn;
+use crate::features::weather;
+use crate::features::zram;
+use crate::http;
+use crate::layout;
+use crate::logging;
+use crate::marquee;
+use crate::notification;
+use crate::output;
+use crate::script;
+use crate::threshold;
 use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use crate::wrapper::file;
 use log::warn;
 use serde_derive::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+const ENV_PREFIX: &str = "DWM_STATUS";
+const HOSTS: &str = "hosts";
+const INCLUDE: &str = "include";
+const PATH_HOSTNAME: &str = "/proc/sys/kernel/hostname";
 
 pub(crate) trait ConfigType {
     fn set_default(_: &mut config::Config) -> Result<()>;
@@ -20,15 +75,34 @@ pub(crate) trait ConfigType {
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct General {
     pub(crate) debug: Option<bool>,
-    pub(crate) order: Vec<String>,
+    pub(crate) enable_if: HashMap<String, Vec<String>>,
+    pub(crate) features: Vec<String>,
+    pub(crate) locale: bool,
+    pub(crate) max_length: usize,
+    pub(crate) priorities: HashMap<String, i64>,
     pub(crate) separator: String,
+    pub(crate) stale_factor: u64,
+    pub(crate) stale_template: String,
+    pub(crate) strip_control_characters: bool,
+    pub(crate) subscriptions: HashMap<String, Vec<String>>,
 }
 
 impl ConfigType for General {
     fn set_default(config: &mut config::Config) -> Result<()> {
         config.set_default("debug", None::<bool>)?;
-        config.set_default("order", Vec::<String>::new())?;
+        config.set_default("enable_if", HashMap::<String, Value>::new())?;
+        config.set_default("features", Vec::<String>::new())?;
+        config.set_default(HOSTS, HashMap::<String, Value>::new())?;
+        config.set_default(INCLUDE, None::<Vec<String>>)?;
+        config.set_default("locale", false)?;
+        config.set_default("max_length", 0)?;
+        config.set_default("order", None::<Vec<String>>)?;
+        config.set_default("priorities", HashMap::<String, Value>::new())?;
         config.set_default("separator", " / ")?;
+        config.set_default("stale_factor", 3)?;
+        config.set_default("stale_template", "{VALUE}*")?;
+        config.set_default("strip_control_characters", true)?;
+        config.set_default("subscriptions", HashMap::<String, Value>::new())?;
 
         Ok(())
     }
@@ -43,10 +117,67 @@ impl ConfigType for General {
             );
         }
 
+        if let Some(order) = config.get_str_list_option("order")? {
+            warn!(
+                "Config option 'order' is deprecated and will be removed in 2.0.0. Use 'features' \
+                 instead."
+            );
+
+            let features = config.get_str_list_option("features")?.unwrap_or_default();
+
+            if features.is_empty() {
+                config.set("features", order)?;
+            }
+        }
+
         Ok(())
     }
 }
 
+fn set_includes(config: &mut config::Config, config_path: &str) -> Result<()> {
+    let includes = config.get_str_list_option(INCLUDE)?.unwrap_or_default();
+    let directory = Path::new(config_path)
+        .parent()
+        .unwrap_or_else(|| Path::new(""));
+
+    for include in includes {
+        let path = directory.join(&include);
+        let path = path
+            .to_str()
+            .wrap_error("settings", format!("invalid include path {}", include))?;
+
+        config.set_path(path)?;
+    }
+
+    Ok(())
+}
+
+fn set_host_overrides(config: &mut config::Config, hostname: &str) -> Result<()> {
+    let overrides = config
+        .get_table_option(HOSTS)?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(hostname));
+
+    if let Some((_, overrides)) = overrides {
+        let overrides = overrides.into_table().wrap_error(
+            "settings",
+            format!("hosts entry of {} must be a table", hostname),
+        )?;
+
+        config.set_table(overrides)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn hostname() -> Option<String> {
+    file::read(PATH_HOSTNAME)
+        .ok()
+        .map(|hostname| hostname.trim().to_owned())
+        .filter(|hostname| !hostname.is_empty())
+}
+
 macro_rules! settings {
     ( $( $mod:ident ),* ) => {
         #[derive(Clone, Debug, Deserialize)]
@@ -59,7 +190,7 @@ macro_rules! settings {
         }
 
         impl Settings {
-            pub(crate) fn init(config_path: &str) -> Result<Self> {
+            pub(crate) fn init(config_path: Option<&str>) -> Result<Self> {
                 let mut config = config::Config::new();
 
                 General::set_default(&mut config)?;
@@ -67,7 +198,15 @@ macro_rules! settings {
                     $mod::ConfigEntry::set_default(&mut config)?;
                 )*
 
-                config.set_path(config_path)?;
+                if let Some(config_path) = config_path {
+                    config.set_path(config_path)?;
+                    set_includes(&mut config, config_path)?;
+
+                    if let Some(hostname) = hostname() {
+                        set_host_overrides(&mut config, &hostname)?;
+                    }
+                }
+                config.set_environment(ENV_PREFIX)?;
 
                 General::set_values(&mut config)?;
                 $(
@@ -80,7 +219,60 @@ macro_rules! settings {
     }
 }
 
-settings!(audio, backlight, battery, cpu_load, network, time);
+settings!(
+    audio,
+    backlight,
+    battery,
+    bluetooth,
+    ci,
+    click,
+    composite,
+    cpu_load,
+    cpu_temp,
+    dhcp,
+    disk_usage,
+    git,
+    gpu,
+    homeassistant,
+    http,
+    inhibitors,
+    inodes,
+    keyboard,
+    kubernetes,
+    lan,
+    layout,
+    library,
+    logging,
+    marquee,
+    memory,
+    meter,
+    mpd,
+    network,
+    nightlight,
+    notification,
+    output,
+    pacman,
+    plugin,
+    ports,
+    pressure,
+    rapl,
+    remote,
+    script,
+    snmp,
+    speedtest,
+    threshold,
+    throughput,
+    time,
+    tls,
+    tracking,
+    units,
+    updates,
+    uptime,
+    urgent,
+    vpn,
+    weather,
+    zram
+);
 
 #[cfg(test)]
 #[cfg(feature = "mocking")]
@@ -89,6 +281,8 @@ mod tests {
     use hamcrest2::assert_that;
     use hamcrest2::prelude::*;
     use mocktopus::mocking::*;
+    use std::env;
+    use std::fs;
 
     mod general_config_type {
         use super::*;
@@ -138,7 +332,7 @@ mod tests {
                     config::Config::set_default::<Vec<String>>.mock_raw(|_, key, value| {
                         counter_second += 1;
 
-                        assert_that!(key, is(equal_to("order")));
+                        assert_that!(key, is(equal_to("features")));
                         assert_that!(&value, is(empty()));
 
                         MockResult::Return(Err(Error::new_custom("name", "description")))
@@ -174,7 +368,7 @@ mod tests {
                     config::Config::set_default::<Vec<String>>.mock_raw(|_, key, value| {
                         counter_second += 1;
 
-                        assert_that!(key, is(equal_to("order")));
+                        assert_that!(key, is(equal_to("features")));
                         assert_that!(&value, is(empty()));
 
                         MockResult::Return(Ok(()))
@@ -205,7 +399,7 @@ mod tests {
             }
 
             #[test]
-            fn when_all_calls_succeed() {
+            fn when_fourth_call_failed() {
                 let mut counter_first = 0;
                 unsafe {
                     config::Config::set_default::<Option<bool>>.mock_raw(|_, key, value| {
@@ -223,7 +417,7 @@ mod tests {
                     config::Config::set_default::<Vec<String>>.mock_raw(|_, key, value| {
                         counter_second += 1;
 
-                        assert_that!(key, is(equal_to("order")));
+                        assert_that!(key, is(equal_to("features")));
                         assert_that!(&value, is(empty()));
 
                         MockResult::Return(Ok(()))
@@ -242,12 +436,124 @@ mod tests {
                     });
                 }
 
+                let mut counter_fourth = 0;
+                unsafe {
+                    config::Config::set_default::<i32>.mock_raw(|_, key, value| {
+                        counter_fourth += 1;
+
+                        match counter_fourth {
+                            1 => {
+                                assert_that!(key, is(equal_to("max_length")));
+                                assert_that!(value, is(equal_to(0)));
+                                MockResult::Return(Ok(()))
+                            },
+                            2 => {
+                                assert_that!(key, is(equal_to("stale_factor")));
+                                assert_that!(value, is(equal_to(3)));
+                                MockResult::Return(Err(Error::new_custom("name", "description")))
+                            },
+                            _ => panic!("set_default called to often: {} times", counter_fourth),
+                        }
+                    });
+                }
+
                 let mut config = config::Config::new();
 
-                assert_that!(General::set_default(&mut config), is(equal_to(Ok(()))));
+                assert_that!(
+                    General::set_default(&mut config),
+                    is(equal_to(Err(Error::new_custom("name", "description"))))
+                );
                 assert_that!(counter_first, is(equal_to(1)));
                 assert_that!(counter_second, is(equal_to(1)));
                 assert_that!(counter_third, is(equal_to(1)));
+                assert_that!(counter_fourth, is(equal_to(2)));
+            }
+
+            #[test]
+            fn when_fifth_call_failed() {
+                test_all_calls(Err(Error::new_custom("name", "description")));
+            }
+
+            #[test]
+            fn when_all_calls_succeed() {
+                test_all_calls(Ok(()));
+            }
+
+            fn test_all_calls(last_result: Result<()>) {
+                let mut counter_first = 0;
+                unsafe {
+                    config::Config::set_default::<Option<bool>>.mock_raw(|_, key, value| {
+                        counter_first += 1;
+
+                        assert_that!(key, is(equal_to("debug")));
+                        assert_that!(value, is(none()));
+
+                        MockResult::Return(Ok(()))
+                    });
+                }
+
+                let mut counter_second = 0;
+                unsafe {
+                    config::Config::set_default::<Vec<String>>.mock_raw(|_, key, value| {
+                        counter_second += 1;
+
+                        assert_that!(key, is(equal_to("features")));
+                        assert_that!(&value, is(empty()));
+
+                        MockResult::Return(Ok(()))
+                    });
+                }
+
+                let mut counter_str = 0;
+                let last_result_ = last_result.clone();
+                unsafe {
+                    config::Config::set_default::<&str>.mock_raw(|_, key, value| {
+                        counter_str += 1;
+
+                        match counter_str {
+                            1 => {
+                                assert_that!(key, is(equal_to("separator")));
+                                assert_that!(value, is(equal_to(" / ")));
+                                MockResult::Return(Ok(()))
+                            },
+                            2 => {
+                                assert_that!(key, is(equal_to("stale_template")));
+                                assert_that!(value, is(equal_to("{VALUE}*")));
+                                MockResult::Return(last_result_.clone())
+                            },
+                            _ => panic!("set_default called to often: {} times", counter_str),
+                        }
+                    });
+                }
+
+                let mut counter_fourth = 0;
+                unsafe {
+                    config::Config::set_default::<i32>.mock_raw(|_, key, value| {
+                        counter_fourth += 1;
+
+                        match counter_fourth {
+                            1 => {
+                                assert_that!(key, is(equal_to("max_length")));
+                                assert_that!(value, is(equal_to(0)));
+                                MockResult::Return(Ok(()))
+                            },
+                            2 => {
+                                assert_that!(key, is(equal_to("stale_factor")));
+                                assert_that!(value, is(equal_to(3)));
+                                MockResult::Return(Ok(()))
+                            },
+                            _ => panic!("set_default called to often: {} times", counter_fourth),
+                        }
+                    });
+                }
+
+                let mut config = config::Config::new();
+
+                assert_that!(General::set_default(&mut config), is(equal_to(last_result)));
+                assert_that!(counter_first, is(equal_to(1)));
+                assert_that!(counter_second, is(equal_to(1)));
+                assert_that!(counter_str, is(equal_to(2)));
+                assert_that!(counter_fourth, is(equal_to(2)));
             }
         }
 
@@ -322,6 +628,235 @@ mod tests {
 
                 assert_that!(General::set_values(&mut config), is(equal_to(Ok(()))));
             }
+
+            #[test]
+            fn when_order_is_not_set() {
+                let path = env::temp_dir().join("dwm-status-test-settings-order.toml");
+                fs::write(&path, "features = [\"time\"]").unwrap();
+
+                let mut config = config::Config::new();
+                General::set_default(&mut config).unwrap();
+                config.set_path(path.to_str().unwrap()).unwrap();
+
+                assert_that!(General::set_values(&mut config), is(equal_to(Ok(()))));
+                assert_that!(
+                    config.get_str_list_option("features"),
+                    is(equal_to(Ok(Some(vec!["time".to_owned()]))))
+                );
+            }
+
+            #[test]
+            fn when_order_is_set() {
+                let logger_context = LoggerContext::new();
+
+                config::Config::get_bool_option.mock_safe(|_, _| MockResult::Return(Ok(None)));
+                config::Config::get_str_list_option.mock_safe(|_, key| {
+                    MockResult::Return(Ok(match key {
+                        "order" => Some(vec!["time".to_owned()]),
+                        _ => Some(Vec::new()),
+                    }))
+                });
+
+                let mut counter = 0;
+                unsafe {
+                    config::Config::set::<Vec<String>>.mock_raw(|_, key, value| {
+                        counter += 1;
+
+                        assert_that!(key, is(equal_to("features")));
+                        assert_that!(value, is(equal_to(vec!["time".to_owned()])));
+
+                        MockResult::Return(Ok(()))
+                    });
+                }
+
+                let mut config = config::Config::new();
+
+                assert_that!(General::set_values(&mut config), is(equal_to(Ok(()))));
+                assert_that!(counter, is(equal_to(1)));
+
+                logger_context.assert_entry(
+                    Level::Warn,
+                    "Config option 'order' is deprecated and will be removed in 2.0.0. Use \
+                     'features' instead.",
+                );
+            }
+        }
+    }
+
+    mod set_includes {
+        use super::*;
+
+        #[test]
+        fn when_include_is_set() {
+            config::Config::get_str_list_option.mock_safe(|_, key| {
+                assert_that!(key, is(equal_to("include")));
+
+                MockResult::Return(Ok(Some(vec![
+                    "base.toml".to_owned(),
+                    "/etc/dwm-status/host.toml".to_owned(),
+                ])))
+            });
+
+            let mut paths = Vec::new();
+            unsafe {
+                config::Config::set_path.mock_raw(|_, path| {
+                    paths.push(path.to_owned());
+
+                    MockResult::Return(Ok(()))
+                });
+            }
+
+            let mut config = config::Config::new();
+
+            assert_that!(
+                set_includes(&mut config, "/home/user/.config/dwm-status/config.toml"),
+                is(equal_to(Ok(())))
+            );
+            assert_that!(
+                paths,
+                is(equal_to(vec![
+                    "/home/user/.config/dwm-status/base.toml".to_owned(),
+                    "/etc/dwm-status/host.toml".to_owned(),
+                ]))
+            );
+        }
+
+        #[test]
+        fn when_include_is_not_set() {
+            let mut counter = 0;
+            unsafe {
+                config::Config::set_path.mock_raw(|_, _| {
+                    counter += 1;
+
+                    MockResult::Return(Ok(()))
+                });
+            }
+
+            let mut config = config::Config::new();
+            General::set_default(&mut config).unwrap();
+
+            assert_that!(
+                set_includes(&mut config, "config.toml"),
+                is(equal_to(Ok(())))
+            );
+            assert_that!(counter, is(equal_to(0)));
+        }
+    }
+
+    mod set_host_overrides {
+        use super::*;
+
+        fn hosts() -> HashMap<String, Value> {
+            let mut battery = HashMap::new();
+            battery.insert("notifier_critical".to_owned(), Value::from(5));
+
+            let mut laptop = HashMap::new();
+            laptop.insert("battery".to_owned(), Value::from(battery));
+            laptop.insert("separator".to_owned(), Value::from(" | "));
+
+            let mut hosts = HashMap::new();
+            hosts.insert("laptop".to_owned(), Value::from(laptop));
+            hosts
+        }
+
+        #[test]
+        fn when_hostname_matches() {
+            config::Config::get_table_option.mock_safe(|_, key| {
+                assert_that!(key, is(equal_to("hosts")));
+
+                MockResult::Return(Ok(Some(hosts())))
+            });
+
+            let mut keys = Vec::new();
+            unsafe {
+                config::Config::set_table.mock_raw(|_, table| {
+                    keys.extend(table.keys().cloned());
+
+                    MockResult::Return(Ok(()))
+                });
+            }
+
+            let mut config = config::Config::new();
+
+            assert_that!(
+                set_host_overrides(&mut config, "Laptop"),
+                is(equal_to(Ok(())))
+            );
+
+            keys.sort();
+            assert_that!(
+                keys,
+                is(equal_to(vec!["battery".to_owned(), "separator".to_owned()]))
+            );
+        }
+
+        #[test]
+        fn when_hostname_does_not_match() {
+            config::Config::get_table_option
+                .mock_safe(|_, _| MockResult::Return(Ok(Some(hosts()))));
+
+            let mut counter = 0;
+            unsafe {
+                config::Config::set_table.mock_raw(|_, _| {
+                    counter += 1;
+
+                    MockResult::Return(Ok(()))
+                });
+            }
+
+            let mut config = config::Config::new();
+
+            assert_that!(
+                set_host_overrides(&mut config, "desktop"),
+                is(equal_to(Ok(())))
+            );
+            assert_that!(counter, is(equal_to(0)));
+        }
+
+        #[test]
+        fn when_hosts_is_not_set() {
+            let mut config = config::Config::new();
+            General::set_default(&mut config).unwrap();
+
+            assert_that!(
+                set_host_overrides(&mut config, "laptop"),
+                is(equal_to(Ok(())))
+            );
+            assert_that!(
+                config.get_str("separator"),
+                is(equal_to(Ok(" / ".to_owned())))
+            );
+        }
+
+        #[test]
+        fn when_environment_variable_is_set() {
+            let mut file = HashMap::new();
+            file.insert(HOSTS.to_owned(), Value::from(hosts()));
+
+            let mut config = config::Config::new();
+            General::set_default(&mut config).unwrap();
+            config.set_table(file).unwrap();
+
+            assert_that!(
+                set_host_overrides(&mut config, "laptop"),
+                is(equal_to(Ok(())))
+            );
+            assert_that!(
+                config.set_variables(
+                    ENV_PREFIX,
+                    vec![("DWM_STATUS__SEPARATOR".to_owned(), " - ".to_owned())],
+                ),
+                is(equal_to(Ok(())))
+            );
+
+            assert_that!(
+                config.get_str("separator"),
+                is(equal_to(Ok(" - ".to_owned())))
+            );
+            assert_that!(
+                config.get_str("battery.notifier_critical"),
+                is(equal_to(Ok("5".to_owned())))
+            );
         }
     }
 }
@@ -0,0 +1,179 @@
+use crate::feature;
+use crate::threshold;
+use serde_derive::*;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+pub(crate) type SharedSnapshot = Arc<Mutex<Snapshot>>;
+
+#[derive(Debug, PartialEq, Serialize)]
+struct FeatureSnapshot {
+    error: Option<String>,
+    last_update: Option<u64>,
+    name: &'static str,
+    stale: bool,
+    state: threshold::State,
+    value: String,
+    values: feature::Values,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct Snapshot {
+    features: Vec<FeatureSnapshot>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(names: &[&'static str]) -> Self {
+        Self {
+            features: names
+                .iter()
+                .map(|&name| FeatureSnapshot {
+                    error: None,
+                    last_update: None,
+                    name,
+                    stale: false,
+                    state: threshold::State::Ok,
+                    value: String::new(),
+                    values: feature::Values::new(),
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn shared(self) -> SharedSnapshot {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub(crate) fn feature_id(&self, name: &str) -> Option<usize> {
+        self.features
+            .iter()
+            .position(|feature| feature.name.eq_ignore_ascii_case(name))
+    }
+
+    pub(crate) fn record_update(
+        &mut self,
+        id: usize,
+        result: Result<feature::Values, String>,
+        time: SystemTime,
+    ) {
+        if let Some(feature) = self.features.get_mut(id) {
+            match result {
+                Ok(values) => {
+                    feature.error = None;
+                    feature.last_update = Some(unix_time(time));
+                    feature.values = values;
+                },
+                Err(error) => feature.error = Some(error),
+            }
+        }
+    }
+
+    pub(crate) fn record_render(
+        &mut self,
+        id: usize,
+        value: &str,
+        stale: bool,
+        state: threshold::State,
+    ) {
+        if let Some(feature) = self.features.get_mut(id) {
+            feature.stale = stale;
+            feature.state = state;
+            feature.value = value.to_owned();
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::to_string(&self.features).unwrap_or_else(|_| "[]".to_owned())
+    }
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use serde_json::Value;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn snapshot_to_json_without_updates() {
+        let snapshot = Snapshot::new(&["time"]);
+
+        assert_that!(
+            serde_json::from_str::<Value>(&snapshot.to_json()).unwrap(),
+            is(equal_to(json!([{
+                "error": null,
+                "last_update": null,
+                "name": "time",
+                "stale": false,
+                "state": "ok",
+                "value": "",
+                "values": {},
+            }])))
+        );
+    }
+
+    #[test]
+    fn snapshot_to_json() {
+        let mut snapshot = Snapshot::new(&["battery", "time"]);
+        let time = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+
+        let mut values = feature::Values::new();
+        values.insert("CAP", "80".to_owned());
+
+        snapshot.record_update(0, Ok(values), time);
+        snapshot.record_render(0, "80%", false, threshold::State::Warning);
+        snapshot.record_update(1, Err("failed".to_owned()), time);
+        snapshot.record_render(1, "12:00", true, threshold::State::Ok);
+
+        assert_that!(
+            serde_json::from_str::<Value>(&snapshot.to_json()).unwrap(),
+            is(equal_to(json!([
+                {
+                    "error": null,
+                    "last_update": 1_600_000_000,
+                    "name": "battery",
+                    "stale": false,
+                    "state": "warning",
+                    "value": "80%",
+                    "values": { "CAP": "80" },
+                },
+                {
+                    "error": "failed",
+                    "last_update": null,
+                    "name": "time",
+                    "stale": true,
+                    "state": "ok",
+                    "value": "12:00",
+                    "values": {},
+                },
+            ])))
+        );
+    }
+
+    #[test]
+    fn snapshot_keeps_values_on_failure() {
+        let mut snapshot = Snapshot::new(&["battery"]);
+        let time = UNIX_EPOCH + Duration::from_secs(10);
+
+        let mut values = feature::Values::new();
+        values.insert("CAP", "80".to_owned());
+
+        snapshot.record_update(0, Ok(values.clone()), time);
+        snapshot.record_update(0, Err("failed".to_owned()), time + Duration::from_secs(10));
+
+        let feature = &snapshot.features[0];
+        assert_that!(feature.error.as_deref(), is(equal_to(Some("failed"))));
+        assert_that!(feature.last_update, is(equal_to(Some(10))));
+        assert_that!(&feature.values, is(equal_to(&values)));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::error::*;
+use crate::wrapper::file;
+use crate::wrapper::xdg;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+const ERROR_NAME: &str = "state";
+const FILE_NAME: &str = "state.json";
+
+type Values = BTreeMap<String, Value>;
+
+#[derive(Debug)]
+pub(crate) struct State {
+    name: &'static str,
+    path: Option<PathBuf>,
+    values: Values,
+}
+
+impl State {
+    pub(crate) fn load(name: &'static str) -> Self {
+        let path = xdg::state_dir().map(|directory| directory.join(FILE_NAME));
+        let values = path
+            .as_ref()
+            .and_then(|path| read_all(path).remove(name))
+            .unwrap_or_default();
+
+        Self { name, path, values }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn in_memory(name: &'static str) -> Self {
+        Self {
+            name,
+            path: None,
+            values: Values::new(),
+        }
+    }
+
+    pub(crate) fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.values
+            .get(key)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    pub(crate) fn set<T: Serialize>(&mut self, key: &str, value: T) -> Result<()> {
+        let value = serde_json::to_value(value).wrap_error(
+            ERROR_NAME,
+            format!("failed to serialize {}.{}", self.name, key),
+        )?;
+
+        if self.values.get(key) == Some(&value) {
+            return Ok(());
+        }
+
+        self.values.insert(key.to_owned(), value);
+
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<()> {
+        let path = match self.path {
+            Some(ref path) => path,
+            None => return Ok(()),
+        };
+
+        let mut all = read_all(path);
+        all.insert(self.name.to_owned(), self.values.clone());
+
+        let content = serde_json::to_string_pretty(&all)
+            .wrap_error(ERROR_NAME, "failed to serialize state")?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .wrap_error(ERROR_NAME, "failed to create state directory")?;
+        }
+
+        let temporary_path = path.with_extension("json.tmp");
+        fs::write(&temporary_path, content).wrap_error(
+            ERROR_NAME,
+            format!("failed to write {}", temporary_path.display()),
+        )?;
+        fs::rename(&temporary_path, path)
+            .wrap_error(ERROR_NAME, format!("failed to write {}", path.display()))
+    }
+}
+
+fn read_all(path: &Path) -> BTreeMap<String, Values> {
+    file::read(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn get_without_value() {
+        let state = State::in_memory("feature");
+
+        assert_that!(state.get::<u64>("key"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn get_with_value() {
+        let mut state = State::in_memory("feature");
+
+        assert_that!(state.set("key", 42), is(equal_to(Ok(()))));
+        assert_that!(state.get::<u64>("key"), is(equal_to(Some(42))));
+    }
+
+    #[test]
+    fn get_with_value_of_wrong_type() {
+        let mut state = State::in_memory("feature");
+
+        assert_that!(state.set("key", "value"), is(equal_to(Ok(()))));
+        assert_that!(state.get::<u64>("key"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn set_overrides_value() {
+        let mut state = State::in_memory("feature");
+
+        assert_that!(state.set("key", Some(1)), is(equal_to(Ok(()))));
+        assert_that!(state.set("key", None::<u64>), is(equal_to(Ok(()))));
+        assert_that!(state.get::<Option<u64>>("key"), is(equal_to(Some(None))));
+    }
+}
@@ -1,21 +1,123 @@
+use crate::click;
 use crate::communication;
 use crate::error::*;
 use crate::feature;
+use crate::layout;
+use crate::logging;
+use crate::marquee;
+use crate::metrics;
+use crate::output;
+use crate::overflow;
+use crate::recording;
+use crate::registry;
+use crate::sanitize;
+use crate::scheduler;
+use crate::script;
 use crate::settings;
-use crate::wrapper::xsetroot;
-use log::info;
+use crate::snapshot;
+use crate::threshold;
+use log::debug;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+const PLACEHOLDER_VALUE: &str = "{VALUE}";
 
 pub(super) struct StatusBar {
+    bindings: click::Bindings,
     features: Vec<Box<dyn feature::Feature>>,
-    xsetroot: xsetroot::XSetRoot,
+    last_updates: Vec<Option<Instant>>,
+    hooks: script::Hooks,
+    layout: layout::Layout,
+    marquee: marquee::Marquee,
+    metrics: metrics::SharedMetrics,
+    output: Box<dyn output::Output>,
+    positions: Vec<Range<usize>>,
+    recorder: Option<recording::Recorder>,
+    registry: registry::SharedRegistry,
+    scheduler: scheduler::Scheduler,
+    snapshot: snapshot::SharedSnapshot,
+    subscribers: Vec<Vec<usize>>,
+    text_width: Option<output::TextWidth>,
+    thresholds: threshold::Thresholds,
 }
 
 impl StatusBar {
-    pub(super) fn init(features: Vec<Box<dyn feature::Feature>>) -> Result<Self> {
-        Ok(Self {
+    pub(super) fn init(
+        features: Vec<Box<dyn feature::Feature>>,
+        metrics: metrics::SharedMetrics,
+        snapshot: snapshot::SharedSnapshot,
+        output: Box<dyn output::Output>,
+        recorder: Option<recording::Recorder>,
+        registry: registry::SharedRegistry,
+        settings: &settings::Settings,
+    ) -> Result<Self> {
+        let names = features
+            .iter()
+            .map(|feature| feature.name())
+            .collect::<Vec<_>>();
+        let subscriptions = features
+            .iter()
+            .map(|feature| {
+                let mut subscriptions = feature.subscriptions();
+                subscriptions.extend(
+                    settings
+                        .general
+                        .subscriptions
+                        .get(feature.name())
+                        .cloned()
+                        .unwrap_or_default(),
+                );
+                subscriptions
+            })
+            .collect::<Vec<_>>();
+
+        let mut status_bar = Self {
+            bindings: click::Bindings::init(&settings.click)?,
+            last_updates: vec![None; features.len()],
             features,
-            xsetroot: xsetroot::XSetRoot::init()?,
-        })
+            hooks: script::Hooks::init(&settings.script)?,
+            layout: layout::Layout::new(&names, &settings.layout)?,
+            marquee: marquee::Marquee::new(&settings.marquee),
+            metrics,
+            output,
+            positions: Vec::new(),
+            recorder,
+            registry,
+            scheduler: scheduler::Scheduler::default(),
+            snapshot,
+            subscribers: registry::subscribers(&names, &subscriptions),
+            text_width: output::TextWidth::init(&settings.output)?,
+            thresholds: threshold::Thresholds::init(&names, &settings.threshold),
+        };
+        status_bar.schedule_all(Instant::now());
+
+        Ok(status_bar)
+    }
+
+    pub(super) fn timeout(&self, now: Instant) -> Option<Duration> {
+        self.scheduler.timeout(now)
+    }
+
+    pub(super) fn update_scheduled(&mut self, settings: &settings::General) -> Result<()> {
+        let now = Instant::now();
+        let ids = self.scheduler.pop_due(now);
+
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        for id in ids {
+            debug!("Update scheduled feature {}", self.features[id].name());
+
+            self.update_with_subscribers(id);
+            self.schedule(id, now);
+        }
+
+        self.render(settings)
     }
 
     pub(super) fn update(
@@ -24,10 +126,14 @@ impl StatusBar {
         settings: &settings::General,
     ) -> Result<()> {
         match message {
+            communication::Message::Click { button, target } => {
+                self.click(*target, *button, settings)
+                    .show_error_and_ignore();
+            },
             communication::Message::FeatureUpdate(id) if *id < self.features.len() => {
-                info!("Update feature {}", self.features[*id].name());
+                debug!("Update feature {}", self.features[*id].name());
 
-                self.features[*id].update()?;
+                self.update_with_subscribers(*id);
                 self.render(settings)?;
             },
             communication::Message::FeatureUpdate(id) => {
@@ -36,12 +142,20 @@ impl StatusBar {
                     format!("feature id {} does not exist", id),
                 ));
             },
+            communication::Message::Tick => {
+                self.marquee.advance();
+                self.render(settings)?;
+            },
             communication::Message::UpdateAll => {
-                info!("Update all features");
+                debug!("Update all features");
 
-                for id in 0..self.features.len() {
-                    self.features[id].update()?;
+                let (subscribers, others): (Vec<_>, Vec<_>) = (0..self.features.len())
+                    .partition(|id| self.subscribers.iter().any(|ids| ids.contains(id)));
+
+                for id in others.into_iter().chain(subscribers) {
+                    self.update_feature(id);
                 }
+                self.schedule_all(Instant::now());
                 self.render(settings)?;
             },
             _ => (),
@@ -50,14 +164,318 @@ impl StatusBar {
         Ok(())
     }
 
-    pub(super) fn render(&self, settings: &settings::General) -> Result<()> {
-        let status = self
+    pub(super) fn render(&mut self, settings: &settings::General) -> Result<()> {
+        let now = Instant::now();
+
+        let mut snapshot = self.snapshot.lock().unwrap();
+
+        let values = self
+            .features
+            .iter()
+            .zip(&self.last_updates)
+            .enumerate()
+            .map(|(id, (feature, last_update))| {
+                let state = self.thresholds.state(id);
+                let rendered = sanitize::sanitize(
+                    self.render_feature(&**feature, state),
+                    settings.strip_control_characters,
+                );
+                let stale = is_stale(
+                    *last_update,
+                    feature.update_interval(),
+                    settings.stale_factor,
+                    now,
+                );
+
+                snapshot.record_render(id, &rendered, stale, state);
+
+                if self.is_hidden(&**feature, &rendered, state) {
+                    return None;
+                }
+
+                let rendered = self.marquee.apply(feature.name(), rendered);
+                let rendered = self.thresholds.apply_template(id, rendered);
+
+                if stale {
+                    return Some(
+                        settings
+                            .stale_template
+                            .replace(PLACEHOLDER_VALUE, &rendered),
+                    );
+                }
+
+                Some(rendered)
+            })
+            .collect::<Vec<_>>();
+
+        drop(snapshot);
+
+        let priorities = self
             .features
             .iter()
-            .map(|f| f.renderable().render())
-            .collect::<Vec<_>>()
-            .join(&settings.separator);
+            .map(|feature| {
+                settings
+                    .priorities
+                    .get(feature.name())
+                    .copied()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let values = overflow::fit(values, &priorities, settings.max_length, |values| {
+            self.layout.length(values, &settings.separator)
+        });
+        let values = match self.text_width {
+            Some(ref mut text_width) if text_width.connect(now) => {
+                let layout = &self.layout;
+                let text_width = &*text_width;
+
+                overflow::fit(values, &priorities, text_width.max_width(), |values| {
+                    text_width.width(&layout.join(values, &settings.separator).0)
+                })
+            },
+            _ => values,
+        };
+
+        let (status, positions) = self.layout.join(&values, &settings.separator);
+        self.positions = positions;
+
+        let result = self.output.write(&status);
+
+        self.metrics.lock().unwrap().record_render(now.elapsed());
+
+        result
+    }
+
+    fn click(
+        &mut self,
+        target: communication::ClickTarget,
+        button: u8,
+        settings: &settings::General,
+    ) -> Result<()> {
+        let id = match target {
+            communication::ClickTarget::Feature(id) if id < self.features.len() => id,
+            communication::ClickTarget::Position(position) => {
+                match self
+                    .positions
+                    .iter()
+                    .position(|range| range.contains(&position))
+                {
+                    Some(id) => id,
+                    None => {
+                        debug!("Ignore click at position {} outside of features", position);
+                        return Ok(());
+                    },
+                }
+            },
+            _ => {
+                return Err(Error::new_custom(
+                    "invalid message",
+                    format!("click target {:?} does not exist", target),
+                ));
+            },
+        };
+
+        let button = click::Button::from_number(button)?;
+        let name = self.features[id].name();
+
+        debug!("Click with button {:?} on feature {}", button, name);
+
+        match self.bindings.action(name, button) {
+            click::Action::Command(command) => click::run_command(&command, name, button),
+            click::Action::Feature => self.features[id].click(button.number()),
+            click::Action::Refresh => {
+                self.update_with_subscribers(id);
+                self.render(settings)
+            },
+        }
+    }
+
+    fn render_feature(&self, feature: &dyn feature::Feature, state: threshold::State) -> String {
+        let renderable = feature.renderable();
+        let rendered = renderable.render();
+
+        match self
+            .hooks
+            .apply(feature.name(), rendered, state.name(), || {
+                renderable.values()
+            }) {
+            Some(Ok(value)) => value,
+            Some(Err(error)) => {
+                error.show_error();
+                rendered.to_owned()
+            },
+            None => rendered.to_owned(),
+        }
+    }
+
+    fn is_hidden(
+        &self,
+        feature: &dyn feature::Feature,
+        rendered: &str,
+        state: threshold::State,
+    ) -> bool {
+        match self
+            .hooks
+            .is_hidden(feature.name(), rendered, state.name(), || {
+                feature.renderable().values()
+            }) {
+            Some(Ok(hidden)) => hidden,
+            Some(Err(error)) => {
+                error.show_error();
+                false
+            },
+            None => false,
+        }
+    }
+
+    fn schedule(&mut self, id: usize, now: Instant) {
+        if let Some(delay) = self.features[id].next_update() {
+            self.scheduler.schedule(id, now + delay);
+        }
+    }
+
+    fn schedule_all(&mut self, now: Instant) {
+        self.scheduler.clear();
+
+        for id in 0..self.features.len() {
+            self.schedule(id, now);
+        }
+    }
+
+    fn update_with_subscribers(&mut self, id: usize) {
+        let mut queue = VecDeque::new();
+        let mut updated = HashSet::new();
+        queue.push_back(id);
+
+        while let Some(id) = queue.pop_front() {
+            if !updated.insert(id) {
+                continue;
+            }
+
+            if self.update_feature(id) {
+                queue.extend(&self.subscribers[id]);
+            }
+        }
+    }
+
+    fn update_feature(&mut self, id: usize) -> bool {
+        let name = self.features[id].name();
+        let start = Instant::now();
+
+        let context = logging::Context {
+            duration: None,
+            feature: Some(name),
+        };
+        let result = logging::with_context(context, || {
+            self.features[id].update().map_err(|error| {
+                let description = error.to_string();
+                error.show_error();
+                description
+            })
+        });
+
+        let duration = start.elapsed();
+        self.metrics
+            .lock()
+            .unwrap()
+            .record_update(id, duration, result.is_ok());
+
+        let context = logging::Context {
+            duration: Some(duration),
+            feature: Some(name),
+        };
+        logging::with_context(context, || {
+            debug!("Updated feature {} in {:?}", name, duration)
+        });
+
+        let values = result
+            .as_ref()
+            .map(|_| self.features[id].renderable().values())
+            .map_err(Clone::clone);
+
+        let mut changed = false;
+
+        if let Ok(ref values) = values {
+            let renderable = self.features[id].renderable();
+            self.thresholds
+                .update(id, name, values, renderable.render(), Instant::now());
+            changed = self.registry.lock().unwrap().record(id, values.clone());
+        }
+
+        self.snapshot
+            .lock()
+            .unwrap()
+            .record_update(id, values, SystemTime::now());
+
+        if result.is_ok() {
+            self.last_updates[id] = Some(Instant::now());
+
+            if let Some(ref mut recorder) = self.recorder {
+                recorder
+                    .record(id, self.features[id].renderable())
+                    .show_error_and_ignore();
+            }
+        }
+
+        changed
+    }
+}
+
+fn is_stale(
+    last_update: Option<Instant>,
+    update_interval: Option<u64>,
+    stale_factor: u64,
+    now: Instant,
+) -> bool {
+    match (last_update, update_interval) {
+        (Some(last_update), Some(update_interval)) if stale_factor > 0 => {
+            now.duration_since(last_update)
+                > Duration::from_secs(update_interval.saturating_mul(stale_factor))
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn is_stale_without_update() {
+        assert_that!(is_stale(None, Some(10), 3, Instant::now()), is(false));
+    }
+
+    #[test]
+    fn is_stale_without_interval() {
+        let last_update = Instant::now();
+        let now = last_update + Duration::from_secs(3600);
+
+        assert_that!(is_stale(Some(last_update), None, 3, now), is(false));
+    }
+
+    #[test]
+    fn is_stale_with_disabled_factor() {
+        let last_update = Instant::now();
+        let now = last_update + Duration::from_secs(3600);
+
+        assert_that!(is_stale(Some(last_update), Some(10), 0, now), is(false));
+    }
+
+    #[test]
+    fn is_stale_when_recently_updated() {
+        let last_update = Instant::now();
+        let now = last_update + Duration::from_secs(30);
+
+        assert_that!(is_stale(Some(last_update), Some(10), 3, now), is(false));
+    }
+
+    #[test]
+    fn is_stale_when_outdated() {
+        let last_update = Instant::now();
+        let now = last_update + Duration::from_secs(31);
 
-        self.xsetroot.render(status)
+        assert_that!(is_stale(Some(last_update), Some(10), 3, now), is(true));
     }
 }
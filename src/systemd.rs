@@ -0,0 +1,170 @@
+use crate::communication;
+use crate::error::*;
+use crate::wrapper::channel;
+use crate::wrapper::thread;
+use log::warn;
+use std::env;
+use std::os::unix::net::UnixDatagram;
+use std::process;
+use std::time::Duration;
+
+const ENV_NOTIFY_SOCKET: &str = "NOTIFY_SOCKET";
+const ENV_WATCHDOG_PID: &str = "WATCHDOG_PID";
+const ENV_WATCHDOG_USEC: &str = "WATCHDOG_USEC";
+const ERROR_NAME: &str = "systemd";
+const STATE_READY: &str = "READY=1";
+const STATE_STOPPING: &str = "STOPPING=1";
+const STATE_WATCHDOG: &str = "WATCHDOG=1";
+
+#[derive(Debug)]
+pub(super) struct Notifier {
+    ready: bool,
+    socket: Option<String>,
+}
+
+impl Notifier {
+    pub(super) fn init() -> Self {
+        let socket = env::var(ENV_NOTIFY_SOCKET)
+            .ok()
+            .filter(|socket| !socket.is_empty());
+
+        let socket = match socket {
+            Some(ref socket) if socket.starts_with('@') => {
+                warn!("abstract notify sockets are not supported, systemd is not notified");
+                None
+            },
+            socket => socket,
+        };
+
+        Self {
+            ready: false,
+            socket,
+        }
+    }
+
+    pub(super) fn init_watchdog(
+        &self,
+        sender: &channel::Sender<communication::Message>,
+    ) -> Result<()> {
+        let interval = match watchdog_interval(
+            env::var(ENV_WATCHDOG_USEC).ok().as_deref(),
+            env::var(ENV_WATCHDOG_PID).ok().as_deref(),
+            process::id(),
+        ) {
+            Some(interval) if self.socket.is_some() => interval,
+            _ => return Ok(()),
+        };
+
+        let watchdog = Watchdog {
+            interval,
+            sender: sender.clone(),
+        };
+
+        thread::Thread::new("watchdog", watchdog).run()
+    }
+
+    pub(super) fn ready(&mut self) {
+        if !self.ready {
+            self.ready = true;
+            self.notify(STATE_READY);
+        }
+    }
+
+    pub(super) fn stopping(&self) {
+        self.notify(STATE_STOPPING);
+    }
+
+    pub(super) fn watchdog(&self) {
+        self.notify(STATE_WATCHDOG);
+    }
+
+    fn notify(&self, state: &str) {
+        if let Some(ref socket) = self.socket {
+            send(socket, state).show_error_and_ignore();
+        }
+    }
+}
+
+fn send(socket: &str, state: &str) -> Result<()> {
+    let datagram = UnixDatagram::unbound().wrap_error(ERROR_NAME, "failed to create socket")?;
+
+    datagram
+        .send_to(state.as_bytes(), socket)
+        .wrap_error(ERROR_NAME, format!("failed to send {}", state))?;
+
+    Ok(())
+}
+
+fn watchdog_interval(usec: Option<&str>, pid: Option<&str>, own_pid: u32) -> Option<Duration> {
+    if let Some(pid) = pid {
+        if pid.parse::<u32>().ok()? != own_pid {
+            return None;
+        }
+    }
+
+    match usec?.parse::<u64>().ok()? {
+        0 => None,
+        usec => Some(Duration::from_micros(usec / 2)),
+    }
+}
+
+struct Watchdog {
+    interval: Duration,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl thread::Runnable for Watchdog {
+    fn run(&self) -> Result<()> {
+        loop {
+            self.sender.send(communication::Message::Watchdog)?;
+
+            thread::sleep(self.interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn watchdog_interval_without_usec() {
+        assert_that!(watchdog_interval(None, None, 42), is(equal_to(None)));
+    }
+
+    #[test]
+    fn watchdog_interval_with_invalid_usec() {
+        assert_that!(watchdog_interval(Some("abc"), None, 42), is(equal_to(None)));
+    }
+
+    #[test]
+    fn watchdog_interval_with_zero_usec() {
+        assert_that!(watchdog_interval(Some("0"), None, 42), is(equal_to(None)));
+    }
+
+    #[test]
+    fn watchdog_interval_with_usec() {
+        assert_that!(
+            watchdog_interval(Some("30000000"), None, 42),
+            is(equal_to(Some(Duration::from_secs(15))))
+        );
+    }
+
+    #[test]
+    fn watchdog_interval_with_own_pid() {
+        assert_that!(
+            watchdog_interval(Some("30000000"), Some("42"), 42),
+            is(equal_to(Some(Duration::from_secs(15))))
+        );
+    }
+
+    #[test]
+    fn watchdog_interval_with_other_pid() {
+        assert_that!(
+            watchdog_interval(Some("30000000"), Some("43"), 42),
+            is(equal_to(None))
+        );
+    }
+}
@@ -25,6 +25,17 @@ pub(crate) fn test_set_default_err<T: settings::ConfigType>(
     );
 }
 
+pub(crate) fn notification_map(summary: &str, body: &str, urgency: &str) -> HashMap<String, Value> {
+    let mut map = HashMap::new();
+    map.insert("body".to_owned(), body.into());
+    map.insert("icon".to_owned(), "".into());
+    map.insert("summary".to_owned(), summary.into());
+    map.insert("timeout".to_owned(), (-1).into());
+    map.insert("urgency".to_owned(), urgency.into());
+
+    map
+}
+
 fn test_set_default<T: settings::ConfigType>(
     name: &'static str,
     default_map_builder: fn() -> HashMap<String, Value>,
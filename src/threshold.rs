@@ -0,0 +1,447 @@
+mod config;
+
+use crate::error::*;
+use crate::feature::Values;
+use crate::notification;
+use crate::notification::NotificationConfig;
+use serde_derive::*;
+use std::time::Duration;
+use std::time::Instant;
+
+pub(crate) use self::config::ConfigEntry;
+pub(self) use self::config::ThresholdConfig;
+
+const PLACEHOLDER_FEATURE: &str = "{FEATURE}";
+const PLACEHOLDER_STATE: &str = "{STATE}";
+const PLACEHOLDER_VALUE: &str = "{VALUE}";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum State {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl State {
+    #[allow(clippy::missing_const_for_fn)]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Ok
+    }
+}
+
+impl ThresholdConfig {
+    fn state(&self, values: &Values) -> State {
+        values
+            .get(&self.key[..])
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .map_or(State::Ok, |value| level(value, self.warning, self.critical))
+    }
+}
+
+fn level(value: f64, warning: f64, critical: f64) -> State {
+    let sign = if critical < warning { -1. } else { 1. };
+
+    if sign * value >= sign * critical {
+        State::Critical
+    } else if sign * value >= sign * warning {
+        State::Warning
+    } else {
+        State::Ok
+    }
+}
+
+pub(crate) fn usage_level(percent: u64, warning: u64, critical: u64) -> State {
+    if critical > 0 && percent >= critical {
+        State::Critical
+    } else if warning > 0 && percent >= warning {
+        State::Warning
+    } else {
+        State::Ok
+    }
+}
+
+pub(crate) struct Tracker {
+    change: Instant,
+    notified: State,
+    notifier: notification::LazyNotifier,
+    state: State,
+}
+
+impl Tracker {
+    pub(crate) fn new(now: Instant) -> Self {
+        Self {
+            change: now,
+            notified: State::Ok,
+            notifier: notification::LazyNotifier::default(),
+            state: State::Ok,
+        }
+    }
+
+    pub(crate) const fn state(&self) -> State {
+        self.state
+    }
+
+    pub(crate) fn update(&mut self, state: State, sustain: u64, now: Instant) -> Option<State> {
+        if state != self.state {
+            self.state = state;
+            self.change = now;
+        }
+
+        if state == self.notified || now.duration_since(self.change) < Duration::from_secs(sustain)
+        {
+            return None;
+        }
+
+        self.notified = state;
+        Some(state)
+    }
+
+    pub(crate) fn notify(
+        &mut self,
+        state: State,
+        warning: &NotificationConfig,
+        critical: &NotificationConfig,
+        recovered: Option<&NotificationConfig>,
+        placeholders: &[(&str, String)],
+    ) -> Result<()> {
+        let notifier = self.notifier.get()?;
+
+        if state == State::Ok {
+            notifier.close()?;
+        }
+
+        match notification(state, warning, critical, recovered) {
+            Some(notification) => notifier.send(notification, placeholders),
+            None => Ok(()),
+        }
+    }
+}
+
+fn notification<'a>(
+    state: State,
+    warning: &'a NotificationConfig,
+    critical: &'a NotificationConfig,
+    recovered: Option<&'a NotificationConfig>,
+) -> Option<&'a NotificationConfig> {
+    match state {
+        State::Ok => recovered,
+        State::Warning => Some(warning),
+        State::Critical => Some(critical),
+    }
+}
+
+pub(crate) struct Thresholds {
+    settings: ConfigEntry,
+    thresholds: Vec<Option<ThresholdConfig>>,
+    trackers: Vec<Tracker>,
+}
+
+impl Thresholds {
+    pub(crate) fn init(names: &[&'static str], settings: &ConfigEntry) -> Self {
+        let thresholds = names
+            .iter()
+            .map(|name| {
+                settings
+                    .features
+                    .iter()
+                    .find(|(feature, _)| feature.eq_ignore_ascii_case(name))
+                    .map(|(_, threshold)| threshold.clone())
+            })
+            .collect::<Vec<_>>();
+
+        let now = Instant::now();
+
+        Self {
+            settings: settings.clone(),
+            thresholds,
+            trackers: names.iter().map(|_| Tracker::new(now)).collect(),
+        }
+    }
+
+    pub(crate) fn state(&self, id: usize) -> State {
+        self.trackers
+            .get(id)
+            .map(Tracker::state)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        id: usize,
+        name: &str,
+        values: &Values,
+        value: &str,
+        now: Instant,
+    ) {
+        let threshold = match self.thresholds.get(id) {
+            Some(Some(threshold)) => threshold,
+            _ => return,
+        };
+
+        let tracker = &mut self.trackers[id];
+
+        let state = match tracker.update(threshold.state(values), threshold.sustain, now) {
+            Some(state) if threshold.notify => state,
+            _ => return,
+        };
+
+        tracker
+            .notify(
+                state,
+                &self.settings.notification_warning,
+                &self.settings.notification_critical,
+                Some(&self.settings.notification_recovered).filter(|_| threshold.notify_recovery),
+                &[
+                    (PLACEHOLDER_FEATURE, name.to_owned()),
+                    (PLACEHOLDER_STATE, state.name().to_owned()),
+                    (PLACEHOLDER_VALUE, value.to_owned()),
+                ],
+            )
+            .show_error_and_ignore();
+    }
+
+    pub(crate) fn apply_template(&self, id: usize, value: String) -> String {
+        let threshold = self.thresholds.get(id).and_then(Option::as_ref);
+
+        let template = match self.state(id) {
+            State::Ok => return value,
+            State::Warning => threshold
+                .and_then(|threshold| threshold.warning_template.as_ref())
+                .unwrap_or(&self.settings.warning_template),
+            State::Critical => threshold
+                .and_then(|threshold| threshold.critical_template.as_ref())
+                .unwrap_or(&self.settings.critical_template),
+        };
+
+        template.replace(PLACEHOLDER_VALUE, &value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+    use serde_json::json;
+    use std::ptr;
+
+    fn threshold(warning: f64, critical: f64) -> ThresholdConfig {
+        ThresholdConfig {
+            critical,
+            critical_template: None,
+            key: "VALUE".to_owned(),
+            notify: false,
+            notify_recovery: false,
+            sustain: 0,
+            warning,
+            warning_template: None,
+        }
+    }
+
+    fn notification_config(summary: &str) -> NotificationConfig {
+        serde_json::from_value(json!({
+            "body": "{VALUE}",
+            "icon": "",
+            "summary": summary,
+            "timeout": -1,
+            "urgency": "normal",
+        }))
+        .unwrap()
+    }
+
+    fn values(value: &str) -> Values {
+        map_values!("VALUE" => value)
+    }
+
+    #[test]
+    fn state_with_higher_values_worse() {
+        let threshold = threshold(70., 90.);
+
+        assert_that!(threshold.state(&values("69.9")), is(equal_to(State::Ok)));
+        assert_that!(threshold.state(&values("70")), is(equal_to(State::Warning)));
+        assert_that!(
+            threshold.state(&values("95")),
+            is(equal_to(State::Critical))
+        );
+    }
+
+    #[test]
+    fn state_with_lower_values_worse() {
+        let threshold = threshold(20., 10.);
+
+        assert_that!(threshold.state(&values("80")), is(equal_to(State::Ok)));
+        assert_that!(threshold.state(&values("20")), is(equal_to(State::Warning)));
+        assert_that!(threshold.state(&values("5")), is(equal_to(State::Critical)));
+    }
+
+    #[test]
+    fn state_without_numeric_value() {
+        let threshold = threshold(70., 90.);
+
+        assert_that!(threshold.state(&values("NA")), is(equal_to(State::Ok)));
+        assert_that!(threshold.state(&Values::new()), is(equal_to(State::Ok)));
+    }
+
+    #[test]
+    fn usage_level_with_levels() {
+        assert_that!(usage_level(89, 90, 95), is(equal_to(State::Ok)));
+        assert_that!(usage_level(90, 90, 95), is(equal_to(State::Warning)));
+        assert_that!(usage_level(95, 90, 95), is(equal_to(State::Critical)));
+    }
+
+    #[test]
+    fn usage_level_with_disabled_levels() {
+        assert_that!(usage_level(100, 0, 95), is(equal_to(State::Critical)));
+        assert_that!(usage_level(92, 90, 0), is(equal_to(State::Warning)));
+        assert_that!(usage_level(100, 90, 0), is(equal_to(State::Warning)));
+        assert_that!(usage_level(100, 0, 0), is(equal_to(State::Ok)));
+    }
+
+    #[test]
+    fn tracker_update_with_sustain() {
+        let now = Instant::now();
+        let mut tracker = Tracker::new(now);
+
+        assert_that!(tracker.update(State::Warning, 60, now), is(none()));
+        assert_that!(
+            tracker.update(State::Warning, 60, now + Duration::from_secs(60)),
+            is(equal_to(Some(State::Warning)))
+        );
+        assert_that!(
+            tracker.update(State::Warning, 60, now + Duration::from_secs(90)),
+            is(none())
+        );
+        assert_that!(tracker.state(), is(equal_to(State::Warning)));
+    }
+
+    #[test]
+    fn tracker_update_with_recovery() {
+        let now = Instant::now();
+        let mut tracker = Tracker::new(now);
+
+        assert_that!(
+            tracker.update(State::Critical, 0, now),
+            is(equal_to(Some(State::Critical)))
+        );
+        assert_that!(
+            tracker.update(State::Ok, 0, now),
+            is(equal_to(Some(State::Ok)))
+        );
+    }
+
+    #[test]
+    fn notification_with_recovery() {
+        let warning = notification_config("warning");
+        let critical = notification_config("critical");
+        let recovered = notification_config("recovered");
+        let selected = |state, config| {
+            notification(state, &warning, &critical, Some(&recovered))
+                .map_or(false, |notification| ptr::eq(notification, config))
+        };
+
+        assert_that!(selected(State::Ok, &recovered), is(true));
+        assert_that!(selected(State::Warning, &warning), is(true));
+        assert_that!(selected(State::Critical, &critical), is(true));
+    }
+
+    #[test]
+    fn notification_without_recovery() {
+        let config = notification_config("warning");
+
+        assert_that!(
+            notification(State::Ok, &config, &config, None).is_none(),
+            is(true)
+        );
+    }
+
+    #[test]
+    fn thresholds_apply_template() {
+        let notification = json!({
+            "body": "{VALUE}",
+            "icon": "",
+            "summary": "{FEATURE} is {STATE}",
+            "timeout": -1,
+            "urgency": "normal",
+        });
+        let settings = serde_json::from_value(json!({
+            "critical_template": "!{VALUE}!",
+            "features": { "CPU_LOAD": { "critical": 2, "key": "VALUE", "warning": 1 } },
+            "notification_critical": notification,
+            "notification_recovered": notification,
+            "notification_warning": notification,
+            "warning_template": "{VALUE}?",
+        }))
+        .unwrap();
+
+        let mut thresholds = Thresholds::init(&["time", "cpu_load"], &settings);
+        let now = Instant::now();
+
+        thresholds.update(0, "time", &values("5"), "12:00", now);
+        thresholds.update(1, "cpu_load", &values("1.5"), "1.50", now);
+
+        assert_that!(thresholds.state(0), is(equal_to(State::Ok)));
+        assert_that!(thresholds.state(1), is(equal_to(State::Warning)));
+        assert_that!(
+            thresholds.apply_template(0, "12:00".to_owned()),
+            is(equal_to("12:00".to_owned()))
+        );
+        assert_that!(
+            thresholds.apply_template(1, "1.50".to_owned()),
+            is(equal_to("1.50?".to_owned()))
+        );
+    }
+
+    #[test]
+    fn thresholds_apply_template_of_feature() {
+        let notification = json!({
+            "body": "{VALUE}",
+            "icon": "",
+            "summary": "{FEATURE} is {STATE}",
+            "timeout": -1,
+            "urgency": "normal",
+        });
+        let settings = serde_json::from_value(json!({
+            "critical_template": "!{VALUE}!",
+            "features": {
+                "cpu_load": {
+                    "critical": 4,
+                    "critical_template": "HOT {VALUE}",
+                    "key": "VALUE",
+                    "warning": 2,
+                },
+            },
+            "notification_critical": notification,
+            "notification_recovered": notification,
+            "notification_warning": notification,
+            "warning_template": "{VALUE}?",
+        }))
+        .unwrap();
+
+        let mut thresholds = Thresholds::init(&["cpu_load"], &settings);
+
+        thresholds.update(0, "cpu_load", &values("4.5"), "4.50", Instant::now());
+
+        assert_that!(
+            thresholds.apply_template(0, "4.50".to_owned()),
+            is(equal_to("HOT 4.50".to_owned()))
+        );
+
+        thresholds.update(0, "cpu_load", &values("2.5"), "2.50", Instant::now());
+
+        assert_that!(
+            thresholds.apply_template(0, "2.50".to_owned()),
+            is(equal_to("2.50?".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::error::*;
+use crate::notification::NotificationConfig;
+use crate::settings::ConfigType;
+use crate::wrapper::config;
+use crate::wrapper::config::Value;
+use serde_derive::*;
+use std::collections::HashMap;
+
+const NOTIFICATION_BODY: &str = "{VALUE}";
+const NOTIFICATION_RECOVERED_SUMMARY: &str = "{FEATURE} recovered";
+const NOTIFICATION_SUMMARY: &str = "{FEATURE} is {STATE}";
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ThresholdConfig {
+    pub(super) critical: f64,
+    #[serde(default)]
+    pub(super) critical_template: Option<String>,
+    pub(super) key: String,
+    #[serde(default)]
+    pub(super) notify: bool,
+    #[serde(default)]
+    pub(super) notify_recovery: bool,
+    #[serde(default)]
+    pub(super) sustain: u64,
+    pub(super) warning: f64,
+    #[serde(default)]
+    pub(super) warning_template: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ConfigEntry {
+    pub(super) critical_template: String,
+    pub(super) features: HashMap<String, ThresholdConfig>,
+    pub(super) notification_critical: NotificationConfig,
+    pub(super) notification_recovered: NotificationConfig,
+    pub(super) notification_warning: NotificationConfig,
+    pub(super) warning_template: String,
+}
+
+impl ConfigType for ConfigEntry {
+    fn set_default(config: &mut config::Config) -> Result<()> {
+        config.set_default(
+            "threshold",
+            map!(
+                "critical_template"      => "{VALUE}",
+                "features"               => HashMap::<String, Value>::new(),
+                "notification_critical"  => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "critical",
+                ),
+                "notification_recovered" => NotificationConfig::default_map(
+                    NOTIFICATION_RECOVERED_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "low",
+                ),
+                "notification_warning"   => NotificationConfig::default_map(
+                    NOTIFICATION_SUMMARY,
+                    NOTIFICATION_BODY,
+                    "normal",
+                ),
+                "warning_template"       => "{VALUE}",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocking")]
+mod tests {
+    use super::*;
+    use crate::test_utils::config::notification_map;
+    use crate::test_utils::config::test_set_default_err;
+    use crate::test_utils::config::test_set_default_ok;
+
+    #[test]
+    fn config_type_set_default_when_ok() {
+        test_set_default_ok::<ConfigEntry>("threshold", default_map);
+    }
+
+    #[test]
+    fn config_type_set_default_when_err() {
+        test_set_default_err::<ConfigEntry>("threshold", default_map);
+    }
+
+    fn default_map() -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+        map.insert("critical_template".to_owned(), "{VALUE}".into());
+        map.insert(
+            "features".to_owned(),
+            HashMap::<String, Value>::new().into(),
+        );
+        map.insert(
+            "notification_critical".to_owned(),
+            notification_map("{FEATURE} is {STATE}", "{VALUE}", "critical").into(),
+        );
+        map.insert(
+            "notification_recovered".to_owned(),
+            notification_map("{FEATURE} recovered", "{VALUE}", "low").into(),
+        );
+        map.insert(
+            "notification_warning".to_owned(),
+            notification_map("{FEATURE} is {STATE}", "{VALUE}", "normal").into(),
+        );
+        map.insert("warning_template".to_owned(), "{VALUE}".into());
+
+        map
+    }
+}
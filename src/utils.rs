@@ -14,6 +14,25 @@ pub(crate) fn icon_by_percentage<I: Into<f64>>(icons: &[String], percentage: I)
     Some(&icons[cmp::min(index, length - 1)])
 }
 
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.;
+    let mut unit = 0;
+
+    while value >= 1024. && unit < UNITS.len() - 1 {
+        value /= 1024.;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +74,17 @@ mod tests {
         assert_eq!(icon_by_percentage(&icons, 100), Some("HIGH"));
         assert_eq!(icon_by_percentage(&icons, 120), Some("HIGH"));
     }
+
+    #[test]
+    fn format_bytes_with_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+    }
+
+    #[test]
+    fn format_bytes_with_prefixes() {
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1_572_864), "1.5 MiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
 }
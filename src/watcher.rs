@@ -0,0 +1,76 @@
+use crate::communication;
+use crate::error::*;
+use crate::feature;
+use crate::wrapper::channel;
+use crate::wrapper::inotify;
+use crate::wrapper::thread;
+use std::collections::HashMap;
+
+const ERROR_NAME: &str = "file watcher";
+
+pub(super) fn init_file_watcher(
+    features: &[Box<dyn feature::Feature>],
+    sender: &channel::Sender<communication::Message>,
+) -> Result<()> {
+    let files = features
+        .iter()
+        .enumerate()
+        .flat_map(|(id, feature)| {
+            feature
+                .watched_files()
+                .into_iter()
+                .map(move |file| (file, id))
+        })
+        .collect::<Vec<_>>();
+
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let watcher = Watcher {
+        files,
+        sender: sender.clone(),
+    };
+
+    thread::Thread::new(ERROR_NAME, watcher).run()
+}
+
+struct Watcher {
+    files: Vec<(String, usize)>,
+    sender: channel::Sender<communication::Message>,
+}
+
+impl thread::Runnable for Watcher {
+    fn run(&self) -> Result<()> {
+        let mut inotify = inotify::Inotify::init()?;
+        let mut features = HashMap::new();
+
+        for (file, id) in &self.files {
+            let descriptor = inotify.add_watch(file, inotify::WatchMask::MODIFY)?;
+
+            features
+                .entry(descriptor)
+                .or_insert_with(Vec::new)
+                .push(*id);
+        }
+
+        inotify.listen_for_events(|descriptors| {
+            let mut ids = descriptors
+                .iter()
+                .filter_map(|descriptor| features.get(descriptor))
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids.dedup();
+
+            for id in ids {
+                communication::send_message(id, &self.sender)?;
+            }
+
+            thread::sleep_prevent_spam();
+
+            Ok(())
+        })
+    }
+}
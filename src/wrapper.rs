@@ -5,9 +5,20 @@ pub(crate) mod date_time;
 pub(crate) mod dbus;
 pub(crate) mod file;
 pub(crate) mod inotify;
+#[cfg(feature = "libnotify")]
 pub(crate) mod libnotify;
+pub(crate) mod library;
+pub(crate) mod locale;
+pub(crate) mod nl80211;
 pub(crate) mod process;
+pub(crate) mod pulse;
+pub(crate) mod statvfs;
 pub(crate) mod termination;
 pub(crate) mod thread;
 pub(crate) mod uom;
+pub(crate) mod xclients;
+pub(crate) mod xdg;
+pub(crate) mod xft;
+pub(crate) mod xgamma;
+pub(crate) mod xkb;
 pub(crate) mod xsetroot;
@@ -1,7 +1,9 @@
 use crate::error::*;
 use log::warn;
+use uom::si::energy::watt_hour;
 use uom::si::f32::Ratio;
 use uom::si::f32::Time;
+use uom::si::power::watt;
 use uom::si::time::second;
 
 #[derive(Debug)]
@@ -16,16 +18,45 @@ pub(crate) enum Battery {
     },
     Empty,
     Full,
+    Held {
+        percentage: Ratio,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Energy {
+    pub(crate) current: f32,
+    pub(crate) full: f32,
+    pub(crate) full_design: f32,
+    pub(crate) rate: f32,
+}
+
+impl Energy {
+    fn of(battery: &battery::Battery) -> Self {
+        Self {
+            current: battery.energy().get::<watt_hour>(),
+            full: battery.energy_full().get::<watt_hour>(),
+            full_design: battery.energy_full_design().get::<watt_hour>(),
+            rate: battery.energy_rate().get::<watt>().abs(),
+        }
+    }
 }
 
-pub(crate) fn all_batteries() -> Result<Vec<Battery>> {
+pub(crate) fn all_batteries() -> Result<Vec<(Battery, Energy)>> {
     let manager = battery::Manager::new().wrap_error("battery", "error in loading battery info")?;
 
     Ok(manager
         .batteries()
         .wrap_error("battery", "error in loading battery info")?
         .flat_map(|maybe_battery| match maybe_battery {
-            Ok(battery) => match battery.state() {
+            Ok(battery) => Some(battery),
+            Err(err) => {
+                warn!("An error occurred reading battery data: {}", err);
+                None
+            },
+        })
+        .flat_map(|battery| {
+            let state = match battery.state() {
                 battery::State::Charging => Some(Battery::Charging {
                     percentage: battery.state_of_charge(),
                     time_to_full: battery
@@ -40,12 +71,13 @@ pub(crate) fn all_batteries() -> Result<Vec<Battery>> {
                 }),
                 battery::State::Empty => Some(Battery::Empty),
                 battery::State::Full => Some(Battery::Full),
+                battery::State::Unknown => Some(Battery::Held {
+                    percentage: battery.state_of_charge(),
+                }),
                 _ => None,
-            },
-            Err(err) => {
-                warn!("An error occurred reading battery data: {}", err);
-                None
-            },
+            };
+
+            state.map(|state| (state, Energy::of(&battery)))
         })
         .collect::<Vec<_>>())
 }
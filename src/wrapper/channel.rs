@@ -1,5 +1,8 @@
+#![cfg_attr(not(feature = "plugin-api"), allow(unreachable_pub))]
+
 use crate::error::*;
 use std::sync::mpsc;
+use std::time::Duration;
 
 pub(crate) fn create<M: Clone>() -> (Sender<M>, Receiver<M>) {
     let (tx, rx) = mpsc::channel();
@@ -17,15 +20,26 @@ impl<M: Clone> Receiver<M> {
             .recv()
             .wrap_error("channel receiver", "read blocking failed")
     }
+
+    pub(crate) fn read_timeout(&self, timeout: Duration) -> Result<Option<M>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(message) => Ok(Some(message)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(Error::new_custom(
+                "channel receiver",
+                "read with timeout failed",
+            )),
+        }
+    }
 }
 
-#[derive(Clone)]
-pub(crate) struct Sender<M> {
+#[derive(Clone, Debug)]
+pub struct Sender<M> {
     sender: mpsc::Sender<M>,
 }
 
 impl<M> Sender<M> {
-    pub(crate) fn send(&self, message: M) -> Result<()> {
+    pub fn send(&self, message: M) -> Result<()> {
         self.sender
             .send(message)
             .wrap_error("channel sender", "notify thread killed")
@@ -1,14 +1,30 @@
 use crate::error::*;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
 
 pub(crate) use config::Value;
 
+const ENV_SEPARATOR: &str = "__";
 const ERROR_NAME: &str = "config";
 
 pub(crate) struct Config {
     config: config::Config,
 }
 
+#[derive(Clone, Debug)]
+struct Table(HashMap<String, Value>);
+
+impl config::Source for Table {
+    fn clone_into_box(&self) -> Box<dyn config::Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> std::result::Result<HashMap<String, Value>, config::ConfigError> {
+        Ok(self.0.clone())
+    }
+}
+
 #[cfg_attr(all(test, feature = "mocking"), mocktopus::macros::mockable)]
 impl Config {
     pub(crate) fn new() -> Self {
@@ -47,12 +63,61 @@ impl Config {
         Ok(())
     }
 
+    pub(crate) fn set_table(&mut self, table: HashMap<String, Value>) -> Result<()> {
+        self.config
+            .merge(Table(table))
+            .wrap_error(ERROR_NAME, "merge table failed")?;
+
+        Ok(())
+    }
+
+    pub(crate) fn set_environment(&mut self, prefix: &str) -> Result<()> {
+        self.set_variables(prefix, env::vars())
+    }
+
+    pub(crate) fn set_variables<I>(&mut self, prefix: &str, variables: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let prefix = format!("{}{}", prefix, ENV_SEPARATOR).to_lowercase();
+
+        let table = variables
+            .into_iter()
+            .map(|(key, value)| (key.to_lowercase(), value))
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                (
+                    key[prefix.len()..].replace(ENV_SEPARATOR, "."),
+                    Value::from(value),
+                )
+            })
+            .collect();
+
+        self.config
+            .merge(Table(table))
+            .wrap_error(ERROR_NAME, "merge environment variables failed")?;
+
+        Ok(())
+    }
+
     pub(crate) fn get_bool_option(&self, key: &str) -> Result<Option<bool>> {
         self.config
             .get(key)
             .wrap_error(ERROR_NAME, "read optional boolean field failed")
     }
 
+    pub(crate) fn get_str_list_option(&self, key: &str) -> Result<Option<Vec<String>>> {
+        self.config
+            .get(key)
+            .wrap_error(ERROR_NAME, "read optional string list field failed")
+    }
+
+    pub(crate) fn get_table_option(&self, key: &str) -> Result<Option<HashMap<String, Value>>> {
+        self.config
+            .get(key)
+            .wrap_error(ERROR_NAME, "read optional table field failed")
+    }
+
     pub(crate) fn get_str(&self, key: &str) -> Result<String> {
         self.config
             .get_str(key)
@@ -1,3 +1,6 @@
+use crate::error::*;
+use crate::wrapper::locale;
+
 pub(crate) struct DateTime {
     date_time: chrono::DateTime<chrono::Local>,
 }
@@ -13,4 +16,12 @@ impl DateTime {
     pub(crate) fn format(&self, format: &str) -> String {
         self.date_time.format(format).to_string()
     }
+
+    pub(crate) fn timestamp(&self) -> i64 {
+        self.date_time.timestamp()
+    }
+
+    pub(crate) fn format_localized(&self, format: &str) -> Result<String> {
+        locale::strftime(self.date_time.timestamp(), format)
+    }
 }
@@ -9,6 +9,8 @@ use dbus::ffidisp::ConnectionItem;
 pub(crate) use self::data::Match;
 pub(crate) use self::message::Message;
 pub(crate) use dbus::Path;
+pub(crate) use dbus::arg::RefArg;
+pub(crate) use dbus::arg::Variant;
 
 const ERROR_NAME: &str = "dbus";
 
@@ -18,7 +20,15 @@ pub(crate) struct Connection {
 
 impl Connection {
     pub(crate) fn init() -> Result<Self> {
-        let connection = DbusConnection::get_private(BusType::System)
+        Self::init_bus(BusType::System)
+    }
+
+    pub(crate) fn init_session() -> Result<Self> {
+        Self::init_bus(BusType::Session)
+    }
+
+    fn init_bus(bus_type: BusType) -> Result<Self> {
+        let connection = DbusConnection::get_private(bus_type)
             .wrap_error(ERROR_NAME, "failed to connect to dbus")?;
 
         Ok(Self { connection })
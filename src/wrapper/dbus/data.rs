@@ -1,6 +1,7 @@
 pub(crate) struct Match<'a> {
     interface: &'static str,
     member: Option<&'static str>,
+    namespace: bool,
     path: &'a str,
 }
 
@@ -13,10 +14,22 @@ impl<'a> Match<'a> {
         Self {
             interface,
             member: member.into(),
+            namespace: false,
             path,
         }
     }
 
+    pub(crate) fn in_namespace<M: Into<Option<&'static str>>>(
+        interface: &'static str,
+        member: M,
+        path: &'a str,
+    ) -> Self {
+        Self {
+            namespace: true,
+            ..Self::new(interface, member, path)
+        }
+    }
+
     pub(crate) fn build(self) -> String {
         let member = if let Some(member) = self.member {
             format!(",member='{}'", member)
@@ -24,9 +37,15 @@ impl<'a> Match<'a> {
             String::new()
         };
 
+        let path = if self.namespace {
+            "path_namespace"
+        } else {
+            "path"
+        };
+
         format!(
-            "type='signal',path='{}',interface='{}'{}",
-            self.path, self.interface, member
+            "type='signal',{}='{}',interface='{}'{}",
+            path, self.path, self.interface, member
         )
     }
 }
@@ -62,4 +81,18 @@ mod tests {
              freedesktop.UPower'"
         );
     }
+
+    #[test]
+    fn match_build_in_namespace() {
+        assert_eq!(
+            Match::in_namespace(
+                "org.freedesktop.DBus.Properties",
+                "PropertiesChanged",
+                "/org/bluez",
+            )
+            .build(),
+            "type='signal',path_namespace='/org/bluez',interface='org.freedesktop.DBus.Properties'\
+             ,member='PropertiesChanged'"
+        );
+    }
 }
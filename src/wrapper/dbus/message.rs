@@ -41,6 +41,15 @@ impl Message {
         })
     }
 
+    pub(crate) fn append<A>(self, value: A) -> Self
+    where
+        A: dbus::arg::Append,
+    {
+        Self {
+            message: self.message.append1(value),
+        }
+    }
+
     #[allow(clippy::missing_const_for_fn)]
     pub(super) fn raw(self) -> dbus::Message {
         self.message
@@ -54,4 +63,14 @@ impl Message {
             .read1::<T>()
             .wrap_error(ERROR_NAME, "failed to read return value of dbus message")
     }
+
+    pub(crate) fn return_values<'a, T1, T2>(&'a self) -> Result<(T1, T2)>
+    where
+        T1: dbus::arg::Arg + dbus::arg::Get<'a>,
+        T2: dbus::arg::Arg + dbus::arg::Get<'a>,
+    {
+        self.message
+            .read2::<T1, T2>()
+            .wrap_error(ERROR_NAME, "failed to read return values of dbus message")
+    }
 }
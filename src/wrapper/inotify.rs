@@ -1,5 +1,6 @@
 use crate::error::*;
 
+pub(crate) use inotify::WatchDescriptor;
 pub(crate) use inotify::WatchMask;
 
 const ERROR_NAME: &str = "inotify";
@@ -15,12 +16,10 @@ impl Inotify {
         Ok(Self { inotify })
     }
 
-    pub(crate) fn add_watch(&mut self, path: &str, mask: WatchMask) -> Result<()> {
+    pub(crate) fn add_watch(&mut self, path: &str, mask: WatchMask) -> Result<WatchDescriptor> {
         self.inotify
             .add_watch(path, mask)
-            .wrap_error(ERROR_NAME, format!("failed to watch '{}'", path))?;
-
-        Ok(())
+            .wrap_error(ERROR_NAME, format!("failed to watch '{}'", path))
     }
 
     pub(crate) fn listen_for_any_events<F>(&mut self, handler: F) -> Result<()>
@@ -36,4 +35,21 @@ impl Inotify {
             handler()?;
         }
     }
+
+    pub(crate) fn listen_for_events<F>(&mut self, mut handler: F) -> Result<()>
+    where
+        F: FnMut(Vec<WatchDescriptor>) -> Result<()>,
+    {
+        let mut buffer = [0; 1024];
+        loop {
+            let descriptors = self
+                .inotify
+                .read_events_blocking(&mut buffer)
+                .wrap_error(ERROR_NAME, "error while reading inotify events")?
+                .map(|event| event.wd)
+                .collect();
+
+            handler(descriptors)?;
+        }
+    }
 }
@@ -4,6 +4,41 @@ pub(crate) use libnotify::Urgency;
 
 const ERROR_NAME: &str = "libnotify";
 
+pub(crate) struct Notification<'a> {
+    pub(crate) body: &'a str,
+    pub(crate) icon: Option<&'a str>,
+    pub(crate) summary: &'a str,
+    pub(crate) timeout: i32,
+    pub(crate) urgency: Urgency,
+}
+
+pub(crate) struct SentNotification {
+    notification: libnotify::Notification,
+}
+
+impl SentNotification {
+    pub(crate) fn close(&self) -> Result<()> {
+        self.notification
+            .close()
+            .wrap_error(ERROR_NAME, "close notification failed")
+    }
+
+    pub(crate) fn update(&self, notification: &Notification<'_>) -> Result<()> {
+        self.notification
+            .update(
+                notification.summary,
+                Some(notification.body),
+                notification.icon,
+            )
+            .wrap_error(ERROR_NAME, "update notification failed")?;
+        self.notification.set_timeout(notification.timeout);
+        self.notification.set_urgency(notification.urgency);
+        self.notification
+            .show()
+            .wrap_error(ERROR_NAME, "send notification failed")
+    }
+}
+
 pub(crate) struct LibNotify;
 
 impl LibNotify {
@@ -15,15 +50,22 @@ impl LibNotify {
 
     pub(crate) fn send_notification(
         &self,
-        summary: &str,
-        body: &str,
-        urgency: Urgency,
-    ) -> Result<()> {
-        let notification = libnotify::Notification::new(summary, Some(body), None);
-        notification.set_urgency(urgency);
-        notification
+        notification: &Notification<'_>,
+    ) -> Result<SentNotification> {
+        let libnotify_notification = libnotify::Notification::new(
+            notification.summary,
+            Some(notification.body),
+            notification.icon,
+        );
+        libnotify_notification.set_timeout(notification.timeout);
+        libnotify_notification.set_urgency(notification.urgency);
+        libnotify_notification
             .show()
-            .wrap_error(ERROR_NAME, "send notification failed")
+            .wrap_error(ERROR_NAME, "send notification failed")?;
+
+        Ok(SentNotification {
+            notification: libnotify_notification,
+        })
     }
 }
 
@@ -0,0 +1,60 @@
+#![allow(unsafe_code)]
+
+use crate::error::*;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+const ERROR_NAME: &str = "library";
+
+#[derive(Debug)]
+pub(crate) struct Library {
+    handle: *mut c_void,
+}
+
+impl Library {
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let path_c = CString::new(path.as_os_str().as_bytes())
+            .wrap_error(ERROR_NAME, "path contains null byte")?;
+
+        let handle = unsafe { libc::dlopen(path_c.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+
+        if handle.is_null() {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!("failed to load {}: {}", path.display(), last_error()),
+            ));
+        }
+
+        Ok(Self { handle })
+    }
+
+    pub(crate) fn symbol(&self, name: &str) -> Result<*mut c_void> {
+        let name_c = CString::new(name).wrap_error(ERROR_NAME, "name contains null byte")?;
+
+        let symbol = unsafe { libc::dlsym(self.handle, name_c.as_ptr()) };
+
+        if symbol.is_null() {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!("symbol {} not found: {}", name, last_error()),
+            ));
+        }
+
+        Ok(symbol)
+    }
+}
+
+fn last_error() -> String {
+    unsafe {
+        let error = libc::dlerror();
+
+        if error.is_null() {
+            return "unknown error".to_owned();
+        }
+
+        CStr::from_ptr(error).to_string_lossy().into_owned()
+    }
+}
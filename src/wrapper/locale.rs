@@ -0,0 +1,82 @@
+#![allow(unsafe_code)]
+
+use crate::error::*;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::mem;
+use std::os::raw::c_char;
+
+const ERROR_NAME: &str = "locale";
+const MAX_BUFFER_SIZE: usize = 4096;
+
+pub(crate) fn init_from_environment() -> Result<()> {
+    let empty = CString::default();
+
+    let locale = unsafe { libc::setlocale(libc::LC_ALL, empty.as_ptr()) };
+
+    if locale.is_null() {
+        return Err(Error::new_custom(
+            ERROR_NAME,
+            "locale of the environment is not supported",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn decimal_point() -> String {
+    unsafe {
+        let conv = libc::localeconv();
+
+        if conv.is_null() || (*conv).decimal_point.is_null() {
+            return ".".to_owned();
+        }
+
+        CStr::from_ptr((*conv).decimal_point)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+pub(crate) fn strftime(timestamp: i64, format: &str) -> Result<String> {
+    if format.is_empty() {
+        return Ok(String::new());
+    }
+
+    let format_c = CString::new(format).wrap_error(ERROR_NAME, "format contains null byte")?;
+    let time = timestamp as libc::time_t;
+
+    let mut tm: libc::tm = unsafe { mem::zeroed() };
+    if unsafe { libc::localtime_r(&time, &mut tm) }.is_null() {
+        return Err(Error::new_custom(
+            ERROR_NAME,
+            "failed to convert local time",
+        ));
+    }
+
+    let mut buffer: Vec<u8> = vec![0; 128];
+
+    while buffer.len() <= MAX_BUFFER_SIZE {
+        let length = unsafe {
+            libc::strftime(
+                buffer.as_mut_ptr().cast::<c_char>(),
+                buffer.len(),
+                format_c.as_ptr(),
+                &tm,
+            )
+        };
+
+        if length > 0 {
+            buffer.truncate(length);
+
+            return Ok(String::from_utf8_lossy(&buffer).into_owned());
+        }
+
+        buffer = vec![0; buffer.len() * 2];
+    }
+
+    Err(Error::new_custom(
+        ERROR_NAME,
+        "formatted time exceeds maximum length",
+    ))
+}
@@ -0,0 +1,440 @@
+#![allow(unsafe_code)]
+
+use crate::error::*;
+use std::convert::TryFrom;
+use std::io;
+use std::mem;
+use std::os::raw::c_int;
+use std::os::raw::c_void;
+
+const ERROR_NAME: &str = "nl80211";
+const FAMILY_NAME: &[u8] = b"nl80211\0";
+const RECEIVE_BUFFER_SIZE: usize = 32 * 1024;
+const RECEIVE_TIMEOUT_SECONDS: libc::time_t = 1;
+
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const NLMSG_HEADER_LENGTH: usize = 16;
+const NLM_F_REQUEST: u16 = 0x01;
+const NLM_F_ACK: u16 = 0x04;
+const NLM_F_DUMP: u16 = 0x300;
+const NLA_TYPE_MASK: u16 = 0x3fff;
+const GENL_HEADER_LENGTH: usize = 4;
+
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+
+const NL80211_CMD_GET_INTERFACE: u8 = 5;
+const NL80211_CMD_GET_STATION: u8 = 17;
+const NL80211_ATTR_IFINDEX: u16 = 3;
+const NL80211_ATTR_IFNAME: u16 = 4;
+const NL80211_ATTR_MAC: u16 = 6;
+const NL80211_ATTR_STA_INFO: u16 = 21;
+const NL80211_ATTR_SSID: u16 = 52;
+const NL80211_STA_INFO_TX_BITRATE: u16 = 8;
+const NL80211_RATE_INFO_BITRATE: u16 = 1;
+const NL80211_RATE_INFO_BITRATE32: u16 = 5;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Connection {
+    pub(crate) bitrate: Option<u32>,
+    pub(crate) bssid: Option<String>,
+    pub(crate) essid: String,
+    pub(crate) interface: String,
+}
+
+pub(crate) fn connections() -> Result<Vec<Connection>> {
+    let mut socket = Socket::open()?;
+    let family = socket.resolve_family()?;
+
+    let interfaces = socket.request(family, NL80211_CMD_GET_INTERFACE, NLM_F_DUMP, &[])?;
+    let mut connections = Vec::new();
+
+    for message in interfaces {
+        let attributes = parse_attributes(&message);
+
+        let essid = match find_attribute(&attributes, NL80211_ATTR_SSID) {
+            Some(ssid) => String::from_utf8_lossy(ssid).into_owned(),
+            None => continue,
+        };
+        let index = match find_attribute(&attributes, NL80211_ATTR_IFINDEX).and_then(read_u32) {
+            Some(index) => index,
+            None => continue,
+        };
+        let interface = find_attribute(&attributes, NL80211_ATTR_IFNAME)
+            .map(|name| {
+                String::from_utf8_lossy(name)
+                    .trim_end_matches('\0')
+                    .to_owned()
+            })
+            .unwrap_or_default();
+
+        let mut connection = Connection {
+            essid,
+            interface,
+            ..Connection::default()
+        };
+
+        let stations = socket.request(
+            family,
+            NL80211_CMD_GET_STATION,
+            NLM_F_DUMP,
+            &[(NL80211_ATTR_IFINDEX, &index.to_ne_bytes())],
+        )?;
+
+        if let Some(station) = stations.first() {
+            let attributes = parse_attributes(station);
+
+            connection.bssid = find_attribute(&attributes, NL80211_ATTR_MAC).map(format_mac);
+            connection.bitrate = find_attribute(&attributes, NL80211_ATTR_STA_INFO)
+                .and_then(|info| {
+                    find_attribute(&parse_attributes(info), NL80211_STA_INFO_TX_BITRATE)
+                })
+                .and_then(|rate| {
+                    let rate = parse_attributes(rate);
+
+                    find_attribute(&rate, NL80211_RATE_INFO_BITRATE32)
+                        .and_then(read_u32)
+                        .or_else(|| {
+                            find_attribute(&rate, NL80211_RATE_INFO_BITRATE)
+                                .and_then(read_u16)
+                                .map(u32::from)
+                        })
+                });
+        }
+
+        connections.push(connection);
+    }
+
+    Ok(connections)
+}
+
+struct Socket {
+    fd: c_int,
+    sequence: u32,
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+impl Socket {
+    fn open() -> Result<Self> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_GENERIC,
+            )
+        };
+
+        if fd < 0 {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!(
+                    "failed to open netlink socket: {}",
+                    io::Error::last_os_error()
+                ),
+            ));
+        }
+
+        let socket = Self { fd, sequence: 0 };
+
+        let timeout = libc::timeval {
+            tv_sec: RECEIVE_TIMEOUT_SECONDS,
+            tv_usec: 0,
+        };
+        let timeout_pointer: *const libc::timeval = &timeout;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let result = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                libc::SO_RCVTIMEO,
+                timeout_pointer.cast::<c_void>(),
+                mem::size_of::<libc::timeval>() as libc::socklen_t,
+            )
+        };
+
+        if result < 0 {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!(
+                    "failed to set receive timeout: {}",
+                    io::Error::last_os_error()
+                ),
+            ));
+        }
+
+        Ok(socket)
+    }
+
+    fn resolve_family(&mut self) -> Result<u16> {
+        let messages = self.request(
+            GENL_ID_CTRL,
+            CTRL_CMD_GETFAMILY,
+            0,
+            &[(CTRL_ATTR_FAMILY_NAME, FAMILY_NAME)],
+        )?;
+
+        messages
+            .first()
+            .and_then(|message| {
+                find_attribute(&parse_attributes(message), CTRL_ATTR_FAMILY_ID).and_then(read_u16)
+            })
+            .wrap_error(ERROR_NAME, "nl80211 is not supported by the kernel")
+    }
+
+    fn request(
+        &mut self,
+        family: u16,
+        command: u8,
+        flags: u16,
+        attributes: &[(u16, &[u8])],
+    ) -> Result<Vec<Vec<u8>>> {
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let message = build_message(
+            family,
+            NLM_F_REQUEST | NLM_F_ACK | flags,
+            self.sequence,
+            command,
+            attributes,
+        );
+
+        let sent =
+            unsafe { libc::send(self.fd, message.as_ptr().cast::<c_void>(), message.len(), 0) };
+
+        if sent < 0 {
+            return Err(Error::new_custom(
+                ERROR_NAME,
+                format!("failed to send request: {}", io::Error::last_os_error()),
+            ));
+        }
+
+        let mut answers = Vec::new();
+        let mut buffer = vec![0_u8; RECEIVE_BUFFER_SIZE];
+
+        loop {
+            let received = unsafe {
+                libc::recv(
+                    self.fd,
+                    buffer.as_mut_ptr().cast::<c_void>(),
+                    buffer.len(),
+                    0,
+                )
+            };
+
+            let received = usize::try_from(received).map_err(|_| {
+                let error = io::Error::last_os_error();
+
+                if error.kind() == io::ErrorKind::WouldBlock {
+                    Error::new_custom(ERROR_NAME, "no answer received within timeout")
+                } else {
+                    Error::new_custom(ERROR_NAME, format!("failed to receive answer: {}", error))
+                }
+            })?;
+
+            if parse_messages(&buffer[..received], self.sequence, &mut answers)? {
+                return Ok(answers);
+            }
+        }
+    }
+}
+
+fn build_message(
+    family: u16,
+    flags: u16,
+    sequence: u32,
+    command: u8,
+    attributes: &[(u16, &[u8])],
+) -> Vec<u8> {
+    let mut payload = vec![command, 1, 0, 0];
+
+    for (kind, value) in attributes {
+        let length = u16::try_from(4 + value.len()).unwrap_or(u16::max_value());
+
+        payload.extend_from_slice(&length.to_ne_bytes());
+        payload.extend_from_slice(&kind.to_ne_bytes());
+        payload.extend_from_slice(value);
+        payload.resize(align(payload.len()), 0);
+    }
+
+    let length = u32::try_from(NLMSG_HEADER_LENGTH + payload.len()).unwrap_or(u32::max_value());
+
+    let mut message = Vec::with_capacity(NLMSG_HEADER_LENGTH + payload.len());
+    message.extend_from_slice(&length.to_ne_bytes());
+    message.extend_from_slice(&family.to_ne_bytes());
+    message.extend_from_slice(&flags.to_ne_bytes());
+    message.extend_from_slice(&sequence.to_ne_bytes());
+    message.extend_from_slice(&0_u32.to_ne_bytes());
+    message.extend_from_slice(&payload);
+
+    message
+}
+
+fn parse_messages(mut buffer: &[u8], sequence: u32, answers: &mut Vec<Vec<u8>>) -> Result<bool> {
+    while buffer.len() >= NLMSG_HEADER_LENGTH {
+        let length = read_u32(&buffer[0..4]).unwrap_or_default();
+        let length = usize::try_from(length).unwrap_or_default();
+
+        if length < NLMSG_HEADER_LENGTH || length > buffer.len() {
+            return Err(Error::new_custom(ERROR_NAME, "invalid message length"));
+        }
+
+        let kind = read_u16(&buffer[4..6]).unwrap_or_default();
+        let message_sequence = read_u32(&buffer[8..12]).unwrap_or_default();
+        let payload = &buffer[NLMSG_HEADER_LENGTH..length];
+
+        if message_sequence == sequence {
+            match kind {
+                NLMSG_DONE => return Ok(true),
+                NLMSG_ERROR => {
+                    let code = payload
+                        .get(0..4)
+                        .and_then(|code| <[u8; 4]>::try_from(code).ok())
+                        .map_or(0, i32::from_ne_bytes);
+
+                    if code == 0 {
+                        return Ok(true);
+                    }
+
+                    return Err(Error::new_custom(
+                        ERROR_NAME,
+                        format!("request failed: {}", io::Error::from_raw_os_error(-code)),
+                    ));
+                },
+                _ => {
+                    if let Some(attributes) = payload.get(GENL_HEADER_LENGTH..) {
+                        answers.push(attributes.to_vec());
+                    }
+                },
+            }
+        }
+
+        buffer = &buffer[align(length).min(buffer.len())..];
+    }
+
+    Ok(false)
+}
+
+fn parse_attributes(mut buffer: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut attributes = Vec::new();
+
+    while buffer.len() >= 4 {
+        let length = usize::from(read_u16(&buffer[0..2]).unwrap_or_default());
+        let kind = read_u16(&buffer[2..4]).unwrap_or_default() & NLA_TYPE_MASK;
+
+        if length < 4 || length > buffer.len() {
+            break;
+        }
+
+        attributes.push((kind, &buffer[4..length]));
+        buffer = &buffer[align(length).min(buffer.len())..];
+    }
+
+    attributes
+}
+
+fn find_attribute<'a>(attributes: &[(u16, &'a [u8])], kind: u16) -> Option<&'a [u8]> {
+    attributes
+        .iter()
+        .find(|(attribute_kind, _)| *attribute_kind == kind)
+        .map(|(_, value)| *value)
+}
+
+fn read_u16(value: &[u8]) -> Option<u16> {
+    <[u8; 2]>::try_from(value.get(0..2)?)
+        .ok()
+        .map(u16::from_ne_bytes)
+}
+
+fn read_u32(value: &[u8]) -> Option<u32> {
+    <[u8; 4]>::try_from(value.get(0..4)?)
+        .ok()
+        .map(u32::from_ne_bytes)
+}
+
+fn format_mac(value: &[u8]) -> String {
+    value
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+const fn align(length: usize) -> usize {
+    (length + 3) & !3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn build_message_with_attribute() {
+        let message = build_message(0x10, NLM_F_REQUEST, 7, 3, &[(2, b"nl80211\0")]);
+
+        assert_that!(message.len(), is(equal_to(32)));
+        assert_that!(read_u32(&message[0..4]), is(equal_to(Some(32))));
+        assert_that!(read_u16(&message[4..6]), is(equal_to(Some(0x10))));
+        assert_that!(read_u32(&message[8..12]), is(equal_to(Some(7))));
+        assert_that!(
+            parse_attributes(&message[NLMSG_HEADER_LENGTH + GENL_HEADER_LENGTH..]),
+            is(equal_to(vec![(2, &b"nl80211\0"[..])]))
+        );
+    }
+
+    #[test]
+    fn parse_attributes_with_padding() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&7_u16.to_ne_bytes());
+        buffer.extend_from_slice(&52_u16.to_ne_bytes());
+        buffer.extend_from_slice(b"abc\0");
+        buffer.extend_from_slice(&8_u16.to_ne_bytes());
+        buffer.extend_from_slice(&3_u16.to_ne_bytes());
+        buffer.extend_from_slice(&42_u32.to_ne_bytes());
+
+        let attributes = parse_attributes(&buffer);
+
+        assert_that!(
+            find_attribute(&attributes, NL80211_ATTR_SSID),
+            is(equal_to(Some(&b"abc"[..])))
+        );
+        assert_that!(
+            find_attribute(&attributes, NL80211_ATTR_IFINDEX).and_then(read_u32),
+            is(equal_to(Some(42)))
+        );
+    }
+
+    #[test]
+    fn parse_messages_until_done() {
+        let mut buffer = build_message(0x1c, 0, 5, 5, &[(52, b"wifi")]);
+        buffer.extend(build_message(NLMSG_DONE, 0, 5, 0, &[]));
+
+        let mut answers = Vec::new();
+
+        assert_that!(
+            parse_messages(&buffer, 5, &mut answers),
+            is(equal_to(Ok(true)))
+        );
+        assert_that!(answers.len(), is(equal_to(1)));
+    }
+
+    #[test]
+    fn format_mac_of_bytes() {
+        assert_that!(
+            format_mac(&[0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc]),
+            is(equal_to("00:11:22:aa:bb:cc".to_owned()))
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use crate::error::*;
 use crate::wrapper::thread;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::Read;
+use std::io::Write;
 use std::process;
 
 const ERROR_NAME: &str = "process";
 
 pub(crate) struct Command {
     command: process::Command,
+    input: Option<String>,
 }
 
 impl Command {
@@ -16,12 +21,45 @@ impl Command {
             command.arg(arg);
         }
 
-        Self { command }
+        Self {
+            command,
+            input: None,
+        }
+    }
+
+    pub(crate) fn env(mut self, key: &str, value: &str) -> Self {
+        self.command.env(key, value);
+        self
+    }
+
+    pub(crate) fn input<I: Into<String>>(mut self, input: I) -> Self {
+        self.input = Some(input.into());
+        self
+    }
+
+    pub(crate) fn spawn(mut self) -> Result<()> {
+        let mut child = self
+            .command
+            .spawn()
+            .wrap_error(ERROR_NAME, "failed to start process")?;
+
+        std::thread::Builder::new()
+            .name(ERROR_NAME.to_owned())
+            .spawn(move || {
+                let _ = child.wait();
+            })
+            .wrap_error(ERROR_NAME, "failed to wait for process")?;
+
+        Ok(())
     }
 
     pub(crate) fn output(mut self) -> Result<String> {
-        self.command
-            .output()
+        let output = match self.input.take() {
+            Some(input) => output_with_input(&mut self.command, &input),
+            None => self.command.output(),
+        };
+
+        output
             .wrap_error(ERROR_NAME, "executing process failed")
             .and_then(|o| {
                 if o.status.success() {
@@ -63,4 +101,91 @@ impl Command {
             thread::sleep_prevent_spam();
         }
     }
+
+    pub(crate) fn listen_chunks<H>(mut self, size: usize, mut chunk_handler: H) -> Result<()>
+    where
+        H: FnMut(&[u8]) -> Result<()>,
+    {
+        let mut child = self
+            .command
+            .stdout(process::Stdio::piped())
+            .spawn()
+            .wrap_error(ERROR_NAME, "failed to start process")?;
+
+        let mut stdout = child
+            .stdout
+            .take()
+            .wrap_error(ERROR_NAME, "failed to pipe process output")?;
+
+        let mut buffer = vec![0; size];
+        let mut read_chunks = || -> Result<()> {
+            loop {
+                stdout
+                    .read_exact(&mut buffer)
+                    .wrap_error(ERROR_NAME, "failed to read process output")?;
+
+                chunk_handler(&buffer)?;
+            }
+        };
+
+        let result = read_chunks();
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        result
+    }
+
+    pub(crate) fn listen_lines<I, H>(mut self, stdin_handler: I, mut line_handler: H) -> Result<()>
+    where
+        I: FnOnce(process::ChildStdin),
+        H: FnMut(&str) -> Result<()>,
+    {
+        let mut child = self
+            .command
+            .stdin(process::Stdio::piped())
+            .stdout(process::Stdio::piped())
+            .spawn()
+            .wrap_error(ERROR_NAME, "failed to start process")?;
+
+        if let Some(stdin) = child.stdin.take() {
+            stdin_handler(stdin);
+        }
+
+        let stdout = child
+            .stdout
+            .take()
+            .wrap_error(ERROR_NAME, "failed to pipe process output")?;
+
+        let result = BufReader::new(stdout).split(b'\n').try_for_each(|line| {
+            let line = line.wrap_error(ERROR_NAME, "failed to read process output")?;
+            line_handler(&String::from_utf8_lossy(&line))
+        });
+
+        let _ = child.kill();
+        let status = child
+            .wait()
+            .wrap_error(ERROR_NAME, "failed to wait for process")?;
+
+        result?;
+
+        Err(Error::new_custom(
+            ERROR_NAME,
+            format!("process exited with {}", status),
+        ))
+    }
+}
+
+fn output_with_input(command: &mut process::Command, input: &str) -> io::Result<process::Output> {
+    let mut child = command
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+
+    child.wait_with_output()
 }
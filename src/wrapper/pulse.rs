@@ -0,0 +1,306 @@
+use crate::error::*;
+use std::convert::TryFrom;
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+const ERROR_NAME: &str = "pulseaudio";
+const CLIENT_NAME: &[u8] = b"dwm-status\0";
+const COOKIE_LENGTH: usize = 256;
+const PROTOCOL_VERSION: u32 = 13;
+
+const DESCRIPTOR_LENGTH: usize = 20;
+const CHANNEL_CONTROL: u32 = u32::max_value();
+const MAX_PACKET_LENGTH: usize = 16 * 1024 * 1024;
+
+const COMMAND_ERROR: u32 = 0;
+const COMMAND_REPLY: u32 = 2;
+const COMMAND_AUTH: u32 = 8;
+const COMMAND_SET_CLIENT_NAME: u32 = 9;
+const COMMAND_SUBSCRIBE: u32 = 35;
+const COMMAND_SUBSCRIBE_EVENT: u32 = 66;
+
+const TAG_ARBITRARY: u8 = b'x';
+const TAG_PROPLIST: u8 = b'P';
+const TAG_STRING: u8 = b't';
+const TAG_STRING_NULL: u8 = b'N';
+const TAG_U32: u8 = b'L';
+
+const PROPERTY_APPLICATION_NAME: &str = "application.name";
+
+pub(crate) const MASK_SINK: u32 = 0x0001;
+pub(crate) const MASK_SOURCE: u32 = 0x0002;
+pub(crate) const MASK_SERVER: u32 = 0x0080;
+pub(crate) const MASK_CARD: u32 = 0x0200;
+
+pub(crate) fn subscribe<F>(mask: u32, handle_event: F) -> Result<()>
+where
+    F: Fn() -> Result<()>,
+{
+    let path = socket_path().wrap_error(ERROR_NAME, "no PulseAudio server socket found")?;
+
+    let mut connection = Connection {
+        stream: UnixStream::connect(&path).wrap_error(
+            ERROR_NAME,
+            format!("failed to connect to {}", path.display()),
+        )?,
+        tag: 0,
+    };
+
+    let mut cookie = read_cookie();
+    cookie.resize(COOKIE_LENGTH, 0);
+
+    let mut auth = Tagstruct::new();
+    auth.put_u32(PROTOCOL_VERSION);
+    auth.put_arbitrary(&cookie);
+    connection.request(COMMAND_AUTH, auth)?;
+
+    let mut client_name = Tagstruct::new();
+    client_name.put_proplist(&[(PROPERTY_APPLICATION_NAME, CLIENT_NAME)]);
+    connection.request(COMMAND_SET_CLIENT_NAME, client_name)?;
+
+    let mut subscription = Tagstruct::new();
+    subscription.put_u32(mask);
+    connection.request(COMMAND_SUBSCRIBE, subscription)?;
+
+    loop {
+        let packet = connection.receive()?;
+
+        if read_u32s(&packet, 1).first() == Some(&COMMAND_SUBSCRIBE_EVENT) {
+            handle_event()?;
+        }
+    }
+}
+
+struct Connection {
+    stream: UnixStream,
+    tag: u32,
+}
+
+impl Connection {
+    fn request(&mut self, command: u32, arguments: Tagstruct) -> Result<()> {
+        self.tag = self.tag.wrapping_add(1);
+
+        let mut packet = Tagstruct::new();
+        packet.put_u32(command);
+        packet.put_u32(self.tag);
+        packet.0.extend(arguments.0);
+
+        self.stream
+            .write_all(&build_packet(&packet.0))
+            .wrap_error(ERROR_NAME, "failed to send command")?;
+
+        loop {
+            let values = read_u32s(&self.receive()?, 3);
+
+            if values.get(1) != Some(&self.tag) {
+                continue;
+            }
+
+            match values[0] {
+                COMMAND_REPLY => return Ok(()),
+                COMMAND_ERROR => {
+                    return Err(Error::new_custom(
+                        ERROR_NAME,
+                        format!(
+                            "command {} failed with error {}",
+                            command,
+                            values.get(2).copied().unwrap_or_default()
+                        ),
+                    ));
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn receive(&mut self) -> Result<Vec<u8>> {
+        loop {
+            let mut descriptor = [0_u8; DESCRIPTOR_LENGTH];
+            self.stream
+                .read_exact(&mut descriptor)
+                .wrap_error(ERROR_NAME, "connection closed")?;
+
+            let length = read_u32(&descriptor[0..4]).unwrap_or_default();
+            let length = usize::try_from(length).unwrap_or(usize::max_value());
+            let channel = read_u32(&descriptor[4..8]).unwrap_or_default();
+
+            if length > MAX_PACKET_LENGTH {
+                return Err(Error::new_custom(ERROR_NAME, "invalid packet length"));
+            }
+
+            let mut payload = vec![0_u8; length];
+            self.stream
+                .read_exact(&mut payload)
+                .wrap_error(ERROR_NAME, "connection closed")?;
+
+            if channel == CHANNEL_CONTROL {
+                return Ok(payload);
+            }
+        }
+    }
+}
+
+struct Tagstruct(Vec<u8>);
+
+impl Tagstruct {
+    const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.0.push(TAG_U32);
+        self.0.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn put_string(&mut self, value: &str) {
+        self.0.push(TAG_STRING);
+        self.0.extend_from_slice(value.as_bytes());
+        self.0.push(0);
+    }
+
+    fn put_arbitrary(&mut self, value: &[u8]) {
+        self.0.push(TAG_ARBITRARY);
+        self.0.extend_from_slice(&length(value).to_be_bytes());
+        self.0.extend_from_slice(value);
+    }
+
+    fn put_proplist(&mut self, properties: &[(&str, &[u8])]) {
+        self.0.push(TAG_PROPLIST);
+
+        for (key, value) in properties {
+            self.put_string(key);
+            self.put_u32(length(value));
+            self.put_arbitrary(value);
+        }
+
+        self.0.push(TAG_STRING_NULL);
+    }
+}
+
+fn build_packet(payload: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(DESCRIPTOR_LENGTH + payload.len());
+    packet.extend_from_slice(&length(payload).to_be_bytes());
+    packet.extend_from_slice(&CHANNEL_CONTROL.to_be_bytes());
+    packet.extend_from_slice(&[0; 12]);
+    packet.extend_from_slice(payload);
+
+    packet
+}
+
+fn read_u32s(mut payload: &[u8], count: usize) -> Vec<u32> {
+    let mut values = Vec::new();
+
+    while values.len() < count && payload.first() == Some(&TAG_U32) {
+        match read_u32(&payload[1..]) {
+            Some(value) => values.push(value),
+            None => break,
+        }
+
+        payload = &payload[5..];
+    }
+
+    values
+}
+
+fn read_u32(value: &[u8]) -> Option<u32> {
+    <[u8; 4]>::try_from(value.get(0..4)?)
+        .ok()
+        .map(u32::from_be_bytes)
+}
+
+fn length(value: &[u8]) -> u32 {
+    u32::try_from(value.len()).unwrap_or(u32::max_value())
+}
+
+fn socket_path() -> Option<PathBuf> {
+    if let Some(server) = env::var_os("PULSE_SERVER") {
+        let server = server.to_string_lossy();
+        let path = server.trim_start_matches("unix:");
+
+        if path.starts_with('/') {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    env::var_os("PULSE_RUNTIME_PATH")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("XDG_RUNTIME_DIR").map(|dir| PathBuf::from(dir).join("pulse")))
+        .map(|dir| dir.join("native"))
+}
+
+fn read_cookie() -> Vec<u8> {
+    let mut paths = Vec::new();
+
+    if let Some(path) = env::var_os("PULSE_COOKIE") {
+        paths.push(PathBuf::from(path));
+    }
+
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        paths.push(PathBuf::from(dir).join("pulse/cookie"));
+    }
+
+    if let Some(home) = env::var_os("HOME").map(PathBuf::from) {
+        paths.push(home.join(".config/pulse/cookie"));
+        paths.push(home.join(".pulse-cookie"));
+    }
+
+    paths
+        .iter()
+        .find_map(|path| fs::read(path).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn build_packet_of_command() {
+        let mut tagstruct = Tagstruct::new();
+        tagstruct.put_u32(COMMAND_SUBSCRIBE);
+        tagstruct.put_u32(3);
+        tagstruct.put_u32(MASK_SINK | MASK_SERVER);
+
+        let packet = build_packet(&tagstruct.0);
+
+        assert_that!(packet.len(), is(equal_to(DESCRIPTOR_LENGTH + 15)));
+        assert_that!(read_u32(&packet[0..4]), is(equal_to(Some(15))));
+        assert_that!(read_u32(&packet[4..8]), is(equal_to(Some(CHANNEL_CONTROL))));
+        assert_that!(
+            read_u32s(&packet[DESCRIPTOR_LENGTH..], 3),
+            is(equal_to(vec![COMMAND_SUBSCRIBE, 3, 0x81]))
+        );
+    }
+
+    #[test]
+    fn put_proplist_with_terminator() {
+        let mut tagstruct = Tagstruct::new();
+        tagstruct.put_proplist(&[("a", b"b\0")]);
+
+        assert_that!(
+            tagstruct.0,
+            is(equal_to(b"Pta\0L\0\0\0\x02x\0\0\0\x02b\0N".to_vec()))
+        );
+    }
+
+    #[test]
+    fn read_u32s_of_event() {
+        let payload = b"L\0\0\0\x42L\xff\xff\xff\xffL\0\0\0\x10L\0\0\0\x01";
+
+        assert_that!(
+            read_u32s(payload, 3),
+            is(equal_to(vec![
+                COMMAND_SUBSCRIBE_EVENT,
+                u32::max_value(),
+                0x10
+            ]))
+        );
+        assert_that!(read_u32s(b"t\0", 1), is(equal_to(Vec::new())));
+    }
+}
@@ -0,0 +1,127 @@
+#![allow(unsafe_code)]
+
+use crate::error::*;
+use std::ffi::CString;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+const ERROR_NAME: &str = "statvfs";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Usage {
+    pub(crate) available: u64,
+    pub(crate) total: u64,
+    pub(crate) used: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Inodes {
+    pub(crate) free: u64,
+    pub(crate) total: u64,
+    pub(crate) used: u64,
+}
+
+impl Usage {
+    pub(crate) fn percent(&self) -> u64 {
+        percent(self.used, self.used + self.available)
+    }
+}
+
+impl Inodes {
+    pub(crate) fn percent(&self) -> u64 {
+        percent(self.used, self.used + self.free)
+    }
+}
+
+#[allow(clippy::useless_conversion)]
+pub(crate) fn usage(path: &Path) -> Result<Usage> {
+    let stat = query(path)?;
+    let block_size = u64::from(stat.f_frsize);
+
+    Ok(Usage {
+        available: u64::from(stat.f_bavail) * block_size,
+        total: u64::from(stat.f_blocks) * block_size,
+        used: u64::from(stat.f_blocks).saturating_sub(u64::from(stat.f_bfree)) * block_size,
+    })
+}
+
+#[allow(clippy::useless_conversion)]
+pub(crate) fn inodes(path: &Path) -> Result<Inodes> {
+    let stat = query(path)?;
+
+    Ok(Inodes {
+        free: u64::from(stat.f_ffree),
+        total: u64::from(stat.f_files),
+        used: u64::from(stat.f_files).saturating_sub(u64::from(stat.f_ffree)),
+    })
+}
+
+fn query(path: &Path) -> Result<libc::statvfs> {
+    let path_c = CString::new(path.as_os_str().as_bytes())
+        .wrap_error(ERROR_NAME, "path contains null byte")?;
+
+    let mut stat: libc::statvfs = unsafe { mem::zeroed() };
+
+    if unsafe { libc::statvfs(path_c.as_ptr(), &mut stat) } != 0 {
+        return Err(Error::new_custom(
+            ERROR_NAME,
+            format!(
+                "failed to query {}: {}",
+                path.display(),
+                std::io::Error::last_os_error()
+            ),
+        ));
+    }
+
+    Ok(stat)
+}
+
+#[allow(clippy::missing_const_for_fn)]
+fn percent(used: u64, usable: u64) -> u64 {
+    if usable == 0 {
+        return 0;
+    }
+
+    (used * 100 + usable - 1) / usable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    #[test]
+    fn percent_rounds_up_like_df() {
+        let usage = Usage {
+            available: 700,
+            total: 1000,
+            used: 201,
+        };
+
+        assert_that!(usage.percent(), is(equal_to(23)));
+    }
+
+    #[test]
+    fn percent_without_space() {
+        let usage = Usage {
+            available: 0,
+            total: 0,
+            used: 0,
+        };
+
+        assert_that!(usage.percent(), is(equal_to(0)));
+    }
+
+    #[test]
+    fn inodes_percent_rounds_up_like_df() {
+        let inodes = Inodes {
+            free: 2_999,
+            total: 4_000,
+            used: 1_001,
+        };
+
+        assert_that!(inodes.percent(), is(equal_to(26)));
+    }
+}
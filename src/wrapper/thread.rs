@@ -4,6 +4,22 @@ use std::time;
 
 pub(crate) trait Runnable: Send + 'static {
     fn run(&self) -> Result<()>;
+
+    fn update_interval(&self) -> Option<u64> {
+        None
+    }
+
+    fn next_update(&self) -> Option<time::Duration> {
+        None
+    }
+
+    fn watched_files(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 pub(crate) struct Thread<R> {
@@ -34,6 +50,10 @@ where
     }
 }
 
+pub(crate) fn sleep(duration: time::Duration) {
+    thread::sleep(duration);
+}
+
 pub(crate) fn sleep_secs(seconds: u64) {
     thread::sleep(time::Duration::from_secs(seconds));
 }
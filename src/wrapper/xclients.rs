@@ -0,0 +1,200 @@
+#![allow(unsafe_code)]
+
+use super::xsetroot;
+use crate::error::*;
+use std::convert::TryFrom;
+use std::mem;
+use std::os::raw::c_int;
+use std::os::raw::c_long;
+use std::os::raw::c_uint;
+use std::os::raw::c_ulong;
+use std::ptr;
+use std::slice;
+use x11::xlib;
+
+const ATOM_CLIENT_LIST: &[u8] = b"_NET_CLIENT_LIST\0";
+const ATOM_DEMANDS_ATTENTION: &[u8] = b"_NET_WM_STATE_DEMANDS_ATTENTION\0";
+const ATOM_WM_STATE: &[u8] = b"_NET_WM_STATE\0";
+const ERROR_NAME: &str = "clients";
+const PROPERTY_LENGTH: c_long = 4096;
+
+#[derive(Debug)]
+pub(crate) struct Clients {
+    client_list: xlib::Atom,
+    demands_attention: xlib::Atom,
+    display: *mut xlib::Display,
+    root: xlib::Window,
+    wm_state: xlib::Atom,
+}
+
+impl Clients {
+    pub(crate) fn open() -> Result<Self> {
+        xsetroot::install_error_handlers();
+
+        unsafe {
+            let display = xlib::XOpenDisplay(ptr::null());
+
+            if display.is_null() {
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    "cannot open display $DISPLAY",
+                ));
+            }
+
+            let root = xlib::XDefaultRootWindow(display);
+
+            xlib::XSelectInput(display, root, xlib::PropertyChangeMask);
+
+            Ok(Self {
+                client_list: intern_atom(display, ATOM_CLIENT_LIST),
+                demands_attention: intern_atom(display, ATOM_DEMANDS_ATTENTION),
+                display,
+                root,
+                wm_state: intern_atom(display, ATOM_WM_STATE),
+            })
+        }
+    }
+
+    pub(crate) fn urgent_count(&self) -> usize {
+        let clients = self.clients();
+
+        for window in &clients {
+            unsafe {
+                xlib::XSelectInput(self.display, *window, xlib::PropertyChangeMask);
+            }
+        }
+
+        clients
+            .into_iter()
+            .filter(|window| self.is_urgent(*window))
+            .count()
+    }
+
+    pub(crate) fn wait_for_change(&self) {
+        let mut event = unsafe { mem::zeroed::<xlib::XEvent>() };
+
+        loop {
+            unsafe {
+                xlib::XNextEvent(self.display, &mut event);
+            }
+
+            if event.get_type() != xlib::PropertyNotify {
+                continue;
+            }
+
+            let atom = unsafe { event.property.atom };
+
+            if atom == xlib::XA_WM_HINTS || atom == self.wm_state || atom == self.client_list {
+                return;
+            }
+        }
+    }
+
+    fn clients(&self) -> Vec<xlib::Window> {
+        if let Some(clients) = self.property(self.root, self.client_list, xlib::XA_WINDOW) {
+            return clients;
+        }
+
+        unsafe {
+            let mut root = 0;
+            let mut parent = 0;
+            let mut children = ptr::null_mut();
+            let mut count: c_uint = 0;
+
+            let status = xlib::XQueryTree(
+                self.display,
+                self.root,
+                &mut root,
+                &mut parent,
+                &mut children,
+                &mut count,
+            );
+
+            if status == 0 || children.is_null() {
+                return Vec::new();
+            }
+
+            let clients = slice::from_raw_parts(children, count as usize).to_vec();
+            xlib::XFree(children.cast());
+
+            clients
+        }
+    }
+
+    fn is_urgent(&self, window: xlib::Window) -> bool {
+        let urgency_hint = unsafe {
+            let hints = xlib::XGetWMHints(self.display, window);
+
+            if hints.is_null() {
+                false
+            } else {
+                let urgent = (*hints).flags & xlib::XUrgencyHint != 0;
+                xlib::XFree(hints.cast());
+
+                urgent
+            }
+        };
+
+        urgency_hint
+            || self
+                .property(window, self.wm_state, xlib::XA_ATOM)
+                .map_or(false, |states| states.contains(&self.demands_attention))
+    }
+
+    fn property(
+        &self,
+        window: xlib::Window,
+        property: xlib::Atom,
+        kind: xlib::Atom,
+    ) -> Option<Vec<c_ulong>> {
+        unsafe {
+            let mut actual_kind = 0;
+            let mut format = 0;
+            let mut count = 0;
+            let mut remaining = 0;
+            let mut data = ptr::null_mut();
+
+            let status = xlib::XGetWindowProperty(
+                self.display,
+                window,
+                property,
+                0,
+                PROPERTY_LENGTH,
+                xlib::False,
+                kind,
+                &mut actual_kind,
+                &mut format,
+                &mut count,
+                &mut remaining,
+                &mut data,
+            );
+
+            if status != c_int::from(xlib::Success) || data.is_null() {
+                return None;
+            }
+
+            let values = if format == 32 {
+                let count = usize::try_from(count).unwrap_or(0);
+                Some(slice::from_raw_parts(data.cast::<c_ulong>(), count).to_vec())
+            } else {
+                None
+            };
+
+            xlib::XFree(data.cast());
+
+            values
+        }
+    }
+}
+
+impl Drop for Clients {
+    fn drop(&mut self) {
+        unsafe {
+            xsetroot::close_display(self.display);
+        }
+    }
+}
+
+unsafe fn intern_atom(display: *mut xlib::Display, name: &[u8]) -> xlib::Atom {
+    xlib::XInternAtom(display, name.as_ptr().cast(), xlib::False)
+}
@@ -0,0 +1,30 @@
+use std::env;
+use std::path::PathBuf;
+
+const APPLICATION_NAME: &str = "dwm-status";
+
+pub(crate) fn cache_dir() -> Option<PathBuf> {
+    base_dir("XDG_CACHE_HOME", ".cache")
+}
+
+pub(crate) fn data_dir() -> Option<PathBuf> {
+    base_dir("XDG_DATA_HOME", ".local/share")
+}
+
+pub(crate) fn runtime_dir() -> Option<PathBuf> {
+    env::var_os("XDG_RUNTIME_DIR")
+        .filter(|value| !value.is_empty())
+        .map(|dir| PathBuf::from(dir).join(APPLICATION_NAME))
+}
+
+pub(crate) fn state_dir() -> Option<PathBuf> {
+    base_dir("XDG_STATE_HOME", ".local/state")
+}
+
+fn base_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(variable)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+        .map(|dir| dir.join(APPLICATION_NAME))
+}
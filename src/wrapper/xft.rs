@@ -0,0 +1,86 @@
+#![allow(unsafe_code)]
+
+use super::xsetroot;
+use crate::error::*;
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::ptr;
+use x11::xft;
+use x11::xlib;
+use x11::xrender;
+
+#[derive(Debug)]
+pub(crate) struct Font {
+    display: *mut xlib::Display,
+    font: *mut xft::XftFont,
+}
+
+impl Font {
+    pub(crate) fn open(name: &str) -> Result<Self> {
+        let name_c =
+            CString::new(name).wrap_error("font", "font name could not be converted to CString")?;
+
+        xsetroot::install_error_handlers();
+
+        unsafe {
+            let display = xlib::XOpenDisplay(ptr::null());
+
+            if display.is_null() {
+                return Err(Error::new_custom("font", "cannot open display $DISPLAY"));
+            }
+
+            let font =
+                xft::XftFontOpenName(display, xlib::XDefaultScreen(display), name_c.as_ptr());
+
+            if font.is_null() {
+                xlib::XCloseDisplay(display);
+
+                return Err(Error::new_custom(
+                    "font",
+                    format!("cannot load font {}", name),
+                ));
+            }
+
+            Ok(Self { display, font })
+        }
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        unsafe { xsetroot::is_connected(self.display) }
+    }
+
+    pub(crate) fn width(&self, text: &str) -> usize {
+        let length = match c_int::try_from(text.len()) {
+            Ok(length) => length,
+            Err(_) => return usize::max_value(),
+        };
+
+        let mut extents = xrender::XGlyphInfo {
+            height: 0,
+            width: 0,
+            x: 0,
+            xOff: 0,
+            y: 0,
+            yOff: 0,
+        };
+
+        unsafe {
+            xft::XftTextExtentsUtf8(self.display, self.font, text.as_ptr(), length, &mut extents);
+        }
+
+        usize::try_from(extents.xOff).unwrap_or_else(|_| usize::max_value())
+    }
+}
+
+impl Drop for Font {
+    fn drop(&mut self) {
+        unsafe {
+            if self.is_connected() {
+                xft::XftFontClose(self.display, self.font);
+            }
+
+            xsetroot::close_display(self.display);
+        }
+    }
+}
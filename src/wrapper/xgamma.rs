@@ -0,0 +1,105 @@
+#![allow(unsafe_code)]
+
+use super::xsetroot;
+use crate::error::*;
+use std::convert::TryFrom;
+use std::ptr;
+use std::slice;
+use x11::xlib;
+use x11::xrandr;
+
+const ERROR_NAME: &str = "gamma";
+
+#[derive(Debug)]
+pub(crate) struct Gamma {
+    display: *mut xlib::Display,
+    root: xlib::Window,
+}
+
+impl Gamma {
+    pub(crate) fn open() -> Result<Self> {
+        xsetroot::install_error_handlers();
+
+        unsafe {
+            let display = xlib::XOpenDisplay(ptr::null());
+
+            if display.is_null() {
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    "cannot open display $DISPLAY",
+                ));
+            }
+
+            Ok(Self {
+                display,
+                root: xlib::XDefaultRootWindow(display),
+            })
+        }
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub(crate) fn set(&self, factors: [f64; 3]) -> Result<()> {
+        unsafe {
+            let resources = xrandr::XRRGetScreenResourcesCurrent(self.display, self.root);
+
+            if resources.is_null() {
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    "screen resources of RandR are missing",
+                ));
+            }
+
+            let crtcs = slice::from_raw_parts(
+                (*resources).crtcs,
+                usize::try_from((*resources).ncrtc).unwrap_or_default(),
+            );
+
+            for crtc in crtcs {
+                let size = xrandr::XRRGetCrtcGammaSize(self.display, *crtc);
+
+                if size < 2 {
+                    continue;
+                }
+
+                let gamma = xrandr::XRRAllocGamma(size);
+                if gamma.is_null() {
+                    continue;
+                }
+
+                let length = usize::try_from(size).unwrap_or_default();
+                let ramps = [
+                    slice::from_raw_parts_mut((*gamma).red, length),
+                    slice::from_raw_parts_mut((*gamma).green, length),
+                    slice::from_raw_parts_mut((*gamma).blue, length),
+                ];
+
+                for (ramp, factor) in ramps.iter_mut().zip(&factors) {
+                    for (index, value) in ramp.iter_mut().enumerate() {
+                        let linear = index as f64 / (length - 1) as f64;
+                        *value = (linear * factor * f64::from(u16::max_value())) as u16;
+                    }
+                }
+
+                xrandr::XRRSetCrtcGamma(self.display, *crtc, gamma);
+                xrandr::XRRFreeGamma(gamma);
+            }
+
+            xrandr::XRRFreeScreenResources(resources);
+            xlib::XFlush(self.display);
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Gamma {
+    fn drop(&mut self) {
+        unsafe {
+            xsetroot::close_display(self.display);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+#![allow(unsafe_code)]
+
+use super::xsetroot;
+use crate::error::*;
+use std::convert::TryFrom;
+use std::mem;
+use std::os::raw::c_int;
+use std::os::raw::c_long;
+use std::os::raw::c_uint;
+use std::os::raw::c_ulong;
+use std::ptr;
+use std::slice;
+use x11::xlib;
+
+const ATOM_RULES_NAMES: &[u8] = b"_XKB_RULES_NAMES\0";
+const ERROR_NAME: &str = "xkb";
+const PROPERTY_LENGTH: c_long = 1024;
+
+const XKB_USE_CORE_KBD: c_uint = 0x0100;
+const XKB_STATE_NOTIFY: c_uint = 2;
+const XKB_GROUP_STATE_MASK: c_ulong = 1 << 4;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Layout {
+    pub(crate) group: usize,
+    pub(crate) name: String,
+    pub(crate) variant: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct Keyboard {
+    display: *mut xlib::Display,
+    event_base: c_int,
+    root: xlib::Window,
+    rules_names: xlib::Atom,
+}
+
+impl Keyboard {
+    pub(crate) fn open() -> Result<Self> {
+        xsetroot::install_error_handlers();
+
+        unsafe {
+            let display = xlib::XOpenDisplay(ptr::null());
+
+            if display.is_null() {
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    "cannot open display $DISPLAY",
+                ));
+            }
+
+            let mut opcode = 0;
+            let mut event_base = 0;
+            let mut error_base = 0;
+            let mut major = 1;
+            let mut minor = 0;
+
+            if xlib::XkbQueryExtension(
+                display,
+                &mut opcode,
+                &mut event_base,
+                &mut error_base,
+                &mut major,
+                &mut minor,
+            ) == xlib::False
+            {
+                xsetroot::close_display(display);
+
+                return Err(Error::new_custom(
+                    ERROR_NAME,
+                    "X keyboard extension is not supported by the display",
+                ));
+            }
+
+            xlib::XkbSelectEventDetails(
+                display,
+                XKB_USE_CORE_KBD,
+                XKB_STATE_NOTIFY,
+                XKB_GROUP_STATE_MASK,
+                XKB_GROUP_STATE_MASK,
+            );
+
+            let root = xlib::XDefaultRootWindow(display);
+            xlib::XSelectInput(display, root, xlib::PropertyChangeMask);
+
+            Ok(Self {
+                display,
+                event_base,
+                root,
+                rules_names: xlib::XInternAtom(
+                    display,
+                    ATOM_RULES_NAMES.as_ptr().cast(),
+                    xlib::False,
+                ),
+            })
+        }
+    }
+
+    pub(crate) fn layout(&self) -> Option<Layout> {
+        let group = unsafe {
+            let mut state = mem::zeroed::<xlib::XkbStateRec>();
+
+            if xlib::XkbGetState(self.display, XKB_USE_CORE_KBD, &mut state)
+                != c_int::from(xlib::Success)
+            {
+                return None;
+            }
+
+            usize::from(state.group)
+        };
+
+        parse_rules_names(&self.rules_names()?, group)
+    }
+
+    pub(crate) fn wait_for_change(&self) {
+        let mut event = unsafe { mem::zeroed::<xlib::XEvent>() };
+
+        loop {
+            unsafe {
+                xlib::XNextEvent(self.display, &mut event);
+            }
+
+            if event.get_type() == self.event_base {
+                return;
+            }
+
+            if event.get_type() == xlib::PropertyNotify
+                && unsafe { event.property.atom } == self.rules_names
+            {
+                return;
+            }
+        }
+    }
+
+    fn rules_names(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let mut actual_kind = 0;
+            let mut format = 0;
+            let mut count = 0;
+            let mut remaining = 0;
+            let mut data = ptr::null_mut();
+
+            let status = xlib::XGetWindowProperty(
+                self.display,
+                self.root,
+                self.rules_names,
+                0,
+                PROPERTY_LENGTH,
+                xlib::False,
+                xlib::XA_STRING,
+                &mut actual_kind,
+                &mut format,
+                &mut count,
+                &mut remaining,
+                &mut data,
+            );
+
+            if status != c_int::from(xlib::Success) || data.is_null() {
+                return None;
+            }
+
+            let names = if format == 8 {
+                let count = usize::try_from(count).unwrap_or(0);
+                Some(slice::from_raw_parts(data, count).to_vec())
+            } else {
+                None
+            };
+
+            xlib::XFree(data.cast());
+
+            names
+        }
+    }
+}
+
+impl Drop for Keyboard {
+    fn drop(&mut self) {
+        unsafe {
+            xsetroot::close_display(self.display);
+        }
+    }
+}
+
+fn parse_rules_names(names: &[u8], group: usize) -> Option<Layout> {
+    let names = String::from_utf8_lossy(names);
+    let mut fields = names.split('\0').skip(2);
+
+    let name = fields.next()?.split(',').nth(group)?.trim().to_owned();
+    let variant = fields
+        .next()
+        .and_then(|variants| variants.split(',').nth(group))
+        .unwrap_or_default()
+        .trim()
+        .to_owned();
+
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(Layout {
+        group,
+        name,
+        variant,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest2::assert_that;
+    use hamcrest2::prelude::*;
+
+    const RULES_NAMES: &[u8] = b"evdev\0pc105\0us,de\0,nodeadkeys\0grp:alt_shift_toggle\0";
+
+    #[test]
+    fn parse_rules_names_of_groups() {
+        assert_that!(
+            parse_rules_names(RULES_NAMES, 0),
+            is(equal_to(Some(Layout {
+                group: 0,
+                name: "us".to_owned(),
+                variant: "".to_owned(),
+            })))
+        );
+        assert_that!(
+            parse_rules_names(RULES_NAMES, 1),
+            is(equal_to(Some(Layout {
+                group: 1,
+                name: "de".to_owned(),
+                variant: "nodeadkeys".to_owned(),
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_rules_names_without_variants() {
+        assert_that!(
+            parse_rules_names(b"evdev\0pc105\0fr\0", 0),
+            is(equal_to(Some(Layout {
+                group: 0,
+                name: "fr".to_owned(),
+                variant: "".to_owned(),
+            })))
+        );
+    }
+
+    #[test]
+    fn parse_rules_names_with_unknown_group() {
+        assert_that!(parse_rules_names(RULES_NAMES, 2), is(none()));
+        assert_that!(parse_rules_names(b"evdev\0pc105\0", 0), is(none()));
+    }
+}
@@ -1,57 +1,160 @@
 #![allow(unsafe_code)]
 
 use crate::error::*;
+use log::error;
+use std::convert::TryFrom;
 use std::ffi::CString;
+use std::io;
 use std::os::raw::c_char;
+use std::os::raw::c_int;
 use std::ptr;
+use std::sync::Once;
 use x11::xlib;
 
+const PROPERTY_WM_NAME: &str = "WM_NAME";
+const TYPE_UTF8_STRING: &str = "UTF8_STRING";
+
+static ERROR_HANDLERS: Once = Once::new();
+
+unsafe extern "C" fn handle_error(_: *mut xlib::Display, event: *mut xlib::XErrorEvent) -> c_int {
+    if let Some(event) = event.as_ref() {
+        error!("X request failed with error code {}", event.error_code);
+    }
+    0
+}
+
+unsafe extern "C" fn handle_io_error(_: *mut xlib::Display) -> c_int {
+    error!("Lost connection to X server");
+    0
+}
+
+pub(super) fn install_error_handlers() {
+    ERROR_HANDLERS.call_once(|| unsafe {
+        xlib::XSetErrorHandler(Some(handle_error));
+        xlib::XSetIOErrorHandler(Some(handle_io_error));
+    });
+}
+
+pub(super) unsafe fn is_connected(display: *mut xlib::Display) -> bool {
+    let mut buffer = [0_u8; 1];
+
+    let result = libc::recv(
+        xlib::XConnectionNumber(display),
+        buffer.as_mut_ptr().cast(),
+        buffer.len(),
+        libc::MSG_PEEK | libc::MSG_DONTWAIT,
+    );
+
+    result > 0 || (result < 0 && io::Error::last_os_error().kind() == io::ErrorKind::WouldBlock)
+}
+
+pub(super) unsafe fn close_display(display: *mut xlib::Display) {
+    if is_connected(display) {
+        xlib::XCloseDisplay(display);
+    } else {
+        libc::close(xlib::XConnectionNumber(display));
+    }
+}
+
+#[derive(Debug)]
 pub(crate) struct XSetRoot {
     display: *mut xlib::Display,
-    root_window: xlib::Window,
+    property: Option<xlib::Atom>,
+    window: xlib::Window,
 }
 
 impl XSetRoot {
-    pub(crate) fn init() -> Result<Self> {
+    pub(crate) fn init(
+        name: Option<&str>,
+        window: Option<xlib::Window>,
+        property: &str,
+    ) -> Result<Self> {
+        let name_c = name
+            .map(CString::new)
+            .transpose()
+            .wrap_error("render", "display name could not be converted to CString")?;
+
+        let property_c = CString::new(property)
+            .wrap_error("render", "property name could not be converted to CString")?;
+
+        install_error_handlers();
+
         unsafe {
-            let display = xlib::XOpenDisplay(ptr::null());
+            let display =
+                xlib::XOpenDisplay(name_c.as_ref().map_or(ptr::null(), |name| name.as_ptr()));
 
             if display.is_null() {
-                return Err(Error::new_custom("render", "cannot open display"));
+                return Err(Error::new_custom(
+                    "render",
+                    format!("cannot open display {}", name.unwrap_or("$DISPLAY")),
+                ));
             }
 
-            let screen = xlib::XDefaultScreen(display);
-            let root_window = xlib::XRootWindow(display, screen);
+            let window = window.unwrap_or_else(|| {
+                let screen = xlib::XDefaultScreen(display);
+                xlib::XRootWindow(display, screen)
+            });
+            let property = if property == PROPERTY_WM_NAME {
+                None
+            } else {
+                Some(xlib::XInternAtom(display, property_c.as_ptr(), xlib::False))
+            };
 
             Ok(Self {
                 display,
-                root_window,
+                property,
+                window,
             })
         }
     }
 
+    pub(crate) fn is_connected(&self) -> bool {
+        unsafe { is_connected(self.display) }
+    }
+
     pub(crate) fn render(&self, text: String) -> Result<()> {
         let status_c = CString::new(text)
             .wrap_error("render", "status text could not be converted to CString")?;
 
         unsafe {
-            xlib::XStoreName(
-                self.display,
-                self.root_window,
-                status_c.as_ptr() as *mut c_char,
-            );
+            match self.property {
+                Some(property) => self.change_property(property, &status_c)?,
+                None => {
+                    xlib::XStoreName(self.display, self.window, status_c.as_ptr() as *mut c_char);
+                },
+            }
 
             xlib::XFlush(self.display);
         }
 
         Ok(())
     }
+
+    unsafe fn change_property(&self, property: xlib::Atom, text: &CString) -> Result<()> {
+        let type_c = CString::new(TYPE_UTF8_STRING)
+            .wrap_error("render", "type name could not be converted to CString")?;
+        let length = c_int::try_from(text.as_bytes().len())
+            .wrap_error("render", "status text is too long")?;
+
+        xlib::XChangeProperty(
+            self.display,
+            self.window,
+            property,
+            xlib::XInternAtom(self.display, type_c.as_ptr(), xlib::False),
+            8,
+            xlib::PropModeReplace,
+            text.as_ptr().cast(),
+            length,
+        );
+
+        Ok(())
+    }
 }
 
 impl Drop for XSetRoot {
     fn drop(&mut self) {
         unsafe {
-            xlib::XCloseDisplay(self.display);
+            close_display(self.display);
         }
     }
 }